@@ -6,7 +6,7 @@ fn add_benchmark(c: &mut Criterion) {
 
     // Vector + Scalar
     group.bench_function("Vector + Scalar", |b| {
-        b.iter_with_setup(|| Vector::<i32, 100>::random(), |v| v + black_box(1));
+        b.iter_with_setup(Vector::<i32, 100>::random, |v| v + black_box(1));
     });
 
     // Vector + Vector
@@ -19,7 +19,7 @@ fn add_benchmark(c: &mut Criterion) {
 
     // Matrix + Scalar
     group.bench_function("Matrix + Scalar", |b| {
-        b.iter_with_setup(|| Matrix::<i32, 100, 100>::random(), |m| m + black_box(1));
+        b.iter_with_setup(Matrix::<i32, 100, 100>::random, |m| m + black_box(1));
     });
 
     // Matrix + Matrix
@@ -34,6 +34,32 @@ fn add_benchmark(c: &mut Criterion) {
             |(m1, m2)| m1 + m2,
         );
     });
+
+    // Matrix + Matrix (f32)
+    group.bench_function("Matrix + Matrix f32", |b| {
+        b.iter_with_setup(
+            || {
+                (
+                    Matrix::<f32, 100, 100>::random(),
+                    Matrix::<f32, 100, 100>::random(),
+                )
+            },
+            |(m1, m2)| m1 + m2,
+        );
+    });
+
+    // Matrix + Matrix (f64)
+    group.bench_function("Matrix + Matrix f64", |b| {
+        b.iter_with_setup(
+            || {
+                (
+                    Matrix::<f64, 100, 100>::random(),
+                    Matrix::<f64, 100, 100>::random(),
+                )
+            },
+            |(m1, m2)| m1 + m2,
+        );
+    });
 }
 
 fn add_assign_benchmark(c: &mut Criterion) {
@@ -41,7 +67,7 @@ fn add_assign_benchmark(c: &mut Criterion) {
 
     // Vector += Scalar
     group.bench_function("Vector += Scalar", |b| {
-        b.iter_with_setup(|| Vector::<i32, 100>::random(), |mut v| v += black_box(1));
+        b.iter_with_setup(Vector::<i32, 100>::random, |mut v| v += black_box(1));
     });
 
     // Vector += Vector
@@ -55,7 +81,7 @@ fn add_assign_benchmark(c: &mut Criterion) {
     // Matrix += Scalar
     group.bench_function("Matrix += Scalar", |b| {
         b.iter_with_setup(
-            || Matrix::<i32, 100, 100>::random(),
+            Matrix::<i32, 100, 100>::random,
             |mut m| m += black_box(1),
         );
     });
@@ -6,12 +6,12 @@ fn div_benchmark(c: &mut Criterion) {
 
     // Vector / Scalar
     group.bench_function("Vector / Scalar", |b| {
-        b.iter_with_setup(|| Vector::<i32, 100>::random(), |v| v / black_box(2));
+        b.iter_with_setup(Vector::<i32, 100>::random, |v| v / black_box(2));
     });
 
     // Matrix / Scalar
     group.bench_function("Matrix / Scalar", |b| {
-        b.iter_with_setup(|| Matrix::<i32, 100, 100>::random(), |m| m / black_box(2));
+        b.iter_with_setup(Matrix::<i32, 100, 100>::random, |m| m / black_box(2));
     });
 }
 
@@ -20,13 +20,13 @@ fn div_assign_benchmark(c: &mut Criterion) {
 
     // Vector /= Scalar
     group.bench_function("Vector /= Scalar", |b| {
-        b.iter_with_setup(|| Vector::<i32, 100>::random(), |mut v| v /= black_box(2));
+        b.iter_with_setup(Vector::<i32, 100>::random, |mut v| v /= black_box(2));
     });
 
     // Matrix /= Scalar
     group.bench_function("Matrix /= Scalar", |b| {
         b.iter_with_setup(
-            || Matrix::<i32, 100, 100>::random(),
+            Matrix::<i32, 100, 100>::random,
             |mut m| m /= black_box(2),
         );
     });
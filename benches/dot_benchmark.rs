@@ -50,6 +50,22 @@ fn dot_product_benchmark(c: &mut Criterion) {
             |(v1, m1)| v1.dot(m1),
         );
     });
+
+    // Vector dot Vector (f32)
+    group.bench_function("Vector.Vector f32", |b| {
+        b.iter_with_setup(
+            || (Vector::<f32, 100>::random(), Vector::<f32, 100>::random()),
+            |(v1, v2)| v1.dot(v2),
+        );
+    });
+
+    // Vector dot Vector (f64)
+    group.bench_function("Vector.Vector f64", |b| {
+        b.iter_with_setup(
+            || (Vector::<f64, 100>::random(), Vector::<f64, 100>::random()),
+            |(v1, v2)| v1.dot(v2),
+        );
+    });
 }
 
 criterion_group!(benches, dot_product_benchmark);
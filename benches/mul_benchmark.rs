@@ -68,6 +68,95 @@ fn mul_matrix_benchmark(c: &mut Criterion) {
             |(m1, m2)| m1 * m2,
         );
     });
+
+    // Matrix * Vector (f32)
+    group.bench_function("Matrix * Vector f32", |b| {
+        b.iter_with_setup(
+            || {
+                (
+                    Matrix::<f32, 100, 100>::random(),
+                    Vector::<f32, 100>::random(),
+                )
+            },
+            |(m1, v1)| m1 * v1,
+        );
+    });
+
+    // Matrix * Vector (f64)
+    group.bench_function("Matrix * Vector f64", |b| {
+        b.iter_with_setup(
+            || {
+                (
+                    Matrix::<f64, 100, 100>::random(),
+                    Vector::<f64, 100>::random(),
+                )
+            },
+            |(m1, v1)| m1 * v1,
+        );
+    });
+
+    // Matrix * Matrix (f32)
+    group.bench_function("Matrix * Matrix f32", |b| {
+        b.iter_with_setup(
+            || {
+                (
+                    Matrix::<f32, 100, 100>::random(),
+                    Matrix::<f32, 100, 100>::random(),
+                )
+            },
+            |(m1, m2)| m1 * m2,
+        );
+    });
+
+    // Matrix * Matrix (f64)
+    group.bench_function("Matrix * Matrix f64", |b| {
+        b.iter_with_setup(
+            || {
+                (
+                    Matrix::<f64, 100, 100>::random(),
+                    Matrix::<f64, 100, 100>::random(),
+                )
+            },
+            |(m1, m2)| m1 * m2,
+        );
+    });
+}
+
+fn mul_large_matrix_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Mul Large Matrix");
+
+    // Large matrices are set up once and multiplied by reference, since moving
+    // them through `iter_with_setup` and `black_box` by value is prohibitively
+    // expensive (and crashes LLVM when optimizing)
+    let m1 = Matrix::<f64, 256, 256>::random();
+    let m2 = Matrix::<f64, 256, 256>::random();
+
+    // Matrix * Matrix (f64, 256 x 256)
+    group.bench_function("Matrix * Matrix f64 256", |b| {
+        b.iter(|| {
+            let m = &m1 * &m2;
+            black_box(&m);
+        });
+    });
+
+    // Matrix * MatrixTransposeView (f64, 256 x 256)
+    group.bench_function("Matrix * MatrixTransposeView f64 256", |b| {
+        b.iter(|| {
+            let m = &m1 * m2.t();
+            black_box(&m);
+        });
+    });
+
+    let m1 = Matrix::<f32, 256, 256>::random();
+    let m2 = Matrix::<f32, 256, 256>::random();
+
+    // Matrix * Matrix (f32, 256 x 256)
+    group.bench_function("Matrix * Matrix f32 256", |b| {
+        b.iter(|| {
+            let m = &m1 * &m2;
+            black_box(&m);
+        });
+    });
 }
 
 fn mul_scalar_benchmark(c: &mut Criterion) {
@@ -75,12 +164,17 @@ fn mul_scalar_benchmark(c: &mut Criterion) {
 
     // Vector * Scalar
     group.bench_function("Vector * Scalar", |b| {
-        b.iter_with_setup(|| Vector::<i32, 100>::random(), |v1| v1 * black_box(2));
+        b.iter_with_setup(Vector::<i32, 100>::random, |v1| v1 * black_box(2));
     });
 
     // Matrix * Scalar
     group.bench_function("Matrix * Scalar", |b| {
-        b.iter_with_setup(|| Matrix::<i32, 100, 100>::random(), |m1| m1 * black_box(2));
+        b.iter_with_setup(Matrix::<i32, 100, 100>::random, |m1| m1 * black_box(2));
+    });
+
+    // Matrix * Scalar (f64)
+    group.bench_function("Matrix * Scalar f64", |b| {
+        b.iter_with_setup(Matrix::<f64, 100, 100>::random, |m1| m1 * black_box(2.0));
     });
 }
 
@@ -89,13 +183,13 @@ fn mul_scalar_assign_benchmark(c: &mut Criterion) {
 
     // Vector *= Scalar
     group.bench_function("Vector *= Scalar", |b| {
-        b.iter_with_setup(|| Vector::<i32, 100>::random(), |mut v1| v1 *= black_box(2));
+        b.iter_with_setup(Vector::<i32, 100>::random, |mut v1| v1 *= black_box(2));
     });
 
     // Matrix *= Scalar
     group.bench_function("Matrix *= Scalar", |b| {
         b.iter_with_setup(
-            || Matrix::<i32, 100, 100>::random(),
+            Matrix::<i32, 100, 100>::random,
             |mut m1| m1 *= black_box(2),
         );
     });
@@ -104,6 +198,7 @@ fn mul_scalar_assign_benchmark(c: &mut Criterion) {
 criterion_group!(
     benches,
     mul_matrix_benchmark,
+    mul_large_matrix_benchmark,
     mul_scalar_benchmark,
     mul_scalar_assign_benchmark
 );
@@ -6,7 +6,7 @@ fn sub_benchmark(c: &mut Criterion) {
 
     // Vector - Scalar
     group.bench_function("Vector - Scalar", |b| {
-        b.iter_with_setup(|| Vector::<i32, 100>::random(), |v1| v1 - black_box(1));
+        b.iter_with_setup(Vector::<i32, 100>::random, |v1| v1 - black_box(1));
     });
 
     // Vector - Vector
@@ -19,7 +19,7 @@ fn sub_benchmark(c: &mut Criterion) {
 
     // Matrix - Scalar
     group.bench_function("Matrix - Scalar", |b| {
-        b.iter_with_setup(|| Matrix::<i32, 100, 100>::random(), |m1| m1 - black_box(1));
+        b.iter_with_setup(Matrix::<i32, 100, 100>::random, |m1| m1 - black_box(1));
     });
 
     // Matrix - Matrix
@@ -41,7 +41,7 @@ fn sub_assign_benchmark(c: &mut Criterion) {
 
     // Vector -= Scalar
     group.bench_function("Vector -= Scalar", |b| {
-        b.iter_with_setup(|| Vector::<i32, 100>::random(), |mut v1| v1 -= black_box(1));
+        b.iter_with_setup(Vector::<i32, 100>::random, |mut v1| v1 -= black_box(1));
     });
 
     // Vector -= Vector
@@ -55,7 +55,7 @@ fn sub_assign_benchmark(c: &mut Criterion) {
     // Matrix -= Scalar
     group.bench_function("Matrix -= Scalar", |b| {
         b.iter_with_setup(
-            || Matrix::<i32, 100, 100>::random(),
+            Matrix::<i32, 100, 100>::random,
             |mut m1| m1 -= black_box(1),
         );
     });
@@ -50,12 +50,10 @@ fn render_frame(theta_a: f64, theta_b: f64) {
             let luminance: f64 = Vector3::from([0.0, 1.0, -1.0]).dot(&normal);
 
             // Only render points facing the viewer
-            if luminance > 0.0 {
-                if ooz > zbuffer[(yp, xp)] {
-                    zbuffer[(yp, xp)] = ooz;
-                    let l_index = (luminance * 8.0) as usize;
-                    output[(yp, xp)] = ".,-~:;=!*#$@".chars().nth(l_index).unwrap();
-                }
+            if luminance > 0.0 && ooz > zbuffer[(yp, xp)] {
+                zbuffer[(yp, xp)] = ooz;
+                let l_index = (luminance * 8.0) as usize;
+                output[(yp, xp)] = ".,-~:;=!*#$@".chars().nth(l_index).unwrap();
             }
 
             // Prepare for next loop
@@ -72,7 +70,7 @@ fn render_frame(theta_a: f64, theta_b: f64) {
         for col in 0..WIDTH {
             print!("{}", output[(row, col)]);
         }
-        print!("\n");
+        println!();
     }
 }
 
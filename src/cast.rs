@@ -0,0 +1,67 @@
+//! Element type conversions.
+//!
+//! [`MatrixRef`](crate::MatrixRef) and [`VectorRef`](crate::VectorRef) provide `cast`, which fails if
+//! an element cannot be represented in the target type, `cast_saturating`, which clamps it instead,
+//! and `cast_lossy`, which converts like `as`.
+//!
+//! [`MatrixRef`](crate::MatrixRef) also provides `matmul_acc` and `matvec_acc`, which convert each
+//! element to a wider accumulator type as it is read, so that e.g. `f16` weights can be multiplied
+//! in `f32` without rounding every partial sum.
+
+use num_traits::{Bounded, NumCast, ToPrimitive, Zero};
+
+use crate::matrix::Matrix;
+use crate::traits::Contiguous;
+use crate::vector::Vector;
+
+/// Converts `x` to `U`, or returns `None` if it is out of range of `U`.
+///
+/// [`NumCast`] rounds a finite float beyond the range of a narrower float type to infinity, so a
+/// finite `x` that converts to a non-finite value is rejected here. NaN converts to NaN if `U` is
+/// a float type.
+#[inline]
+pub(crate) fn checked<T, U>(x: T) -> Option<U>
+where
+    T: ToPrimitive,
+    U: NumCast,
+{
+    let finite = x.to_f64().is_some_and(f64::is_finite);
+    U::from(x).filter(|y| !finite || y.to_f64().is_some_and(f64::is_finite))
+}
+
+/// Converts `x` to `U`, clamping it to the bounds of `U` if it is out of range. NaN becomes zero
+/// if `U` is an integer type and stays NaN if `U` is a float type.
+#[inline]
+pub(crate) fn saturating<T, U>(x: T) -> U
+where
+    T: ToPrimitive + PartialOrd + Zero,
+    U: NumCast + Bounded + Zero,
+{
+    let nan = x.partial_cmp(&x).is_none();
+    let negative = x < T::zero();
+    checked(x).unwrap_or_else(|| {
+        if nan {
+            U::zero()
+        } else if negative {
+            U::min_value()
+        } else {
+            U::max_value()
+        }
+    })
+}
+
+/// Returns the unwrapped elements of `m`, or `None` if any of them is `None`.
+pub(crate) fn transpose_matrix<U, const R: usize, const C: usize>(mut m: Matrix<Option<U>, R, C>) -> Option<Matrix<U, R, C>> {
+    if !m.as_slice().iter().all(Option::is_some) {
+        return None;
+    }
+    Some(Matrix::from(core::array::from_fn(|i| core::array::from_fn(|j| m[(i, j)].take().unwrap()))))
+}
+
+/// Returns the unwrapped elements of `v`, or `None` if any of them is `None`.
+pub(crate) fn transpose_vector<U, const N: usize>(mut v: Vector<Option<U>, N>) -> Option<Vector<U, N>> {
+    if !v.as_slice().iter().all(Option::is_some) {
+        return None;
+    }
+    Some(Vector::from(core::array::from_fn(|i| v[i].take().unwrap())))
+}
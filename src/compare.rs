@@ -0,0 +1,310 @@
+//! Element-wise approximate comparison.
+//!
+//! [`MatrixRef`] and [`VectorRef`] provide `approx_eq_abs` and `approx_eq_rel` for floating-point
+//! elements, and [`assert_matrix_eq!`](crate::assert_matrix_eq) and
+//! [`assert_vector_eq!`](crate::assert_vector_eq) list the elements that differ when they fail.
+//!
+//! With the `approx` feature, every matrix and vector type also implements
+//! [`AbsDiffEq`](approx::AbsDiffEq), [`RelativeEq`](approx::RelativeEq) and
+//! [`UlpsEq`](approx::UlpsEq) against each type it implements [`PartialEq`] for.
+
+use core::fmt::{self, Debug, Display};
+use num_traits::float::FloatCore;
+
+use crate::traits::{MatrixRef, VectorRef};
+
+/// Maximum number of differing elements listed by a failed assertion.
+const MAX_LISTED: usize = 16;
+
+/// Returns `true` if `a` and `b` are within `epsilon` of each other.
+#[inline]
+pub fn approx_eq_abs<T: FloatCore>(a: T, b: T, epsilon: T) -> bool {
+    a == b || (a - b).abs() <= epsilon
+}
+
+/// Returns `true` if `a` and `b` are within `epsilon` of each other, or if their difference
+/// is at most `max_relative` times the larger of their magnitudes.
+#[inline]
+pub fn approx_eq_rel<T: FloatCore>(a: T, b: T, epsilon: T, max_relative: T) -> bool {
+    if a == b {
+        return true;
+    }
+    if a.is_infinite() || b.is_infinite() {
+        return false;
+    }
+    let diff = (a - b).abs();
+    diff <= epsilon || diff <= a.abs().max(b.abs()) * max_relative
+}
+
+/// Lists the elements for which `eq` fails, one per line.
+struct Mismatches<'a, T, F> {
+    left: &'a dyn Fn(usize) -> T,
+    right: &'a dyn Fn(usize) -> T,
+    index: &'a dyn Fn(usize) -> (usize, usize),
+    len: usize,
+    eq: F,
+    vector: bool,
+}
+
+impl<T: Copy + Debug, F: Fn(T, T) -> bool> Display for Mismatches<'_, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut listed = 0;
+        for k in 0..self.len {
+            let (a, b) = ((self.left)(k), (self.right)(k));
+            if (self.eq)(a, b) {
+                continue;
+            }
+            if listed == MAX_LISTED {
+                return write!(f, "\n  ...");
+            }
+            let (i, j) = (self.index)(k);
+            if self.vector {
+                write!(f, "\n  [{}]: left = {:?}, right = {:?}", i, a, b)?;
+            } else {
+                write!(f, "\n  ({}, {}): left = {:?}, right = {:?}", i, j, a, b)?;
+            }
+            listed += 1;
+        }
+        Ok(())
+    }
+}
+
+#[track_caller]
+fn assert_eq_inner<T: Copy + Debug, F: Fn(T, T) -> bool>(mismatches: Mismatches<'_, T, F>, op: &str, mode: fmt::Arguments<'_>) {
+    let count = (0..mismatches.len)
+        .filter(|&k| !(mismatches.eq)((mismatches.left)(k), (mismatches.right)(k)))
+        .count();
+    if count > 0 {
+        panic!(
+            "assertion `left {} right` failed{}\n{} of {} elements differ:{}",
+            op, mode, count, mismatches.len, mismatches
+        );
+    }
+}
+
+/// Implementation of [`assert_matrix_eq!`](crate::assert_matrix_eq).
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_matrix_eq<T, L, R, F, const M: usize, const N: usize>(left: &L, right: &R, op: &str, mode: fmt::Arguments<'_>, eq: F)
+where
+    T: Copy + Debug,
+    L: MatrixRef<T, M, N> + ?Sized,
+    R: MatrixRef<T, M, N> + ?Sized,
+    F: Fn(T, T) -> bool,
+{
+    let index = |k: usize| (k / N, k % N);
+    let mismatches = Mismatches {
+        left: &|k| left[index(k)],
+        right: &|k| right[index(k)],
+        index: &index,
+        len: M * N,
+        eq,
+        vector: false,
+    };
+    assert_eq_inner(mismatches, op, mode);
+}
+
+/// Implementation of [`assert_vector_eq!`](crate::assert_vector_eq).
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_vector_eq<T, L, R, F, const N: usize>(left: &L, right: &R, op: &str, mode: fmt::Arguments<'_>, eq: F)
+where
+    T: Copy + Debug,
+    L: VectorRef<T, N> + ?Sized,
+    R: VectorRef<T, N> + ?Sized,
+    F: Fn(T, T) -> bool,
+{
+    let mismatches = Mismatches {
+        left: &|k| left[k],
+        right: &|k| right[k],
+        index: &|k| (k, 0),
+        len: N,
+        eq,
+        vector: true,
+    };
+    assert_eq_inner(mismatches, op, mode);
+}
+
+/// Asserts that two matrices are equal, element by element.
+///
+/// Accepts any pair of [`MatrixRef`] types with the same shape. With `epsilon`, elements
+/// are compared with [`MatrixRef::approx_eq_abs`]; with `epsilon` and `max_relative`, with
+/// [`MatrixRef::approx_eq_rel`]. On failure, the message lists the elements that differ.
+///
+/// # Examples
+///
+/// ```
+/// use ferrix::{assert_matrix_eq, Matrix};
+///
+/// let a = Matrix::from([[1.0, 2.0], [3.0, 4.0]]);
+/// let b = Matrix::from([[1.0, 2.0 + 1e-12], [3.0, 4.0]]);
+///
+/// assert_matrix_eq!(a, a.t().t());
+/// assert_matrix_eq!(a, b, epsilon = 1e-9);
+/// assert_matrix_eq!(a * 1e6, b * 1e6, epsilon = 0.0, max_relative = 1e-9);
+/// ```
+#[macro_export]
+macro_rules! assert_matrix_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::__private::assert_matrix_eq(&$left, &$right, "==", format_args!(""), |a, b| a == b)
+    };
+    ($left:expr, $right:expr, epsilon = $epsilon:expr $(,)?) => {
+        match $epsilon {
+            epsilon => $crate::__private::assert_matrix_eq(
+                &$left,
+                &$right,
+                "≈",
+                format_args!(" (epsilon = {:?})", epsilon),
+                |a, b| $crate::__private::approx_eq_abs(a, b, epsilon),
+            ),
+        }
+    };
+    ($left:expr, $right:expr, epsilon = $epsilon:expr, max_relative = $max_relative:expr $(,)?) => {
+        match ($epsilon, $max_relative) {
+            (epsilon, max_relative) => $crate::__private::assert_matrix_eq(
+                &$left,
+                &$right,
+                "≈",
+                format_args!(" (epsilon = {:?}, max_relative = {:?})", epsilon, max_relative),
+                |a, b| $crate::__private::approx_eq_rel(a, b, epsilon, max_relative),
+            ),
+        }
+    };
+}
+
+/// Asserts that two vectors are equal, element by element.
+///
+/// Accepts any pair of [`VectorRef`] types with the same length, and the same `epsilon` and
+/// `max_relative` options as [`assert_matrix_eq!`](crate::assert_matrix_eq).
+///
+/// # Examples
+///
+/// ```
+/// use ferrix::{assert_vector_eq, RowVector, Vector};
+///
+/// let a = Vector::from([1.0, 2.0, 3.0]);
+/// let b = RowVector::from([1.0, 2.0, 3.0 + 1e-12]);
+///
+/// assert_vector_eq!(a, b, epsilon = 1e-9);
+/// assert_vector_eq!(a.view::<2>(1).unwrap(), b.view::<2>(1).unwrap(), epsilon = 0.0, max_relative = 1e-9);
+/// ```
+#[macro_export]
+macro_rules! assert_vector_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::__private::assert_vector_eq(&$left, &$right, "==", format_args!(""), |a, b| a == b)
+    };
+    ($left:expr, $right:expr, epsilon = $epsilon:expr $(,)?) => {
+        match $epsilon {
+            epsilon => $crate::__private::assert_vector_eq(
+                &$left,
+                &$right,
+                "≈",
+                format_args!(" (epsilon = {:?})", epsilon),
+                |a, b| $crate::__private::approx_eq_abs(a, b, epsilon),
+            ),
+        }
+    };
+    ($left:expr, $right:expr, epsilon = $epsilon:expr, max_relative = $max_relative:expr $(,)?) => {
+        match ($epsilon, $max_relative) {
+            (epsilon, max_relative) => $crate::__private::assert_vector_eq(
+                &$left,
+                &$right,
+                "≈",
+                format_args!(" (epsilon = {:?}, max_relative = {:?})", epsilon, max_relative),
+                |a, b| $crate::__private::approx_eq_rel(a, b, epsilon, max_relative),
+            ),
+        }
+    };
+}
+
+#[cfg(feature = "approx")]
+mod approx_impls {
+    use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+    use core::ops::Index;
+
+    use crate::matrix::Matrix;
+    use crate::matrix_cm::MatrixCM;
+    use crate::matrix_strided_view::MatrixStridedView;
+    use crate::matrix_strided_view_mut::MatrixStridedViewMut;
+    use crate::matrix_transpose_view::MatrixTransposeView;
+    use crate::matrix_transpose_view_mut::MatrixTransposeViewMut;
+    use crate::matrix_view::MatrixView;
+    use crate::matrix_view_mut::MatrixViewMut;
+    use crate::row_vector::RowVector;
+    use crate::row_vector_view::RowVectorView;
+    use crate::row_vector_view_mut::RowVectorViewMut;
+    use crate::traits::{MatrixRef, VectorRef};
+    use crate::vector::Vector;
+    use crate::vector_view::VectorView;
+    use crate::vector_view_mut::VectorViewMut;
+
+    macro_rules! impl_approx {
+        ($type:ty, $bound:path, ($($index:tt)*), $($generics:tt)*) => {
+            impl<T: AbsDiffEq, Rhs: $bound + ?Sized, $($generics)*> AbsDiffEq<Rhs> for $type
+            where
+                T::Epsilon: Clone,
+                Self: PartialEq<Rhs>,
+            {
+                type Epsilon = T::Epsilon;
+
+                fn default_epsilon() -> T::Epsilon {
+                    T::default_epsilon()
+                }
+
+                fn abs_diff_eq(&self, other: &Rhs, epsilon: T::Epsilon) -> bool {
+                    impl_approx!(@all $($index)*, |k| T::abs_diff_eq(&self[k], &other[k], epsilon.clone()))
+                }
+            }
+
+            impl<T: RelativeEq, Rhs: $bound + ?Sized, $($generics)*> RelativeEq<Rhs> for $type
+            where
+                T::Epsilon: Clone,
+                Self: PartialEq<Rhs>,
+            {
+                fn default_max_relative() -> T::Epsilon {
+                    T::default_max_relative()
+                }
+
+                fn relative_eq(&self, other: &Rhs, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+                    impl_approx!(@all $($index)*, |k| T::relative_eq(&self[k], &other[k], epsilon.clone(), max_relative.clone()))
+                }
+            }
+
+            impl<T: UlpsEq, Rhs: $bound + ?Sized, $($generics)*> UlpsEq<Rhs> for $type
+            where
+                T::Epsilon: Clone,
+                Self: PartialEq<Rhs>,
+            {
+                fn default_max_ulps() -> u32 {
+                    T::default_max_ulps()
+                }
+
+                fn ulps_eq(&self, other: &Rhs, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+                    impl_approx!(@all $($index)*, |k| T::ulps_eq(&self[k], &other[k], epsilon.clone(), max_ulps))
+                }
+            }
+        };
+        (@all $rows:ident, $cols:ident, $eq:expr) => {
+            (0..$rows).all(|i| (0..$cols).all(|j| ($eq)((i, j))))
+        };
+        (@all $len:ident, $eq:expr) => {
+            (0..$len).all($eq)
+        };
+    }
+
+    impl_approx!(Matrix<T, R, C>, MatrixRef<T, R, C>, (R, C), const R: usize, const C: usize);
+    impl_approx!(MatrixCM<T, R, C>, MatrixRef<T, R, C>, (R, C), const R: usize, const C: usize);
+    impl_approx!(MatrixView<'_, T, R, C, VR, VC>, MatrixRef<T, VR, VC>, (VR, VC), const R: usize, const C: usize, const VR: usize, const VC: usize);
+    impl_approx!(MatrixViewMut<'_, T, R, C, VR, VC>, MatrixRef<T, VR, VC>, (VR, VC), const R: usize, const C: usize, const VR: usize, const VC: usize);
+    impl_approx!(MatrixTransposeView<'_, T, R, C, VR, VC>, MatrixRef<T, VR, VC>, (VR, VC), const R: usize, const C: usize, const VR: usize, const VC: usize);
+    impl_approx!(MatrixTransposeViewMut<'_, T, R, C, VR, VC>, MatrixRef<T, VR, VC>, (VR, VC), const R: usize, const C: usize, const VR: usize, const VC: usize);
+    impl_approx!(MatrixStridedView<'_, T, R, C, VR, VC>, MatrixRef<T, VR, VC>, (VR, VC), const R: usize, const C: usize, const VR: usize, const VC: usize);
+    impl_approx!(MatrixStridedViewMut<'_, T, R, C, VR, VC>, MatrixRef<T, VR, VC>, (VR, VC), const R: usize, const C: usize, const VR: usize, const VC: usize);
+
+    impl_approx!(Vector<T, N>, VectorRef<T, N>, (N), const N: usize);
+    impl_approx!(RowVector<T, N>, VectorRef<T, N>, (N), const N: usize);
+    impl_approx!(VectorView<'_, V, T, A, N>, VectorRef<T, N>, (N), V: Index<usize, Output = T>, const A: usize, const N: usize);
+    impl_approx!(VectorViewMut<'_, V, T, A, N>, VectorRef<T, N>, (N), V: Index<usize, Output = T>, const A: usize, const N: usize);
+    impl_approx!(RowVectorView<'_, V, T, A, N>, VectorRef<T, N>, (N), V: Index<usize, Output = T>, const A: usize, const N: usize);
+    impl_approx!(RowVectorViewMut<'_, V, T, A, N>, VectorRef<T, N>, (N), V: Index<usize, Output = T>, const A: usize, const N: usize);
+}
@@ -0,0 +1,264 @@
+//! Diagonal matrices.
+//!
+//! A [`Diagonal`] stores only its diagonal. Products with a dense right-hand side, whether a
+//! [`Vector`], a [`Matrix`], a reference to either or one of their views, scale the rows of the
+//! right-hand side, and products with a dense left-hand side scale its columns.
+
+use core::ops::{Add, Index, IndexMut, Mul, Neg, Sub};
+use num_traits::{Num, Zero};
+
+use crate::matrix::Matrix;
+use crate::matrix_cm::MatrixCM;
+use crate::matrix_strided_view::MatrixStridedView;
+use crate::matrix_strided_view_mut::MatrixStridedViewMut;
+use crate::matrix_transpose_view::MatrixTransposeView;
+use crate::matrix_transpose_view_mut::MatrixTransposeViewMut;
+use crate::matrix_view::MatrixView;
+use crate::matrix_view_mut::MatrixViewMut;
+use crate::traits::{MatrixRef, VectorRef};
+use crate::vector::Vector;
+use crate::vector_view::VectorView;
+use crate::vector_view_mut::VectorViewMut;
+
+/// A static diagonal matrix type.
+///
+/// Only the diagonal is stored, so products with a [`Diagonal`] scale rows or columns in `O(N)` per
+/// row or column instead of taking a dense `O(N^2)` product.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagonal<T, const N: usize> {
+    diag: Vector<T, N>,
+}
+
+impl<T, const N: usize> Diagonal<T, N> {
+    /// Creates a new [`Diagonal`] matrix with the given diagonal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{Diagonal, Vector};
+    ///
+    /// let d = Diagonal::new(Vector::from([1, 2, 3]));
+    /// assert_eq!(d[1], 2);
+    /// ```
+    pub fn new(diag: Vector<T, N>) -> Self {
+        Self { diag }
+    }
+
+    /// Returns a reference to the diagonal of the [`Diagonal`] matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{Diagonal, Vector};
+    ///
+    /// let d = Diagonal::from([1, 2, 3]);
+    /// assert_eq!(d.diag(), &Vector::from([1, 2, 3]));
+    /// ```
+    pub fn diag(&self) -> &Vector<T, N> {
+        &self.diag
+    }
+}
+
+impl<T: Clone + Zero, const N: usize> Diagonal<T, N> {
+    /// Returns the dense [`Matrix`] with the same elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{Diagonal, Matrix};
+    ///
+    /// let d = Diagonal::from([1, 2]);
+    /// assert_eq!(d.to_matrix(), Matrix::from([[1, 0], [0, 2]]));
+    /// ```
+    pub fn to_matrix(&self) -> Matrix<T, N, N> {
+        self.diag.diag()
+    }
+}
+
+impl<T: Clone + Num, const N: usize> Diagonal<T, N> {
+    /// Solves `D x = b` by dividing each element of `b` by the diagonal.
+    ///
+    /// Returns `None` if a diagonal element is zero. For integer elements the division truncates, so
+    /// the solution is only exact when each element of `b` is a multiple of its diagonal element;
+    /// convert them to a rational type first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{Diagonal, Vector};
+    ///
+    /// let d = Diagonal::from([2.0, 4.0]);
+    /// assert_eq!(d.solve(&Vector::from([1.0, 1.0])), Some(Vector::from([0.5, 0.25])));
+    /// assert_eq!(Diagonal::from([1.0, 0.0]).solve(&Vector::from([1.0, 1.0])), None);
+    /// ```
+    pub fn solve<V: VectorRef<T, N> + ?Sized>(&self, b: &V) -> Option<Vector<T, N>> {
+        if (0..N).any(|i| self.diag[i].is_zero()) {
+            return None;
+        }
+        Some(Vector::from(core::array::from_fn(|i| b[i].clone() / self.diag[i].clone())))
+    }
+}
+
+//////////////////////////////////
+//  From Trait Implementations  //
+//////////////////////////////////
+
+impl<T, const N: usize> From<Vector<T, N>> for Diagonal<T, N> {
+    fn from(diag: Vector<T, N>) -> Self {
+        Self::new(diag)
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for Diagonal<T, N> {
+    fn from(diag: [T; N]) -> Self {
+        Self::new(Vector::from(diag))
+    }
+}
+
+impl<T: Clone + Zero, const N: usize> From<Diagonal<T, N>> for Matrix<T, N, N> {
+    fn from(diag: Diagonal<T, N>) -> Self {
+        diag.to_matrix()
+    }
+}
+
+///////////////////////////////////
+//  Index Trait Implementations  //
+///////////////////////////////////
+
+impl<T, const N: usize> Index<usize> for Diagonal<T, N> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.diag[index]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for Diagonal<T, N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.diag[index]
+    }
+}
+
+////////////////////////////////////////
+//  Arithmetic Trait Implementations  //
+////////////////////////////////////////
+
+impl<T: Clone + Mul<T, Output = T>, const N: usize> Diagonal<T, N> {
+    fn mul_vector<V: VectorRef<T, N> + ?Sized>(&self, other: &V) -> Matrix<T, N, 1> {
+        Matrix::from(core::array::from_fn(|i| [self.diag[i].clone() * other[i].clone()]))
+    }
+
+    fn mul_matrix<M: MatrixRef<T, N, C> + ?Sized, const C: usize>(&self, other: &M) -> Matrix<T, N, C> {
+        Matrix::from(core::array::from_fn(|i| {
+            core::array::from_fn(|j| self.diag[i].clone() * other[(i, j)].clone())
+        }))
+    }
+}
+
+// `$arg` borrows `$other` for `mul_vector` or `mul_matrix`, which read it in place
+macro_rules! impl_diagonal_dense_mul {
+    ($method:ident, $output:ty, $rhs:ty, [$($generics:tt)*], |$other:ident| $arg:expr) => {
+        impl<T: Clone + Mul<T, Output = T>, const N: usize, $($generics)*> Mul<$rhs> for Diagonal<T, N> {
+            type Output = $output;
+
+            fn mul(self, $other: $rhs) -> Self::Output {
+                self.$method($arg)
+            }
+        }
+
+        impl<T: Clone + Mul<T, Output = T>, const N: usize, $($generics)*> Mul<$rhs> for &Diagonal<T, N> {
+            type Output = $output;
+
+            fn mul(self, $other: $rhs) -> Self::Output {
+                self.$method($arg)
+            }
+        }
+    };
+}
+
+impl_diagonal_dense_mul!(mul_vector, Matrix<T, N, 1>, Vector<T, N>, [], |other| &other);
+impl_diagonal_dense_mul!(mul_vector, Matrix<T, N, 1>, &Vector<T, N>, [], |other| other);
+impl_diagonal_dense_mul!(mul_vector, Matrix<T, N, 1>, VectorView<'_, V, T, A, N>, [V: Index<usize, Output = T>, const A: usize], |other| &other);
+impl_diagonal_dense_mul!(mul_vector, Matrix<T, N, 1>, VectorViewMut<'_, V, T, A, N>, [V: Index<usize, Output = T>, const A: usize], |other| &other);
+
+impl_diagonal_dense_mul!(mul_matrix, Matrix<T, N, C>, Matrix<T, N, C>, [const C: usize], |other| &other);
+impl_diagonal_dense_mul!(mul_matrix, Matrix<T, N, C>, &Matrix<T, N, C>, [const C: usize], |other| other);
+impl_diagonal_dense_mul!(mul_matrix, Matrix<T, N, C>, MatrixCM<T, N, C>, [const C: usize], |other| &other);
+impl_diagonal_dense_mul!(mul_matrix, Matrix<T, N, C>, &MatrixCM<T, N, C>, [const C: usize], |other| other);
+impl_diagonal_dense_mul!(mul_matrix, Matrix<T, N, C>, MatrixView<'_, T, A, B, N, C>, [const A: usize, const B: usize, const C: usize], |other| &other);
+impl_diagonal_dense_mul!(mul_matrix, Matrix<T, N, C>, MatrixViewMut<'_, T, A, B, N, C>, [const A: usize, const B: usize, const C: usize], |other| &other);
+impl_diagonal_dense_mul!(mul_matrix, Matrix<T, N, C>, MatrixTransposeView<'_, T, A, B, N, C>, [const A: usize, const B: usize, const C: usize], |other| &other);
+impl_diagonal_dense_mul!(mul_matrix, Matrix<T, N, C>, MatrixTransposeViewMut<'_, T, A, B, N, C>, [const A: usize, const B: usize, const C: usize], |other| &other);
+impl_diagonal_dense_mul!(mul_matrix, Matrix<T, N, C>, MatrixStridedView<'_, T, A, B, N, C>, [const A: usize, const B: usize, const C: usize], |other| &other);
+impl_diagonal_dense_mul!(mul_matrix, Matrix<T, N, C>, MatrixStridedViewMut<'_, T, A, B, N, C>, [const A: usize, const B: usize, const C: usize], |other| &other);
+
+macro_rules! impl_diagonal_ops {
+    ($($lhs:ty),*) => {
+        $(
+            impl<T: Clone + Mul<T, Output = T>, const N: usize> Mul<Diagonal<T, N>> for $lhs {
+                type Output = Diagonal<T, N>;
+
+                fn mul(self, other: Diagonal<T, N>) -> Self::Output {
+                    Diagonal::from(core::array::from_fn(|i| self.diag[i].clone() * other.diag[i].clone()))
+                }
+            }
+
+            impl<T: Clone + Mul<T, Output = T>, const N: usize> Mul<T> for $lhs {
+                type Output = Diagonal<T, N>;
+
+                fn mul(self, scalar: T) -> Self::Output {
+                    Diagonal::from(core::array::from_fn(|i| self.diag[i].clone() * scalar.clone()))
+                }
+            }
+
+            impl<T: Clone + Add<T, Output = T>, const N: usize> Add<Diagonal<T, N>> for $lhs {
+                type Output = Diagonal<T, N>;
+
+                fn add(self, other: Diagonal<T, N>) -> Self::Output {
+                    Diagonal::from(core::array::from_fn(|i| self.diag[i].clone() + other.diag[i].clone()))
+                }
+            }
+
+            impl<T: Clone + Sub<T, Output = T>, const N: usize> Sub<Diagonal<T, N>> for $lhs {
+                type Output = Diagonal<T, N>;
+
+                fn sub(self, other: Diagonal<T, N>) -> Self::Output {
+                    Diagonal::from(core::array::from_fn(|i| self.diag[i].clone() - other.diag[i].clone()))
+                }
+            }
+
+            impl<T: Clone + Neg<Output = T>, const N: usize> Neg for $lhs {
+                type Output = Diagonal<T, N>;
+
+                fn neg(self) -> Self::Output {
+                    Diagonal::from(core::array::from_fn(|i| -self.diag[i].clone()))
+                }
+            }
+        )*
+    };
+}
+
+impl_diagonal_ops!(Diagonal<T, N>, &Diagonal<T, N>);
+
+macro_rules! impl_matrix_diagonal_mul {
+    ($($lhs:ty, $rhs:ty);*) => {
+        $(
+            impl<T: Clone + Mul<T, Output = T>, const R: usize, const N: usize> Mul<$rhs> for $lhs {
+                type Output = Matrix<T, R, N>;
+
+                fn mul(self, other: $rhs) -> Self::Output {
+                    Matrix::from(core::array::from_fn(|i| {
+                        core::array::from_fn(|j| self[(i, j)].clone() * other.diag[j].clone())
+                    }))
+                }
+            }
+        )*
+    };
+}
+
+impl_matrix_diagonal_mul!(
+    Matrix<T, R, N>, Diagonal<T, N>;
+    &Matrix<T, R, N>, Diagonal<T, N>;
+    Matrix<T, R, N>, &Diagonal<T, N>;
+    &Matrix<T, R, N>, &Diagonal<T, N>
+);
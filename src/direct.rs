@@ -0,0 +1,259 @@
+//! Direct solvers for triangular, tridiagonal and banded linear systems.
+//!
+//! Unlike the iterative solvers, these return the exact solution up to the rounding of `T`, so they
+//! are exact for rational element types. Only the elements inside the relevant triangle or band are
+//! read, which allows e.g. both factors of a packed LU decomposition to share one [`Matrix`]. With the
+//! `blas` feature, large `f32`/`f64` triangular systems are solved by LAPACK.
+//!
+//! For integer elements the divisions truncate, so the solution is only exact when every division
+//! is; convert them to a rational type first. The unit triangular solvers never divide.
+
+use num_traits::Num;
+
+use crate::matrix::Matrix;
+use crate::ops::lapack;
+use crate::traits::{Contiguous, VectorRef};
+use crate::vector::Vector;
+
+/// Solves a triangular system by substitution in place of `x`, with `at(i, j)` returning the element
+/// `(i, j)` of the triangular matrix.
+///
+/// Returns `None` if `unit` is false and a diagonal element is zero.
+fn substitute<T, F, const N: usize>(mut x: Vector<T, N>, lower: bool, unit: bool, at: F) -> Option<Vector<T, N>>
+where
+    T: Clone + Num,
+    F: Fn(usize, usize) -> T,
+{
+    for step in 0..N {
+        let i = if lower { step } else { N - 1 - step };
+        let known = if lower { 0..i } else { i + 1..N };
+        let sum = known.fold(x[i].clone(), |sum, j| sum - at(i, j) * x[j].clone());
+        x[i] = if unit {
+            sum
+        } else {
+            let diagonal = at(i, i);
+            if diagonal.is_zero() {
+                return None;
+            }
+            sum / diagonal
+        };
+    }
+    Some(x)
+}
+
+/// Solves `A x = b`, or `A^T x = b` if `transpose` is set, where `A` is the lower or upper triangle
+/// of `a`. Large `f32`/`f64` systems are handed to LAPACK with the `blas` feature.
+fn solve_triangular<T, V, const N: usize>(a: &Matrix<T, N, N>, lower: bool, transpose: bool, b: &V) -> Option<Vector<T, N>>
+where
+    T: Clone + Num,
+    V: VectorRef<T, N> + ?Sized,
+{
+    let mut x = b.to_vector();
+    if let Some(solved) = lapack::solve_triangular::<T, N>(a.as_slice(), lower, transpose, x.as_mut_slice()) {
+        return solved.then_some(x);
+    }
+    if transpose {
+        substitute(x, !lower, false, |i, j| a[(j, i)].clone())
+    } else {
+        substitute(x, lower, false, |i, j| a[(i, j)].clone())
+    }
+}
+
+impl<T: Clone + Num, const N: usize> Matrix<T, N, N> {
+    /// Solves `L x = b` by forward substitution, where `L` is the lower triangle of the [`Matrix`].
+    ///
+    /// The elements above the diagonal are ignored. Returns `None` if a diagonal element is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{Matrix, Vector};
+    ///
+    /// let l = Matrix::from([[2.0, 0.0], [1.0, 4.0]]);
+    /// let x = l.solve_lower(&Vector::from([2.0, 9.0])).unwrap();
+    /// assert_eq!(x, Vector::from([1.0, 2.0]));
+    /// ```
+    pub fn solve_lower<V: VectorRef<T, N> + ?Sized>(&self, b: &V) -> Option<Vector<T, N>> {
+        solve_triangular(self, true, false, b)
+    }
+
+    /// Solves `U x = b` by back substitution, where `U` is the upper triangle of the [`Matrix`].
+    ///
+    /// The elements below the diagonal are ignored. Returns `None` if a diagonal element is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{Matrix, Vector};
+    ///
+    /// let u = Matrix::from([[2.0, 1.0], [0.0, 4.0]]);
+    /// let x = u.solve_upper(&Vector::from([4.0, 8.0])).unwrap();
+    /// assert_eq!(x, Vector::from([1.0, 2.0]));
+    /// ```
+    pub fn solve_upper<V: VectorRef<T, N> + ?Sized>(&self, b: &V) -> Option<Vector<T, N>> {
+        solve_triangular(self, false, false, b)
+    }
+
+    /// Solves `L^T x = b` by back substitution, where `L` is the lower triangle of the [`Matrix`].
+    ///
+    /// The elements above the diagonal are ignored. Returns `None` if a diagonal element is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{Matrix, Vector};
+    ///
+    /// let l = Matrix::from([[2.0, 0.0], [1.0, 4.0]]);
+    /// let x = l.solve_lower_transpose(&Vector::from([4.0, 8.0])).unwrap();
+    /// assert_eq!(x, Vector::from([1.0, 2.0]));
+    /// ```
+    pub fn solve_lower_transpose<V: VectorRef<T, N> + ?Sized>(&self, b: &V) -> Option<Vector<T, N>> {
+        solve_triangular(self, true, true, b)
+    }
+
+    /// Solves `U^T x = b` by forward substitution, where `U` is the upper triangle of the [`Matrix`].
+    ///
+    /// The elements below the diagonal are ignored. Returns `None` if a diagonal element is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{Matrix, Vector};
+    ///
+    /// let u = Matrix::from([[2.0, 1.0], [0.0, 4.0]]);
+    /// let x = u.solve_upper_transpose(&Vector::from([2.0, 9.0])).unwrap();
+    /// assert_eq!(x, Vector::from([1.0, 2.0]));
+    /// ```
+    pub fn solve_upper_transpose<V: VectorRef<T, N> + ?Sized>(&self, b: &V) -> Option<Vector<T, N>> {
+        solve_triangular(self, false, true, b)
+    }
+
+    /// Solves `A x = b` for a banded [`Matrix`] with `lower` subdiagonals and `upper` superdiagonals.
+    ///
+    /// Gaussian elimination is restricted to the band, so this takes `O(N * lower * upper)` arithmetic
+    /// operations instead of `O(N^3)`, and the elements outside the band are ignored. No pivoting is
+    /// done, which is stable for diagonally dominant and symmetric positive definite matrices. Returns
+    /// `None` if a zero pivot is encountered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{assert_vector_eq, Matrix, Vector};
+    ///
+    /// let a = Matrix::from([
+    ///     [4.0, 1.0, 0.0, 0.0],
+    ///     [1.0, 4.0, 1.0, 0.0],
+    ///     [2.0, 1.0, 4.0, 1.0],
+    ///     [0.0, 2.0, 1.0, 4.0],
+    /// ]);
+    /// let x = Vector::from([1.0, 2.0, 3.0, 4.0]);
+    /// assert_vector_eq!(a.solve_banded(2, 1, &Vector::from(&a * &x)).unwrap(), x, epsilon = 1e-15);
+    /// ```
+    pub fn solve_banded<V: VectorRef<T, N> + ?Sized>(&self, lower: usize, upper: usize, b: &V) -> Option<Vector<T, N>> {
+        let mut a = self.clone();
+        let mut x = b.to_vector();
+        for k in 0..N {
+            if a[(k, k)].is_zero() {
+                return None;
+            }
+            for i in k + 1..N.min(lower.saturating_add(k + 1)) {
+                let factor = a[(i, k)].clone() / a[(k, k)].clone();
+                for j in k + 1..N.min(upper.saturating_add(k + 1)) {
+                    a[(i, j)] = a[(i, j)].clone() - factor.clone() * a[(k, j)].clone();
+                }
+                x[i] = x[i].clone() - factor * x[k].clone();
+            }
+        }
+        for i in (0..N).rev() {
+            let sum = (i + 1..N.min(upper.saturating_add(i + 1))).fold(x[i].clone(), |sum, j| sum - a[(i, j)].clone() * x[j].clone());
+            x[i] = sum / a[(i, i)].clone();
+        }
+        Some(x)
+    }
+
+    /// Solves `L x = b` by forward substitution, where `L` is the lower triangle of the [`Matrix`]
+    /// with ones on the diagonal.
+    ///
+    /// The diagonal and the elements above it are ignored, as for the `L` factor of an LU
+    /// decomposition stored alongside `U`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{Matrix, Vector};
+    ///
+    /// let l = Matrix::from([[7, 0], [3, 7]]);
+    /// assert_eq!(l.solve_lower_unit(&Vector::from([1, 5])), Vector::from([1, 2]));
+    /// ```
+    pub fn solve_lower_unit<V: VectorRef<T, N> + ?Sized>(&self, b: &V) -> Vector<T, N> {
+        substitute(b.to_vector(), true, true, |i, j| self[(i, j)].clone()).unwrap()
+    }
+
+    /// Solves `U x = b` by back substitution, where `U` is the upper triangle of the [`Matrix`]
+    /// with ones on the diagonal.
+    ///
+    /// The diagonal and the elements below it are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{Matrix, Vector};
+    ///
+    /// let u = Matrix::from([[7, 3], [0, 7]]);
+    /// assert_eq!(u.solve_upper_unit(&Vector::from([7, 2])), Vector::from([1, 2]));
+    /// ```
+    pub fn solve_upper_unit<V: VectorRef<T, N> + ?Sized>(&self, b: &V) -> Vector<T, N> {
+        substitute(b.to_vector(), false, true, |i, j| self[(i, j)].clone()).unwrap()
+    }
+}
+
+/// Solves the tridiagonal system `A x = b` with the Thomas algorithm.
+///
+/// `diag` holds the diagonal of `A`, `lower[i]` the element `(i, i - 1)` and `upper[i]` the element
+/// `(i, i + 1)`, so `lower[0]` and `upper[N - 1]` are ignored. This takes `O(N)` operations and
+/// storage. No pivoting is done, which is stable for diagonally dominant and symmetric positive
+/// definite matrices. Returns `None` if a zero pivot is encountered.
+///
+/// # Examples
+///
+/// ```
+/// use ferrix::{assert_vector_eq, solve_tridiagonal, Vector};
+///
+/// // The 1D Poisson equation -u'' = 1 with zero boundary values
+/// let lower = Vector::from([0.0, -1.0, -1.0]);
+/// let diag = Vector::from([2.0, 2.0, 2.0]);
+/// let upper = Vector::from([-1.0, -1.0, 0.0]);
+/// let u = solve_tridiagonal(&lower, &diag, &upper, &Vector::fill(1.0)).unwrap();
+/// assert_vector_eq!(u, Vector::from([1.5, 2.0, 1.5]), epsilon = 1e-15);
+/// ```
+pub fn solve_tridiagonal<T, L, D, U, B, const N: usize>(lower: &L, diag: &D, upper: &U, b: &B) -> Option<Vector<T, N>>
+where
+    T: Clone + Num,
+    L: VectorRef<T, N> + ?Sized,
+    D: VectorRef<T, N> + ?Sized,
+    U: VectorRef<T, N> + ?Sized,
+    B: VectorRef<T, N> + ?Sized,
+{
+    // Forward sweep, storing the modified superdiagonal in `c` and right-hand side in `x`
+    let mut c = upper.to_vector();
+    let mut x = b.to_vector();
+    for i in 0..N {
+        let pivot = if i == 0 {
+            diag[0].clone()
+        } else {
+            diag[i].clone() - lower[i].clone() * c[i - 1].clone()
+        };
+        if pivot.is_zero() {
+            return None;
+        }
+        if i > 0 {
+            x[i] = x[i].clone() - lower[i].clone() * x[i - 1].clone();
+        }
+        c[i] = c[i].clone() / pivot.clone();
+        x[i] = x[i].clone() / pivot;
+    }
+    for i in (0..N.saturating_sub(1)).rev() {
+        x[i] = x[i].clone() - c[i].clone() * x[i + 1].clone();
+    }
+    Some(x)
+}
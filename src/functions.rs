@@ -0,0 +1,233 @@
+//! Matrix functions.
+//!
+//! - [`Matrix::expm`] computes the matrix exponential by Padé approximation with scaling and squaring.
+//! - [`Matrix::sqrtm`] computes the principal square root by the Denman–Beavers iteration.
+//! - [`Matrix::logm`] computes the principal logarithm by inverse scaling and squaring.
+//! - [`Matrix::powi`] raises a matrix to an integer power by repeated squaring.
+
+use num_traits::Float;
+
+use crate::matrix::Matrix;
+use crate::ops::{lapack, linalg};
+
+/// The maximum number of iterations of [`Matrix::sqrtm`] and of square roots taken by [`Matrix::logm`].
+const MAX_ITERATIONS: usize = 100;
+
+/// Returns the 1-norm of `a`, i.e. its maximum absolute column sum.
+fn norm1<T: Float, const N: usize>(a: &Matrix<T, N, N>) -> T {
+    if let Some(norm) = linalg::norm1::<T, N>(a.as_slice()) {
+        return norm;
+    }
+    (0..N)
+        .map(|j| (0..N).fold(T::zero(), |sum, i| sum + a[(i, j)].abs()))
+        .fold(T::zero(), T::max)
+}
+
+/// Returns `X` with `a * X == b`, by Gaussian elimination with partial pivoting.
+///
+/// A singular `a` gives non-finite elements rather than an error.
+fn solve<T: Float, const N: usize>(mut a: Matrix<T, N, N>, mut b: Matrix<T, N, N>) -> Matrix<T, N, N> {
+    if lapack::solve::<T, N>(a.as_mut_slice(), b.as_mut_slice()) || linalg::solve::<T, N>(a.as_mut_slice(), b.as_mut_slice()) {
+        return b;
+    }
+
+    for k in 0..N {
+        let pivot = (k + 1..N).fold(k, |p, i| if a[(i, k)].abs() > a[(p, k)].abs() { i } else { p });
+        a.swap_rows(k, pivot);
+        b.swap_rows(k, pivot);
+        for i in k + 1..N {
+            let factor = a[(i, k)] / a[(k, k)];
+            for j in k + 1..N {
+                a[(i, j)] = a[(i, j)] - factor * a[(k, j)];
+            }
+            for j in 0..N {
+                b[(i, j)] = b[(i, j)] - factor * b[(k, j)];
+            }
+        }
+    }
+    for k in (0..N).rev() {
+        for j in 0..N {
+            let sum = (k + 1..N).fold(b[(k, j)], |sum, i| sum - a[(k, i)] * b[(i, j)]);
+            b[(k, j)] = sum / a[(k, k)];
+        }
+    }
+    b
+}
+
+/// Returns the inverse of `a`, or `None` if it is singular to working precision.
+fn inverse<T: Float, const N: usize>(a: &Matrix<T, N, N>) -> Option<Matrix<T, N, N>> {
+    let inv = solve(a.clone(), Matrix::eye());
+    inv.as_slice().iter().all(|x| x.is_finite()).then_some(inv)
+}
+
+impl<T: Float, const N: usize> Matrix<T, N, N> {
+    /// Returns the exponential of the [`Matrix`].
+    ///
+    /// The [`Matrix`] is scaled by a power of two until its 1-norm is at most `1/2`, the exponential
+    /// of the scaled matrix is approximated by the degree 6 diagonal Padé approximant, and the result is
+    /// squared back. This is accurate to roughly machine precision for `f64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{assert_matrix_eq, Matrix};
+    ///
+    /// // The discrete-time transition matrix of a double integrator with time step 0.5
+    /// let a = Matrix::from([[0.0, 1.0], [0.0, 0.0]]);
+    /// assert_matrix_eq!((a * 0.5).expm(), Matrix::from([[1.0, 0.5], [0.0, 1.0]]), epsilon = 1e-15);
+    ///
+    /// let d = Matrix::from([[1.0, 0.0], [0.0, 2.0]]);
+    /// assert_matrix_eq!(d.expm(), Matrix::from([[1.0f64.exp(), 0.0], [0.0, 2.0f64.exp()]]), epsilon = 0.0, max_relative = 1e-14);
+    /// ```
+    pub fn expm(&self) -> Self {
+        const DEGREE: usize = 6;
+        let half = T::from(0.5).unwrap();
+
+        let norm = norm1(self);
+        let squarings = if norm > half { (norm / half).log2().ceil().to_i32().unwrap_or(0) } else { 0 };
+        let a = self * T::from(2).unwrap().powi(-squarings);
+        let minus_a = -&a;
+
+        // Horner's scheme for the numerator p(A) and the denominator q(A) = p(-A)
+        let mut c = T::one();
+        let mut coefficients = [T::one(); DEGREE + 1];
+        for (k, coefficient) in coefficients.iter_mut().enumerate().skip(1) {
+            c = c * T::from(DEGREE - k + 1).unwrap() / T::from(k * (2 * DEGREE - k + 1)).unwrap();
+            *coefficient = c;
+        }
+        let mut p = Self::eye() * coefficients[DEGREE];
+        let mut q = p.clone();
+        for k in (0..DEGREE).rev() {
+            p = &a * &p + Self::eye() * coefficients[k];
+            q = &minus_a * &q + Self::eye() * coefficients[k];
+        }
+
+        let mut result = solve(q, p);
+        for _ in 0..squarings {
+            result = &result * &result;
+        }
+        result
+    }
+
+    /// Returns the principal square root of the [`Matrix`], or `None` if it does not exist.
+    ///
+    /// The principal square root is the unique square root whose eigenvalues have positive real parts.
+    /// It exists when the [`Matrix`] has no eigenvalues on the closed negative real axis, and is computed
+    /// by the Denman–Beavers iteration. `None` is also returned if the iteration does not converge within
+    /// 100 steps.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{assert_matrix_eq, Matrix};
+    ///
+    /// let a = Matrix::from([[4.0, 1.0], [0.0, 9.0]]);
+    /// let root = a.sqrtm().unwrap();
+    /// assert_matrix_eq!(root, Matrix::from([[2.0, 0.2], [0.0, 3.0]]), epsilon = 1e-14);
+    ///
+    /// let negative = Matrix::from([[-1.0, 0.0], [0.0, 1.0]]);
+    /// assert_eq!(negative.sqrtm(), None);
+    /// ```
+    pub fn sqrtm(&self) -> Option<Self> {
+        let half = T::from(0.5).unwrap();
+        let tolerance = T::epsilon() * T::from(N).unwrap();
+
+        let mut y = self.clone();
+        let mut z = Self::eye();
+        let mut previous = T::infinity();
+        for _ in 0..MAX_ITERATIONS {
+            let next_y = (&y + inverse(&z)?) * half;
+            let next_z = (&z + inverse(&y)?) * half;
+            let change = norm1(&(&next_y - &y));
+            y = next_y;
+            z = next_z;
+
+            // Once converged, rounding errors stop the change from decreasing any further
+            let norm = norm1(&y);
+            if change <= tolerance * norm || (change >= previous && change <= tolerance.sqrt() * norm) {
+                return Some(y);
+            }
+            previous = change;
+        }
+        None
+    }
+
+    /// Returns the principal logarithm of the [`Matrix`], or `None` if it does not exist.
+    ///
+    /// The principal logarithm is the unique logarithm whose eigenvalues have imaginary parts in
+    /// `(-π, π)`. It exists when the [`Matrix`] has no eigenvalues on the closed negative real axis.
+    /// Square roots are taken with [`Matrix::sqrtm`] until the [`Matrix`] is close to the identity, the
+    /// logarithm is evaluated by its `atanh` series there, and the result is scaled back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{assert_matrix_eq, Matrix};
+    ///
+    /// let a = Matrix::from([[1.0, 2.0], [0.5, 3.0]]);
+    /// assert_matrix_eq!(a.logm().unwrap().expm(), a, epsilon = 1e-12);
+    ///
+    /// let identity = Matrix::<f64, 3, 3>::eye();
+    /// assert_eq!(identity.logm(), Some(Matrix::zeros()));
+    /// ```
+    pub fn logm(&self) -> Option<Self> {
+        let quarter = T::from(0.25).unwrap();
+
+        let mut b = self.clone();
+        let mut roots = 0;
+        while norm1(&(&b - Self::eye())) > quarter {
+            if roots == MAX_ITERATIONS {
+                return None;
+            }
+            b = b.sqrtm()?;
+            roots += 1;
+        }
+
+        // log(B) = 2 atanh(Z) = 2 (Z + Z^3 / 3 + Z^5 / 5 + ...) with Z = (B - I)(B + I)^-1
+        let z = (&b - Self::eye()) * inverse(&(&b + Self::eye()))?;
+        let z2 = &z * &z;
+        let mut power = z.clone();
+        let mut sum = z;
+        for k in 1..MAX_ITERATIONS {
+            power = power * &z2;
+            let term = &power / T::from(2 * k + 1).unwrap();
+            sum = sum + &term;
+            if norm1(&term) <= T::epsilon() * norm1(&sum) {
+                break;
+            }
+        }
+        Some(sum * T::from(2).unwrap().powi(roots as i32 + 1))
+    }
+
+    /// Returns the [`Matrix`] raised to the integer power `k`.
+    ///
+    /// The power is computed by repeated squaring, in `O(log |k|)` matrix products. Negative powers
+    /// invert the [`Matrix`] first, so like [`Float::powi`] they give non-finite elements for a
+    /// singular [`Matrix`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{assert_matrix_eq, Matrix};
+    ///
+    /// let fib = Matrix::from([[1.0, 1.0], [1.0, 0.0]]);
+    /// assert_eq!(fib.powi(10), Matrix::from([[89.0, 55.0], [55.0, 34.0]]));
+    /// assert_eq!(fib.powi(0), Matrix::eye());
+    /// assert_matrix_eq!(fib.powi(-3) * fib.powi(3), Matrix::eye(), epsilon = 1e-12);
+    /// ```
+    pub fn powi(&self, k: i32) -> Self {
+        let mut base = if k < 0 { solve(self.clone(), Self::eye()) } else { self.clone() };
+        let mut exponent = k.unsigned_abs();
+        let mut result = Self::eye();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result * &base;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = &base * &base;
+            }
+        }
+        result
+    }
+}
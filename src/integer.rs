@@ -0,0 +1,236 @@
+//! Exact integer linear algebra.
+//!
+//! The algorithms here never divide inexactly, so they give exact results for integer matrices
+//! as long as the element type does not overflow:
+//!
+//! - [`Matrix::det_bareiss`] computes the determinant with fraction-free Gaussian elimination.
+//! - [`Matrix::hermite_normal_form`] row-reduces a matrix to its Hermite normal form `H = U A`.
+//! - [`Matrix::smith_normal_form`] diagonalizes a matrix to its Smith normal form `D = U A V`.
+//!
+//! `U` and `V` are unimodular, i.e. integer matrices with determinant `±1`, so they are invertible
+//! over the integers.
+
+use num_traits::{PrimInt, Signed};
+
+use crate::matrix::Matrix;
+
+/// Swaps columns `a` and `b` of `m`.
+fn swap_cols<T: Copy, const R: usize, const C: usize>(m: &mut Matrix<T, R, C>, a: usize, b: usize) {
+    for i in 0..R {
+        let tmp = m[(i, a)];
+        m[(i, a)] = m[(i, b)];
+        m[(i, b)] = tmp;
+    }
+}
+
+/// Subtracts `q` times row `src` from row `dst` of `m`.
+fn sub_row<T: PrimInt, const R: usize, const C: usize>(m: &mut Matrix<T, R, C>, dst: usize, src: usize, q: T) {
+    for j in 0..C {
+        m[(dst, j)] = m[(dst, j)] - q * m[(src, j)];
+    }
+}
+
+/// Subtracts `q` times column `src` from column `dst` of `m`.
+fn sub_col<T: PrimInt, const R: usize, const C: usize>(m: &mut Matrix<T, R, C>, dst: usize, src: usize, q: T) {
+    for i in 0..R {
+        m[(i, dst)] = m[(i, dst)] - q * m[(i, src)];
+    }
+}
+
+/// Negates row `i` of `m`.
+fn negate_row<T: PrimInt + Signed, const R: usize, const C: usize>(m: &mut Matrix<T, R, C>, i: usize) {
+    for j in 0..C {
+        m[(i, j)] = -m[(i, j)];
+    }
+}
+
+/// Returns `a / b` rounded towards negative infinity, for `b > 0`.
+fn div_floor<T: PrimInt + Signed>(a: T, b: T) -> T {
+    let q = a / b;
+    if (a % b).is_negative() {
+        q - T::one()
+    } else {
+        q
+    }
+}
+
+impl<T: PrimInt + Signed, const N: usize> Matrix<T, N, N> {
+    /// Returns the determinant of the [`Matrix`], computed with the fraction-free Bareiss algorithm.
+    ///
+    /// Every division is exact, so the result is exact for integer elements. The intermediate values
+    /// are minors of the matrix, but their products before each division are up to twice as wide, so
+    /// the computation can overflow even when the determinant itself fits in `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[2, -1, 0], [-1, 2, -1], [0, -1, 2]]);
+    /// assert_eq!(mat.det_bareiss(), 4);
+    ///
+    /// let singular = Matrix::from([[1, 2], [2, 4]]);
+    /// assert_eq!(singular.det_bareiss(), 0);
+    /// ```
+    pub fn det_bareiss(&self) -> T {
+        let mut a = self.clone();
+        let mut sign = T::one();
+        let mut prev = T::one();
+        for k in 0..N {
+            if a[(k, k)].is_zero() {
+                let Some(pivot) = (k + 1..N).find(|&i| !a[(i, k)].is_zero()) else {
+                    return T::zero();
+                };
+                a.swap_rows(k, pivot);
+                sign = -sign;
+            }
+            for i in k + 1..N {
+                for j in k + 1..N {
+                    a[(i, j)] = (a[(i, j)] * a[(k, k)] - a[(i, k)] * a[(k, j)]) / prev;
+                }
+            }
+            prev = a[(k, k)];
+        }
+        sign * prev
+    }
+}
+
+impl<T: PrimInt + Signed, const R: usize, const C: usize> Matrix<T, R, C> {
+    /// Returns the row-style Hermite normal form `H` of the [`Matrix`] and the unimodular `U` with
+    /// `U * self == H`.
+    ///
+    /// `H` is in row echelon form: the first nonzero entry of each row, its pivot, is positive and
+    /// lies to the right of the pivot of the row above, any zero rows are at the bottom, and the
+    /// entries above each pivot are nonnegative and smaller than it. The rows of `H` span the same
+    /// integer lattice as the rows of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let a = Matrix::from([[2i64, 3, 6], [4, 1, 3], [6, 2, 1]]);
+    /// let (h, u) = a.hermite_normal_form();
+    /// assert_eq!(h, Matrix::from([[2, 0, 5], [0, 1, 15], [0, 0, 22]]));
+    /// assert_eq!(&u * &a, h);
+    /// assert_eq!(u.det_bareiss().abs(), 1);
+    /// ```
+    pub fn hermite_normal_form(&self) -> (Matrix<T, R, C>, Matrix<T, R, R>) {
+        let mut h = self.clone();
+        let mut u = Matrix::<T, R, R>::eye();
+        let mut row = 0;
+        for col in 0..C {
+            if row == R {
+                break;
+            }
+
+            // Euclid's algorithm on the column, until only the pivot row is nonzero
+            loop {
+                let pivot = (row..R).filter(|&i| !h[(i, col)].is_zero()).min_by_key(|&i| h[(i, col)].abs());
+                let Some(pivot) = pivot else {
+                    break;
+                };
+                h.swap_rows(row, pivot);
+                u.swap_rows(row, pivot);
+
+                let mut reduced = true;
+                for i in row + 1..R {
+                    let q = h[(i, col)] / h[(row, col)];
+                    sub_row(&mut h, i, row, q);
+                    sub_row(&mut u, i, row, q);
+                    reduced &= h[(i, col)].is_zero();
+                }
+                if reduced {
+                    break;
+                }
+            }
+            if h[(row, col)].is_zero() {
+                continue;
+            }
+
+            if h[(row, col)].is_negative() {
+                negate_row(&mut h, row);
+                negate_row(&mut u, row);
+            }
+            for i in 0..row {
+                let q = div_floor(h[(i, col)], h[(row, col)]);
+                sub_row(&mut h, i, row, q);
+                sub_row(&mut u, i, row, q);
+            }
+            row += 1;
+        }
+        (h, u)
+    }
+
+    /// Returns the Smith normal form `D` of the [`Matrix`] and the unimodular `U` and `V` with
+    /// `U * self * V == D`.
+    ///
+    /// `D` is diagonal, its diagonal entries are nonnegative, and each divides the next. They are
+    /// the invariant factors of `self`, so e.g. the abelian group `Z^C / self^T Z^R` is isomorphic to
+    /// the direct sum of `Z / D[(i, i)] Z`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let a = Matrix::from([[2, 4, 4], [-6, 6, 12], [10, 4, 16]]);
+    /// let (d, u, v) = a.smith_normal_form();
+    /// assert_eq!(d, Matrix::from([[2, 0, 0], [0, 2, 0], [0, 0, 156]]));
+    /// assert_eq!(u * a * v, d);
+    /// ```
+    pub fn smith_normal_form(&self) -> (Matrix<T, R, C>, Matrix<T, R, R>, Matrix<T, C, C>) {
+        let mut d = self.clone();
+        let mut u = Matrix::<T, R, R>::eye();
+        let mut v = Matrix::<T, C, C>::eye();
+        for t in 0..R.min(C) {
+            loop {
+                // Move the smallest nonzero entry of the trailing submatrix to the pivot
+                let pivot = (t..R)
+                    .flat_map(|i| (t..C).map(move |j| (i, j)))
+                    .filter(|&(i, j)| !d[(i, j)].is_zero())
+                    .min_by_key(|&(i, j)| d[(i, j)].abs());
+                let Some((pi, pj)) = pivot else {
+                    return (d, u, v);
+                };
+                d.swap_rows(t, pi);
+                u.swap_rows(t, pi);
+                swap_cols(&mut d, t, pj);
+                swap_cols(&mut v, t, pj);
+
+                // Reduce the pivot row and column; any remainder is smaller than the pivot
+                let mut reduced = true;
+                for i in t + 1..R {
+                    let q = d[(i, t)] / d[(t, t)];
+                    sub_row(&mut d, i, t, q);
+                    sub_row(&mut u, i, t, q);
+                    reduced &= d[(i, t)].is_zero();
+                }
+                for j in t + 1..C {
+                    let q = d[(t, j)] / d[(t, t)];
+                    sub_col(&mut d, j, t, q);
+                    sub_col(&mut v, j, t, q);
+                    reduced &= d[(t, j)].is_zero();
+                }
+                if !reduced {
+                    continue;
+                }
+
+                // Adding a row the pivot does not divide leaves a remainder in the pivot row
+                let p = d[(t, t)];
+                let indivisible = (t + 1..R).find(|&i| (t + 1..C).any(|j| !(d[(i, j)] % p).is_zero()));
+                let Some(i) = indivisible else {
+                    break;
+                };
+                sub_row(&mut d, t, i, -T::one());
+                sub_row(&mut u, t, i, -T::one());
+            }
+
+            if d[(t, t)].is_negative() {
+                negate_row(&mut d, t);
+                negate_row(&mut u, t);
+            }
+        }
+        (d, u, v)
+    }
+}
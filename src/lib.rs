@@ -8,15 +8,52 @@
 //! - Matrix addition: `A + B` and `A += B`
 //! - Matrix subtraction: `A - B` and `A -= B`
 //! - Matrix multiplication: `A * B`
+//! - Negation: `-A`
+//! - Element-wise remainder: `A % B` and `A %= B`
+//! - Element-wise bitwise operations on integer elements: `A & B`, `A | B`, `A ^ B`, `A << B`, `A >> B`, `!A`
+//!   and their assign forms
 //!
 //! Scalar operations are also supported.
 //! - Scalar addition: `A + s` and `A += s`
 //! - Scalar subtraction: `A - s` and `A -= s`
 //! - Scalar multiplication: `A * s` and `A *= s`
 //! - Scalar division: `A / s` and `A /= s`
+//! - Scalar remainder, bitwise and shift operations: `A % s`, `A & s`, `A << s`, etc.
+//!
+//! Elements can be converted with [`MatrixRef::cast`], [`MatrixRef::cast_saturating`] and
+//! [`MatrixRef::cast_lossy`], and [`MatrixRef::matmul_acc`] accumulates a product in a wider type.
+//!
+//! Integer overflow can be detected with [`MatrixRef::checked_add`], [`MatrixRef::checked_matmul`] and
+//! friends, or avoided with the `wrapping_*` and `saturating_*` variants.
+//!
+//! The operators only require the element type to be [`Clone`], so arbitrary-precision and rational
+//! types such as `BigRational` can be used alongside the primitive types. [`Matrix::det`] is exact
+//! for such types.
+//!
+//! Integer matrices have exact [`Matrix::det_bareiss`], [`Matrix::hermite_normal_form`] and
+//! [`Matrix::smith_normal_form`], which return the unimodular transforms alongside the result.
+//!
+//! Square floating-point matrices have the matrix functions [`Matrix::expm`], [`Matrix::logm`],
+//! [`Matrix::sqrtm`] and [`Matrix::powi`].
+//!
+//! Triangular systems are solved by substitution with [`Matrix::solve_lower`], [`Matrix::solve_upper`]
+//! and their unit-diagonal and transposed variants, tridiagonal systems with [`solve_tridiagonal`] and
+//! banded systems with [`Matrix::solve_banded`]. The structured types [`Diagonal`], [`UpperTriangular`],
+//! [`LowerTriangular`] and [`Symmetric`] carry their invariant, and their products with vectors, matrices
+//! and views skip the known zeros or, for [`Symmetric`], read each mirrored pair once.
 //!
 //! The [`DotProduct`] trait is implemented for [`Vector`] and [`RowVector`].
 //!
+//! The [`MatrixRef`] and [`MatrixMut`] traits are implemented by [`Matrix`] and its views, and
+//! [`VectorRef`] and [`VectorMut`] by the vector types, their views and the diagonal views of
+//! [`Matrix`], for writing generic code.
+//! Floating-point results can be compared with [`MatrixRef::approx_eq_rel`] or [`assert_matrix_eq!`],
+//! which lists the elements that differ.
+//!
+//! With the `alloc` feature, [`SparseMatrix`] stores large, mostly-zero matrices in COO, CSR or CSC format.
+//! The iterative solvers [`cg`], [`pcg`], [`gmres`] and [`bicgstab`] accept any [`LinearOperator`],
+//! including matrices, sparse matrices and closures.
+//!
 //! # Example
 //!
 //! ```
@@ -37,6 +74,44 @@
 //! assert_eq!(c, Matrix::from([[14.0], [32.0], [50.0]]));
 //!
 //! ```
+//!
+//! # Features
+//!
+//! - `std` (default): Links against the standard library. Without it, the crate is `#![no_std]`.
+//! - `alloc`: Enables APIs that need heap allocation, such as [`SparseMatrix`]. Implied by `std`.
+//! - `fmt` (default): Implements [`Display`](core::fmt::Display) for all types.
+//! - `rand` (default): Implements [`IntRandom`] and [`FloatRandom`]. Requires `std`.
+//! - `libm`: Provides floating-point methods such as `magnitude` and the rotation matrices without `std`.
+//! - `complex`: Implements [`Conjugate`] for [`Complex`](num_complex::Complex) elements and re-exports it, so that
+//!   [`DotProduct::dotc`], `magnitude` and [`Matrix::h`] are Hermitian.
+//! - `rayon`: Runs large `f32`/`f64` products, element-wise operations, dot products and norms, and the
+//!   elimination behind [`Matrix::det`] and the matrix functions on multiple threads. See [`Parallelism`].
+//!   Requires `std`.
+//! - `bytemuck`: Implements [`Zeroable`](bytemuck::Zeroable) and [`TransparentWrapper`](bytemuck::TransparentWrapper)
+//!   for [`Matrix`], [`MatrixCM`], [`Vector`] and [`RowVector`], so they can be cast to and from bytes through
+//!   their underlying arrays. The types are not `Copy`, so they cannot implement [`Pod`](bytemuck::Pod);
+//!   instead, `as_bytes` and `slice_as_bytes` view them as bytes without copying, e.g. for GPU uploads.
+//! - `blas`: Hands large `f32`/`f64` matrix and matrix-vector products to CBLAS, and the LU factorizations
+//!   behind [`Matrix::det`] and the matrix functions as well as the triangular solves to LAPACKE. Only the
+//!   `cblas-sys` and `lapacke-sys` bindings are pulled in, so a provider exporting both must be linked
+//!   separately: enable `openblas-system`, or depend on a crate such as `openblas-src` or `intel-mkl-src` and
+//!   add `extern crate` for it. The iterative solvers always run natively.
+//! - `openblas-system`: Enables `blas` and links the OpenBLAS installed on the system through `openblas-src`.
+//!   Where LAPACKE is packaged separately, as on Debian and Ubuntu, it must be linked as well.
+//! - `rational`: Implements [`Conjugate`] for [`Ratio`](num_rational::Ratio) elements and re-exports it, so that
+//!   [`DotProduct::dotc`] works on exact rational vectors. [`DotProduct::dot`] does not need it.
+//! - `half`: Implements [`Conjugate`] for the [`f16`](half::f16) and [`bf16`](half::bf16) half-precision
+//!   floats and re-exports them. Use [`MatrixRef::matmul_acc`] to multiply them in `f32`.
+//! - `approx`: Implements [`AbsDiffEq`](approx::AbsDiffEq), [`RelativeEq`](approx::RelativeEq) and
+//!   [`UlpsEq`](approx::UlpsEq) for the matrix and vector types and their views.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "openblas-system")]
+extern crate openblas_src;
 
 mod vector;
 mod vector_view;
@@ -47,16 +122,49 @@ mod row_vector_view;
 mod row_vector_view_mut;
 
 mod matrix;
+mod matrix_cm;
+mod matrix_strided_view;
+mod matrix_strided_view_mut;
 mod matrix_transpose_view;
 mod matrix_transpose_view_mut;
 mod matrix_view;
 mod matrix_view_mut;
 
+mod diagonal;
+mod symmetric;
+mod triangular;
+
+mod cast;
+mod compare;
+mod direct;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod functions;
+mod integer;
 mod ops;
+mod overflow;
+mod parallel;
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+mod solvers;
+#[cfg(feature = "alloc")]
+mod sparse;
 mod traits;
 
+#[cfg(feature = "rayon")]
+pub use self::parallel::{
+    parallel_threshold, parallelism, set_parallel_threshold, set_parallelism, with_parallelism, Parallelism,
+};
+pub use self::traits::Conjugate;
 pub use self::traits::DotProduct;
+pub use self::traits::{LinearOperator, TransposeOperator};
+pub use self::traits::{MatrixMut, MatrixRef, VectorMut, VectorRef};
+
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::compare::{approx_eq_abs, approx_eq_rel, assert_matrix_eq, assert_vector_eq};
+}
+#[cfg(feature = "rand")]
 pub use self::traits::FloatRandom;
+#[cfg(feature = "rand")]
 pub use self::traits::IntRandom;
 
 pub use self::vector::Vector;
@@ -72,7 +180,31 @@ pub use self::row_vector_view_mut::RowVectorViewMut;
 pub use self::matrix::Matrix;
 pub use self::matrix::Matrix2;
 pub use self::matrix::Matrix3;
+pub use self::matrix_cm::MatrixCM;
+pub use self::matrix_strided_view::MatrixStridedView;
+pub use self::matrix_strided_view_mut::MatrixStridedViewMut;
 pub use self::matrix_transpose_view::MatrixTransposeView;
 pub use self::matrix_transpose_view_mut::MatrixTransposeViewMut;
 pub use self::matrix_view::MatrixView;
 pub use self::matrix_view_mut::MatrixViewMut;
+
+pub use self::diagonal::Diagonal;
+pub use self::symmetric::Symmetric;
+pub use self::triangular::{LowerTriangular, UpperTriangular};
+
+pub use self::direct::solve_tridiagonal;
+
+#[cfg(feature = "alloc")]
+pub use self::sparse::{SparseFormat, SparseMatrix};
+
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+pub use self::solvers::{bicgstab, cg, gmres, pcg, ConvergenceReport};
+
+#[cfg(feature = "complex")]
+pub use num_complex::Complex;
+
+#[cfg(feature = "rational")]
+pub use num_rational::Ratio;
+
+#[cfg(feature = "half")]
+pub use half::{bf16, f16};
@@ -1,24 +1,45 @@
-use num_traits::{Float, One, PrimInt, Zero};
+#[cfg(feature = "fmt")]
+use core::fmt;
+#[cfg(any(feature = "std", feature = "libm"))]
+use core::ops::Neg;
+use core::ops::{Add, Index, IndexMut, Mul};
+use core::ptr::NonNull;
+#[cfg(any(feature = "std", feature = "libm"))]
+use num_traits::Float;
+#[cfg(feature = "rand")]
+use num_traits::PrimInt;
+use num_traits::{Inv, One, Signed, Zero};
+#[cfg(feature = "rand")]
 use rand::distributions::uniform::SampleUniform;
+#[cfg(feature = "rand")]
 use rand::distributions::{Distribution, Standard, Uniform};
+#[cfg(feature = "rand")]
 use rand::Rng;
-use std::fmt;
-use std::ops::{Index, IndexMut, Neg};
 
+use crate::matrix_cm::MatrixCM;
+use crate::matrix_strided_view::{axis_strides, diagonal_strides, MatrixStridedView};
+use crate::matrix_strided_view_mut::MatrixStridedViewMut;
 use crate::matrix_transpose_view::MatrixTransposeView;
 use crate::matrix_transpose_view_mut::MatrixTransposeViewMut;
 use crate::matrix_view::MatrixView;
 use crate::matrix_view_mut::MatrixViewMut;
+use crate::ops::{lapack, linalg};
 use crate::row_vector::RowVector;
 use crate::row_vector_view::RowVectorView;
 use crate::row_vector_view_mut::RowVectorViewMut;
+use crate::traits::{Conjugate, Contiguous, LinearOperator, MatrixMut, MatrixRef, Strided, StridedRef, TransposeOperator};
+#[cfg(feature = "rand")]
 use crate::traits::{FloatRandom, IntRandom};
 use crate::vector::Vector;
 use crate::vector_view::VectorView;
 use crate::vector_view_mut::VectorViewMut;
 
 /// A static matrix type.
+///
+/// The elements are stored in row-major order, and a `Matrix<T, R, C>` is guaranteed to have the
+/// same memory layout as `[[T; C]; R]`.
 #[derive(Debug, Clone)]
+#[repr(transparent)]
 pub struct Matrix<T, const R: usize, const C: usize> {
     data: [[T; C]; R],
 }
@@ -32,7 +53,7 @@ pub type Matrix3<T> = Matrix<T, 3, 3>;
 impl<T: Default, const R: usize, const C: usize> Default for Matrix<T, R, C> {
     fn default() -> Self {
         Self {
-            data: std::array::from_fn(|_| std::array::from_fn(|_| T::default())),
+            data: core::array::from_fn(|_| core::array::from_fn(|_| T::default())),
         }
     }
 }
@@ -123,9 +144,128 @@ impl<T, const R: usize, const C: usize> Matrix<T, R, C> {
     pub fn cols(&self) -> usize {
         C
     }
+
+    /// Returns the elements of the [`Matrix`] as a slice in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(mat.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    /// ```
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        self.data.as_flattened()
+    }
+
+    /// Returns the elements of the [`Matrix`] as a mutable slice in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mut mat = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+    /// mat.as_mut_slice()[4] = 0;
+    /// assert_eq!(mat, Matrix::from([[1, 2, 3], [4, 0, 6]]));
+    /// ```
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.data.as_flattened_mut()
+    }
+
+    /// Returns a raw pointer to the first element of the [`Matrix`].
+    ///
+    /// The `R * C` elements are contiguous and in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[1, 2], [3, 4]]);
+    /// let ptr = mat.as_ptr();
+    /// assert_eq!(unsafe { *ptr.add(2) }, 3);
+    /// ```
+    #[inline]
+    pub fn as_ptr(&self) -> *const T {
+        self.data.as_ptr().cast()
+    }
+
+    /// Returns a mutable raw pointer to the first element of the [`Matrix`].
+    ///
+    /// The `R * C` elements are contiguous and in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mut mat = Matrix::from([[1, 2], [3, 4]]);
+    /// let ptr = mat.as_mut_ptr();
+    /// unsafe { *ptr.add(1) = 0 };
+    /// assert_eq!(mat, Matrix::from([[1, 0], [3, 4]]));
+    /// ```
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.data.as_mut_ptr().cast()
+    }
+
+    /// Reinterprets the [`Matrix`] as its column-major transpose.
+    ///
+    /// A row-major RxC matrix and a column-major CxR matrix share the same layout, so this
+    /// does not copy or move any elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{Matrix, MatrixCM};
+    ///
+    /// let mat = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(mat.as_transposed_cm(), &MatrixCM::from([[1, 4], [2, 5], [3, 6]]));
+    /// assert_eq!(mat.as_transposed_cm().as_slice(), mat.as_slice());
+    /// ```
+    #[inline]
+    pub fn as_transposed_cm(&self) -> &MatrixCM<T, C, R> {
+        // SAFETY: both types are `repr(transparent)` over `[[T; C]; R]`
+        unsafe { &*(self as *const Self).cast::<MatrixCM<T, C, R>>() }
+    }
+
+    /// Reinterprets the [`Matrix`] as its mutable column-major transpose.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mut mat = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+    /// mat.as_transposed_cm_mut()[(2, 0)] = 0;
+    /// assert_eq!(mat, Matrix::from([[1, 2, 0], [4, 5, 6]]));
+    /// ```
+    #[inline]
+    pub fn as_transposed_cm_mut(&mut self) -> &mut MatrixCM<T, C, R> {
+        // SAFETY: both types are `repr(transparent)` over `[[T; C]; R]`
+        unsafe { &mut *(self as *mut Self).cast::<MatrixCM<T, C, R>>() }
+    }
+
+    /// Converts the [`Matrix`] into its column-major transpose without rearranging any elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{Matrix, MatrixCM};
+    ///
+    /// let mat = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(mat.into_transposed_cm(), MatrixCM::from([[1, 4], [2, 5], [3, 6]]));
+    /// ```
+    pub fn into_transposed_cm(self) -> MatrixCM<T, C, R> {
+        MatrixCM::from_columns(self.data)
+    }
 }
 
-impl<T: Copy> Matrix<T, 1, 1> {
+impl<T> Matrix<T, 1, 1> {
     /// Converts a 1x1 [`Matrix`] into its scalar value.
     ///
     /// # Examples
@@ -137,11 +277,12 @@ impl<T: Copy> Matrix<T, 1, 1> {
     /// assert_eq!(mat.into(), 42);
     /// ```
     pub fn into(self) -> T {
-        self[(0, 0)]
+        let [[value]] = self.data;
+        value
     }
 }
 
-impl<T: Copy + Zero, const R: usize, const C: usize> Matrix<T, R, C> {
+impl<T: Clone + Zero, const R: usize, const C: usize> Matrix<T, R, C> {
     /// Creates a new [`Matrix`] filled with zeros.
     ///
     /// This method initializes a new [`Matrix`] of size RxC, where each element is set to zero.
@@ -155,13 +296,11 @@ impl<T: Copy + Zero, const R: usize, const C: usize> Matrix<T, R, C> {
     /// assert_eq!(mat, Matrix::from([[0.0, 0.0, 0.0], [0.0, 0.0, 0.0]]));
     /// ```
     pub fn zeros() -> Self {
-        Self {
-            data: [[T::zero(); C]; R],
-        }
+        Self::fill(T::zero())
     }
 }
 
-impl<T: Copy + One, const R: usize, const C: usize> Matrix<T, R, C> {
+impl<T: Clone + One, const R: usize, const C: usize> Matrix<T, R, C> {
     /// Creates a new [`Matrix`] filled with ones.
     ///
     /// This method initializes a new [`Matrix`] of size RxC, where each element is set to one.
@@ -175,13 +314,11 @@ impl<T: Copy + One, const R: usize, const C: usize> Matrix<T, R, C> {
     /// assert_eq!(mat, Matrix::from([[1.0, 1.0, 1.0], [1.0, 1.0, 1.0]]));
     /// ```
     pub fn ones() -> Self {
-        Self {
-            data: [[T::one(); C]; R],
-        }
+        Self::fill(T::one())
     }
 }
 
-impl<T: Copy + Zero + One, const R: usize, const C: usize> Matrix<T, R, C> {
+impl<T: Clone + Zero + One, const R: usize, const C: usize> Matrix<T, R, C> {
     /// Creates a new identity [`Matrix`].
     ///
     /// This method initializes a new [`Matrix`] of size RxC, where diagonal elements are set to one and all others to zero.
@@ -195,7 +332,7 @@ impl<T: Copy + Zero + One, const R: usize, const C: usize> Matrix<T, R, C> {
     /// assert_eq!(mat, Matrix::from([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]));
     /// ```
     pub fn eye() -> Self {
-        let mut data = [[T::zero(); C]; R];
+        let mut data: [[T; C]; R] = core::array::from_fn(|_| core::array::from_fn(|_| T::zero()));
         for (i, row) in data.iter_mut().enumerate() {
             row[i] = T::one();
         }
@@ -203,7 +340,7 @@ impl<T: Copy + Zero + One, const R: usize, const C: usize> Matrix<T, R, C> {
     }
 }
 
-impl<T: Copy, const R: usize, const C: usize> Matrix<T, R, C> {
+impl<T: Clone, const R: usize, const C: usize> Matrix<T, R, C> {
     /// Creates a new [`Matrix`] filled with a specified value.
     ///
     /// This method initializes a new [`Matrix`] of size RxC, where each element is set to the provided value.
@@ -218,11 +355,328 @@ impl<T: Copy, const R: usize, const C: usize> Matrix<T, R, C> {
     /// ```
     pub fn fill(value: T) -> Self {
         Self {
-            data: [[value; C]; R],
+            data: core::array::from_fn(|_| core::array::from_fn(|_| value.clone())),
         }
     }
 }
 
+impl<T: Clone, const R: usize, const C: usize> Matrix<T, R, C> {
+    /// Concatenates two matrices horizontally.
+    ///
+    /// This method returns a new [`Matrix`] with the columns of `left` followed by the columns of `right`.
+    /// Returns `None` if `C1 + C2` is not equal to the number of columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let a = Matrix::from([[1, 2], [3, 4]]);
+    /// let b = Matrix::from([[5], [6]]);
+    /// let mat = Matrix::<i32, 2, 3>::hstack(&a, &b).unwrap();
+    /// assert_eq!(mat, Matrix::from([[1, 2, 5], [3, 4, 6]]));
+    /// ```
+    pub fn hstack<const C1: usize, const C2: usize>(
+        left: &impl MatrixRef<T, R, C1>,
+        right: &impl MatrixRef<T, R, C2>,
+    ) -> Option<Self> {
+        if C1 + C2 != C {
+            return None;
+        }
+        Some(Self::from(core::array::from_fn(|i| {
+            core::array::from_fn(|j| if j < C1 { left[(i, j)].clone() } else { right[(i, j - C1)].clone() })
+        })))
+    }
+
+    /// Concatenates two matrices vertically.
+    ///
+    /// This method returns a new [`Matrix`] with the rows of `top` followed by the rows of `bottom`.
+    /// Returns `None` if `R1 + R2` is not equal to the number of rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let a = Matrix::from([[1, 2], [3, 4]]);
+    /// let b = Matrix::from([[5, 6]]);
+    /// let mat = Matrix::<i32, 3, 2>::vstack(&a, &b).unwrap();
+    /// assert_eq!(mat, Matrix::from([[1, 2], [3, 4], [5, 6]]));
+    /// ```
+    pub fn vstack<const R1: usize, const R2: usize>(
+        top: &impl MatrixRef<T, R1, C>,
+        bottom: &impl MatrixRef<T, R2, C>,
+    ) -> Option<Self> {
+        if R1 + R2 != R {
+            return None;
+        }
+        Some(Self::from(core::array::from_fn(|i| {
+            core::array::from_fn(|j| if i < R1 { top[(i, j)].clone() } else { bottom[(i - R1, j)].clone() })
+        })))
+    }
+
+    /// Creates a new [`Matrix`] from a 2x2 grid of blocks.
+    ///
+    /// The blocks are laid out as `[[a11, a12], [a21, a22]]`, so `a11` is `K x P`, `a12` is `K x Q`,
+    /// `a21` is `L x P` and `a22` is `L x Q`. Returns `None` if `K + L` is not equal to the number
+    /// of rows or `P + Q` is not equal to the number of columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let a = Matrix::from([[1, 2], [3, 4]]);
+    /// let b = Matrix::from([[5], [6]]);
+    /// let c = Matrix::from([[0, 0]]);
+    /// let d = Matrix::from([[1]]);
+    /// let mat = Matrix::<i32, 3, 3>::from_blocks(&a, &b, &c, &d).unwrap();
+    /// assert_eq!(mat, Matrix::from([[1, 2, 5], [3, 4, 6], [0, 0, 1]]));
+    /// ```
+    pub fn from_blocks<const K: usize, const L: usize, const P: usize, const Q: usize>(
+        a11: &impl MatrixRef<T, K, P>,
+        a12: &impl MatrixRef<T, K, Q>,
+        a21: &impl MatrixRef<T, L, P>,
+        a22: &impl MatrixRef<T, L, Q>,
+    ) -> Option<Self> {
+        if K + L != R || P + Q != C {
+            return None;
+        }
+        Some(Self::from(core::array::from_fn(|i| {
+            core::array::from_fn(|j| match (i < K, j < P) {
+                (true, true) => a11[(i, j)].clone(),
+                (true, false) => a12[(i, j - P)].clone(),
+                (false, true) => a21[(i - K, j)].clone(),
+                (false, false) => a22[(i - K, j - P)].clone(),
+            })
+        })))
+    }
+}
+
+impl<T: Clone + Zero, const R: usize, const C: usize> Matrix<T, R, C> {
+    /// Creates a new block diagonal [`Matrix`].
+    ///
+    /// This method places `a` in the top-left corner and `b` in the bottom-right corner, with zeros
+    /// everywhere else. Returns `None` if `R1 + R2` is not equal to the number of rows or `C1 + C2`
+    /// is not equal to the number of columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let a = Matrix::from([[1, 2], [3, 4]]);
+    /// let b = Matrix::from([[5]]);
+    /// let mat = Matrix::<i32, 3, 3>::block_diag(&a, &b).unwrap();
+    /// assert_eq!(mat, Matrix::from([[1, 2, 0], [3, 4, 0], [0, 0, 5]]));
+    /// ```
+    pub fn block_diag<const R1: usize, const C1: usize, const R2: usize, const C2: usize>(
+        a: &impl MatrixRef<T, R1, C1>,
+        b: &impl MatrixRef<T, R2, C2>,
+    ) -> Option<Self> {
+        if R1 + R2 != R || C1 + C2 != C {
+            return None;
+        }
+        Some(Self::from(core::array::from_fn(|i| {
+            core::array::from_fn(|j| match (i < R1, j < C1) {
+                (true, true) => a[(i, j)].clone(),
+                (false, false) => b[(i - R1, j - C1)].clone(),
+                _ => T::zero(),
+            })
+        })))
+    }
+}
+
+impl<T: Clone + Mul<T, Output = T>, const R: usize, const C: usize> Matrix<T, R, C> {
+    /// Returns the Kronecker product of the [`Matrix`] with another matrix.
+    ///
+    /// The result is the `RO x CO` block matrix whose `(i, j)` block is `self[(i, j)] * other`.
+    /// Returns `None` if `RO` is not equal to `R * R2` or `CO` is not equal to `C * C2`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let a = Matrix::from([[1, 2], [3, 4]]);
+    /// let b = Matrix::from([[0, 1], [1, 0]]);
+    /// let mat: Matrix<i32, 4, 4> = a.kron(&b).unwrap();
+    /// assert_eq!(mat, Matrix::from([[0, 1, 0, 2], [1, 0, 2, 0], [0, 3, 0, 4], [3, 0, 4, 0]]));
+    /// ```
+    pub fn kron<const R2: usize, const C2: usize, const RO: usize, const CO: usize>(
+        &self,
+        other: &impl MatrixRef<T, R2, C2>,
+    ) -> Option<Matrix<T, RO, CO>> {
+        if R * R2 != RO || C * C2 != CO {
+            return None;
+        }
+        Some(Matrix::from(core::array::from_fn(|i| {
+            core::array::from_fn(|j| self.data[i / R2][j / C2].clone() * other[(i % R2, j % C2)].clone())
+        })))
+    }
+}
+
+impl<T: Clone + Signed + PartialOrd + Inv<Output = T>, const N: usize> Matrix<T, N, N> {
+    /// Returns the determinant of the [`Matrix`].
+    ///
+    /// The determinant is computed by Gaussian elimination with partial pivoting. No rounding is
+    /// introduced beyond that of `T` itself, so the result is exact for rational element types such as
+    /// `BigRational`. Integer matrices do not implement [`Inv`], since elimination would truncate;
+    /// convert them to a rational type first. With the `blas` feature, large `f32`/`f64` matrices are
+    /// factorized by LAPACK instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[2.0, 1.0], [1.0, 3.0]]);
+    /// assert_eq!(mat.det(), 5.0);
+    ///
+    /// let singular = Matrix::from([[1.0, 2.0], [2.0, 4.0]]);
+    /// assert_eq!(singular.det(), 0.0);
+    /// ```
+    pub fn det(&self) -> T {
+        let mut a = self.clone();
+        if let Some(det) = lapack::det::<T, N>(a.as_mut_slice()).or_else(|| linalg::det::<T, N>(a.as_mut_slice())) {
+            return det;
+        }
+
+        let mut det = T::one();
+        for k in 0..N {
+            let pivot = (k + 1..N).fold(k, |p, i| if a[(i, k)].abs() > a[(p, k)].abs() { i } else { p });
+            if a[(pivot, k)].is_zero() {
+                return T::zero();
+            }
+            if pivot != k {
+                a.data.swap(pivot, k);
+                det = -det;
+            }
+
+            let inv = a[(k, k)].clone().inv();
+            det = det * a[(k, k)].clone();
+            for i in k + 1..N {
+                let factor = a[(i, k)].clone() * inv.clone();
+                for j in k + 1..N {
+                    a[(i, j)] = a[(i, j)].clone() - factor.clone() * a[(k, j)].clone();
+                }
+            }
+        }
+        det
+    }
+}
+
+impl<T: Clone, const R: usize, const C: usize> Matrix<T, R, C> {
+    /// Creates a new [`Matrix`] from a slice in row-major order.
+    ///
+    /// This matches the layout returned by [`Matrix::as_slice`]. Returns `None` if the length of
+    /// the slice is not equal to `R * C`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mat = Matrix::<i32, 2, 3>::from_row_slice(&[1, 2, 3, 4, 5, 6]).unwrap();
+    /// assert_eq!(mat, Matrix::from([[1, 2, 3], [4, 5, 6]]));
+    /// assert!(Matrix::<i32, 2, 3>::from_row_slice(&[1, 2, 3]).is_none());
+    /// ```
+    pub fn from_row_slice(slice: &[T]) -> Option<Self> {
+        if slice.len() != R * C {
+            return None;
+        }
+        Some(Self::from(core::array::from_fn(|i| {
+            core::array::from_fn(|j| slice[i * C + j].clone())
+        })))
+    }
+
+    /// Creates a new [`Matrix`] from a slice in column-major order.
+    ///
+    /// Returns `None` if the length of the slice is not equal to `R * C`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mat = Matrix::<i32, 2, 3>::from_column_slice(&[1, 2, 3, 4, 5, 6]).unwrap();
+    /// assert_eq!(mat, Matrix::from([[1, 3, 5], [2, 4, 6]]));
+    /// assert!(Matrix::<i32, 2, 3>::from_column_slice(&[1, 2, 3]).is_none());
+    /// ```
+    pub fn from_column_slice(slice: &[T]) -> Option<Self> {
+        if slice.len() != R * C {
+            return None;
+        }
+        Some(Self::from(core::array::from_fn(|i| {
+            core::array::from_fn(|j| slice[j * R + i].clone())
+        })))
+    }
+
+    /// Returns a copy of the [`Matrix`] with a new shape.
+    ///
+    /// The elements keep their row-major order. Returns `None` if `R2 * C2` is not equal to `R * C`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(mat.reshape::<3, 2>().unwrap(), Matrix::from([[1, 2], [3, 4], [5, 6]]));
+    /// assert!(mat.reshape::<2, 2>().is_none());
+    /// ```
+    pub fn reshape<const R2: usize, const C2: usize>(&self) -> Option<Matrix<T, R2, C2>> {
+        if R2 * C2 != R * C {
+            return None;
+        }
+        Some(Matrix::from(core::array::from_fn(|i| {
+            core::array::from_fn(|j| self[i * C2 + j].clone())
+        })))
+    }
+
+    /// Returns the elements of the [`Matrix`] as a [`Vector`] in row-major order.
+    ///
+    /// Returns `None` if `N` is not equal to `R * C`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{Matrix, Vector};
+    ///
+    /// let mat = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(mat.flatten::<6>().unwrap(), Vector::from([1, 2, 3, 4, 5, 6]));
+    /// assert!(mat.flatten::<4>().is_none());
+    /// ```
+    pub fn flatten<const N: usize>(&self) -> Option<Vector<T, N>> {
+        if N != R * C {
+            return None;
+        }
+        Some(Vector::from(core::array::from_fn(|i| self[i].clone())))
+    }
+
+    /// Returns the columns of the [`Matrix`] stacked into a [`Vector`].
+    ///
+    /// This is the `vec` operator, i.e. the elements in column-major order.
+    /// Returns `None` if `N` is not equal to `R * C`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{Matrix, Vector};
+    ///
+    /// let mat = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(mat.vec::<6>().unwrap(), Vector::from([1, 4, 2, 5, 3, 6]));
+    /// assert!(mat.vec::<4>().is_none());
+    /// ```
+    pub fn vec<const N: usize>(&self) -> Option<Vector<T, N>> {
+        if N != R * C {
+            return None;
+        }
+        Some(Vector::from(core::array::from_fn(|i| self[(i % R, i / R)].clone())))
+    }
+}
+
+#[cfg(feature = "rand")]
 impl<T: PrimInt, const R: usize, const C: usize> IntRandom for Matrix<T, R, C>
 where
     Standard: Distribution<T>,
@@ -230,11 +684,12 @@ where
     fn random() -> Self {
         let mut rng = rand::thread_rng();
         Self {
-            data: std::array::from_fn(|_| std::array::from_fn(|_| rng.gen())),
+            data: core::array::from_fn(|_| core::array::from_fn(|_| rng.gen())),
         }
     }
 }
 
+#[cfg(feature = "rand")]
 impl<T: Float + SampleUniform, const R: usize, const C: usize> FloatRandom for Matrix<T, R, C>
 where
     Standard: Distribution<T>,
@@ -243,7 +698,7 @@ where
         let mut rng = rand::thread_rng();
         let dist = Uniform::new_inclusive(-T::one(), T::one());
         Self {
-            data: std::array::from_fn(|_| std::array::from_fn(|_| dist.sample(&mut rng))),
+            data: core::array::from_fn(|_| core::array::from_fn(|_| dist.sample(&mut rng))),
         }
     }
 }
@@ -262,7 +717,7 @@ impl<T, const R: usize, const C: usize> Matrix<T, R, C> {
     /// let transposed = mat.t();
     /// assert_eq!(transposed, Matrix::from([[1, 4], [2, 5], [3, 6]]));
     /// ```
-    pub fn t(&self) -> MatrixTransposeView<T, R, C, C, R> {
+    pub fn t(&self) -> MatrixTransposeView<'_, T, R, C, C, R> {
         MatrixTransposeView::new(self, (0, 0))
     }
 
@@ -280,11 +735,57 @@ impl<T, const R: usize, const C: usize> Matrix<T, R, C> {
     /// transposed[(1, 0)] = 10;
     /// assert_eq!(mat, Matrix::from([[1, 10, 3], [4, 5, 6]]));
     /// ```
-    pub fn t_mut(&mut self) -> MatrixTransposeViewMut<T, R, C, C, R> {
+    pub fn t_mut(&mut self) -> MatrixTransposeViewMut<'_, T, R, C, C, R> {
         MatrixTransposeViewMut::new(self, (0, 0))
     }
 }
 
+impl<T: Conjugate, const R: usize, const C: usize> Matrix<T, R, C> {
+    /// Returns the element-wise complex conjugate of the [`Matrix`].
+    ///
+    /// Real numbers are their own conjugate, so for real element types this is a copy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "complex")]
+    /// # {
+    /// use ferrix::{Complex, Matrix};
+    ///
+    /// let mat = Matrix::from([[Complex::new(1.0, 2.0), Complex::new(3.0, -4.0)]]);
+    /// assert_eq!(mat.conj(), Matrix::from([[Complex::new(1.0, -2.0), Complex::new(3.0, 4.0)]]));
+    /// # }
+    /// ```
+    pub fn conj(&self) -> Self {
+        Self {
+            data: core::array::from_fn(|i| core::array::from_fn(|j| self.data[i][j].clone().conj())),
+        }
+    }
+
+    /// Returns the conjugate transpose of the [`Matrix`].
+    ///
+    /// The conjugated elements have to be computed, so unlike [`t`](Self::t) this returns a new
+    /// [`Matrix`] rather than a view. For real element types it is the same as copying the transpose.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "complex")]
+    /// # {
+    /// use ferrix::{Complex, Matrix};
+    ///
+    /// let mat = Matrix::from([[Complex::new(1.0, 2.0), Complex::new(3.0, -4.0)]]);
+    /// let adjoint = mat.h();
+    /// assert_eq!(adjoint, Matrix::from([[Complex::new(1.0, -2.0)], [Complex::new(3.0, 4.0)]]));
+    /// # }
+    /// ```
+    pub fn h(&self) -> Matrix<T, C, R> {
+        Matrix {
+            data: core::array::from_fn(|i| core::array::from_fn(|j| self.data[j][i].clone().conj())),
+        }
+    }
+}
+
 impl<T, const R: usize, const C: usize> Matrix<T, R, C> {
     /// Returns a view of [`Matrix`].
     ///
@@ -303,7 +804,7 @@ impl<T, const R: usize, const C: usize> Matrix<T, R, C> {
     pub fn view<const VR: usize, const VC: usize>(
         &self,
         start: (usize, usize),
-    ) -> Option<MatrixView<T, R, C, VR, VC>> {
+    ) -> Option<MatrixView<'_, T, R, C, VR, VC>> {
         if start.0 + VR > R || start.1 + VC > C {
             return None;
         }
@@ -328,14 +829,315 @@ impl<T, const R: usize, const C: usize> Matrix<T, R, C> {
     pub fn view_mut<const VR: usize, const VC: usize>(
         &mut self,
         start: (usize, usize),
-    ) -> Option<MatrixViewMut<T, R, C, VR, VC>> {
+    ) -> Option<MatrixViewMut<'_, T, R, C, VR, VC>> {
         if start.0 + VR > R || start.1 + VC > C {
             return None;
         }
         Some(MatrixViewMut::new(self, start))
     }
+
+    /// Returns a strided view of [`Matrix`].
+    ///
+    /// This method returns a [`MatrixStridedView`] of size `VR x VC` whose element `(i, j)` is the
+    /// element `(start.0 + i * step.0, start.1 + j * step.1)` of the [`Matrix`]. Negative steps walk
+    /// the matrix in reverse. Returns `None` if a step is zero or the view is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    ///
+    /// // Every other row, with the columns reversed
+    /// let view = mat.strided_view::<2, 3>((0, 2), (2, -1)).unwrap();
+    /// assert_eq!(view, Matrix::from([[3, 2, 1], [9, 8, 7]]));
+    /// ```
+    pub fn strided_view<const VR: usize, const VC: usize>(
+        &self,
+        start: (usize, usize),
+        step: (isize, isize),
+    ) -> Option<MatrixStridedView<'_, T, R, C, VR, VC>> {
+        let (offset, stride) = axis_strides::<R, C, VR, VC>(start, step)?;
+        Some(MatrixStridedView::new(self, offset, stride))
+    }
+
+    /// Returns a mutable strided view of [`Matrix`].
+    ///
+    /// This method returns a [`MatrixStridedViewMut`] laid out like [`Matrix::strided_view`].
+    /// Returns `None` if a step is zero or the view is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mut mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// let mut view = mat.strided_view_mut::<2, 2>((0, 0), (2, 2)).unwrap();
+    /// view[(1, 1)] = 0;
+    /// assert_eq!(mat, Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 0]]));
+    /// ```
+    pub fn strided_view_mut<const VR: usize, const VC: usize>(
+        &mut self,
+        start: (usize, usize),
+        step: (isize, isize),
+    ) -> Option<MatrixStridedViewMut<'_, T, R, C, VR, VC>> {
+        let (offset, stride) = axis_strides::<R, C, VR, VC>(start, step)?;
+        Some(MatrixStridedViewMut::new(self, offset, stride))
+    }
+
+    /// Returns a view of a diagonal of [`Matrix`].
+    ///
+    /// This method returns an `N x 1` [`MatrixStridedView`] over the elements
+    /// `(start.0 + k, start.1 + k)`. Returns `None` if the diagonal is out of bounds.
+    /// The view can also be used as a vector through [`VectorRef`](crate::traits::VectorRef) and
+    /// [`DotProduct`](crate::traits::DotProduct).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{DotProduct, Matrix, Vector, VectorRef};
+    ///
+    /// let mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// assert_eq!(mat.diagonal_view::<3>((0, 0)).unwrap(), Vector::from([1, 5, 9]));
+    /// assert_eq!(mat.diagonal_view::<2>((0, 1)).unwrap(), Vector::from([2, 6]));
+    ///
+    /// let diagonal = mat.diagonal_view::<3>((0, 0)).unwrap();
+    /// assert_eq!((&diagonal).dot(Vector::from([1, 1, 1])), 15);
+    /// assert_eq!(diagonal.to_vector(), Vector::from([1, 5, 9]));
+    /// ```
+    pub fn diagonal_view<const N: usize>(
+        &self,
+        start: (usize, usize),
+    ) -> Option<MatrixStridedView<'_, T, R, C, N, 1>> {
+        let (offset, stride) = diagonal_strides::<R, C, N>(start, false)?;
+        Some(MatrixStridedView::new(self, offset, stride))
+    }
+
+    /// Returns a mutable view of a diagonal of [`Matrix`].
+    ///
+    /// This method returns an `N x 1` [`MatrixStridedViewMut`] over the elements
+    /// `(start.0 + k, start.1 + k)`. Returns `None` if the diagonal is out of bounds.
+    /// The view can also be used as a vector through [`VectorMut`](crate::traits::VectorMut) and
+    /// [`DotProduct`](crate::traits::DotProduct).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mut mat = Matrix::from([[1, 2], [3, 4]]);
+    /// mat.diagonal_view_mut::<2>((0, 0)).unwrap()[1] = 0;
+    /// assert_eq!(mat, Matrix::from([[1, 2], [3, 0]]));
+    /// ```
+    pub fn diagonal_view_mut<const N: usize>(
+        &mut self,
+        start: (usize, usize),
+    ) -> Option<MatrixStridedViewMut<'_, T, R, C, N, 1>> {
+        let (offset, stride) = diagonal_strides::<R, C, N>(start, false)?;
+        Some(MatrixStridedViewMut::new(self, offset, stride))
+    }
+
+    /// Returns a view of an anti-diagonal of [`Matrix`].
+    ///
+    /// This method returns an `N x 1` [`MatrixStridedView`] over the elements
+    /// `(start.0 + k, start.1 - k)`. Returns `None` if the anti-diagonal is out of bounds.
+    /// The view can also be used as a vector through [`VectorRef`](crate::traits::VectorRef) and
+    /// [`DotProduct`](crate::traits::DotProduct).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{Matrix, Vector};
+    ///
+    /// let mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// assert_eq!(mat.anti_diagonal_view::<3>((0, 2)).unwrap(), Vector::from([3, 5, 7]));
+    /// assert!(mat.anti_diagonal_view::<3>((0, 1)).is_none());
+    /// ```
+    pub fn anti_diagonal_view<const N: usize>(
+        &self,
+        start: (usize, usize),
+    ) -> Option<MatrixStridedView<'_, T, R, C, N, 1>> {
+        let (offset, stride) = diagonal_strides::<R, C, N>(start, true)?;
+        Some(MatrixStridedView::new(self, offset, stride))
+    }
+
+    /// Returns a mutable view of an anti-diagonal of [`Matrix`].
+    ///
+    /// This method returns an `N x 1` [`MatrixStridedViewMut`] over the elements
+    /// `(start.0 + k, start.1 - k)`. Returns `None` if the anti-diagonal is out of bounds.
+    /// The view can also be used as a vector through [`VectorMut`](crate::traits::VectorMut) and
+    /// [`DotProduct`](crate::traits::DotProduct).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mut mat = Matrix::from([[1, 2], [3, 4]]);
+    /// mat.anti_diagonal_view_mut::<2>((0, 1)).unwrap()[1] = 0;
+    /// assert_eq!(mat, Matrix::from([[1, 2], [0, 4]]));
+    /// ```
+    pub fn anti_diagonal_view_mut<const N: usize>(
+        &mut self,
+        start: (usize, usize),
+    ) -> Option<MatrixStridedViewMut<'_, T, R, C, N, 1>> {
+        let (offset, stride) = diagonal_strides::<R, C, N>(start, true)?;
+        Some(MatrixStridedViewMut::new(self, offset, stride))
+    }
+
+    /// Splits the [`Matrix`] into two disjoint mutable views at row `K`.
+    ///
+    /// This method returns a [`MatrixViewMut`] over the first `K` rows and one over the remaining
+    /// `L` rows. Returns `None` if `K + L` is not equal to the number of rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mut mat = Matrix::from([[1, 2], [3, 4], [5, 6]]);
+    /// let (mut top, mut bottom) = mat.split_at_mut_rows::<1, 2>().unwrap();
+    /// top[(0, 0)] = 10;
+    /// bottom[(1, 1)] = 60;
+    /// assert_eq!(mat, Matrix::from([[10, 2], [3, 4], [5, 60]]));
+    /// ```
+    pub fn split_at_mut_rows<const K: usize, const L: usize>(
+        &mut self,
+    ) -> Option<(
+        MatrixViewMut<'_, T, R, C, K, C>,
+        MatrixViewMut<'_, T, R, C, L, C>,
+    )> {
+        if K + L != R {
+            return None;
+        }
+        let data = NonNull::from(self);
+        // SAFETY: the two blocks are disjoint and `self` is borrowed mutably for their lifetime
+        unsafe {
+            Some((
+                MatrixViewMut::from_raw(data, (0, 0)),
+                MatrixViewMut::from_raw(data, (K, 0)),
+            ))
+        }
+    }
+
+    /// Splits the [`Matrix`] into two disjoint mutable views at column `K`.
+    ///
+    /// This method returns a [`MatrixViewMut`] over the first `K` columns and one over the remaining
+    /// `L` columns. Returns `None` if `K + L` is not equal to the number of columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mut mat = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+    /// let (mut left, mut right) = mat.split_at_mut_cols::<2, 1>().unwrap();
+    /// left[(1, 0)] = 40;
+    /// right[(0, 0)] = 30;
+    /// assert_eq!(mat, Matrix::from([[1, 2, 30], [40, 5, 6]]));
+    /// ```
+    pub fn split_at_mut_cols<const K: usize, const L: usize>(
+        &mut self,
+    ) -> Option<(
+        MatrixViewMut<'_, T, R, C, R, K>,
+        MatrixViewMut<'_, T, R, C, R, L>,
+    )> {
+        if K + L != C {
+            return None;
+        }
+        let data = NonNull::from(self);
+        // SAFETY: the two blocks are disjoint and `self` is borrowed mutably for their lifetime
+        unsafe {
+            Some((
+                MatrixViewMut::from_raw(data, (0, 0)),
+                MatrixViewMut::from_raw(data, (0, K)),
+            ))
+        }
+    }
+
+    /// Splits the [`Matrix`] into four disjoint mutable views.
+    ///
+    /// The split happens after row `K` and column `P`, so this method returns the top-left
+    /// `K x P`, top-right `K x Q`, bottom-left `L x P` and bottom-right `L x Q` blocks as
+    /// [`MatrixViewMut`]s. Returns `None` if `K + L` is not equal to the number of rows or
+    /// `P + Q` is not equal to the number of columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mut mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// let (a11, mut a12, a21, mut a22) = mat.split_quadrants_mut::<1, 2, 1, 2>().unwrap();
+    /// assert_eq!(a11, Matrix::from([[1]]));
+    /// assert_eq!(a21, Matrix::from([[4], [7]]));
+    /// a12[(0, 1)] = 0;
+    /// a22[(1, 0)] = 0;
+    /// assert_eq!(mat, Matrix::from([[1, 2, 0], [4, 5, 6], [7, 0, 9]]));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn split_quadrants_mut<const K: usize, const L: usize, const P: usize, const Q: usize>(
+        &mut self,
+    ) -> Option<(
+        MatrixViewMut<'_, T, R, C, K, P>,
+        MatrixViewMut<'_, T, R, C, K, Q>,
+        MatrixViewMut<'_, T, R, C, L, P>,
+        MatrixViewMut<'_, T, R, C, L, Q>,
+    )> {
+        if K + L != R || P + Q != C {
+            return None;
+        }
+        let data = NonNull::from(self);
+        // SAFETY: the four blocks are disjoint and `self` is borrowed mutably for their lifetime
+        unsafe {
+            Some((
+                MatrixViewMut::from_raw(data, (0, 0)),
+                MatrixViewMut::from_raw(data, (0, P)),
+                MatrixViewMut::from_raw(data, (K, 0)),
+                MatrixViewMut::from_raw(data, (K, P)),
+            ))
+        }
+    }
+
+    /// Returns an iterator over mutable views of the rows of the [`Matrix`].
+    ///
+    /// Each row is yielded as a [`RowVectorViewMut`], and all of them can be held at once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mut mat = Matrix::from([[1, 2], [3, 4]]);
+    /// for (i, mut row) in mat.rows_mut().enumerate() {
+    ///     row[0] *= 10 * (i + 1);
+    /// }
+    /// assert_eq!(mat, Matrix::from([[10, 2], [60, 4]]));
+    /// ```
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = RowVectorViewMut<'_, [T; C], T, C, C>> + '_ {
+        self.data.iter_mut().map(|row| RowVectorViewMut::new(row, 0))
+    }
+
+    /// Swaps rows `a` and `b` of the [`Matrix`].
+    #[inline]
+    pub(crate) fn swap_rows(&mut self, a: usize, b: usize) {
+        self.data.swap(a, b);
+    }
+
+    /// Returns a raw pointer to the element at `index` without creating a reference to the
+    /// whole [`Matrix`].
+    ///
+    /// # Safety
+    ///
+    /// `data` must point to a valid [`Matrix`]. Panics if `index` is out of bounds.
+    #[inline]
+    pub(crate) unsafe fn ptr_at(data: *mut Self, index: (usize, usize)) -> *mut T {
+        core::ptr::addr_of_mut!((*data).data[index.0][index.1])
+    }
 }
 
+#[cfg(any(feature = "std", feature = "libm"))]
 impl<T: Float + Neg<Output = T>> Matrix<T, 2, 2> {
     /// Creates a 2D rotation [`Matrix`].
     ///
@@ -358,6 +1160,7 @@ impl<T: Float + Neg<Output = T>> Matrix<T, 2, 2> {
     }
 }
 
+#[cfg(any(feature = "std", feature = "libm"))]
 impl<T: Float + Neg<Output = T>> Matrix<T, 3, 3> {
     /// Creates a 3D rotation [`Matrix`] around the X-axis.
     ///
@@ -470,6 +1273,31 @@ impl<T: PartialEq, const R: usize, const C: usize, const VR: usize, const VC: us
     }
 }
 
+// Matrix == MatrixStridedView
+impl<T: PartialEq, const R: usize, const C: usize, const VR: usize, const VC: usize>
+    PartialEq<MatrixStridedView<'_, T, R, C, VR, VC>> for Matrix<T, VR, VC>
+{
+    fn eq(&self, other: &MatrixStridedView<'_, T, R, C, VR, VC>) -> bool {
+        (0..VR).all(|i| (0..VC).all(|j| self[(i, j)] == other[(i, j)]))
+    }
+}
+
+// Matrix == MatrixStridedViewMut
+impl<T: PartialEq, const R: usize, const C: usize, const VR: usize, const VC: usize>
+    PartialEq<MatrixStridedViewMut<'_, T, R, C, VR, VC>> for Matrix<T, VR, VC>
+{
+    fn eq(&self, other: &MatrixStridedViewMut<'_, T, R, C, VR, VC>) -> bool {
+        (0..VR).all(|i| (0..VC).all(|j| self[(i, j)] == other[(i, j)]))
+    }
+}
+
+// Matrix == MatrixCM
+impl<T: PartialEq, const R: usize, const C: usize> PartialEq<MatrixCM<T, R, C>> for Matrix<T, R, C> {
+    fn eq(&self, other: &MatrixCM<T, R, C>) -> bool {
+        (0..R).all(|i| (0..C).all(|j| self[(i, j)] == other[(i, j)]))
+    }
+}
+
 impl<T: Eq, const R: usize, const C: usize> Eq for Matrix<T, R, C> {}
 
 /////////////////////////////////////
@@ -477,6 +1305,7 @@ impl<T: Eq, const R: usize, const C: usize> Eq for Matrix<T, R, C> {}
 //  Display Trait Implementations  //
 /////////////////////////////////////
 
+#[cfg(feature = "fmt")]
 impl<T: fmt::Display, const R: usize, const C: usize> fmt::Display for Matrix<T, R, C> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if f.alternate() {
@@ -506,13 +1335,21 @@ impl<T: fmt::Display, const R: usize, const C: usize> fmt::Display for Matrix<T,
         write!(f, "]")?;
 
         if f.alternate() {
-            write!(f, ", dtype={})", std::any::type_name::<T>())?;
+            write!(f, ", dtype={})", core::any::type_name::<T>())?;
         }
 
         Ok(())
     }
 }
 
+///////////////////////////////////////
+//  MatrixRef Trait Implementations  //
+///////////////////////////////////////
+
+impl<T, const R: usize, const C: usize> MatrixRef<T, R, C> for Matrix<T, R, C> {}
+
+impl<T, const R: usize, const C: usize> MatrixMut<T, R, C> for Matrix<T, R, C> {}
+
 ///////////////////////////////////
 //  Index Trait Implementations  //
 ///////////////////////////////////
@@ -561,6 +1398,56 @@ impl<T, const R: usize, const C: usize> IndexMut<(usize, usize)> for Matrix<T, R
     }
 }
 
+////////////////////////////////////////
+//  Contiguous Trait Implementations  //
+////////////////////////////////////////
+
+impl<T, const R: usize, const C: usize> Contiguous<T> for Matrix<T, R, C> {
+    fn from_fn<F: FnMut(usize) -> T>(mut f: F) -> Self {
+        Self {
+            data: core::array::from_fn(|i| core::array::from_fn(|j| f(i * C + j))),
+        }
+    }
+
+    fn as_slice(&self) -> &[T] {
+        self.data.as_flattened()
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self.data.as_flattened_mut()
+    }
+}
+
+/////////////////////////////////////
+//  Strided Trait Implementations  //
+/////////////////////////////////////
+
+impl<T, const R: usize, const C: usize> Strided<T> for Matrix<T, R, C> {
+    fn strided(&self) -> StridedRef<'_, T> {
+        StridedRef::new(self.data.as_flattened(), C, 1)
+    }
+}
+
+////////////////////////////////////////////
+//  LinearOperator Trait Implementations  //
+////////////////////////////////////////////
+
+impl<T: Clone + Zero + Mul<T, Output = T> + Add<T, Output = T>, const R: usize, const C: usize>
+    LinearOperator<T, R, C> for Matrix<T, R, C>
+{
+    fn apply(&self, x: &Vector<T, C>) -> Vector<T, R> {
+        Vector::from(self * x)
+    }
+}
+
+impl<T: Clone + Zero + Mul<T, Output = T> + Add<T, Output = T>, const R: usize, const C: usize>
+    TransposeOperator<T, R, C> for Matrix<T, R, C>
+{
+    fn apply_transpose(&self, x: &Vector<T, R>) -> Vector<T, C> {
+        Vector::from(self.t() * x)
+    }
+}
+
 //////////////////////////////////
 //  From Trait Implementations  //
 //////////////////////////////////
@@ -571,98 +1458,147 @@ impl<T, const R: usize, const C: usize> From<[[T; C]; R]> for Matrix<T, R, C> {
     }
 }
 
-impl<T: Copy, const C: usize> From<Vector<T, C>> for Matrix<T, C, 1> {
+impl<T: Clone, const C: usize> From<Vector<T, C>> for Matrix<T, C, 1> {
     fn from(vector: Vector<T, C>) -> Self {
         Self {
-            data: std::array::from_fn(|i| [vector[i]]),
+            data: core::array::from_fn(|i| [vector[i].clone()]),
         }
     }
 }
 
-impl<T: Copy, const C: usize> From<RowVector<T, C>> for Matrix<T, 1, C> {
+impl<T: Clone, const C: usize> From<RowVector<T, C>> for Matrix<T, 1, C> {
     fn from(vector: RowVector<T, C>) -> Self {
         Self {
-            data: [std::array::from_fn(|i| vector[i])],
+            data: [core::array::from_fn(|i| vector[i].clone())],
         }
     }
 }
 
-impl<V: Index<usize, Output = T>, T: Copy, const A: usize, const N: usize>
+impl<V: Index<usize, Output = T>, T: Clone, const A: usize, const N: usize>
     From<VectorView<'_, V, T, A, N>> for Matrix<T, N, 1>
 {
     fn from(view: VectorView<'_, V, T, A, N>) -> Self {
         Self {
-            data: std::array::from_fn(|i| [view[i]]),
+            data: core::array::from_fn(|i| [view[i].clone()]),
         }
     }
 }
 
-impl<V: IndexMut<usize, Output = T>, T: Copy, const A: usize, const N: usize>
+impl<V: IndexMut<usize, Output = T>, T: Clone, const A: usize, const N: usize>
     From<VectorViewMut<'_, V, T, A, N>> for Matrix<T, N, 1>
 {
     fn from(view: VectorViewMut<'_, V, T, A, N>) -> Self {
         Self {
-            data: std::array::from_fn(|i| [view[i]]),
+            data: core::array::from_fn(|i| [view[i].clone()]),
         }
     }
 }
 
-impl<V: Index<usize, Output = T>, T: Copy, const A: usize, const N: usize>
+impl<V: Index<usize, Output = T>, T: Clone, const A: usize, const N: usize>
     From<RowVectorView<'_, V, T, A, N>> for Matrix<T, 1, N>
 {
     fn from(view: RowVectorView<'_, V, T, A, N>) -> Self {
         Self {
-            data: [std::array::from_fn(|i| view[i]); 1],
+            data: [core::array::from_fn(|i| view[i].clone()); 1],
         }
     }
 }
 
-impl<V: Index<usize, Output = T>, T: Copy, const A: usize, const N: usize>
+impl<V: Index<usize, Output = T>, T: Clone, const A: usize, const N: usize>
     From<RowVectorViewMut<'_, V, T, A, N>> for Matrix<T, 1, N>
 {
     fn from(view: RowVectorViewMut<'_, V, T, A, N>) -> Self {
         Self {
-            data: [std::array::from_fn(|i| view[i]); 1],
+            data: [core::array::from_fn(|i| view[i].clone()); 1],
         }
     }
 }
 
-impl<T: Copy, const A: usize, const B: usize, const R: usize, const C: usize>
+impl<T: Clone, const A: usize, const B: usize, const R: usize, const C: usize>
     From<MatrixView<'_, T, A, B, R, C>> for Matrix<T, R, C>
 {
     fn from(view: MatrixView<'_, T, A, B, R, C>) -> Self {
         Self {
-            data: std::array::from_fn(|i| std::array::from_fn(|j| view[(i, j)])),
+            data: core::array::from_fn(|i| core::array::from_fn(|j| view[(i, j)].clone())),
         }
     }
 }
 
-impl<T: Copy, const A: usize, const B: usize, const R: usize, const C: usize>
+impl<T: Clone, const A: usize, const B: usize, const R: usize, const C: usize>
     From<MatrixViewMut<'_, T, A, B, R, C>> for Matrix<T, R, C>
 {
     fn from(view: MatrixViewMut<'_, T, A, B, R, C>) -> Self {
         Self {
-            data: std::array::from_fn(|i| std::array::from_fn(|j| view[(i, j)])),
+            data: core::array::from_fn(|i| core::array::from_fn(|j| view[(i, j)].clone())),
         }
     }
 }
 
-impl<T: Copy, const A: usize, const B: usize, const R: usize, const C: usize>
+impl<T: Clone, const A: usize, const B: usize, const R: usize, const C: usize>
     From<MatrixTransposeView<'_, T, A, B, R, C>> for Matrix<T, R, C>
 {
     fn from(view: MatrixTransposeView<'_, T, A, B, R, C>) -> Self {
         Self {
-            data: std::array::from_fn(|i| std::array::from_fn(|j| view[(i, j)])),
+            data: core::array::from_fn(|i| core::array::from_fn(|j| view[(i, j)].clone())),
         }
     }
 }
 
-impl<T: Copy, const A: usize, const B: usize, const R: usize, const C: usize>
+impl<T: Clone, const A: usize, const B: usize, const R: usize, const C: usize>
     From<MatrixTransposeViewMut<'_, T, A, B, R, C>> for Matrix<T, R, C>
 {
     fn from(view: MatrixTransposeViewMut<'_, T, A, B, R, C>) -> Self {
         Self {
-            data: std::array::from_fn(|i| std::array::from_fn(|j| view[(i, j)])),
+            data: core::array::from_fn(|i| core::array::from_fn(|j| view[(i, j)].clone())),
         }
     }
 }
+
+#[cfg(feature = "bytemuck")]
+impl<T: bytemuck::Pod, const R: usize, const C: usize> Matrix<T, R, C> {
+    /// Returns the elements of the [`Matrix`] as bytes in row-major order, without copying.
+    ///
+    /// Use this in place of `bytemuck::bytes_of`, e.g. to upload the [`Matrix`] to a GPU buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[1u16, 2], [3, 4]]);
+    /// assert_eq!(mat.as_bytes(), bytemuck::cast_slice::<u16, u8>(&[1, 2, 3, 4]));
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(self.as_slice())
+    }
+
+    /// Returns the elements of a slice of [`Matrix`] values as bytes, without copying.
+    ///
+    /// The matrices are laid out one after another, each in row-major order. Use this in place of
+    /// `bytemuck::cast_slice`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let matrices = [Matrix::from([[1u16, 2], [3, 4]]), Matrix::from([[5u16, 6], [7, 8]])];
+    /// let bytes = Matrix::slice_as_bytes(&matrices);
+    /// assert_eq!(bytes, bytemuck::cast_slice::<u16, u8>(&[1, 2, 3, 4, 5, 6, 7, 8]));
+    /// ```
+    pub fn slice_as_bytes(matrices: &[Self]) -> &[u8] {
+        bytemuck::cast_slice(<Self as bytemuck::TransparentWrapper<_>>::peel_slice(matrices).as_flattened().as_flattened())
+    }
+}
+
+//////////////////////////////////////
+//  Bytemuck Trait Implementations  //
+//////////////////////////////////////
+
+// SAFETY: `Matrix` is `repr(transparent)` over `[[T; C]; R]`, which is zeroable when `T` is
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Zeroable, const R: usize, const C: usize> bytemuck::Zeroable for Matrix<T, R, C> {}
+
+// SAFETY: `Matrix` is `repr(transparent)` over `[[T; C]; R]`
+#[cfg(feature = "bytemuck")]
+unsafe impl<T, const R: usize, const C: usize> bytemuck::TransparentWrapper<[[T; C]; R]> for Matrix<T, R, C> {}
@@ -0,0 +1,599 @@
+#[cfg(feature = "fmt")]
+use core::fmt;
+use core::ops::{Add, Index, IndexMut, Mul};
+use num_traits::{One, Zero};
+
+use crate::matrix::Matrix;
+use crate::matrix_transpose_view::MatrixTransposeView;
+use crate::traits::{Contiguous, LinearOperator, MatrixMut, MatrixRef, Strided, StridedRef, TransposeOperator};
+use crate::vector::Vector;
+
+/// A static column-major matrix type.
+///
+/// [`MatrixCM`] is indexed exactly like [`Matrix`], but stores its elements column by column, which is
+/// the layout expected by BLAS, LAPACK and most GPU shader languages. A `MatrixCM<T, R, C>` is
+/// guaranteed to have the same memory layout as `[[T; R]; C]`.
+///
+/// Operations between two [`MatrixCM`]s return a [`MatrixCM`], while operations that mix it with a
+/// [`Matrix`] or a view return a [`Matrix`].
+#[derive(Debug, Clone)]
+#[repr(transparent)]
+pub struct MatrixCM<T, const R: usize, const C: usize> {
+    data: [[T; R]; C],
+}
+
+impl<T: Default, const R: usize, const C: usize> Default for MatrixCM<T, R, C> {
+    fn default() -> Self {
+        Self {
+            data: core::array::from_fn(|_| core::array::from_fn(|_| T::default())),
+        }
+    }
+}
+
+impl<T: Default, const R: usize, const C: usize> MatrixCM<T, R, C> {
+    /// Creates a new [`MatrixCM`] with default values.
+    ///
+    /// This method initializes a new [`MatrixCM`] of size RxC, where each element is set to its default value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::MatrixCM;
+    ///
+    /// let mat: MatrixCM<f64, 2, 3> = MatrixCM::new();
+    /// assert_eq!(mat, MatrixCM::from([[0.0, 0.0, 0.0], [0.0, 0.0, 0.0]]));
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<T, const R: usize, const C: usize> MatrixCM<T, R, C> {
+    /// Creates a new [`MatrixCM`] from an array of columns.
+    ///
+    /// This moves the array in without rearranging any elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::MatrixCM;
+    ///
+    /// let mat = MatrixCM::from_columns([[1, 4], [2, 5], [3, 6]]);
+    /// assert_eq!(mat, MatrixCM::from([[1, 2, 3], [4, 5, 6]]));
+    /// ```
+    pub fn from_columns(columns: [[T; R]; C]) -> Self {
+        Self { data: columns }
+    }
+
+    /// Returns the shape of the [`MatrixCM`].
+    ///
+    /// The shape is always equal to `(R, C)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::MatrixCM;
+    ///
+    /// let mat: MatrixCM<f64, 2, 3> = MatrixCM::new();
+    /// assert_eq!(mat.shape(), (2, 3));
+    /// ```
+    #[inline]
+    pub fn shape(&self) -> (usize, usize) {
+        (R, C)
+    }
+
+    /// Returns the total number of elements in the [`MatrixCM`].
+    ///
+    /// The total number of elements is always equal to `R * C`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::MatrixCM;
+    ///
+    /// let mat: MatrixCM<f64, 2, 3> = MatrixCM::new();
+    /// assert_eq!(mat.capacity(), 6);
+    /// ```
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        R * C
+    }
+
+    /// Returns the number of rows in the [`MatrixCM`].
+    ///
+    /// The number of rows is always equal to `R`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::MatrixCM;
+    ///
+    /// let mat: MatrixCM<f64, 2, 3> = MatrixCM::new();
+    /// assert_eq!(mat.rows(), 2);
+    /// ```
+    #[inline]
+    pub fn rows(&self) -> usize {
+        R
+    }
+
+    /// Returns the number of columns in the [`MatrixCM`].
+    ///
+    /// The number of columns is always equal to `C`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::MatrixCM;
+    ///
+    /// let mat: MatrixCM<f64, 2, 3> = MatrixCM::new();
+    /// assert_eq!(mat.cols(), 3);
+    /// ```
+    #[inline]
+    pub fn cols(&self) -> usize {
+        C
+    }
+
+    /// Returns the elements of the [`MatrixCM`] as a slice in column-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::MatrixCM;
+    ///
+    /// let mat = MatrixCM::from([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(mat.as_slice(), &[1, 4, 2, 5, 3, 6]);
+    /// ```
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        self.data.as_flattened()
+    }
+
+    /// Returns the elements of the [`MatrixCM`] as a mutable slice in column-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::MatrixCM;
+    ///
+    /// let mut mat = MatrixCM::from([[1, 2, 3], [4, 5, 6]]);
+    /// mat.as_mut_slice()[1] = 0;
+    /// assert_eq!(mat, MatrixCM::from([[1, 2, 3], [0, 5, 6]]));
+    /// ```
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.data.as_flattened_mut()
+    }
+
+    /// Returns a raw pointer to the first element of the [`MatrixCM`].
+    ///
+    /// The `R * C` elements are contiguous and in column-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::MatrixCM;
+    ///
+    /// let mat = MatrixCM::from([[1, 2], [3, 4]]);
+    /// let ptr = mat.as_ptr();
+    /// assert_eq!(unsafe { *ptr.add(1) }, 3);
+    /// ```
+    #[inline]
+    pub fn as_ptr(&self) -> *const T {
+        self.data.as_ptr().cast()
+    }
+
+    /// Returns a mutable raw pointer to the first element of the [`MatrixCM`].
+    ///
+    /// The `R * C` elements are contiguous and in column-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::MatrixCM;
+    ///
+    /// let mut mat = MatrixCM::from([[1, 2], [3, 4]]);
+    /// let ptr = mat.as_mut_ptr();
+    /// unsafe { *ptr.add(2) = 0 };
+    /// assert_eq!(mat, MatrixCM::from([[1, 0], [3, 4]]));
+    /// ```
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.data.as_mut_ptr().cast()
+    }
+
+    /// Reinterprets the [`MatrixCM`] as its row-major transpose.
+    ///
+    /// A column-major RxC matrix and a row-major CxR matrix share the same layout, so this
+    /// does not copy or move any elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{Matrix, MatrixCM};
+    ///
+    /// let mat = MatrixCM::from([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(mat.as_transposed(), &Matrix::from([[1, 4], [2, 5], [3, 6]]));
+    /// ```
+    #[inline]
+    pub fn as_transposed(&self) -> &Matrix<T, C, R> {
+        // SAFETY: both types are `repr(transparent)` over `[[T; R]; C]`
+        unsafe { &*(self as *const Self).cast::<Matrix<T, C, R>>() }
+    }
+
+    /// Reinterprets the [`MatrixCM`] as its mutable row-major transpose.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::MatrixCM;
+    ///
+    /// let mut mat = MatrixCM::from([[1, 2, 3], [4, 5, 6]]);
+    /// mat.as_transposed_mut()[(2, 0)] = 0;
+    /// assert_eq!(mat, MatrixCM::from([[1, 2, 0], [4, 5, 6]]));
+    /// ```
+    #[inline]
+    pub fn as_transposed_mut(&mut self) -> &mut Matrix<T, C, R> {
+        // SAFETY: both types are `repr(transparent)` over `[[T; R]; C]`
+        unsafe { &mut *(self as *mut Self).cast::<Matrix<T, C, R>>() }
+    }
+
+    /// Converts the [`MatrixCM`] into its row-major transpose without rearranging any elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{Matrix, MatrixCM};
+    ///
+    /// let mat = MatrixCM::from([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(mat.into_transposed(), Matrix::from([[1, 4], [2, 5], [3, 6]]));
+    /// ```
+    pub fn into_transposed(self) -> Matrix<T, C, R> {
+        Matrix::from(self.data)
+    }
+}
+
+impl<T: Clone + Zero, const R: usize, const C: usize> MatrixCM<T, R, C> {
+    /// Creates a new [`MatrixCM`] filled with zeros.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::MatrixCM;
+    ///
+    /// let mat = MatrixCM::<f64, 2, 3>::zeros();
+    /// assert_eq!(mat, MatrixCM::from([[0.0, 0.0, 0.0], [0.0, 0.0, 0.0]]));
+    /// ```
+    pub fn zeros() -> Self {
+        Self::fill(T::zero())
+    }
+}
+
+impl<T: Clone + One, const R: usize, const C: usize> MatrixCM<T, R, C> {
+    /// Creates a new [`MatrixCM`] filled with ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::MatrixCM;
+    ///
+    /// let mat = MatrixCM::<f64, 2, 3>::ones();
+    /// assert_eq!(mat, MatrixCM::from([[1.0, 1.0, 1.0], [1.0, 1.0, 1.0]]));
+    /// ```
+    pub fn ones() -> Self {
+        Self::fill(T::one())
+    }
+}
+
+impl<T: Clone + Zero + One, const R: usize, const C: usize> MatrixCM<T, R, C> {
+    /// Creates a new identity [`MatrixCM`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::MatrixCM;
+    ///
+    /// let mat = MatrixCM::<f64, 2, 3>::eye();
+    /// assert_eq!(mat, MatrixCM::from([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]));
+    /// ```
+    pub fn eye() -> Self {
+        let mut data: [[T; R]; C] = core::array::from_fn(|_| core::array::from_fn(|_| T::zero()));
+        for (j, column) in data.iter_mut().enumerate().take(R) {
+            column[j] = T::one();
+        }
+        Self { data }
+    }
+}
+
+impl<T: Clone, const R: usize, const C: usize> MatrixCM<T, R, C> {
+    /// Creates a new [`MatrixCM`] filled with a specified value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::MatrixCM;
+    ///
+    /// let mat = MatrixCM::<i32, 2, 3>::fill(42);
+    /// assert_eq!(mat, MatrixCM::from([[42, 42, 42], [42, 42, 42]]));
+    /// ```
+    pub fn fill(value: T) -> Self {
+        Self {
+            data: core::array::from_fn(|_| core::array::from_fn(|_| value.clone())),
+        }
+    }
+
+    /// Creates a new [`MatrixCM`] from a slice in column-major order.
+    ///
+    /// This matches the layout returned by [`MatrixCM::as_slice`]. Returns `None` if the length of
+    /// the slice is not equal to `R * C`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::MatrixCM;
+    ///
+    /// let mat = MatrixCM::<i32, 2, 3>::from_slice(&[1, 4, 2, 5, 3, 6]).unwrap();
+    /// assert_eq!(mat, MatrixCM::from([[1, 2, 3], [4, 5, 6]]));
+    /// assert!(MatrixCM::<i32, 2, 3>::from_slice(&[1, 2, 3]).is_none());
+    /// ```
+    pub fn from_slice(slice: &[T]) -> Option<Self> {
+        if slice.len() != R * C {
+            return None;
+        }
+        Some(Self::from_fn(|i| slice[i].clone()))
+    }
+}
+
+//////////////////////////////////////
+//  Equality Trait Implementations  //
+//////////////////////////////////////
+
+// MatrixCM == MatrixCM
+impl<T: PartialEq, const R: usize, const C: usize> PartialEq for MatrixCM<T, R, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data
+    }
+}
+
+// MatrixCM == Matrix
+impl<T: PartialEq, const R: usize, const C: usize> PartialEq<Matrix<T, R, C>> for MatrixCM<T, R, C> {
+    fn eq(&self, other: &Matrix<T, R, C>) -> bool {
+        (0..R).all(|i| (0..C).all(|j| self[(i, j)] == other[(i, j)]))
+    }
+}
+
+impl<T: Eq, const R: usize, const C: usize> Eq for MatrixCM<T, R, C> {}
+
+////////////////////////////////////////////
+//  LinearOperator Trait Implementations  //
+////////////////////////////////////////////
+
+impl<T: Clone + Zero + Mul<T, Output = T> + Add<T, Output = T>, const R: usize, const C: usize>
+    LinearOperator<T, R, C> for MatrixCM<T, R, C>
+{
+    fn apply(&self, x: &Vector<T, C>) -> Vector<T, R> {
+        Vector::from(self * x)
+    }
+}
+
+impl<T: Clone + Zero + Mul<T, Output = T> + Add<T, Output = T>, const R: usize, const C: usize>
+    TransposeOperator<T, R, C> for MatrixCM<T, R, C>
+{
+    fn apply_transpose(&self, x: &Vector<T, R>) -> Vector<T, C> {
+        Vector::from(self.as_transposed() * x)
+    }
+}
+
+/////////////////////////////////////
+//  Display Trait Implementations  //
+/////////////////////////////////////
+
+#[cfg(feature = "fmt")]
+impl<T: fmt::Display, const R: usize, const C: usize> fmt::Display for MatrixCM<T, R, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "MatrixCM(")?;
+        }
+
+        write!(f, "[")?;
+        for i in 0..R {
+            if i > 0 {
+                write!(f, " ")?;
+                if f.alternate() {
+                    write!(f, "         ")?;
+                }
+            }
+            write!(f, "[")?;
+            for j in 0..C {
+                write!(f, "{}", self[(i, j)])?;
+                if j < C - 1 {
+                    write!(f, ", ")?;
+                }
+            }
+            write!(f, "]")?;
+            if i < R - 1 {
+                writeln!(f)?;
+            }
+        }
+        write!(f, "]")?;
+
+        if f.alternate() {
+            write!(f, ", dtype={})", core::any::type_name::<T>())?;
+        }
+
+        Ok(())
+    }
+}
+
+///////////////////////////////////////
+//  MatrixRef Trait Implementations  //
+///////////////////////////////////////
+
+impl<T, const R: usize, const C: usize> MatrixRef<T, R, C> for MatrixCM<T, R, C> {}
+
+impl<T, const R: usize, const C: usize> MatrixMut<T, R, C> for MatrixCM<T, R, C> {}
+
+///////////////////////////////////
+//  Index Trait Implementations  //
+///////////////////////////////////
+
+// Linear indices are row-major, like every other matrix type, regardless of the storage order
+impl<T, const R: usize, const C: usize> Index<usize> for MatrixCM<T, R, C> {
+    type Output = T;
+    fn index(&self, index: usize) -> &Self::Output {
+        if index >= R * C {
+            panic!("Index out of bounds");
+        }
+
+        let row_idx = index / C;
+        let col_idx = index % C;
+        &self.data[col_idx][row_idx]
+    }
+}
+
+impl<T, const R: usize, const C: usize> IndexMut<usize> for MatrixCM<T, R, C> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        if index >= R * C {
+            panic!("Index out of bounds");
+        }
+
+        let row_idx = index / C;
+        let col_idx = index % C;
+        &mut self.data[col_idx][row_idx]
+    }
+}
+
+impl<T, const R: usize, const C: usize> Index<(usize, usize)> for MatrixCM<T, R, C> {
+    type Output = T;
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        if index.0 >= R || index.1 >= C {
+            panic!("Index out of bounds");
+        }
+        &self.data[index.1][index.0]
+    }
+}
+
+impl<T, const R: usize, const C: usize> IndexMut<(usize, usize)> for MatrixCM<T, R, C> {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        if index.0 >= R || index.1 >= C {
+            panic!("Index out of bounds");
+        }
+        &mut self.data[index.1][index.0]
+    }
+}
+
+////////////////////////////////////////
+//  Contiguous Trait Implementations  //
+////////////////////////////////////////
+
+// Linear indices follow the column-major storage order here
+impl<T, const R: usize, const C: usize> Contiguous<T> for MatrixCM<T, R, C> {
+    fn from_fn<F: FnMut(usize) -> T>(mut f: F) -> Self {
+        Self {
+            data: core::array::from_fn(|j| core::array::from_fn(|i| f(j * R + i))),
+        }
+    }
+
+    fn as_slice(&self) -> &[T] {
+        self.data.as_flattened()
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self.data.as_flattened_mut()
+    }
+}
+
+/////////////////////////////////////
+//  Strided Trait Implementations  //
+/////////////////////////////////////
+
+impl<T, const R: usize, const C: usize> Strided<T> for MatrixCM<T, R, C> {
+    fn strided(&self) -> StridedRef<'_, T> {
+        StridedRef::new(self.data.as_flattened(), 1, R)
+    }
+}
+
+//////////////////////////////////
+//  From Trait Implementations  //
+//////////////////////////////////
+
+impl<T: Clone, const R: usize, const C: usize> From<[[T; C]; R]> for MatrixCM<T, R, C> {
+    fn from(data: [[T; C]; R]) -> Self {
+        Self {
+            data: core::array::from_fn(|j| core::array::from_fn(|i| data[i][j].clone())),
+        }
+    }
+}
+
+impl<T: Clone, const R: usize, const C: usize> From<Matrix<T, R, C>> for MatrixCM<T, R, C> {
+    fn from(matrix: Matrix<T, R, C>) -> Self {
+        Self {
+            data: core::array::from_fn(|j| core::array::from_fn(|i| matrix[(i, j)].clone())),
+        }
+    }
+}
+
+impl<T: Clone, const R: usize, const C: usize> From<MatrixCM<T, R, C>> for Matrix<T, R, C> {
+    fn from(matrix: MatrixCM<T, R, C>) -> Self {
+        Matrix::from(core::array::from_fn(|i| core::array::from_fn(|j| matrix[(i, j)].clone())))
+    }
+}
+
+impl<T: Clone, const A: usize, const B: usize, const R: usize, const C: usize>
+    From<MatrixTransposeView<'_, T, A, B, R, C>> for MatrixCM<T, R, C>
+{
+    fn from(view: MatrixTransposeView<'_, T, A, B, R, C>) -> Self {
+        // Each column of the result is a contiguous row of the underlying matrix
+        Self {
+            data: core::array::from_fn(|j| core::array::from_fn(|i| view[(i, j)].clone())),
+        }
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+impl<T: bytemuck::Pod, const R: usize, const C: usize> MatrixCM<T, R, C> {
+    /// Returns the elements of the [`MatrixCM`] as bytes in column-major order, without copying.
+    ///
+    /// Use this in place of `bytemuck::bytes_of`, e.g. to upload the [`MatrixCM`] to a GPU buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::MatrixCM;
+    ///
+    /// let mat = MatrixCM::from_columns([[1u16, 2], [3, 4]]);
+    /// assert_eq!(mat.as_bytes(), bytemuck::cast_slice::<u16, u8>(&[1, 2, 3, 4]));
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(self.data.as_flattened())
+    }
+
+    /// Returns the elements of a slice of [`MatrixCM`] values as bytes, without copying.
+    ///
+    /// The matrices are laid out one after another, each in column-major order. Use this in place of
+    /// `bytemuck::cast_slice`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::MatrixCM;
+    ///
+    /// let matrices = [MatrixCM::from_columns([[1u16, 2], [3, 4]]), MatrixCM::from_columns([[5u16, 6], [7, 8]])];
+    /// let bytes = MatrixCM::slice_as_bytes(&matrices);
+    /// assert_eq!(bytes, bytemuck::cast_slice::<u16, u8>(&[1, 2, 3, 4, 5, 6, 7, 8]));
+    /// ```
+    pub fn slice_as_bytes(matrices: &[Self]) -> &[u8] {
+        bytemuck::cast_slice(<Self as bytemuck::TransparentWrapper<_>>::peel_slice(matrices).as_flattened().as_flattened())
+    }
+}
+
+//////////////////////////////////////
+//  Bytemuck Trait Implementations  //
+//////////////////////////////////////
+
+// SAFETY: `MatrixCM` is `repr(transparent)` over `[[T; R]; C]`, which is zeroable when `T` is
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Zeroable, const R: usize, const C: usize> bytemuck::Zeroable for MatrixCM<T, R, C> {}
+
+// SAFETY: `MatrixCM` is `repr(transparent)` over `[[T; R]; C]`
+#[cfg(feature = "bytemuck")]
+unsafe impl<T, const R: usize, const C: usize> bytemuck::TransparentWrapper<[[T; R]; C]> for MatrixCM<T, R, C> {}
@@ -0,0 +1,405 @@
+use crate::matrix::Matrix;
+use crate::matrix_strided_view_mut::MatrixStridedViewMut;
+use crate::matrix_transpose_view::MatrixTransposeView;
+use crate::matrix_transpose_view_mut::MatrixTransposeViewMut;
+use crate::matrix_view::MatrixView;
+use crate::matrix_view_mut::MatrixViewMut;
+use crate::traits::{Conjugate, LinearOperator, MatrixRef, TransposeOperator, VectorRef};
+use crate::vector::Vector;
+#[cfg(feature = "fmt")]
+use core::fmt;
+use core::ops::{Add, Index, Mul};
+use num_traits::Zero;
+
+/// A static strided view of a matrix.
+///
+/// Unlike a [`MatrixView`], consecutive rows and columns of the view do not have to be adjacent
+/// in the matrix. Each axis has its own step, which may be negative to walk the matrix in reverse.
+/// Diagonal and anti-diagonal views are strided views with a single column.
+#[derive(Debug)]
+pub struct MatrixStridedView<'a, T, const R: usize, const C: usize, const VR: usize, const VC: usize>
+{
+    data: &'a Matrix<T, R, C>,
+    offset: usize,
+    stride: (isize, isize), // In terms of the row-major elements of the matrix
+}
+
+impl<'a, T, const R: usize, const C: usize, const VR: usize, const VC: usize>
+    MatrixStridedView<'a, T, R, C, VR, VC>
+{
+    pub(super) fn new(data: &'a Matrix<T, R, C>, offset: usize, stride: (isize, isize)) -> Self {
+        if !fits(offset, stride, VR, VC, R * C) {
+            panic!("View size out of bounds");
+        }
+        Self {
+            data,
+            offset,
+            stride,
+        }
+    }
+
+    /// Returns the shape of the [`MatrixStridedView`].
+    ///
+    /// The shape is always equal to `(VR, VC)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// let view = mat.strided_view::<2, 3>((0, 0), (2, 1)).unwrap();
+    /// assert_eq!(view.shape(), (2, 3));
+    /// ```
+    #[inline]
+    pub fn shape(&self) -> (usize, usize) {
+        (VR, VC)
+    }
+
+    /// Returns the total number of elements in the [`MatrixStridedView`].
+    ///
+    /// The total number of elements is always equal to `VR * VC`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// let view = mat.strided_view::<2, 3>((0, 0), (2, 1)).unwrap();
+    /// assert_eq!(view.capacity(), 6);
+    /// ```
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        VR * VC
+    }
+
+    /// Returns the number of rows in the [`MatrixStridedView`].
+    ///
+    /// The number of rows is always equal to `VR`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// let view = mat.strided_view::<2, 3>((0, 0), (2, 1)).unwrap();
+    /// assert_eq!(view.rows(), 2);
+    /// ```
+    #[inline]
+    pub fn rows(&self) -> usize {
+        VR
+    }
+
+    /// Returns the number of columns in the [`MatrixStridedView`].
+    ///
+    /// The number of columns is always equal to `VC`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// let view = mat.strided_view::<2, 3>((0, 0), (2, 1)).unwrap();
+    /// assert_eq!(view.cols(), 3);
+    /// ```
+    #[inline]
+    pub fn cols(&self) -> usize {
+        VC
+    }
+
+    /// Returns a transposed view of the [`MatrixStridedView`].
+    ///
+    /// This method returns a [`MatrixStridedView`] with the row and column steps swapped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// let view = mat.strided_view::<2, 3>((0, 0), (2, 1)).unwrap();
+    /// assert_eq!(view.t(), Matrix::from([[1, 7], [2, 8], [3, 9]]));
+    /// ```
+    pub fn t(&self) -> MatrixStridedView<'a, T, R, C, VC, VR> {
+        MatrixStridedView::new(self.data, self.offset, (self.stride.1, self.stride.0))
+    }
+}
+
+/// Returns `true` if every element of a `rows x cols` strided view lies within `len` elements.
+pub(crate) fn fits(offset: usize, stride: (isize, isize), rows: usize, cols: usize, len: usize) -> bool {
+    if rows == 0 || cols == 0 {
+        return offset <= len;
+    }
+    [(0, 0), (rows - 1, 0), (0, cols - 1), (rows - 1, cols - 1)]
+        .iter()
+        .all(|&(i, j)| matches!(position(offset, stride, (i, j)), Some(p) if p < len))
+}
+
+/// Returns the row-major position of element `index` of a strided view.
+#[inline]
+pub(crate) fn position(offset: usize, stride: (isize, isize), index: (usize, usize)) -> Option<usize> {
+    let i = (index.0 as isize).checked_mul(stride.0)?;
+    let j = (index.1 as isize).checked_mul(stride.1)?;
+    let p = (offset as isize).checked_add(i)?.checked_add(j)?;
+    usize::try_from(p).ok()
+}
+
+/// Returns the offset and strides of a per-axis strided view, or `None` if it is out of bounds.
+pub(crate) fn axis_strides<const R: usize, const C: usize, const VR: usize, const VC: usize>(
+    start: (usize, usize),
+    step: (isize, isize),
+) -> Option<(usize, (isize, isize))> {
+    let axis_fits = |start: usize, step: isize, len: usize, size: usize| {
+        len == 0
+            || (start < size
+                && matches!(position(start, (step, 0), (len - 1, 0)), Some(p) if p < size))
+    };
+    if step.0 == 0 || step.1 == 0 || !axis_fits(start.0, step.0, VR, R) || !axis_fits(start.1, step.1, VC, C) {
+        return None;
+    }
+    Some((start.0 * C + start.1, (step.0.checked_mul(C as isize)?, step.1)))
+}
+
+/// Returns the offset and strides of a diagonal view, or `None` if it is out of bounds.
+///
+/// The column moves forwards along the diagonal and backwards along the anti-diagonal.
+pub(crate) fn diagonal_strides<const R: usize, const C: usize, const N: usize>(
+    start: (usize, usize),
+    anti: bool,
+) -> Option<(usize, (isize, isize))> {
+    let step = if anti { -1 } else { 1 };
+    let (offset, (rs, cs)) = axis_strides::<R, C, N, N>(start, (1, step))?;
+    Some((offset, (rs + cs, 1)))
+}
+
+impl<T: Conjugate, const R: usize, const C: usize, const VR: usize, const VC: usize>
+    MatrixStridedView<'_, T, R, C, VR, VC>
+{
+    /// Returns the element-wise complex conjugate of the [`MatrixStridedView`] as a new [`Matrix`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// let view = mat.strided_view::<2, 2>((0, 0), (2, 2)).unwrap();
+    /// assert_eq!(view.conj(), Matrix::from([[1, 3], [7, 9]]));
+    /// ```
+    pub fn conj(&self) -> Matrix<T, VR, VC> {
+        Matrix::from(core::array::from_fn(|i| core::array::from_fn(|j| self[(i, j)].clone().conj())))
+    }
+
+    /// Returns the conjugate transpose of the [`MatrixStridedView`] as a new [`Matrix`].
+    ///
+    /// For real element types this is the same as copying the transpose.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// let view = mat.strided_view::<2, 2>((0, 0), (2, 2)).unwrap();
+    /// assert_eq!(view.h(), Matrix::from([[1, 7], [3, 9]]));
+    /// ```
+    pub fn h(&self) -> Matrix<T, VC, VR> {
+        Matrix::from(core::array::from_fn(|i| core::array::from_fn(|j| self[(j, i)].clone().conj())))
+    }
+}
+
+//////////////////////////////////////
+//  Equality Trait Implementations  //
+//////////////////////////////////////
+
+macro_rules! impl_strided_eq {
+    ($lhs:ident, $rhs:ident) => {
+        impl<
+                T: PartialEq,
+                const A: usize,
+                const B: usize,
+                const R: usize,
+                const C: usize,
+                const VR: usize,
+                const VC: usize,
+            > PartialEq<$rhs<'_, T, A, B, VR, VC>> for $lhs<'_, T, R, C, VR, VC>
+        {
+            fn eq(&self, other: &$rhs<'_, T, A, B, VR, VC>) -> bool {
+                (0..VR).all(|i| (0..VC).all(|j| self[(i, j)] == other[(i, j)]))
+            }
+        }
+    };
+}
+
+pub(crate) use impl_strided_eq;
+
+// MatrixStridedView == Matrix
+impl<T: PartialEq, const R: usize, const C: usize, const VR: usize, const VC: usize>
+    PartialEq<Matrix<T, VR, VC>> for MatrixStridedView<'_, T, R, C, VR, VC>
+{
+    fn eq(&self, other: &Matrix<T, VR, VC>) -> bool {
+        (0..VR).all(|i| (0..VC).all(|j| self[(i, j)] == other[(i, j)]))
+    }
+}
+
+// MatrixStridedView == Vector
+impl<T: PartialEq, const R: usize, const C: usize, const N: usize> PartialEq<Vector<T, N>>
+    for MatrixStridedView<'_, T, R, C, N, 1>
+{
+    fn eq(&self, other: &Vector<T, N>) -> bool {
+        (0..N).all(|i| self[i] == other[i])
+    }
+}
+
+impl_strided_eq!(MatrixStridedView, MatrixView);
+impl_strided_eq!(MatrixStridedView, MatrixViewMut);
+impl_strided_eq!(MatrixStridedView, MatrixTransposeView);
+impl_strided_eq!(MatrixStridedView, MatrixTransposeViewMut);
+impl_strided_eq!(MatrixStridedView, MatrixStridedView);
+impl_strided_eq!(MatrixStridedView, MatrixStridedViewMut);
+
+impl<T: Eq, const R: usize, const C: usize, const VR: usize, const VC: usize> Eq
+    for MatrixStridedView<'_, T, R, C, VR, VC>
+{
+}
+
+////////////////////////////////////////////
+//  LinearOperator Trait Implementations  //
+////////////////////////////////////////////
+
+impl<
+        T: Clone + Zero + Mul<T, Output = T> + Add<T, Output = T>,
+        const R: usize,
+        const C: usize,
+        const VR: usize,
+        const VC: usize,
+    > LinearOperator<T, VR, VC> for MatrixStridedView<'_, T, R, C, VR, VC>
+{
+    fn apply(&self, x: &Vector<T, VC>) -> Vector<T, VR> {
+        self.matvec(x)
+    }
+}
+
+impl<
+        T: Clone + Zero + Mul<T, Output = T> + Add<T, Output = T>,
+        const R: usize,
+        const C: usize,
+        const VR: usize,
+        const VC: usize,
+    > TransposeOperator<T, VR, VC> for MatrixStridedView<'_, T, R, C, VR, VC>
+{
+    fn apply_transpose(&self, x: &Vector<T, VR>) -> Vector<T, VC> {
+        self.t().matvec(x)
+    }
+}
+
+/////////////////////////////////////
+//  Display Trait Implementations  //
+/////////////////////////////////////
+
+#[cfg(feature = "fmt")]
+impl<T: fmt::Display, const R: usize, const C: usize, const VR: usize, const VC: usize> fmt::Display
+    for MatrixStridedView<'_, T, R, C, VR, VC>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "MatrixStridedView(")?;
+        }
+
+        write!(f, "[")?;
+        for i in 0..VR {
+            if i > 0 {
+                write!(f, " ")?;
+                if f.alternate() {
+                    write!(f, "                  ")?;
+                }
+            }
+            write!(f, "[")?;
+            for j in 0..VC {
+                write!(f, "{}", self[(i, j)])?;
+                if j < VC - 1 {
+                    write!(f, ", ")?;
+                }
+            }
+            write!(f, "]")?;
+            if i < VR - 1 {
+                writeln!(f)?;
+            }
+        }
+        write!(f, "]")?;
+
+        if f.alternate() {
+            write!(f, ", dtype={})", core::any::type_name::<T>())?;
+        }
+
+        Ok(())
+    }
+}
+
+///////////////////////////////////////
+//  MatrixRef Trait Implementations  //
+///////////////////////////////////////
+
+impl<T, const R: usize, const C: usize, const VR: usize, const VC: usize> MatrixRef<T, VR, VC>
+    for MatrixStridedView<'_, T, R, C, VR, VC>
+{
+}
+
+///////////////////////////////////////
+//  VectorRef Trait Implementations  //
+///////////////////////////////////////
+
+// `N x 1` strided views are what `diagonal_view` and `anti_diagonal_view` return
+impl<T, const R: usize, const C: usize, const N: usize> VectorRef<T, N>
+    for MatrixStridedView<'_, T, R, C, N, 1>
+{
+}
+
+///////////////////////////////////
+//  Index Trait Implementations  //
+///////////////////////////////////
+
+impl<T, const R: usize, const C: usize, const VR: usize, const VC: usize>
+    MatrixStridedView<'_, T, R, C, VR, VC>
+{
+    #[inline]
+    fn offset(&self, index: (usize, usize)) -> usize {
+        (self.offset as isize + index.0 as isize * self.stride.0 + index.1 as isize * self.stride.1)
+            as usize
+    }
+
+    #[inline]
+    fn validate_index(&self, index: (usize, usize)) -> bool {
+        index.0 < VR && index.1 < VC
+    }
+}
+
+impl<T, const R: usize, const C: usize, const VR: usize, const VC: usize> Index<usize>
+    for MatrixStridedView<'_, T, R, C, VR, VC>
+{
+    type Output = T;
+    fn index(&self, index: usize) -> &Self::Output {
+        if index >= VR * VC {
+            panic!("Index out of bounds");
+        }
+
+        let row_idx = index / VC;
+        let col_idx = index % VC;
+        &self.data[self.offset((row_idx, col_idx))]
+    }
+}
+
+impl<T, const R: usize, const C: usize, const VR: usize, const VC: usize> Index<(usize, usize)>
+    for MatrixStridedView<'_, T, R, C, VR, VC>
+{
+    type Output = T;
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        if !self.validate_index(index) {
+            panic!("Index out of bounds");
+        }
+        &self.data[self.offset(index)]
+    }
+}
@@ -0,0 +1,396 @@
+use crate::matrix::Matrix;
+use crate::matrix_strided_view::{fits, impl_strided_eq, MatrixStridedView};
+use crate::matrix_transpose_view::MatrixTransposeView;
+use crate::matrix_transpose_view_mut::MatrixTransposeViewMut;
+use crate::matrix_view::MatrixView;
+use crate::matrix_view_mut::MatrixViewMut;
+use crate::traits::{Conjugate, LinearOperator, MatrixMut, MatrixRef, TransposeOperator, VectorMut, VectorRef};
+use crate::vector::Vector;
+#[cfg(feature = "fmt")]
+use core::fmt;
+use core::ops::{Add, Index, IndexMut, Mul};
+use num_traits::Zero;
+
+/// A static mutable strided view of a matrix.
+///
+/// See [`MatrixStridedView`] for how the elements of the view are laid out.
+#[derive(Debug)]
+pub struct MatrixStridedViewMut<
+    'a,
+    T,
+    const R: usize,
+    const C: usize,
+    const VR: usize,
+    const VC: usize,
+> {
+    data: &'a mut Matrix<T, R, C>,
+    offset: usize,
+    stride: (isize, isize), // In terms of the row-major elements of the matrix
+}
+
+impl<'a, T, const R: usize, const C: usize, const VR: usize, const VC: usize>
+    MatrixStridedViewMut<'a, T, R, C, VR, VC>
+{
+    pub(super) fn new(data: &'a mut Matrix<T, R, C>, offset: usize, stride: (isize, isize)) -> Self {
+        if !fits(offset, stride, VR, VC, R * C) {
+            panic!("View size out of bounds");
+        }
+        Self {
+            data,
+            offset,
+            stride,
+        }
+    }
+
+    /// Returns the shape of the [`MatrixStridedViewMut`].
+    ///
+    /// The shape is always equal to `(VR, VC)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mut mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// let view = mat.strided_view_mut::<2, 3>((0, 0), (2, 1)).unwrap();
+    /// assert_eq!(view.shape(), (2, 3));
+    /// ```
+    #[inline]
+    pub fn shape(&self) -> (usize, usize) {
+        (VR, VC)
+    }
+
+    /// Returns the total number of elements in the [`MatrixStridedViewMut`].
+    ///
+    /// The total number of elements is always equal to `VR * VC`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mut mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// let view = mat.strided_view_mut::<2, 3>((0, 0), (2, 1)).unwrap();
+    /// assert_eq!(view.capacity(), 6);
+    /// ```
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        VR * VC
+    }
+
+    /// Returns the number of rows in the [`MatrixStridedViewMut`].
+    ///
+    /// The number of rows is always equal to `VR`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mut mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// let view = mat.strided_view_mut::<2, 3>((0, 0), (2, 1)).unwrap();
+    /// assert_eq!(view.rows(), 2);
+    /// ```
+    #[inline]
+    pub fn rows(&self) -> usize {
+        VR
+    }
+
+    /// Returns the number of columns in the [`MatrixStridedViewMut`].
+    ///
+    /// The number of columns is always equal to `VC`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mut mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// let view = mat.strided_view_mut::<2, 3>((0, 0), (2, 1)).unwrap();
+    /// assert_eq!(view.cols(), 3);
+    /// ```
+    #[inline]
+    pub fn cols(&self) -> usize {
+        VC
+    }
+
+    /// Returns a transposed view of the [`MatrixStridedViewMut`].
+    ///
+    /// This method returns a [`MatrixStridedView`] with the row and column steps swapped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mut mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// let view = mat.strided_view_mut::<2, 3>((0, 0), (2, 1)).unwrap();
+    /// assert_eq!(view.t(), Matrix::from([[1, 7], [2, 8], [3, 9]]));
+    /// ```
+    pub fn t(&self) -> MatrixStridedView<'_, T, R, C, VC, VR> {
+        MatrixStridedView::new(self.data, self.offset, (self.stride.1, self.stride.0))
+    }
+
+    /// Returns a mutable transposed view of the [`MatrixStridedViewMut`].
+    ///
+    /// This method returns a [`MatrixStridedViewMut`] with the row and column steps swapped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mut mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// let mut view = mat.strided_view_mut::<2, 3>((0, 0), (2, 1)).unwrap();
+    /// view.t_mut()[(2, 1)] = 0;
+    /// assert_eq!(mat, Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 0]]));
+    /// ```
+    pub fn t_mut(&mut self) -> MatrixStridedViewMut<'_, T, R, C, VC, VR> {
+        MatrixStridedViewMut::new(self.data, self.offset, (self.stride.1, self.stride.0))
+    }
+}
+
+impl<T: Conjugate, const R: usize, const C: usize, const VR: usize, const VC: usize>
+    MatrixStridedViewMut<'_, T, R, C, VR, VC>
+{
+    /// Returns the element-wise complex conjugate of the [`MatrixStridedViewMut`] as a new [`Matrix`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mut mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// let view = mat.strided_view_mut::<2, 2>((0, 0), (2, 2)).unwrap();
+    /// assert_eq!(view.conj(), Matrix::from([[1, 3], [7, 9]]));
+    /// ```
+    pub fn conj(&self) -> Matrix<T, VR, VC> {
+        Matrix::from(core::array::from_fn(|i| core::array::from_fn(|j| self[(i, j)].clone().conj())))
+    }
+
+    /// Returns the conjugate transpose of the [`MatrixStridedViewMut`] as a new [`Matrix`].
+    ///
+    /// For real element types this is the same as copying the transpose.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mut mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// let view = mat.strided_view_mut::<2, 2>((0, 0), (2, 2)).unwrap();
+    /// assert_eq!(view.h(), Matrix::from([[1, 7], [3, 9]]));
+    /// ```
+    pub fn h(&self) -> Matrix<T, VC, VR> {
+        Matrix::from(core::array::from_fn(|i| core::array::from_fn(|j| self[(j, i)].clone().conj())))
+    }
+}
+
+//////////////////////////////////////
+//  Equality Trait Implementations  //
+//////////////////////////////////////
+
+// MatrixStridedViewMut == Matrix
+impl<T: PartialEq, const R: usize, const C: usize, const VR: usize, const VC: usize>
+    PartialEq<Matrix<T, VR, VC>> for MatrixStridedViewMut<'_, T, R, C, VR, VC>
+{
+    fn eq(&self, other: &Matrix<T, VR, VC>) -> bool {
+        (0..VR).all(|i| (0..VC).all(|j| self[(i, j)] == other[(i, j)]))
+    }
+}
+
+// MatrixStridedViewMut == Vector
+impl<T: PartialEq, const R: usize, const C: usize, const N: usize> PartialEq<Vector<T, N>>
+    for MatrixStridedViewMut<'_, T, R, C, N, 1>
+{
+    fn eq(&self, other: &Vector<T, N>) -> bool {
+        (0..N).all(|i| self[i] == other[i])
+    }
+}
+
+impl_strided_eq!(MatrixStridedViewMut, MatrixView);
+impl_strided_eq!(MatrixStridedViewMut, MatrixViewMut);
+impl_strided_eq!(MatrixStridedViewMut, MatrixTransposeView);
+impl_strided_eq!(MatrixStridedViewMut, MatrixTransposeViewMut);
+impl_strided_eq!(MatrixStridedViewMut, MatrixStridedView);
+impl_strided_eq!(MatrixStridedViewMut, MatrixStridedViewMut);
+
+impl<T: Eq, const R: usize, const C: usize, const VR: usize, const VC: usize> Eq
+    for MatrixStridedViewMut<'_, T, R, C, VR, VC>
+{
+}
+
+////////////////////////////////////////////
+//  LinearOperator Trait Implementations  //
+////////////////////////////////////////////
+
+impl<
+        T: Clone + Zero + Mul<T, Output = T> + Add<T, Output = T>,
+        const R: usize,
+        const C: usize,
+        const VR: usize,
+        const VC: usize,
+    > LinearOperator<T, VR, VC> for MatrixStridedViewMut<'_, T, R, C, VR, VC>
+{
+    fn apply(&self, x: &Vector<T, VC>) -> Vector<T, VR> {
+        self.matvec(x)
+    }
+}
+
+impl<
+        T: Clone + Zero + Mul<T, Output = T> + Add<T, Output = T>,
+        const R: usize,
+        const C: usize,
+        const VR: usize,
+        const VC: usize,
+    > TransposeOperator<T, VR, VC> for MatrixStridedViewMut<'_, T, R, C, VR, VC>
+{
+    fn apply_transpose(&self, x: &Vector<T, VR>) -> Vector<T, VC> {
+        self.t().matvec(x)
+    }
+}
+
+/////////////////////////////////////
+//  Display Trait Implementations  //
+/////////////////////////////////////
+
+#[cfg(feature = "fmt")]
+impl<T: fmt::Display, const R: usize, const C: usize, const VR: usize, const VC: usize> fmt::Display
+    for MatrixStridedViewMut<'_, T, R, C, VR, VC>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "MatrixStridedViewMut(")?;
+        }
+
+        write!(f, "[")?;
+        for i in 0..VR {
+            if i > 0 {
+                write!(f, " ")?;
+                if f.alternate() {
+                    write!(f, "                     ")?;
+                }
+            }
+            write!(f, "[")?;
+            for j in 0..VC {
+                write!(f, "{}", self[(i, j)])?;
+                if j < VC - 1 {
+                    write!(f, ", ")?;
+                }
+            }
+            write!(f, "]")?;
+            if i < VR - 1 {
+                writeln!(f)?;
+            }
+        }
+        write!(f, "]")?;
+
+        if f.alternate() {
+            write!(f, ", dtype={})", core::any::type_name::<T>())?;
+        }
+
+        Ok(())
+    }
+}
+
+///////////////////////////////////////
+//  MatrixRef Trait Implementations  //
+///////////////////////////////////////
+
+impl<T, const R: usize, const C: usize, const VR: usize, const VC: usize> MatrixRef<T, VR, VC>
+    for MatrixStridedViewMut<'_, T, R, C, VR, VC>
+{
+}
+
+impl<T, const R: usize, const C: usize, const VR: usize, const VC: usize> MatrixMut<T, VR, VC>
+    for MatrixStridedViewMut<'_, T, R, C, VR, VC>
+{
+}
+
+///////////////////////////////////////
+//  VectorRef Trait Implementations  //
+///////////////////////////////////////
+
+// `N x 1` strided views are what `diagonal_view` and `anti_diagonal_view` return
+impl<T, const R: usize, const C: usize, const N: usize> VectorRef<T, N>
+    for MatrixStridedViewMut<'_, T, R, C, N, 1>
+{
+}
+
+impl<T, const R: usize, const C: usize, const N: usize> VectorMut<T, N>
+    for MatrixStridedViewMut<'_, T, R, C, N, 1>
+{
+}
+
+///////////////////////////////////
+//  Index Trait Implementations  //
+///////////////////////////////////
+
+impl<T, const R: usize, const C: usize, const VR: usize, const VC: usize>
+    MatrixStridedViewMut<'_, T, R, C, VR, VC>
+{
+    #[inline]
+    fn offset(&self, index: (usize, usize)) -> usize {
+        (self.offset as isize + index.0 as isize * self.stride.0 + index.1 as isize * self.stride.1)
+            as usize
+    }
+
+    #[inline]
+    fn validate_index(&self, index: (usize, usize)) -> bool {
+        index.0 < VR && index.1 < VC
+    }
+}
+
+impl<T, const R: usize, const C: usize, const VR: usize, const VC: usize> Index<usize>
+    for MatrixStridedViewMut<'_, T, R, C, VR, VC>
+{
+    type Output = T;
+    fn index(&self, index: usize) -> &Self::Output {
+        if index >= VR * VC {
+            panic!("Index out of bounds");
+        }
+
+        let row_idx = index / VC;
+        let col_idx = index % VC;
+        &self.data[self.offset((row_idx, col_idx))]
+    }
+}
+
+impl<T, const R: usize, const C: usize, const VR: usize, const VC: usize> IndexMut<usize>
+    for MatrixStridedViewMut<'_, T, R, C, VR, VC>
+{
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        if index >= VR * VC {
+            panic!("Index out of bounds");
+        }
+
+        let row_idx = index / VC;
+        let col_idx = index % VC;
+        let offset = self.offset((row_idx, col_idx));
+        &mut self.data[offset]
+    }
+}
+
+impl<T, const R: usize, const C: usize, const VR: usize, const VC: usize> Index<(usize, usize)>
+    for MatrixStridedViewMut<'_, T, R, C, VR, VC>
+{
+    type Output = T;
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        if !self.validate_index(index) {
+            panic!("Index out of bounds");
+        }
+        &self.data[self.offset(index)]
+    }
+}
+
+impl<T, const R: usize, const C: usize, const VR: usize, const VC: usize> IndexMut<(usize, usize)>
+    for MatrixStridedViewMut<'_, T, R, C, VR, VC>
+{
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        if !self.validate_index(index) {
+            panic!("Index out of bounds");
+        }
+        let offset = self.offset(index);
+        &mut self.data[offset]
+    }
+}
@@ -1,12 +1,19 @@
 use crate::matrix::Matrix;
+use crate::matrix_strided_view::{impl_strided_eq, MatrixStridedView};
+use crate::matrix_strided_view_mut::MatrixStridedViewMut;
 use crate::matrix_transpose_view::MatrixTransposeView;
 use crate::matrix_view::MatrixView;
 use crate::matrix_view_mut::MatrixViewMut;
-use std::fmt;
-use std::ops::{Index, IndexMut};
+use crate::traits::{Conjugate, LinearOperator, MatrixMut, MatrixRef, Strided, StridedRef, TransposeOperator};
+use crate::vector::Vector;
+#[cfg(feature = "fmt")]
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Add, Index, IndexMut, Mul};
+use core::ptr::NonNull;
+use num_traits::Zero;
 
 /// A static mutable transposed view of a matrix.
-#[derive(Debug)]
 pub struct MatrixTransposeViewMut<
     'a,
     T,
@@ -15,18 +22,46 @@ pub struct MatrixTransposeViewMut<
     const VR: usize,
     const VC: usize,
 > {
-    data: &'a mut Matrix<T, R, C>,
+    data: NonNull<Matrix<T, R, C>>,
     start: (usize, usize), // In terms of the transposed matrix
+    _marker: PhantomData<&'a mut Matrix<T, R, C>>,
+}
+
+// SAFETY: a `MatrixTransposeViewMut` behaves like a `&'a mut` to its block of the matrix.
+unsafe impl<T: Send, const R: usize, const C: usize, const VR: usize, const VC: usize> Send
+    for MatrixTransposeViewMut<'_, T, R, C, VR, VC>
+{
+}
+
+// SAFETY: shared access to a `MatrixTransposeViewMut` only hands out shared references.
+unsafe impl<T: Sync, const R: usize, const C: usize, const VR: usize, const VC: usize> Sync
+    for MatrixTransposeViewMut<'_, T, R, C, VR, VC>
+{
 }
 
 impl<'a, T, const R: usize, const C: usize, const VR: usize, const VC: usize>
     MatrixTransposeViewMut<'a, T, R, C, VR, VC>
 {
     pub(super) fn new(data: &'a mut Matrix<T, R, C>, start: (usize, usize)) -> Self {
+        // SAFETY: `data` is a unique borrow for `'a`
+        unsafe { Self::from_raw(NonNull::from(data), start) }
+    }
+
+    /// Creates a transposed view from a raw matrix pointer.
+    ///
+    /// # Safety
+    ///
+    /// `data` must be valid for `'a`, and no other live view or reference may access the
+    /// elements covered by the view during `'a`.
+    pub(super) unsafe fn from_raw(data: NonNull<Matrix<T, R, C>>, start: (usize, usize)) -> Self {
         if start.0 + VR > C || start.1 + VC > R {
             panic!("View size out of bounds");
         }
-        Self { data, start }
+        Self {
+            data,
+            start,
+            _marker: PhantomData,
+        }
     }
 }
 
@@ -119,8 +154,9 @@ impl<'a, T, const R: usize, const C: usize, const VR: usize, const VC: usize>
     /// let original = transposed.t();
     /// assert_eq!(original, Matrix::from([[1, 2, 3], [4, 5, 6]]));
     /// ```
-    pub fn t(&'a self) -> MatrixView<'a, T, R, C, VC, VR> {
-        MatrixView::new(self.data, (self.start.1, self.start.0))
+    pub fn t(&self) -> MatrixView<'_, T, R, C, VC, VR> {
+        // SAFETY: the transposed view covers the same block and borrows `self`
+        unsafe { MatrixView::from_raw(self.data, (self.start.1, self.start.0)) }
     }
 
     /// Returns a mutable transposed view of the [`MatrixTransposeViewMut`].
@@ -138,8 +174,105 @@ impl<'a, T, const R: usize, const C: usize, const VR: usize, const VC: usize>
     /// original[(0, 1)] = 10;
     /// assert_eq!(mat, Matrix::from([[1, 10, 3], [4, 5, 6]]));
     /// ```
-    pub fn t_mut(&'a mut self) -> MatrixViewMut<'a, T, R, C, VC, VR> {
-        MatrixViewMut::new(self.data, (self.start.1, self.start.0))
+    pub fn t_mut(&mut self) -> MatrixViewMut<'_, T, R, C, VC, VR> {
+        // SAFETY: the untransposed view covers the same block and borrows `self` mutably
+        unsafe { MatrixViewMut::from_raw(self.data, (self.start.1, self.start.0)) }
+    }
+
+    /// Returns a view of the [`MatrixTransposeViewMut`].
+    ///
+    /// This method returns a [`MatrixTransposeView`] of size `NR x NC` starting from the given index,
+    /// relative to the [`MatrixTransposeViewMut`]. Returns `None` if the requested view is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mut mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// let transposed = mat.t_mut();
+    /// let view = transposed.view::<2, 2>((1, 0)).unwrap();
+    /// assert_eq!(view, Matrix::from([[2, 5], [3, 6]]));
+    /// ```
+    pub fn view<const NR: usize, const NC: usize>(
+        &self,
+        start: (usize, usize),
+    ) -> Option<MatrixTransposeView<'_, T, R, C, NR, NC>> {
+        if start.0 + NR > VR || start.1 + NC > VC {
+            return None;
+        }
+        // SAFETY: the inner view lies within this view's block and borrows `self`
+        Some(unsafe {
+            MatrixTransposeView::from_raw(self.data, (self.start.0 + start.0, self.start.1 + start.1))
+        })
+    }
+
+    /// Returns a mutable view of the [`MatrixTransposeViewMut`].
+    ///
+    /// This method returns a [`MatrixTransposeViewMut`] of size `NR x NC` starting from the given index,
+    /// relative to the [`MatrixTransposeViewMut`]. Returns `None` if the requested view is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mut mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// let mut transposed = mat.t_mut();
+    /// let mut view = transposed.view_mut::<2, 2>((1, 0)).unwrap();
+    /// view[(0, 1)] = 10;
+    /// assert_eq!(mat, Matrix::from([[1, 2, 3], [4, 10, 6], [7, 8, 9]]));
+    /// ```
+    pub fn view_mut<const NR: usize, const NC: usize>(
+        &mut self,
+        start: (usize, usize),
+    ) -> Option<MatrixTransposeViewMut<'_, T, R, C, NR, NC>> {
+        if start.0 + NR > VR || start.1 + NC > VC {
+            return None;
+        }
+        // SAFETY: the inner block lies within this view, which is borrowed mutably
+        Some(unsafe {
+            MatrixTransposeViewMut::from_raw(
+                self.data,
+                (self.start.0 + start.0, self.start.1 + start.1),
+            )
+        })
+    }
+}
+
+impl<T: Conjugate, const R: usize, const C: usize, const VR: usize, const VC: usize>
+    MatrixTransposeViewMut<'_, T, R, C, VR, VC>
+{
+    /// Returns the element-wise complex conjugate of the [`MatrixTransposeViewMut`] as a new [`Matrix`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mut mat = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+    /// let view = mat.t_mut();
+    /// assert_eq!(view.conj(), Matrix::from([[1, 4], [2, 5], [3, 6]]));
+    /// ```
+    pub fn conj(&self) -> Matrix<T, VR, VC> {
+        Matrix::from(core::array::from_fn(|i| core::array::from_fn(|j| self[(i, j)].clone().conj())))
+    }
+
+    /// Returns the conjugate transpose of the [`MatrixTransposeViewMut`] as a new [`Matrix`].
+    ///
+    /// For real element types this is the same as copying the transpose.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mut mat = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+    /// let view = mat.t_mut();
+    /// assert_eq!(view.h(), Matrix::from([[1, 2, 3], [4, 5, 6]]));
+    /// ```
+    pub fn h(&self) -> Matrix<T, VC, VR> {
+        Matrix::from(core::array::from_fn(|i| core::array::from_fn(|j| self[(j, i)].clone().conj())))
     }
 }
 
@@ -227,15 +360,70 @@ impl<
     }
 }
 
+// MatrixTransposeViewMut == MatrixStridedView
+impl_strided_eq!(MatrixTransposeViewMut, MatrixStridedView);
+
+// MatrixTransposeViewMut == MatrixStridedViewMut
+impl_strided_eq!(MatrixTransposeViewMut, MatrixStridedViewMut);
+
 impl<'a, T: Eq, const R: usize, const C: usize, const VR: usize, const VC: usize> Eq
     for MatrixTransposeViewMut<'a, T, R, C, VR, VC>
 {
 }
 
+////////////////////////////////////////////
+//  LinearOperator Trait Implementations  //
+////////////////////////////////////////////
+
+impl<
+        T: Clone + Zero + Mul<T, Output = T> + Add<T, Output = T>,
+        const R: usize,
+        const C: usize,
+        const VR: usize,
+        const VC: usize,
+    > LinearOperator<T, VR, VC> for MatrixTransposeViewMut<'_, T, R, C, VR, VC>
+{
+    fn apply(&self, x: &Vector<T, VC>) -> Vector<T, VR> {
+        Vector::from(self * x)
+    }
+}
+
+impl<
+        T: Clone + Zero + Mul<T, Output = T> + Add<T, Output = T>,
+        const R: usize,
+        const C: usize,
+        const VR: usize,
+        const VC: usize,
+    > TransposeOperator<T, VR, VC> for MatrixTransposeViewMut<'_, T, R, C, VR, VC>
+{
+    fn apply_transpose(&self, x: &Vector<T, VR>) -> Vector<T, VC> {
+        Vector::from(self.t() * x)
+    }
+}
+
+///////////////////////////////////
+//  Debug Trait Implementations  //
+///////////////////////////////////
+
+impl<T: core::fmt::Debug, const R: usize, const C: usize, const VR: usize, const VC: usize>
+    core::fmt::Debug for MatrixTransposeViewMut<'_, T, R, C, VR, VC>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // Only the elements inside the view may be read
+        let data: [[&T; VC]; VR] =
+            core::array::from_fn(|i| core::array::from_fn(|j| &self[(i, j)]));
+        f.debug_struct("MatrixTransposeViewMut")
+            .field("data", &data)
+            .field("start", &self.start)
+            .finish()
+    }
+}
+
 /////////////////////////////////////
 //  Display Trait Implementations  //
 /////////////////////////////////////
 
+#[cfg(feature = "fmt")]
 impl<T: fmt::Display, const R: usize, const C: usize, const VR: usize, const VC: usize> fmt::Display
     for MatrixTransposeViewMut<'_, T, R, C, VR, VC>
 {
@@ -267,13 +455,41 @@ impl<T: fmt::Display, const R: usize, const C: usize, const VR: usize, const VC:
         write!(f, "]")?;
 
         if f.alternate() {
-            write!(f, ", dtype={})", std::any::type_name::<T>())?;
+            write!(f, ", dtype={})", core::any::type_name::<T>())?;
         }
 
         Ok(())
     }
 }
 
+/////////////////////////////////////
+//  Strided Trait Implementations  //
+/////////////////////////////////////
+
+impl<T, const R: usize, const C: usize, const VR: usize, const VC: usize> Strided<T>
+    for MatrixTransposeViewMut<'_, T, R, C, VR, VC>
+{
+    fn strided(&self) -> StridedRef<'_, T> {
+        // `start` is in terms of the transposed matrix
+        // SAFETY: the kernels only read the elements inside the view
+        unsafe { StridedRef::from_raw(self.data.as_ptr().cast(), self.start.1 * C + self.start.0, 1, C) }
+    }
+}
+
+///////////////////////////////////////
+//  MatrixRef Trait Implementations  //
+///////////////////////////////////////
+
+impl<T, const R: usize, const C: usize, const VR: usize, const VC: usize> MatrixRef<T, VR, VC>
+    for MatrixTransposeViewMut<'_, T, R, C, VR, VC>
+{
+}
+
+impl<T, const R: usize, const C: usize, const VR: usize, const VC: usize> MatrixMut<T, VR, VC>
+    for MatrixTransposeViewMut<'_, T, R, C, VR, VC>
+{
+}
+
 ///////////////////////////////////
 //  Index Trait Implementations  //
 ///////////////////////////////////
@@ -295,6 +511,12 @@ impl<'a, T, const R: usize, const C: usize, const VR: usize, const VC: usize>
     fn validate_index(&self, index: (usize, usize)) -> bool {
         index.0 < VR && index.1 < VC
     }
+
+    #[inline]
+    fn ptr(&self, index: (usize, usize)) -> *mut T {
+        // SAFETY: `data` is valid and `index` has been offset into the view's block
+        unsafe { Matrix::ptr_at(self.data.as_ptr(), index) }
+    }
 }
 
 impl<T, const R: usize, const C: usize, const VR: usize, const VC: usize> Index<usize>
@@ -302,13 +524,14 @@ impl<T, const R: usize, const C: usize, const VR: usize, const VC: usize> Index<
 {
     type Output = T;
     fn index(&self, index: usize) -> &Self::Output {
-        if index >= R * C {
+        if index >= VR * VC {
             panic!("Index out of bounds");
         }
 
         let row_idx = index / VC;
         let col_idx = index % VC;
-        &self.data[self.flip(self.offset((row_idx, col_idx)))]
+        // SAFETY: the element lies within the view's block
+        unsafe { &*self.ptr(self.flip(self.offset((row_idx, col_idx)))) }
     }
 }
 
@@ -316,14 +539,14 @@ impl<T, const R: usize, const C: usize, const VR: usize, const VC: usize> IndexM
     for MatrixTransposeViewMut<'_, T, R, C, VR, VC>
 {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        if index >= R * C {
+        if index >= VR * VC {
             panic!("Index out of bounds");
         }
 
         let row_idx = index / VC;
         let col_idx = index % VC;
-        let index = self.flip(self.offset((row_idx, col_idx)));
-        &mut self.data[index]
+        // SAFETY: the element lies within the view's block and `self` is borrowed mutably
+        unsafe { &mut *self.ptr(self.flip(self.offset((row_idx, col_idx)))) }
     }
 }
 
@@ -335,7 +558,8 @@ impl<T, const R: usize, const C: usize, const VR: usize, const VC: usize> Index<
         if !self.validate_index(index) {
             panic!("Index out of bounds");
         }
-        &self.data[self.flip(self.offset(index))]
+        // SAFETY: the element lies within the view's block
+        unsafe { &*self.ptr(self.flip(self.offset(index))) }
     }
 }
 
@@ -346,7 +570,7 @@ impl<T, const R: usize, const C: usize, const VR: usize, const VC: usize> IndexM
         if !self.validate_index(index) {
             panic!("Index out of bounds");
         }
-        let index = self.flip(self.offset(index));
-        &mut self.data[index]
+        // SAFETY: the element lies within the view's block and `self` is borrowed mutably
+        unsafe { &mut *self.ptr(self.flip(self.offset(index))) }
     }
 }
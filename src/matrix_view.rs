@@ -1,25 +1,64 @@
 use crate::matrix::Matrix;
+use crate::matrix_strided_view::{impl_strided_eq, MatrixStridedView};
+use crate::matrix_strided_view_mut::MatrixStridedViewMut;
 use crate::matrix_transpose_view::MatrixTransposeView;
 use crate::matrix_transpose_view_mut::MatrixTransposeViewMut;
 use crate::matrix_view_mut::MatrixViewMut;
-use std::fmt;
-use std::ops::Index;
+use crate::traits::{Conjugate, LinearOperator, MatrixRef, Strided, StridedRef, TransposeOperator};
+use crate::vector::Vector;
+#[cfg(feature = "fmt")]
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Add, Index, Mul};
+use core::ptr::NonNull;
+use num_traits::Zero;
 
 /// A static view of a matrix.
-#[derive(Debug)]
+///
+/// The view holds a pointer to the matrix and only ever reads the elements inside its own
+/// block, so it can also be taken from a [`MatrixViewMut`] while other mutable views of the
+/// same [`Matrix`] are in use.
 pub struct MatrixView<'a, T, const R: usize, const C: usize, const VR: usize, const VC: usize> {
-    data: &'a Matrix<T, R, C>,
+    data: NonNull<Matrix<T, R, C>>,
     start: (usize, usize),
+    _marker: PhantomData<&'a Matrix<T, R, C>>,
+}
+
+// SAFETY: a `MatrixView` behaves like a `&'a` to its block of the matrix.
+unsafe impl<T: Sync, const R: usize, const C: usize, const VR: usize, const VC: usize> Send
+    for MatrixView<'_, T, R, C, VR, VC>
+{
+}
+
+// SAFETY: a `MatrixView` only hands out shared references.
+unsafe impl<T: Sync, const R: usize, const C: usize, const VR: usize, const VC: usize> Sync
+    for MatrixView<'_, T, R, C, VR, VC>
+{
 }
 
 impl<'a, T, const R: usize, const C: usize, const VR: usize, const VC: usize>
     MatrixView<'a, T, R, C, VR, VC>
 {
     pub(super) fn new(data: &'a Matrix<T, R, C>, start: (usize, usize)) -> Self {
+        // SAFETY: `data` is a shared borrow for `'a`
+        unsafe { Self::from_raw(NonNull::from(data), start) }
+    }
+
+    /// Creates a view from a raw matrix pointer.
+    ///
+    /// # Safety
+    ///
+    /// `data` must be valid for `'a`, and nothing may write to the elements of the
+    /// `VR x VC` block starting at `start` during `'a`.
+    pub(super) unsafe fn from_raw(data: NonNull<Matrix<T, R, C>>, start: (usize, usize)) -> Self {
         if start.0 + VR > R || start.1 + VC > C {
             panic!("View size out of bounds");
         }
-        Self { data, start }
+        Self {
+            data,
+            start,
+            _marker: PhantomData,
+        }
     }
 
     /// Returns the shape of the [`MatrixView`].
@@ -109,7 +148,72 @@ impl<'a, T, const R: usize, const C: usize, const VR: usize, const VC: usize>
     /// assert_eq!(transposed, Matrix::from([[5, 8], [6, 9]]));
     /// ```
     pub fn t(&self) -> MatrixTransposeView<'a, T, R, C, VC, VR> {
-        MatrixTransposeView::new(self.data, (self.start.1, self.start.0))
+        // SAFETY: the transposed view covers the same block for the same lifetime
+        unsafe { MatrixTransposeView::from_raw(self.data, (self.start.1, self.start.0)) }
+    }
+
+    /// Returns a view of the [`MatrixView`].
+    ///
+    /// This method returns a [`MatrixView`] of size `NR x NC` starting from the given index,
+    /// relative to the [`MatrixView`]. Returns `None` if the requested view is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// let view = mat.view::<2, 3>((1, 0)).unwrap();
+    /// let inner = view.view::<2, 2>((0, 1)).unwrap();
+    /// assert_eq!(inner, Matrix::from([[5, 6], [8, 9]]));
+    /// ```
+    pub fn view<const NR: usize, const NC: usize>(
+        &self,
+        start: (usize, usize),
+    ) -> Option<MatrixView<'a, T, R, C, NR, NC>> {
+        if start.0 + NR > VR || start.1 + NC > VC {
+            return None;
+        }
+        // SAFETY: the inner view lies within this view's block
+        Some(unsafe {
+            MatrixView::from_raw(self.data, (self.start.0 + start.0, self.start.1 + start.1))
+        })
+    }
+}
+
+impl<T: Conjugate, const R: usize, const C: usize, const VR: usize, const VC: usize>
+    MatrixView<'_, T, R, C, VR, VC>
+{
+    /// Returns the element-wise complex conjugate of the [`MatrixView`] as a new [`Matrix`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// let view = mat.view::<2, 2>((1, 1)).unwrap();
+    /// assert_eq!(view.conj(), Matrix::from([[5, 6], [8, 9]]));
+    /// ```
+    pub fn conj(&self) -> Matrix<T, VR, VC> {
+        Matrix::from(core::array::from_fn(|i| core::array::from_fn(|j| self[(i, j)].clone().conj())))
+    }
+
+    /// Returns the conjugate transpose of the [`MatrixView`] as a new [`Matrix`].
+    ///
+    /// For real element types this is the same as copying the transpose.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// let view = mat.view::<2, 2>((1, 1)).unwrap();
+    /// assert_eq!(view.h(), Matrix::from([[5, 8], [6, 9]]));
+    /// ```
+    pub fn h(&self) -> Matrix<T, VC, VR> {
+        Matrix::from(core::array::from_fn(|i| core::array::from_fn(|j| self[(j, i)].clone().conj())))
     }
 }
 
@@ -194,15 +298,70 @@ impl<
     }
 }
 
+// MatrixView == MatrixStridedView
+impl_strided_eq!(MatrixView, MatrixStridedView);
+
+// MatrixView == MatrixStridedViewMut
+impl_strided_eq!(MatrixView, MatrixStridedViewMut);
+
 impl<'a, T: Eq, const R: usize, const C: usize, const VR: usize, const VC: usize> Eq
     for MatrixView<'a, T, R, C, VR, VC>
 {
 }
 
+////////////////////////////////////////////
+//  LinearOperator Trait Implementations  //
+////////////////////////////////////////////
+
+impl<
+        T: Clone + Zero + Mul<T, Output = T> + Add<T, Output = T>,
+        const R: usize,
+        const C: usize,
+        const VR: usize,
+        const VC: usize,
+    > LinearOperator<T, VR, VC> for MatrixView<'_, T, R, C, VR, VC>
+{
+    fn apply(&self, x: &Vector<T, VC>) -> Vector<T, VR> {
+        Vector::from(self * x)
+    }
+}
+
+impl<
+        T: Clone + Zero + Mul<T, Output = T> + Add<T, Output = T>,
+        const R: usize,
+        const C: usize,
+        const VR: usize,
+        const VC: usize,
+    > TransposeOperator<T, VR, VC> for MatrixView<'_, T, R, C, VR, VC>
+{
+    fn apply_transpose(&self, x: &Vector<T, VR>) -> Vector<T, VC> {
+        Vector::from(self.t() * x)
+    }
+}
+
+///////////////////////////////////
+//  Debug Trait Implementations  //
+///////////////////////////////////
+
+impl<T: core::fmt::Debug, const R: usize, const C: usize, const VR: usize, const VC: usize>
+    core::fmt::Debug for MatrixView<'_, T, R, C, VR, VC>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // Only the elements inside the view may be read
+        let data: [[&T; VC]; VR] =
+            core::array::from_fn(|i| core::array::from_fn(|j| &self[(i, j)]));
+        f.debug_struct("MatrixView")
+            .field("data", &data)
+            .field("start", &self.start)
+            .finish()
+    }
+}
+
 /////////////////////////////////////
 //  Display Trait Implementations  //
 /////////////////////////////////////
 
+#[cfg(feature = "fmt")]
 impl<T: fmt::Display, const R: usize, const C: usize, const VR: usize, const VC: usize> fmt::Display
     for MatrixView<'_, T, R, C, VR, VC>
 {
@@ -234,13 +393,35 @@ impl<T: fmt::Display, const R: usize, const C: usize, const VR: usize, const VC:
         write!(f, "]")?;
 
         if f.alternate() {
-            write!(f, ", dtype={})", std::any::type_name::<T>())?;
+            write!(f, ", dtype={})", core::any::type_name::<T>())?;
         }
 
         Ok(())
     }
 }
 
+/////////////////////////////////////
+//  Strided Trait Implementations  //
+/////////////////////////////////////
+
+impl<T, const R: usize, const C: usize, const VR: usize, const VC: usize> Strided<T>
+    for MatrixView<'_, T, R, C, VR, VC>
+{
+    fn strided(&self) -> StridedRef<'_, T> {
+        // SAFETY: the kernels only read the elements inside the view
+        unsafe { StridedRef::from_raw(self.data.as_ptr().cast(), self.start.0 * C + self.start.1, C, 1) }
+    }
+}
+
+///////////////////////////////////////
+//  MatrixRef Trait Implementations  //
+///////////////////////////////////////
+
+impl<T, const R: usize, const C: usize, const VR: usize, const VC: usize> MatrixRef<T, VR, VC>
+    for MatrixView<'_, T, R, C, VR, VC>
+{
+}
+
 ///////////////////////////////////
 //  Index Trait Implementations  //
 ///////////////////////////////////
@@ -257,6 +438,12 @@ impl<'a, T, const R: usize, const C: usize, const VR: usize, const VC: usize>
     fn validate_index(&self, index: (usize, usize)) -> bool {
         index.0 < VR && index.1 < VC
     }
+
+    #[inline]
+    fn ptr(&self, index: (usize, usize)) -> *const T {
+        // SAFETY: `data` is valid and `index` has been offset into the view's block
+        unsafe { Matrix::ptr_at(self.data.as_ptr(), index) }
+    }
 }
 
 impl<T, const R: usize, const C: usize, const VR: usize, const VC: usize> Index<usize>
@@ -264,13 +451,14 @@ impl<T, const R: usize, const C: usize, const VR: usize, const VC: usize> Index<
 {
     type Output = T;
     fn index(&self, index: usize) -> &Self::Output {
-        if index >= R * C {
+        if index >= VR * VC {
             panic!("Index out of bounds");
         }
 
         let row_idx = index / VC;
         let col_idx = index % VC;
-        &self.data[self.offset((row_idx, col_idx))]
+        // SAFETY: the element lies within the view's block
+        unsafe { &*self.ptr(self.offset((row_idx, col_idx))) }
     }
 }
 
@@ -282,6 +470,7 @@ impl<T, const R: usize, const C: usize, const VR: usize, const VC: usize> Index<
         if !self.validate_index(index) {
             panic!("Index out of bounds");
         }
-        &self.data[self.offset(index)]
+        // SAFETY: the element lies within the view's block
+        unsafe { &*self.ptr(self.offset(index)) }
     }
 }
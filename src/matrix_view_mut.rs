@@ -1,25 +1,64 @@
 use crate::matrix::Matrix;
+use crate::matrix_strided_view::{impl_strided_eq, MatrixStridedView};
+use crate::matrix_strided_view_mut::MatrixStridedViewMut;
 use crate::matrix_transpose_view::MatrixTransposeView;
 use crate::matrix_transpose_view_mut::MatrixTransposeViewMut;
 use crate::matrix_view::MatrixView;
-use std::fmt;
-use std::ops::{Index, IndexMut};
+use crate::traits::{Conjugate, LinearOperator, MatrixMut, MatrixRef, Strided, StridedRef, TransposeOperator};
+use crate::vector::Vector;
+#[cfg(feature = "fmt")]
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Add, Index, IndexMut, Mul};
+use core::ptr::NonNull;
+use num_traits::Zero;
 
 /// A static mutable view of a matrix.
-#[derive(Debug)]
+///
+/// Several [`MatrixViewMut`]s into disjoint blocks of the same [`Matrix`] can coexist (see
+/// [`Matrix::split_at_mut_rows`]), so the view holds a pointer to the matrix and only ever
+/// accesses the elements inside its own block.
 pub struct MatrixViewMut<'a, T, const R: usize, const C: usize, const VR: usize, const VC: usize> {
-    data: &'a mut Matrix<T, R, C>,
+    data: NonNull<Matrix<T, R, C>>,
     start: (usize, usize),
+    _marker: PhantomData<&'a mut Matrix<T, R, C>>,
+}
+
+// SAFETY: a `MatrixViewMut` behaves like a `&'a mut` to its block of the matrix.
+unsafe impl<T: Send, const R: usize, const C: usize, const VR: usize, const VC: usize> Send
+    for MatrixViewMut<'_, T, R, C, VR, VC>
+{
+}
+
+// SAFETY: shared access to a `MatrixViewMut` only hands out shared references.
+unsafe impl<T: Sync, const R: usize, const C: usize, const VR: usize, const VC: usize> Sync
+    for MatrixViewMut<'_, T, R, C, VR, VC>
+{
 }
 
 impl<'a, T, const R: usize, const C: usize, const VR: usize, const VC: usize>
     MatrixViewMut<'a, T, R, C, VR, VC>
 {
     pub(super) fn new(data: &'a mut Matrix<T, R, C>, start: (usize, usize)) -> Self {
+        // SAFETY: `data` is a unique borrow for `'a`
+        unsafe { Self::from_raw(NonNull::from(data), start) }
+    }
+
+    /// Creates a view from a raw matrix pointer.
+    ///
+    /// # Safety
+    ///
+    /// `data` must be valid for `'a`, and no other live view or reference may access the
+    /// elements of the `VR x VC` block starting at `start` during `'a`.
+    pub(super) unsafe fn from_raw(data: NonNull<Matrix<T, R, C>>, start: (usize, usize)) -> Self {
         if start.0 + VR > R || start.1 + VC > C {
             panic!("View size out of bounds");
         }
-        Self { data, start }
+        Self {
+            data,
+            start,
+            _marker: PhantomData,
+        }
     }
 }
 
@@ -112,8 +151,9 @@ impl<'a, T, const R: usize, const C: usize, const VR: usize, const VC: usize>
     /// let transposed = view.t();
     /// assert_eq!(transposed, Matrix::from([[5, 8], [6, 9]]));
     /// ```
-    pub fn t(&'a self) -> MatrixTransposeView<'a, T, R, C, VC, VR> {
-        MatrixTransposeView::new(self.data, (self.start.1, self.start.0))
+    pub fn t(&self) -> MatrixTransposeView<'_, T, R, C, VC, VR> {
+        // SAFETY: the transposed view covers the same block and borrows `self`
+        unsafe { MatrixTransposeView::from_raw(self.data, (self.start.1, self.start.0)) }
     }
 
     /// Returns a mutable transposed view of the [`MatrixViewMut`].
@@ -131,8 +171,215 @@ impl<'a, T, const R: usize, const C: usize, const VR: usize, const VC: usize>
     /// transposed[(0, 1)] = 10;
     /// assert_eq!(mat, Matrix::from([[1, 2, 3], [4, 5, 6], [7, 10, 9]]));
     /// ```
-    pub fn t_mut(&'a mut self) -> MatrixTransposeViewMut<'a, T, R, C, VC, VR> {
-        MatrixTransposeViewMut::new(self.data, (self.start.1, self.start.0))
+    pub fn t_mut(&mut self) -> MatrixTransposeViewMut<'_, T, R, C, VC, VR> {
+        // SAFETY: the transposed view covers the same block and borrows `self` mutably
+        unsafe { MatrixTransposeViewMut::from_raw(self.data, (self.start.1, self.start.0)) }
+    }
+
+    /// Returns a view of the [`MatrixViewMut`].
+    ///
+    /// This method returns a [`MatrixView`] of size `NR x NC` starting from the given index,
+    /// relative to the [`MatrixViewMut`]. Returns `None` if the requested view is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mut mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// let view = mat.view_mut::<2, 3>((1, 0)).unwrap();
+    /// let inner = view.view::<2, 2>((0, 1)).unwrap();
+    /// assert_eq!(inner, Matrix::from([[5, 6], [8, 9]]));
+    /// ```
+    pub fn view<const NR: usize, const NC: usize>(
+        &self,
+        start: (usize, usize),
+    ) -> Option<MatrixView<'_, T, R, C, NR, NC>> {
+        if start.0 + NR > VR || start.1 + NC > VC {
+            return None;
+        }
+        // SAFETY: the inner view lies within this view's block and borrows `self`
+        Some(unsafe {
+            MatrixView::from_raw(self.data, (self.start.0 + start.0, self.start.1 + start.1))
+        })
+    }
+
+    /// Returns a mutable view of the [`MatrixViewMut`].
+    ///
+    /// This method returns a [`MatrixViewMut`] of size `NR x NC` starting from the given index,
+    /// relative to the [`MatrixViewMut`]. Returns `None` if the requested view is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mut mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// let mut view = mat.view_mut::<2, 3>((1, 0)).unwrap();
+    /// let mut inner = view.view_mut::<2, 2>((0, 1)).unwrap();
+    /// inner[(1, 0)] = 10;
+    /// assert_eq!(mat, Matrix::from([[1, 2, 3], [4, 5, 6], [7, 10, 9]]));
+    /// ```
+    pub fn view_mut<const NR: usize, const NC: usize>(
+        &mut self,
+        start: (usize, usize),
+    ) -> Option<MatrixViewMut<'_, T, R, C, NR, NC>> {
+        if start.0 + NR > VR || start.1 + NC > VC {
+            return None;
+        }
+        // SAFETY: the inner block lies within this view, which is borrowed mutably
+        Some(unsafe {
+            MatrixViewMut::from_raw(self.data, (self.start.0 + start.0, self.start.1 + start.1))
+        })
+    }
+
+    /// Splits the [`MatrixViewMut`] into two disjoint mutable views at row `K`.
+    ///
+    /// This method returns a [`MatrixViewMut`] over the first `K` rows of the view and one over
+    /// the remaining `L` rows. Returns `None` if `K + L` is not equal to `VR`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mut mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// let mut view = mat.view_mut::<3, 2>((0, 1)).unwrap();
+    /// let (mut top, mut bottom) = view.split_at_mut_rows::<1, 2>().unwrap();
+    /// top[(0, 1)] = 30;
+    /// bottom[(0, 0)] = 50;
+    /// assert_eq!(mat, Matrix::from([[1, 2, 30], [4, 50, 6], [7, 8, 9]]));
+    /// ```
+    pub fn split_at_mut_rows<const K: usize, const L: usize>(
+        &mut self,
+    ) -> Option<(
+        MatrixViewMut<'_, T, R, C, K, VC>,
+        MatrixViewMut<'_, T, R, C, L, VC>,
+    )> {
+        if K + L != VR {
+            return None;
+        }
+        let (i, j) = self.start;
+        // SAFETY: the two blocks are disjoint, lie within this view and borrow `self` mutably
+        unsafe {
+            Some((
+                MatrixViewMut::from_raw(self.data, (i, j)),
+                MatrixViewMut::from_raw(self.data, (i + K, j)),
+            ))
+        }
+    }
+
+    /// Splits the [`MatrixViewMut`] into two disjoint mutable views at column `K`.
+    ///
+    /// This method returns a [`MatrixViewMut`] over the first `K` columns of the view and one over
+    /// the remaining `L` columns. Returns `None` if `K + L` is not equal to `VC`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mut mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// let mut view = mat.view_mut::<2, 3>((1, 0)).unwrap();
+    /// let (mut left, mut right) = view.split_at_mut_cols::<1, 2>().unwrap();
+    /// left[(1, 0)] = 70;
+    /// right[(0, 1)] = 60;
+    /// assert_eq!(mat, Matrix::from([[1, 2, 3], [4, 5, 60], [70, 8, 9]]));
+    /// ```
+    pub fn split_at_mut_cols<const K: usize, const L: usize>(
+        &mut self,
+    ) -> Option<(
+        MatrixViewMut<'_, T, R, C, VR, K>,
+        MatrixViewMut<'_, T, R, C, VR, L>,
+    )> {
+        if K + L != VC {
+            return None;
+        }
+        let (i, j) = self.start;
+        // SAFETY: the two blocks are disjoint, lie within this view and borrow `self` mutably
+        unsafe {
+            Some((
+                MatrixViewMut::from_raw(self.data, (i, j)),
+                MatrixViewMut::from_raw(self.data, (i, j + K)),
+            ))
+        }
+    }
+
+    /// Splits the [`MatrixViewMut`] into four disjoint mutable views.
+    ///
+    /// The split happens after row `K` and column `P` of the view, so this method returns the
+    /// top-left `K x P`, top-right `K x Q`, bottom-left `L x P` and bottom-right `L x Q` blocks.
+    /// Returns `None` if `K + L` is not equal to `VR` or `P + Q` is not equal to `VC`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mut mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// let mut view = mat.view_mut::<2, 2>((1, 1)).unwrap();
+    /// let (a11, _, _, mut a22) = view.split_quadrants_mut::<1, 1, 1, 1>().unwrap();
+    /// a22[(0, 0)] = a11[(0, 0)];
+    /// assert_eq!(mat, Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 5]]));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn split_quadrants_mut<const K: usize, const L: usize, const P: usize, const Q: usize>(
+        &mut self,
+    ) -> Option<(
+        MatrixViewMut<'_, T, R, C, K, P>,
+        MatrixViewMut<'_, T, R, C, K, Q>,
+        MatrixViewMut<'_, T, R, C, L, P>,
+        MatrixViewMut<'_, T, R, C, L, Q>,
+    )> {
+        if K + L != VR || P + Q != VC {
+            return None;
+        }
+        let (i, j) = self.start;
+        // SAFETY: the four blocks are disjoint, lie within this view and borrow `self` mutably
+        unsafe {
+            Some((
+                MatrixViewMut::from_raw(self.data, (i, j)),
+                MatrixViewMut::from_raw(self.data, (i, j + P)),
+                MatrixViewMut::from_raw(self.data, (i + K, j)),
+                MatrixViewMut::from_raw(self.data, (i + K, j + P)),
+            ))
+        }
+    }
+}
+
+impl<T: Conjugate, const R: usize, const C: usize, const VR: usize, const VC: usize>
+    MatrixViewMut<'_, T, R, C, VR, VC>
+{
+    /// Returns the element-wise complex conjugate of the [`MatrixViewMut`] as a new [`Matrix`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mut mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// let view = mat.view_mut::<2, 2>((1, 1)).unwrap();
+    /// assert_eq!(view.conj(), Matrix::from([[5, 6], [8, 9]]));
+    /// ```
+    pub fn conj(&self) -> Matrix<T, VR, VC> {
+        Matrix::from(core::array::from_fn(|i| core::array::from_fn(|j| self[(i, j)].clone().conj())))
+    }
+
+    /// Returns the conjugate transpose of the [`MatrixViewMut`] as a new [`Matrix`].
+    ///
+    /// For real element types this is the same as copying the transpose.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Matrix;
+    ///
+    /// let mut mat = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    /// let view = mat.view_mut::<2, 2>((1, 1)).unwrap();
+    /// assert_eq!(view.h(), Matrix::from([[5, 8], [6, 9]]));
+    /// ```
+    pub fn h(&self) -> Matrix<T, VC, VR> {
+        Matrix::from(core::array::from_fn(|i| core::array::from_fn(|j| self[(j, i)].clone().conj())))
     }
 }
 
@@ -218,15 +465,70 @@ impl<
     }
 }
 
+// MatrixViewMut == MatrixStridedView
+impl_strided_eq!(MatrixViewMut, MatrixStridedView);
+
+// MatrixViewMut == MatrixStridedViewMut
+impl_strided_eq!(MatrixViewMut, MatrixStridedViewMut);
+
 impl<'a, T: Eq, const R: usize, const C: usize, const VR: usize, const VC: usize> Eq
     for MatrixViewMut<'a, T, R, C, VR, VC>
 {
 }
 
+////////////////////////////////////////////
+//  LinearOperator Trait Implementations  //
+////////////////////////////////////////////
+
+impl<
+        T: Clone + Zero + Mul<T, Output = T> + Add<T, Output = T>,
+        const R: usize,
+        const C: usize,
+        const VR: usize,
+        const VC: usize,
+    > LinearOperator<T, VR, VC> for MatrixViewMut<'_, T, R, C, VR, VC>
+{
+    fn apply(&self, x: &Vector<T, VC>) -> Vector<T, VR> {
+        Vector::from(self * x)
+    }
+}
+
+impl<
+        T: Clone + Zero + Mul<T, Output = T> + Add<T, Output = T>,
+        const R: usize,
+        const C: usize,
+        const VR: usize,
+        const VC: usize,
+    > TransposeOperator<T, VR, VC> for MatrixViewMut<'_, T, R, C, VR, VC>
+{
+    fn apply_transpose(&self, x: &Vector<T, VR>) -> Vector<T, VC> {
+        Vector::from(self.t() * x)
+    }
+}
+
+///////////////////////////////////
+//  Debug Trait Implementations  //
+///////////////////////////////////
+
+impl<T: core::fmt::Debug, const R: usize, const C: usize, const VR: usize, const VC: usize>
+    core::fmt::Debug for MatrixViewMut<'_, T, R, C, VR, VC>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // Only the elements inside the view may be read
+        let data: [[&T; VC]; VR] =
+            core::array::from_fn(|i| core::array::from_fn(|j| &self[(i, j)]));
+        f.debug_struct("MatrixViewMut")
+            .field("data", &data)
+            .field("start", &self.start)
+            .finish()
+    }
+}
+
 /////////////////////////////////////
 //  Display Trait Implementations  //
 /////////////////////////////////////
 
+#[cfg(feature = "fmt")]
 impl<T: fmt::Display, const R: usize, const C: usize, const VR: usize, const VC: usize> fmt::Display
     for MatrixViewMut<'_, T, R, C, VR, VC>
 {
@@ -258,13 +560,40 @@ impl<T: fmt::Display, const R: usize, const C: usize, const VR: usize, const VC:
         write!(f, "]")?;
 
         if f.alternate() {
-            write!(f, ", dtype={})", std::any::type_name::<T>())?;
+            write!(f, ", dtype={})", core::any::type_name::<T>())?;
         }
 
         Ok(())
     }
 }
 
+/////////////////////////////////////
+//  Strided Trait Implementations  //
+/////////////////////////////////////
+
+impl<T, const R: usize, const C: usize, const VR: usize, const VC: usize> Strided<T>
+    for MatrixViewMut<'_, T, R, C, VR, VC>
+{
+    fn strided(&self) -> StridedRef<'_, T> {
+        // SAFETY: the kernels only read the elements inside the view
+        unsafe { StridedRef::from_raw(self.data.as_ptr().cast(), self.start.0 * C + self.start.1, C, 1) }
+    }
+}
+
+///////////////////////////////////////
+//  MatrixRef Trait Implementations  //
+///////////////////////////////////////
+
+impl<T, const R: usize, const C: usize, const VR: usize, const VC: usize> MatrixRef<T, VR, VC>
+    for MatrixViewMut<'_, T, R, C, VR, VC>
+{
+}
+
+impl<T, const R: usize, const C: usize, const VR: usize, const VC: usize> MatrixMut<T, VR, VC>
+    for MatrixViewMut<'_, T, R, C, VR, VC>
+{
+}
+
 ///////////////////////////////////
 //  Index Trait Implementations  //
 ///////////////////////////////////
@@ -281,6 +610,12 @@ impl<'a, T, const R: usize, const C: usize, const VR: usize, const VC: usize>
     fn validate_index(&self, index: (usize, usize)) -> bool {
         index.0 < VR && index.1 < VC
     }
+
+    #[inline]
+    fn ptr(&self, index: (usize, usize)) -> *mut T {
+        // SAFETY: `data` is valid and `index` has been offset into the view's block
+        unsafe { Matrix::ptr_at(self.data.as_ptr(), index) }
+    }
 }
 
 impl<T, const R: usize, const C: usize, const VR: usize, const VC: usize> Index<usize>
@@ -288,13 +623,14 @@ impl<T, const R: usize, const C: usize, const VR: usize, const VC: usize> Index<
 {
     type Output = T;
     fn index(&self, index: usize) -> &Self::Output {
-        if index >= R * C {
+        if index >= VR * VC {
             panic!("Index out of bounds");
         }
 
         let row_idx = index / VC;
         let col_idx = index % VC;
-        &self.data[self.offset((row_idx, col_idx))]
+        // SAFETY: the element lies within the view's block
+        unsafe { &*self.ptr(self.offset((row_idx, col_idx))) }
     }
 }
 
@@ -302,14 +638,14 @@ impl<T, const R: usize, const C: usize, const VR: usize, const VC: usize> IndexM
     for MatrixViewMut<'_, T, R, C, VR, VC>
 {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        if index >= R * C {
+        if index >= VR * VC {
             panic!("Index out of bounds");
         }
 
         let row_idx = index / VC;
         let col_idx = index % VC;
-        let offset = self.offset((row_idx, col_idx));
-        &mut self.data[offset]
+        // SAFETY: the element lies within the view's block and `self` is borrowed mutably
+        unsafe { &mut *self.ptr(self.offset((row_idx, col_idx))) }
     }
 }
 
@@ -321,7 +657,8 @@ impl<T, const R: usize, const C: usize, const VR: usize, const VC: usize> Index<
         if !self.validate_index(index) {
             panic!("Index out of bounds");
         }
-        &self.data[self.offset(index)]
+        // SAFETY: the element lies within the view's block
+        unsafe { &*self.ptr(self.offset(index)) }
     }
 }
 
@@ -332,7 +669,7 @@ impl<T, const R: usize, const C: usize, const VR: usize, const VC: usize> IndexM
         if !self.validate_index(index) {
             panic!("Index out of bounds");
         }
-        let offset = self.offset(index);
-        &mut self.data[offset]
+        // SAFETY: the element lies within the view's block and `self` is borrowed mutably
+        unsafe { &mut *self.ptr(self.offset(index)) }
     }
 }
@@ -1,13 +1,18 @@
-use std::ops::{Add, Index};
+use core::ops::{Add, Index};
 
 use crate::matrix::Matrix;
+use crate::matrix_cm::MatrixCM;
+use crate::matrix_strided_view::MatrixStridedView;
+use crate::matrix_strided_view_mut::MatrixStridedViewMut;
 use crate::matrix_transpose_view::MatrixTransposeView;
 use crate::matrix_transpose_view_mut::MatrixTransposeViewMut;
 use crate::matrix_view::MatrixView;
 use crate::matrix_view_mut::MatrixViewMut;
+use crate::ops::simd;
 use crate::row_vector::RowVector;
 use crate::row_vector_view::RowVectorView;
 use crate::row_vector_view_mut::RowVectorViewMut;
+use crate::traits::Contiguous;
 use crate::vector::Vector;
 use crate::vector_view::VectorView;
 use crate::vector_view_mut::VectorViewMut;
@@ -29,6 +34,8 @@ impl_vm_op_mat_view!(Vector<T, M>, MatrixView<'_, T, A, B, M, 1>);
 impl_vm_op_mat_view!(Vector<T, M>, MatrixViewMut<'_, T, A, B, M, 1>);
 impl_vm_op_mat_view!(Vector<T, M>, MatrixTransposeView<'_, T, A, B, M, 1>);
 impl_vm_op_mat_view!(Vector<T, M>, MatrixTransposeViewMut<'_, T, A, B, M, 1>);
+impl_vm_op_mat_view!(Vector<T, M>, MatrixStridedView<'_, T, A, B, M, 1>);
+impl_vm_op_mat_view!(Vector<T, M>, MatrixStridedViewMut<'_, T, A, B, M, 1>);
 
 //////////////////
 //  VectorView  //
@@ -44,6 +51,8 @@ impl_vm_op_view_view!(VectorView<'_, V, T, N, M>, MatrixView<'_, T, A, B, M, 1>)
 impl_vm_op_view_view!(VectorView<'_, V, T, N, M>, MatrixViewMut<'_, T, A, B, M, 1>);
 impl_vm_op_view_view!(VectorView<'_, V, T, N, M>, MatrixTransposeView<'_, T, A, B, M, 1>);
 impl_vm_op_view_view!(VectorView<'_, V, T, N, M>, MatrixTransposeViewMut<'_, T, A, B, M, 1>);
+impl_vm_op_view_view!(VectorView<'_, V, T, N, M>, MatrixStridedView<'_, T, A, B, M, 1>);
+impl_vm_op_view_view!(VectorView<'_, V, T, N, M>, MatrixStridedViewMut<'_, T, A, B, M, 1>);
 
 /////////////////////
 //  VectorViewMut  //
@@ -59,6 +68,8 @@ impl_vm_op_view_view!(VectorViewMut<'_, V, T, N, M>, MatrixView<'_, T, A, B, M,
 impl_vm_op_view_view!(VectorViewMut<'_, V, T, N, M>, MatrixViewMut<'_, T, A, B, M, 1>);
 impl_vm_op_view_view!(VectorViewMut<'_, V, T, N, M>, MatrixTransposeView<'_, T, A, B, M, 1>);
 impl_vm_op_view_view!(VectorViewMut<'_, V, T, N, M>, MatrixTransposeViewMut<'_, T, A, B, M, 1>);
+impl_vm_op_view_view!(VectorViewMut<'_, V, T, N, M>, MatrixStridedView<'_, T, A, B, M, 1>);
+impl_vm_op_view_view!(VectorViewMut<'_, V, T, N, M>, MatrixStridedViewMut<'_, T, A, B, M, 1>);
 
 /////////////////
 //  RowVector  //
@@ -74,6 +85,8 @@ impl_vm_op_mat_view_row!(RowVector<T, M>, MatrixView<'_, T, A, B, 1, M>);
 impl_vm_op_mat_view_row!(RowVector<T, M>, MatrixViewMut<'_, T, A, B, 1, M>);
 impl_vm_op_mat_view_row!(RowVector<T, M>, MatrixTransposeView<'_, T, A, B, 1, M>);
 impl_vm_op_mat_view_row!(RowVector<T, M>, MatrixTransposeViewMut<'_, T, A, B, 1, M>);
+impl_vm_op_mat_view_row!(RowVector<T, M>, MatrixStridedView<'_, T, A, B, 1, M>);
+impl_vm_op_mat_view_row!(RowVector<T, M>, MatrixStridedViewMut<'_, T, A, B, 1, M>);
 
 /////////////////////
 //  RowVectorView  //
@@ -89,6 +102,8 @@ impl_vm_op_view_view_row!(RowVectorView<'_, V, T, N, M>, MatrixView<'_, T, A, B,
 impl_vm_op_view_view_row!(RowVectorView<'_, V, T, N, M>, MatrixViewMut<'_, T, A, B, 1, M>);
 impl_vm_op_view_view_row!(RowVectorView<'_, V, T, N, M>, MatrixTransposeView<'_, T, A, B, 1, M>);
 impl_vm_op_view_view_row!(RowVectorView<'_, V, T, N, M>, MatrixTransposeViewMut<'_, T, A, B, 1, M>);
+impl_vm_op_view_view_row!(RowVectorView<'_, V, T, N, M>, MatrixStridedView<'_, T, A, B, 1, M>);
+impl_vm_op_view_view_row!(RowVectorView<'_, V, T, N, M>, MatrixStridedViewMut<'_, T, A, B, 1, M>);
 
 ////////////////////////
 //  RowVectorViewMut  //
@@ -104,6 +119,8 @@ impl_vm_op_view_view_row!(RowVectorViewMut<'_, V, T, N, M>, MatrixView<'_, T, A,
 impl_vm_op_view_view_row!(RowVectorViewMut<'_, V, T, N, M>, MatrixViewMut<'_, T, A, B, 1, M>);
 impl_vm_op_view_view_row!(RowVectorViewMut<'_, V, T, N, M>, MatrixTransposeView<'_, T, A, B, 1, M>);
 impl_vm_op_view_view_row!(RowVectorViewMut<'_, V, T, N, M>, MatrixTransposeViewMut<'_, T, A, B, 1, M>);
+impl_vm_op_view_view_row!(RowVectorViewMut<'_, V, T, N, M>, MatrixStridedView<'_, T, A, B, 1, M>);
+impl_vm_op_view_view_row!(RowVectorViewMut<'_, V, T, N, M>, MatrixStridedViewMut<'_, T, A, B, 1, M>);
 
 //////////////
 //  Matrix  //
@@ -123,6 +140,23 @@ impl_mm_op_view!(Matrix<T, M, N>, MatrixView<'_, T, A, B, M, N>);
 impl_mm_op_view!(Matrix<T, M, N>, MatrixViewMut<'_, T, A, B, M, N>);
 impl_mm_op_view!(Matrix<T, M, N>, MatrixTransposeView<'_, T, A, B, M, N>);
 impl_mm_op_view!(Matrix<T, M, N>, MatrixTransposeViewMut<'_, T, A, B, M, N>);
+impl_mm_op_view!(Matrix<T, M, N>, MatrixStridedView<'_, T, A, B, M, N>);
+impl_mm_op_view!(Matrix<T, M, N>, MatrixStridedViewMut<'_, T, A, B, M, N>);
+impl_mm_op_mixed!(Matrix<T, M, N>, MatrixCM<T, M, N>);
+
+////////////////
+//  MatrixCM  //
+////////////////
+
+impl_mm_op_cm!(MatrixCM<T, M, N>); // Scalar
+impl_mm_op_cm!(MatrixCM<T, M, N>, MatrixCM<T, M, N>);
+impl_mm_op_mixed!(MatrixCM<T, M, N>, Matrix<T, M, N>);
+impl_mm_op_view!(MatrixCM<T, M, N>, MatrixView<'_, T, A, B, M, N>);
+impl_mm_op_view!(MatrixCM<T, M, N>, MatrixViewMut<'_, T, A, B, M, N>);
+impl_mm_op_view!(MatrixCM<T, M, N>, MatrixTransposeView<'_, T, A, B, M, N>);
+impl_mm_op_view!(MatrixCM<T, M, N>, MatrixTransposeViewMut<'_, T, A, B, M, N>);
+impl_mm_op_view!(MatrixCM<T, M, N>, MatrixStridedView<'_, T, A, B, M, N>);
+impl_mm_op_view!(MatrixCM<T, M, N>, MatrixStridedViewMut<'_, T, A, B, M, N>);
 
 //////////////////
 //  MatrixView  //
@@ -138,10 +172,13 @@ impl_mv_op_view_view_row!(MatrixView<'_, T, A, B, 1, M>, RowVectorViewMut<'_, V,
 
 impl_mm_op_view!(MatrixView<'_, T, A, B, M, N>); // Scalar
 impl_mm_op_view!(MatrixView<'_, T, A, B, M, N>, Matrix<T, M, N>);
+impl_mm_op_view!(MatrixView<'_, T, A, B, M, N>, MatrixCM<T, M, N>);
 impl_mm_op_view_view!(MatrixView<'_, T, A, B, M, N>, MatrixView<'_, T, C, D, M, N>);
 impl_mm_op_view_view!(MatrixView<'_, T, A, B, M, N>, MatrixViewMut<'_, T, C, D, M, N>);
 impl_mm_op_view_view!(MatrixView<'_, T, A, B, M, N>, MatrixTransposeView<'_, T, C, D, M, N>);
 impl_mm_op_view_view!(MatrixView<'_, T, A, B, M, N>, MatrixTransposeViewMut<'_, T, C, D, M, N>);
+impl_mm_op_view_view!(MatrixView<'_, T, A, B, M, N>, MatrixStridedView<'_, T, C, D, M, N>);
+impl_mm_op_view_view!(MatrixView<'_, T, A, B, M, N>, MatrixStridedViewMut<'_, T, C, D, M, N>);
 
 /////////////////////
 //  MatrixViewMut  //
@@ -157,10 +194,13 @@ impl_mv_op_view_view_row!(MatrixViewMut<'_, T, A, B, 1, M>, RowVectorViewMut<'_,
 
 impl_mm_op_view!(MatrixViewMut<'_, T, A, B, M, N>); // Scalar
 impl_mm_op_view!(MatrixViewMut<'_, T, A, B, M, N>, Matrix<T, M, N>);
+impl_mm_op_view!(MatrixViewMut<'_, T, A, B, M, N>, MatrixCM<T, M, N>);
 impl_mm_op_view_view!(MatrixViewMut<'_, T, A, B, M, N>, MatrixView<'_, T, C, D, M, N>);
 impl_mm_op_view_view!(MatrixViewMut<'_, T, A, B, M, N>, MatrixViewMut<'_, T, C, D, M, N>);
 impl_mm_op_view_view!(MatrixViewMut<'_, T, A, B, M, N>, MatrixTransposeView<'_, T, C, D, M, N>);
 impl_mm_op_view_view!(MatrixViewMut<'_, T, A, B, M, N>, MatrixTransposeViewMut<'_, T, C, D, M, N>);
+impl_mm_op_view_view!(MatrixViewMut<'_, T, A, B, M, N>, MatrixStridedView<'_, T, C, D, M, N>);
+impl_mm_op_view_view!(MatrixViewMut<'_, T, A, B, M, N>, MatrixStridedViewMut<'_, T, C, D, M, N>);
 
 ///////////////////////////
 //  MatrixTransposeView  //
@@ -176,10 +216,13 @@ impl_mv_op_view_view_row!(MatrixTransposeView<'_, T, A, B, 1, M>, RowVectorViewM
 
 impl_mm_op_view!(MatrixTransposeView<'_, T, A, B, M, N>); // Scalar
 impl_mm_op_view!(MatrixTransposeView<'_, T, A, B, M, N>, Matrix<T, M, N>);
+impl_mm_op_view!(MatrixTransposeView<'_, T, A, B, M, N>, MatrixCM<T, M, N>);
 impl_mm_op_view_view!(MatrixTransposeView<'_, T, A, B, M, N>, MatrixView<'_, T, C, D, M, N>);
 impl_mm_op_view_view!(MatrixTransposeView<'_, T, A, B, M, N>, MatrixViewMut<'_, T, C, D, M, N>);
 impl_mm_op_view_view!(MatrixTransposeView<'_, T, A, B, M, N>, MatrixTransposeView<'_, T, C, D, M, N>);
 impl_mm_op_view_view!(MatrixTransposeView<'_, T, A, B, M, N>, MatrixTransposeViewMut<'_, T, C, D, M, N>);
+impl_mm_op_view_view!(MatrixTransposeView<'_, T, A, B, M, N>, MatrixStridedView<'_, T, C, D, M, N>);
+impl_mm_op_view_view!(MatrixTransposeView<'_, T, A, B, M, N>, MatrixStridedViewMut<'_, T, C, D, M, N>);
 
 //////////////////////////////
 //  MatrixTransposeViewMut  //
@@ -195,7 +238,54 @@ impl_mv_op_view_view_row!(MatrixTransposeViewMut<'_, T, A, B, 1, M>, RowVectorVi
 
 impl_mm_op_view!(MatrixTransposeViewMut<'_, T, A, B, M, N>); // Scalar
 impl_mm_op_view!(MatrixTransposeViewMut<'_, T, A, B, M, N>, Matrix<T, M, N>);
+impl_mm_op_view!(MatrixTransposeViewMut<'_, T, A, B, M, N>, MatrixCM<T, M, N>);
 impl_mm_op_view_view!(MatrixTransposeViewMut<'_, T, A, B, M, N>, MatrixView<'_, T, C, D, M, N>);
 impl_mm_op_view_view!(MatrixTransposeViewMut<'_, T, A, B, M, N>, MatrixViewMut<'_, T, C, D, M, N>);
 impl_mm_op_view_view!(MatrixTransposeViewMut<'_, T, A, B, M, N>, MatrixTransposeView<'_, T, C, D, M, N>);
 impl_mm_op_view_view!(MatrixTransposeViewMut<'_, T, A, B, M, N>, MatrixTransposeViewMut<'_, T, C, D, M, N>);
+impl_mm_op_view_view!(MatrixTransposeViewMut<'_, T, A, B, M, N>, MatrixStridedView<'_, T, C, D, M, N>);
+impl_mm_op_view_view!(MatrixTransposeViewMut<'_, T, A, B, M, N>, MatrixStridedViewMut<'_, T, C, D, M, N>);
+
+/////////////////////////
+//  MatrixStridedView  //
+/////////////////////////
+
+impl_mv_op_mat_view!(MatrixStridedView<'_, T, A, B, M, 1>, Vector<T, M>);
+impl_mv_op_view_view!(MatrixStridedView<'_, T, A, B, M, 1>, VectorView<'_, V, T, N, M>);
+impl_mv_op_view_view!(MatrixStridedView<'_, T, A, B, M, 1>, VectorViewMut<'_, V, T, N, M>);
+
+impl_mv_op_mat_view_row!(MatrixStridedView<'_, T, A, B, 1, M>, RowVector<T, M>);
+impl_mv_op_view_view_row!(MatrixStridedView<'_, T, A, B, 1, M>, RowVectorView<'_, V, T, N, M>);
+impl_mv_op_view_view_row!(MatrixStridedView<'_, T, A, B, 1, M>, RowVectorViewMut<'_, V, T, N, M>);
+
+impl_mm_op_view!(MatrixStridedView<'_, T, A, B, M, N>); // Scalar
+impl_mm_op_view!(MatrixStridedView<'_, T, A, B, M, N>, Matrix<T, M, N>);
+impl_mm_op_view!(MatrixStridedView<'_, T, A, B, M, N>, MatrixCM<T, M, N>);
+impl_mm_op_view_view!(MatrixStridedView<'_, T, A, B, M, N>, MatrixView<'_, T, C, D, M, N>);
+impl_mm_op_view_view!(MatrixStridedView<'_, T, A, B, M, N>, MatrixViewMut<'_, T, C, D, M, N>);
+impl_mm_op_view_view!(MatrixStridedView<'_, T, A, B, M, N>, MatrixTransposeView<'_, T, C, D, M, N>);
+impl_mm_op_view_view!(MatrixStridedView<'_, T, A, B, M, N>, MatrixTransposeViewMut<'_, T, C, D, M, N>);
+impl_mm_op_view_view!(MatrixStridedView<'_, T, A, B, M, N>, MatrixStridedView<'_, T, C, D, M, N>);
+impl_mm_op_view_view!(MatrixStridedView<'_, T, A, B, M, N>, MatrixStridedViewMut<'_, T, C, D, M, N>);
+
+////////////////////////////
+//  MatrixStridedViewMut  //
+////////////////////////////
+
+impl_mv_op_mat_view!(MatrixStridedViewMut<'_, T, A, B, M, 1>, Vector<T, M>);
+impl_mv_op_view_view!(MatrixStridedViewMut<'_, T, A, B, M, 1>, VectorView<'_, V, T, N, M>);
+impl_mv_op_view_view!(MatrixStridedViewMut<'_, T, A, B, M, 1>, VectorViewMut<'_, V, T, N, M>);
+
+impl_mv_op_mat_view_row!(MatrixStridedViewMut<'_, T, A, B, 1, M>, RowVector<T, M>);
+impl_mv_op_view_view_row!(MatrixStridedViewMut<'_, T, A, B, 1, M>, RowVectorView<'_, V, T, N, M>);
+impl_mv_op_view_view_row!(MatrixStridedViewMut<'_, T, A, B, 1, M>, RowVectorViewMut<'_, V, T, N, M>);
+
+impl_mm_op_view!(MatrixStridedViewMut<'_, T, A, B, M, N>); // Scalar
+impl_mm_op_view!(MatrixStridedViewMut<'_, T, A, B, M, N>, Matrix<T, M, N>);
+impl_mm_op_view!(MatrixStridedViewMut<'_, T, A, B, M, N>, MatrixCM<T, M, N>);
+impl_mm_op_view_view!(MatrixStridedViewMut<'_, T, A, B, M, N>, MatrixView<'_, T, C, D, M, N>);
+impl_mm_op_view_view!(MatrixStridedViewMut<'_, T, A, B, M, N>, MatrixViewMut<'_, T, C, D, M, N>);
+impl_mm_op_view_view!(MatrixStridedViewMut<'_, T, A, B, M, N>, MatrixTransposeView<'_, T, C, D, M, N>);
+impl_mm_op_view_view!(MatrixStridedViewMut<'_, T, A, B, M, N>, MatrixTransposeViewMut<'_, T, C, D, M, N>);
+impl_mm_op_view_view!(MatrixStridedViewMut<'_, T, A, B, M, N>, MatrixStridedView<'_, T, C, D, M, N>);
+impl_mm_op_view_view!(MatrixStridedViewMut<'_, T, A, B, M, N>, MatrixStridedViewMut<'_, T, C, D, M, N>);
@@ -1,13 +1,18 @@
-use std::ops::{AddAssign, Index, IndexMut};
+use core::ops::{AddAssign, Index, IndexMut};
 
 use crate::matrix::Matrix;
+use crate::matrix_cm::MatrixCM;
+use crate::matrix_strided_view::MatrixStridedView;
+use crate::matrix_strided_view_mut::MatrixStridedViewMut;
 use crate::matrix_transpose_view::MatrixTransposeView;
 use crate::matrix_transpose_view_mut::MatrixTransposeViewMut;
 use crate::matrix_view::MatrixView;
 use crate::matrix_view_mut::MatrixViewMut;
+use crate::ops::simd;
 use crate::row_vector::RowVector;
 use crate::row_vector_view::RowVectorView;
 use crate::row_vector_view_mut::RowVectorViewMut;
+use crate::traits::Contiguous;
 use crate::vector::Vector;
 use crate::vector_view::VectorView;
 use crate::vector_view_mut::VectorViewMut;
@@ -29,6 +34,8 @@ impl_vm_op_assign_mat_view!(Vector<T, M>, MatrixView<'_, T, A, B, M, 1>);
 impl_vm_op_assign_mat_view!(Vector<T, M>, MatrixViewMut<'_, T, A, B, M, 1>);
 impl_vm_op_assign_mat_view!(Vector<T, M>, MatrixTransposeView<'_, T, A, B, M, 1>);
 impl_vm_op_assign_mat_view!(Vector<T, M>, MatrixTransposeViewMut<'_, T, A, B, M, 1>);
+impl_vm_op_assign_mat_view!(Vector<T, M>, MatrixStridedView<'_, T, A, B, M, 1>);
+impl_vm_op_assign_mat_view!(Vector<T, M>, MatrixStridedViewMut<'_, T, A, B, M, 1>);
 
 /////////////////////
 //  VectorViewMut  //
@@ -44,6 +51,8 @@ impl_vm_op_assign_view_view!(VectorViewMut<'_, V, T, N, M>, MatrixView<'_, T, A,
 impl_vm_op_assign_view_view!(VectorViewMut<'_, V, T, N, M>, MatrixViewMut<'_, T, A, B, M, 1>);
 impl_vm_op_assign_view_view!(VectorViewMut<'_, V, T, N, M>, MatrixTransposeView<'_, T, A, B, M, 1>);
 impl_vm_op_assign_view_view!(VectorViewMut<'_, V, T, N, M>, MatrixTransposeViewMut<'_, T, A, B, M, 1>);
+impl_vm_op_assign_view_view!(VectorViewMut<'_, V, T, N, M>, MatrixStridedView<'_, T, A, B, M, 1>);
+impl_vm_op_assign_view_view!(VectorViewMut<'_, V, T, N, M>, MatrixStridedViewMut<'_, T, A, B, M, 1>);
 
 /////////////////
 //  RowVector  //
@@ -59,6 +68,8 @@ impl_vm_op_assign_mat_view!(RowVector<T, M>, MatrixView<'_, T, A, B, 1, M>);
 impl_vm_op_assign_mat_view!(RowVector<T, M>, MatrixViewMut<'_, T, A, B, 1, M>);
 impl_vm_op_assign_mat_view!(RowVector<T, M>, MatrixTransposeView<'_, T, A, B, 1, M>);
 impl_vm_op_assign_mat_view!(RowVector<T, M>, MatrixTransposeViewMut<'_, T, A, B, 1, M>);
+impl_vm_op_assign_mat_view!(RowVector<T, M>, MatrixStridedView<'_, T, A, B, 1, M>);
+impl_vm_op_assign_mat_view!(RowVector<T, M>, MatrixStridedViewMut<'_, T, A, B, 1, M>);
 
 ////////////////////////
 //  RowVectorViewMut  //
@@ -74,6 +85,8 @@ impl_vm_op_assign_view_view!(RowVectorViewMut<'_, V, T, N, M>, MatrixView<'_, T,
 impl_vm_op_assign_view_view!(RowVectorViewMut<'_, V, T, N, M>, MatrixViewMut<'_, T, A, B, 1, M>);
 impl_vm_op_assign_view_view!(RowVectorViewMut<'_, V, T, N, M>, MatrixTransposeView<'_, T, A, B, 1, M>);
 impl_vm_op_assign_view_view!(RowVectorViewMut<'_, V, T, N, M>, MatrixTransposeViewMut<'_, T, A, B, 1, M>);
+impl_vm_op_assign_view_view!(RowVectorViewMut<'_, V, T, N, M>, MatrixStridedView<'_, T, A, B, 1, M>);
+impl_vm_op_assign_view_view!(RowVectorViewMut<'_, V, T, N, M>, MatrixStridedViewMut<'_, T, A, B, 1, M>);
 
 //////////////
 //  Matrix  //
@@ -93,6 +106,23 @@ impl_mm_op_assign_view!(Matrix<T, M, N>, MatrixView<'_, T, A, B, M, N>);
 impl_mm_op_assign_view!(Matrix<T, M, N>, MatrixViewMut<'_, T, A, B, M, N>);
 impl_mm_op_assign_view!(Matrix<T, M, N>, MatrixTransposeView<'_, T, A, B, M, N>);
 impl_mm_op_assign_view!(Matrix<T, M, N>, MatrixTransposeViewMut<'_, T, A, B, M, N>);
+impl_mm_op_assign_view!(Matrix<T, M, N>, MatrixStridedView<'_, T, A, B, M, N>);
+impl_mm_op_assign_view!(Matrix<T, M, N>, MatrixStridedViewMut<'_, T, A, B, M, N>);
+impl_mm_op_assign_mixed!(Matrix<T, M, N>, MatrixCM<T, M, N>);
+
+////////////////
+//  MatrixCM  //
+////////////////
+
+impl_mm_op_assign!(MatrixCM<T, M, N>); // Scalar
+impl_mm_op_assign!(MatrixCM<T, M, N>, MatrixCM<T, M, N>);
+impl_mm_op_assign_mixed!(MatrixCM<T, M, N>, Matrix<T, M, N>);
+impl_mm_op_assign_view!(MatrixCM<T, M, N>, MatrixView<'_, T, A, B, M, N>);
+impl_mm_op_assign_view!(MatrixCM<T, M, N>, MatrixViewMut<'_, T, A, B, M, N>);
+impl_mm_op_assign_view!(MatrixCM<T, M, N>, MatrixTransposeView<'_, T, A, B, M, N>);
+impl_mm_op_assign_view!(MatrixCM<T, M, N>, MatrixTransposeViewMut<'_, T, A, B, M, N>);
+impl_mm_op_assign_view!(MatrixCM<T, M, N>, MatrixStridedView<'_, T, A, B, M, N>);
+impl_mm_op_assign_view!(MatrixCM<T, M, N>, MatrixStridedViewMut<'_, T, A, B, M, N>);
 
 /////////////////////
 //  MatrixViewMut  //
@@ -108,10 +138,13 @@ impl_vm_op_assign_view_view!(MatrixViewMut<'_, T, A, B, 1, M>, RowVectorViewMut<
 
 impl_mm_op_assign_view!(MatrixViewMut<'_, T, A, B, M, N>); // Scalar
 impl_mm_op_assign_view!(MatrixViewMut<'_, T, A, B, M, N>, Matrix<T, M, N>);
+impl_mm_op_assign_view!(MatrixViewMut<'_, T, A, B, M, N>, MatrixCM<T, M, N>);
 impl_mm_op_assign_view_view!(MatrixViewMut<'_, T, A, B, M, N>, MatrixView<'_, T, C, D, M, N>);
 impl_mm_op_assign_view_view!(MatrixViewMut<'_, T, A, B, M, N>, MatrixViewMut<'_, T, C, D, M, N>);
 impl_mm_op_assign_view_view!(MatrixViewMut<'_, T, A, B, M, N>, MatrixTransposeView<'_, T, C, D, M, N>);
 impl_mm_op_assign_view_view!(MatrixViewMut<'_, T, A, B, M, N>, MatrixTransposeViewMut<'_, T, C, D, M, N>);
+impl_mm_op_assign_view_view!(MatrixViewMut<'_, T, A, B, M, N>, MatrixStridedView<'_, T, C, D, M, N>);
+impl_mm_op_assign_view_view!(MatrixViewMut<'_, T, A, B, M, N>, MatrixStridedViewMut<'_, T, C, D, M, N>);
 
 //////////////////////////////
 //  MatrixTransposeViewMut  //
@@ -127,7 +160,32 @@ impl_vm_op_assign_view_view!(MatrixTransposeViewMut<'_, T, A, B, 1, M>, RowVecto
 
 impl_mm_op_assign_view!(MatrixTransposeViewMut<'_, T, A, B, M, N>); // Scalar
 impl_mm_op_assign_view!(MatrixTransposeViewMut<'_, T, A, B, M, N>, Matrix<T, M, N>);
+impl_mm_op_assign_view!(MatrixTransposeViewMut<'_, T, A, B, M, N>, MatrixCM<T, M, N>);
 impl_mm_op_assign_view_view!(MatrixTransposeViewMut<'_, T, A, B, M, N>, MatrixView<'_, T, C, D, M, N>);
 impl_mm_op_assign_view_view!(MatrixTransposeViewMut<'_, T, A, B, M, N>, MatrixViewMut<'_, T, C, D, M, N>);
 impl_mm_op_assign_view_view!(MatrixTransposeViewMut<'_, T, A, B, M, N>, MatrixTransposeView<'_, T, C, D, M, N>);
 impl_mm_op_assign_view_view!(MatrixTransposeViewMut<'_, T, A, B, M, N>, MatrixTransposeViewMut<'_, T, C, D, M, N>);
+impl_mm_op_assign_view_view!(MatrixTransposeViewMut<'_, T, A, B, M, N>, MatrixStridedView<'_, T, C, D, M, N>);
+impl_mm_op_assign_view_view!(MatrixTransposeViewMut<'_, T, A, B, M, N>, MatrixStridedViewMut<'_, T, C, D, M, N>);
+
+////////////////////////////
+//  MatrixStridedViewMut  //
+////////////////////////////
+
+impl_vm_op_assign_mat_view!(MatrixStridedViewMut<'_, T, A, B, M, 1>, Vector<T, M>);
+impl_vm_op_assign_view_view!(MatrixStridedViewMut<'_, T, A, B, M, 1>, VectorView<'_, V, T, N, M>);
+impl_vm_op_assign_view_view!(MatrixStridedViewMut<'_, T, A, B, M, 1>, VectorViewMut<'_, V, T, N, M>);
+
+impl_vm_op_assign_mat_view!(MatrixStridedViewMut<'_, T, A, B, 1, M>, RowVector<T, M>);
+impl_vm_op_assign_view_view!(MatrixStridedViewMut<'_, T, A, B, 1, M>, RowVectorView<'_, V, T, N, M>);
+impl_vm_op_assign_view_view!(MatrixStridedViewMut<'_, T, A, B, 1, M>, RowVectorViewMut<'_, V, T, N, M>);
+
+impl_mm_op_assign_view!(MatrixStridedViewMut<'_, T, A, B, M, N>); // Scalar
+impl_mm_op_assign_view!(MatrixStridedViewMut<'_, T, A, B, M, N>, Matrix<T, M, N>);
+impl_mm_op_assign_view!(MatrixStridedViewMut<'_, T, A, B, M, N>, MatrixCM<T, M, N>);
+impl_mm_op_assign_view_view!(MatrixStridedViewMut<'_, T, A, B, M, N>, MatrixView<'_, T, C, D, M, N>);
+impl_mm_op_assign_view_view!(MatrixStridedViewMut<'_, T, A, B, M, N>, MatrixViewMut<'_, T, C, D, M, N>);
+impl_mm_op_assign_view_view!(MatrixStridedViewMut<'_, T, A, B, M, N>, MatrixTransposeView<'_, T, C, D, M, N>);
+impl_mm_op_assign_view_view!(MatrixStridedViewMut<'_, T, A, B, M, N>, MatrixTransposeViewMut<'_, T, C, D, M, N>);
+impl_mm_op_assign_view_view!(MatrixStridedViewMut<'_, T, A, B, M, N>, MatrixStridedView<'_, T, C, D, M, N>);
+impl_mm_op_assign_view_view!(MatrixStridedViewMut<'_, T, A, B, M, N>, MatrixStridedViewMut<'_, T, C, D, M, N>);
@@ -0,0 +1,116 @@
+//! Optional BLAS backend.
+//!
+//! With the `blas` feature, large `f32`/`f64` matrix products and matrix-vector products
+//! are handed to `cblas_?gemm` and `cblas_?gemv`. Operands are passed in place: a
+//! row-major operand as-is and a column-major one (such as a transpose view) with the
+//! transpose flag set. Operands whose strides BLAS cannot describe, other element types
+//! and small products return `false` so that the caller runs the native kernels.
+//!
+//! Without the feature, every entry point returns `false`.
+
+#[cfg(feature = "blas")]
+use cblas_sys::CBLAS_LAYOUT::CblasRowMajor;
+#[cfg(feature = "blas")]
+use cblas_sys::CBLAS_TRANSPOSE::{self, CblasNoTrans, CblasTrans};
+
+#[cfg(feature = "blas")]
+use crate::ops::simd;
+use crate::traits::StridedRef;
+
+/// Matrix products with at least this many multiply-adds are handed to BLAS.
+#[cfg(feature = "blas")]
+const GEMM_THRESHOLD: usize = 48 * 48 * 48;
+
+/// Matrix-vector products with at least this many multiply-adds are handed to BLAS.
+#[cfg(feature = "blas")]
+const GEMV_THRESHOLD: usize = 128 * 128;
+
+/// Computes `out = a * b` with BLAS, where `a` is `m x n`, `b` is `n x p` and `out` is a
+/// row-major `m x p` buffer. Returns `false` if the product was not handed to BLAS.
+#[cfg(feature = "blas")]
+pub(crate) fn matmul<T>(a: StridedRef<'_, T>, b: StridedRef<'_, T>, out: &mut [T], m: usize, n: usize, p: usize) -> bool {
+    if m * n * p < GEMM_THRESHOLD {
+        return false;
+    }
+    let (Some((ta, lda)), Some((tb, ldb))) = (layout(&a, m, n), layout(&b, n, p)) else {
+        return false;
+    };
+    let (Ok(m), Ok(n), Ok(p)) = (i32::try_from(m), i32::try_from(n), i32::try_from(p)) else {
+        return false;
+    };
+
+    if simd::is::<T, f32>() {
+        // SAFETY: `T` is `f32`
+        let (a, b) = unsafe { (a.cast::<f32>(), b.cast::<f32>()) };
+        let out: &mut [f32] = simd::cast_mut(out);
+        unsafe {
+            cblas_sys::cblas_sgemm(CblasRowMajor, ta, tb, m, p, n, 1.0, a.as_ptr(), lda, b.as_ptr(), ldb, 0.0, out.as_mut_ptr(), p);
+        }
+    } else if simd::is::<T, f64>() {
+        // SAFETY: `T` is `f64`
+        let (a, b) = unsafe { (a.cast::<f64>(), b.cast::<f64>()) };
+        let out: &mut [f64] = simd::cast_mut(out);
+        unsafe {
+            cblas_sys::cblas_dgemm(CblasRowMajor, ta, tb, m, p, n, 1.0, a.as_ptr(), lda, b.as_ptr(), ldb, 0.0, out.as_mut_ptr(), p);
+        }
+    } else {
+        return false;
+    }
+    true
+}
+
+/// Computes `out = a * x` with BLAS for a row-major `a` with `n` columns.
+/// Returns `false` if the product was not handed to BLAS.
+#[cfg(feature = "blas")]
+pub(crate) fn matvec<T>(a: &[T], x: &[T], out: &mut [T], n: usize) -> bool {
+    if n == 0 || a.len() < GEMV_THRESHOLD {
+        return false;
+    }
+    let (Ok(m), Ok(n)) = (i32::try_from(out.len()), i32::try_from(n)) else {
+        return false;
+    };
+
+    if simd::is::<T, f32>() {
+        let (a, x, out): (&[f32], &[f32], &mut [f32]) = (simd::cast(a), simd::cast(x), simd::cast_mut(out));
+        unsafe {
+            cblas_sys::cblas_sgemv(CblasRowMajor, CblasNoTrans, m, n, 1.0, a.as_ptr(), n, x.as_ptr(), 1, 0.0, out.as_mut_ptr(), 1);
+        }
+    } else if simd::is::<T, f64>() {
+        let (a, x, out): (&[f64], &[f64], &mut [f64]) = (simd::cast(a), simd::cast(x), simd::cast_mut(out));
+        unsafe {
+            cblas_sys::cblas_dgemv(CblasRowMajor, CblasNoTrans, m, n, 1.0, a.as_ptr(), n, x.as_ptr(), 1, 0.0, out.as_mut_ptr(), 1);
+        }
+    } else {
+        return false;
+    }
+    true
+}
+
+/// Returns the transpose flag and leading dimension that describe a `rows x cols`
+/// operand to a row-major BLAS call, or `None` if its strides cannot be described.
+#[cfg(feature = "blas")]
+fn layout<T>(s: &StridedRef<'_, T>, rows: usize, cols: usize) -> Option<(CBLAS_TRANSPOSE, i32)> {
+    // The stride along a dimension of length one is never used, so it may be anything
+    let (rs, cs) = (if rows == 1 { cols } else { s.rs }, if cols == 1 { 1 } else { s.cs });
+    if cs == 1 && rs >= cols {
+        return i32::try_from(rs).ok().map(|ld| (CblasNoTrans, ld));
+    }
+
+    let (rs, cs) = (if rows == 1 { 1 } else { s.rs }, if cols == 1 { rows } else { s.cs });
+    if rs == 1 && cs >= rows {
+        return i32::try_from(cs).ok().map(|ld| (CblasTrans, ld));
+    }
+    None
+}
+
+#[cfg(not(feature = "blas"))]
+#[inline(always)]
+pub(crate) fn matmul<T>(_a: StridedRef<'_, T>, _b: StridedRef<'_, T>, _out: &mut [T], _m: usize, _n: usize, _p: usize) -> bool {
+    false
+}
+
+#[cfg(not(feature = "blas"))]
+#[inline(always)]
+pub(crate) fn matvec<T>(_a: &[T], _x: &[T], _out: &mut [T], _n: usize) -> bool {
+    false
+}
@@ -1,10 +1,14 @@
-use std::ops::{DivAssign, IndexMut};
+use core::ops::{DivAssign, IndexMut};
 
 use crate::matrix::Matrix;
+use crate::matrix_cm::MatrixCM;
+use crate::matrix_strided_view_mut::MatrixStridedViewMut;
 use crate::matrix_transpose_view_mut::MatrixTransposeViewMut;
 use crate::matrix_view_mut::MatrixViewMut;
+use crate::ops::simd;
 use crate::row_vector::RowVector;
 use crate::row_vector_view_mut::RowVectorViewMut;
+use crate::traits::Contiguous;
 use crate::vector::Vector;
 use crate::vector_view_mut::VectorViewMut;
 
@@ -18,5 +22,7 @@ impl_vv_op_assign!(RowVector<T, N>);
 impl_vv_op_assign_view!(RowVectorViewMut<'_, V, T, N, M>);
 
 impl_mm_op_assign!(Matrix<T, M, N>);
+impl_mm_op_assign!(MatrixCM<T, M, N>);
 impl_mm_op_assign_view!(MatrixViewMut<'_, T, A, B, M, N>);
 impl_mm_op_assign_view!(MatrixTransposeViewMut<'_, T, A, B, M, N>);
+impl_mm_op_assign_view!(MatrixStridedViewMut<'_, T, A, B, M, N>);
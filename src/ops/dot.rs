@@ -1,17 +1,20 @@
 use crate::matrix::Matrix;
+use crate::matrix_strided_view::MatrixStridedView;
+use crate::matrix_strided_view_mut::MatrixStridedViewMut;
 use crate::matrix_transpose_view::MatrixTransposeView;
 use crate::matrix_transpose_view_mut::MatrixTransposeViewMut;
 use crate::matrix_view::MatrixView;
 use crate::matrix_view_mut::MatrixViewMut;
+use crate::ops::simd;
 use crate::row_vector::RowVector;
 use crate::row_vector_view::RowVectorView;
 use crate::row_vector_view_mut::RowVectorViewMut;
-use crate::traits::DotProduct;
+use crate::traits::{Conjugate, Contiguous, DotProduct};
 use crate::vector::Vector;
 use crate::vector_view::VectorView;
 use crate::vector_view_mut::VectorViewMut;
 use num_traits::Zero;
-use std::ops::{Add, Index, Mul};
+use core::ops::{Add, Index, Mul};
 
 // Generate macros
 generate_dot_macros!();
@@ -29,6 +32,8 @@ impl_dot_mat_view!(Vector<T, M>, MatrixView<'_, T, A, B, M, 1>);
 impl_dot_mat_view!(Vector<T, M>, MatrixViewMut<'_, T, A, B, M, 1>);
 impl_dot_mat_view!(Vector<T, M>, MatrixTransposeView<'_, T, A, B, M, 1>);
 impl_dot_mat_view!(Vector<T, M>, MatrixTransposeViewMut<'_, T, A, B, M, 1>);
+impl_dot_mat_view!(Vector<T, M>, MatrixStridedView<'_, T, A, B, M, 1>);
+impl_dot_mat_view!(Vector<T, M>, MatrixStridedViewMut<'_, T, A, B, M, 1>);
 
 //////////////////
 //  VectorView  //
@@ -43,6 +48,8 @@ impl_dot_mat_view_view!(VectorView<'_, V, T, N, M>, MatrixView<'_, T, A, B, M, 1
 impl_dot_mat_view_view!(VectorView<'_, V, T, N, M>, MatrixViewMut<'_, T, A, B, M, 1>);
 impl_dot_mat_view_view!(VectorView<'_, V, T, N, M>, MatrixTransposeView<'_, T, A, B, M, 1>);
 impl_dot_mat_view_view!(VectorView<'_, V, T, N, M>, MatrixTransposeViewMut<'_, T, A, B, M, 1>);
+impl_dot_mat_view_view!(VectorView<'_, V, T, N, M>, MatrixStridedView<'_, T, A, B, M, 1>);
+impl_dot_mat_view_view!(VectorView<'_, V, T, N, M>, MatrixStridedViewMut<'_, T, A, B, M, 1>);
 
 /////////////////////
 //  VectorViewMut  //
@@ -57,6 +64,28 @@ impl_dot_mat_view_view!(VectorViewMut<'_, V, T, N, M>, MatrixView<'_, T, A, B, M
 impl_dot_mat_view_view!(VectorViewMut<'_, V, T, N, M>, MatrixViewMut<'_, T, A, B, M, 1>);
 impl_dot_mat_view_view!(VectorViewMut<'_, V, T, N, M>, MatrixTransposeView<'_, T, A, B, M, 1>);
 impl_dot_mat_view_view!(VectorViewMut<'_, V, T, N, M>, MatrixTransposeViewMut<'_, T, A, B, M, 1>);
+impl_dot_mat_view_view!(VectorViewMut<'_, V, T, N, M>, MatrixStridedView<'_, T, A, B, M, 1>);
+impl_dot_mat_view_view!(VectorViewMut<'_, V, T, N, M>, MatrixStridedViewMut<'_, T, A, B, M, 1>);
+
+/////////////////////////
+//  MatrixStridedView  //
+/////////////////////////
+
+impl_dot_mat_view!(MatrixStridedView<'_, T, A, B, M, 1>, Vector<T, M>);
+impl_dot_mat_view_view!(MatrixStridedView<'_, T, A, B, M, 1>, VectorView<'_, V, T, N, M>);
+impl_dot_mat_view_view!(MatrixStridedView<'_, T, A, B, M, 1>, VectorViewMut<'_, V, T, N, M>);
+impl_dot_mat_view_mat_view!(MatrixStridedView<'_, T, A, B, M, 1>, MatrixStridedView<'_, T, P, Q, M, 1>);
+impl_dot_mat_view_mat_view!(MatrixStridedView<'_, T, A, B, M, 1>, MatrixStridedViewMut<'_, T, P, Q, M, 1>);
+
+////////////////////////////
+//  MatrixStridedViewMut  //
+////////////////////////////
+
+impl_dot_mat_view!(MatrixStridedViewMut<'_, T, A, B, M, 1>, Vector<T, M>);
+impl_dot_mat_view_view!(MatrixStridedViewMut<'_, T, A, B, M, 1>, VectorView<'_, V, T, N, M>);
+impl_dot_mat_view_view!(MatrixStridedViewMut<'_, T, A, B, M, 1>, VectorViewMut<'_, V, T, N, M>);
+impl_dot_mat_view_mat_view!(MatrixStridedViewMut<'_, T, A, B, M, 1>, MatrixStridedView<'_, T, P, Q, M, 1>);
+impl_dot_mat_view_mat_view!(MatrixStridedViewMut<'_, T, A, B, M, 1>, MatrixStridedViewMut<'_, T, P, Q, M, 1>);
 
 /////////////////
 //  RowVector  //
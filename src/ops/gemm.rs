@@ -0,0 +1,237 @@
+//! Matrix-matrix multiplication.
+//!
+//! Large `f32`/`f64` products use a GEBP-style blocked kernel: `B` is packed into
+//! `KC x NC` panels, `A` into `MC x KC` blocks, and an `MR x NR` register-tiled
+//! micro-kernel accumulates into the output. Packing reads the operands through
+//! their strides, so a transposed operand is copied into contiguous panels once
+//! instead of being walked column-wise in the inner loop.
+//!
+//! With the `blas` feature, large `f32`/`f64` products are handed to BLAS instead
+//! when it can read both operands in place. See [`blas`](super::blas).
+//!
+//! Smaller products fall back to the SIMD `i-k-j` kernel when both operands are
+//! contiguous, and to a scalar strided loop otherwise.
+//!
+//! With the `rayon` feature, large `f32`/`f64` products are split into bands of
+//! output rows, each of which runs the sequential path above on its own thread.
+
+use core::ops::{Add, Mul};
+use num_traits::Zero;
+
+use crate::ops::{blas, simd};
+use crate::parallel;
+use crate::traits::StridedRef;
+
+/// Products with at least this many multiply-adds take the blocked path.
+const BLOCKED_THRESHOLD: usize = 48 * 48 * 48;
+
+/// Computes `out = a * b` where `a` is `m x n`, `b` is `n x p` and `out` is a
+/// zeroed, row-major `m x p` buffer.
+pub(crate) fn matmul<T>(a: StridedRef<'_, T>, b: StridedRef<'_, T>, out: &mut [T], m: usize, n: usize, p: usize)
+where
+    T: Clone + Zero + Mul<T, Output = T> + Add<T, Output = T>,
+{
+    if m == 0 || n == 0 || p == 0 || blas::matmul(a, b, out, m, n, p) {
+        return;
+    }
+
+    // Decided on the whole product so that every band takes the same path
+    let blocked = cfg!(feature = "alloc") && m * n * p >= BLOCKED_THRESHOLD;
+    if simd::is::<T, f32>() {
+        bands::<f32>(cast(a), cast(b), simd::cast_mut(out), m, n, p, blocked);
+    } else if simd::is::<T, f64>() {
+        bands::<f64>(cast(a), cast(b), simd::cast_mut(out), m, n, p, blocked);
+    } else {
+        rows(a, b, out, m, n, p, blocked);
+    }
+}
+
+/// Splits the product into bands of output rows, in parallel if it is large enough.
+#[allow(clippy::too_many_arguments)]
+fn bands<T>(a: StridedRef<'_, T>, b: StridedRef<'_, T>, out: &mut [T], m: usize, n: usize, p: usize, blocked: bool)
+where
+    T: Copy + Send + Sync + Zero + Mul<T, Output = T> + Add<T, Output = T>,
+{
+    parallel::for_each_chunk(out, p, m * n * p, |start, band| {
+        // Shift `a` down to the first row of the band
+        let mut a = a;
+        a.offset += (start / p) * a.rs;
+        rows(a, b, band, band.len() / p, n, p, blocked);
+    });
+}
+
+/// Computes the product on the calling thread.
+#[allow(clippy::too_many_arguments)]
+fn rows<T>(a: StridedRef<'_, T>, b: StridedRef<'_, T>, out: &mut [T], m: usize, n: usize, p: usize, blocked: bool)
+where
+    T: Clone + Zero + Mul<T, Output = T> + Add<T, Output = T>,
+{
+    #[cfg(feature = "alloc")]
+    if blocked && blocked::matmul(a, b, out, m, n, p) {
+        return;
+    }
+    #[cfg(not(feature = "alloc"))]
+    let _ = blocked;
+
+    let contiguous = (m == 1 || a.rs == n) && a.cs == 1 && (n == 1 || b.rs == p) && b.cs == 1;
+    if contiguous {
+        // SAFETY: both operands store their elements contiguously
+        let (a_slice, b_slice) = unsafe { (a.as_slice(m * n), b.as_slice(n * p)) };
+        if simd::matmul(a_slice, b_slice, out, n, p) {
+            return;
+        }
+    }
+
+    for (i, out_row) in out.chunks_exact_mut(p).enumerate() {
+        for k in 0..n {
+            // SAFETY: `i < m`, `k < n` and `j < p` index inside the `m x n` and `n x p` operands
+            let a_ik = unsafe { a.at(i, k) };
+            for (j, o) in out_row.iter_mut().enumerate() {
+                *o = o.clone() + (a_ik.clone() * unsafe { b.at(k, j) });
+            }
+        }
+    }
+}
+
+fn cast<T, U>(s: StridedRef<'_, T>) -> StridedRef<'_, U> {
+    debug_assert!(simd::is::<T, U>());
+    // SAFETY: `T` and `U` are the same type
+    unsafe { s.cast() }
+}
+
+#[cfg(feature = "alloc")]
+mod blocked {
+    use alloc::vec;
+    use core::ops::{Add, Mul};
+    use num_traits::Zero;
+
+    use super::cast;
+    use crate::ops::simd;
+    use crate::traits::StridedRef;
+
+    const MR: usize = 4;
+    const NR: usize = 8;
+    const KC: usize = 256;
+    const MC: usize = 64;
+    const NC: usize = 512;
+
+    /// Runs the blocked kernel. Returns `false` if `T` has no kernel.
+    pub(super) fn matmul<T>(a: StridedRef<'_, T>, b: StridedRef<'_, T>, out: &mut [T], m: usize, n: usize, p: usize) -> bool {
+        if simd::is::<T, f32>() {
+            dispatch::<f32>(cast(a), cast(b), simd::cast_mut(out), m, n, p);
+        } else if simd::is::<T, f64>() {
+            dispatch::<f64>(cast(a), cast(b), simd::cast_mut(out), m, n, p);
+        } else {
+            return false;
+        }
+        true
+    }
+
+    fn dispatch<T>(a: StridedRef<'_, T>, b: StridedRef<'_, T>, out: &mut [T], m: usize, n: usize, p: usize)
+    where
+        T: Copy + Zero + Mul<T, Output = T> + Add<T, Output = T>,
+    {
+        match simd::level() {
+            // The generic kernel is inlined here, so LLVM vectorizes it for AVX2
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            simd::Level::Avx2 => unsafe { gemm_avx2(a, b, out, m, n, p) },
+            _ => gemm(a, b, out, m, n, p),
+        }
+    }
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    #[target_feature(enable = "avx2")]
+    unsafe fn gemm_avx2<T>(a: StridedRef<'_, T>, b: StridedRef<'_, T>, out: &mut [T], m: usize, n: usize, p: usize)
+    where
+        T: Copy + Zero + Mul<T, Output = T> + Add<T, Output = T>,
+    {
+        gemm(a, b, out, m, n, p)
+    }
+
+    #[inline(always)]
+    fn gemm<T>(a: StridedRef<'_, T>, b: StridedRef<'_, T>, out: &mut [T], m: usize, n: usize, p: usize)
+    where
+        T: Copy + Zero + Mul<T, Output = T> + Add<T, Output = T>,
+    {
+        let mut packed_a = vec![T::zero(); MC.min(m).next_multiple_of(MR) * KC.min(n)];
+        let mut packed_b = vec![T::zero(); NC.min(p).next_multiple_of(NR) * KC.min(n)];
+
+        for jc in (0..p).step_by(NC) {
+            let nc = NC.min(p - jc);
+            for pc in (0..n).step_by(KC) {
+                let kc = KC.min(n - pc);
+                pack_b(&b, &mut packed_b, pc, jc, kc, nc);
+
+                for ic in (0..m).step_by(MC) {
+                    let mc = MC.min(m - ic);
+                    pack_a(&a, &mut packed_a, ic, pc, mc, kc);
+
+                    for jr in (0..nc).step_by(NR) {
+                        let nr = NR.min(nc - jr);
+                        let b_sliver = &packed_b[jr * kc..(jr + NR) * kc];
+                        for ir in (0..mc).step_by(MR) {
+                            let mr = MR.min(mc - ir);
+                            let a_sliver = &packed_a[ir * kc..(ir + MR) * kc];
+                            let c = &mut out[(ic + ir) * p + jc + jr..];
+                            kernel(a_sliver, b_sliver, c, p, mr, nr);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Packs the `mc x kc` block of `a` at `(ic, pc)` into slivers of `MR` rows,
+    /// each stored column by column. Rows past `mc` are zero-padded.
+    #[inline(always)]
+    fn pack_a<T: Copy + Zero>(a: &StridedRef<'_, T>, buf: &mut [T], ic: usize, pc: usize, mc: usize, kc: usize) {
+        for ir in (0..mc).step_by(MR) {
+            let sliver = &mut buf[ir * kc..(ir + MR) * kc];
+            for (k, col) in sliver.chunks_exact_mut(MR).enumerate() {
+                for (i, x) in col.iter_mut().enumerate() {
+                    // SAFETY: the block lies inside `a`, and rows past `mc` are not read
+                    *x = if ir + i < mc { unsafe { a.at(ic + ir + i, pc + k) } } else { T::zero() };
+                }
+            }
+        }
+    }
+
+    /// Packs the `kc x nc` panel of `b` at `(pc, jc)` into slivers of `NR` columns,
+    /// each stored row by row. Columns past `nc` are zero-padded.
+    #[inline(always)]
+    fn pack_b<T: Copy + Zero>(b: &StridedRef<'_, T>, buf: &mut [T], pc: usize, jc: usize, kc: usize, nc: usize) {
+        for jr in (0..nc).step_by(NR) {
+            let sliver = &mut buf[jr * kc..(jr + NR) * kc];
+            for (k, row) in sliver.chunks_exact_mut(NR).enumerate() {
+                for (j, x) in row.iter_mut().enumerate() {
+                    // SAFETY: the panel lies inside `b`, and columns past `nc` are not read
+                    *x = if jr + j < nc { unsafe { b.at(pc + k, jc + jr + j) } } else { T::zero() };
+                }
+            }
+        }
+    }
+
+    /// Accumulates the product of an `MR x kc` and a `kc x NR` sliver into the
+    /// top-left `mr x nr` corner of `c`, whose rows are `ldc` apart.
+    #[inline(always)]
+    fn kernel<T>(a: &[T], b: &[T], c: &mut [T], ldc: usize, mr: usize, nr: usize)
+    where
+        T: Copy + Zero + Mul<T, Output = T> + Add<T, Output = T>,
+    {
+        let mut acc = [[T::zero(); NR]; MR];
+        for (a_col, b_row) in a.chunks_exact(MR).zip(b.chunks_exact(NR)) {
+            for i in 0..MR {
+                for j in 0..NR {
+                    acc[i][j] = acc[i][j] + (a_col[i] * b_row[j]);
+                }
+            }
+        }
+
+        for (i, acc_row) in acc.iter().enumerate().take(mr) {
+            let c_row = &mut c[i * ldc..i * ldc + nr];
+            for (x, &y) in c_row.iter_mut().zip(acc_row) {
+                *x = *x + y;
+            }
+        }
+    }
+}
@@ -0,0 +1,138 @@
+//! Optional LAPACK backend.
+//!
+//! With the `blas` feature, the LU factorizations of large `f32`/`f64` matrices are handed to
+//! `LAPACKE_?getrf` and `LAPACKE_?gesv`, and their triangular solves to `LAPACKE_?trtrs`. Other
+//! element types and small matrices return `None` so that the caller runs the native code.
+//!
+//! Without the feature, every entry point returns `None`.
+
+#[cfg(feature = "blas")]
+use core::ffi::c_char;
+#[cfg(feature = "blas")]
+use core::ops::Neg;
+#[cfg(feature = "blas")]
+use lapacke_sys::LAPACK_ROW_MAJOR;
+#[cfg(feature = "blas")]
+use num_traits::Num;
+
+#[cfg(feature = "blas")]
+use crate::ops::simd;
+
+/// Matrices with at least this many rows are handed to LAPACK.
+#[cfg(feature = "blas")]
+const LAPACK_THRESHOLD: usize = 32;
+
+/// Returns the determinant of the row-major `N x N` matrix `a` from its LU factorization with
+/// `?getrf`, overwriting `a` with the factors. Returns `None` if `a` was not handed to LAPACK.
+#[cfg(feature = "blas")]
+pub(crate) fn det<T, const N: usize>(a: &mut [T]) -> Option<T> {
+    if N < LAPACK_THRESHOLD {
+        return None;
+    }
+    let n = i32::try_from(N).ok()?;
+    let mut ipiv = [0; N];
+
+    if simd::is::<T, f32>() {
+        let a: &mut [f32] = simd::cast_mut(a);
+        let info = unsafe { lapacke_sys::LAPACKE_sgetrf(LAPACK_ROW_MAJOR, n, n, a.as_mut_ptr(), n, ipiv.as_mut_ptr()) };
+        Some(simd::cast_value(lu_det(a, &ipiv, info)))
+    } else if simd::is::<T, f64>() {
+        let a: &mut [f64] = simd::cast_mut(a);
+        let info = unsafe { lapacke_sys::LAPACKE_dgetrf(LAPACK_ROW_MAJOR, n, n, a.as_mut_ptr(), n, ipiv.as_mut_ptr()) };
+        Some(simd::cast_value(lu_det(a, &ipiv, info)))
+    } else {
+        None
+    }
+}
+
+/// Overwrites the row-major `N x N` matrix `b` with `X` such that `a * X == b`, using `?gesv`.
+///
+/// A singular `a` fills `b` with NaN, like the native elimination gives non-finite elements.
+/// Returns `false` if the system was not handed to LAPACK.
+#[cfg(all(feature = "blas", any(feature = "std", feature = "libm")))]
+pub(crate) fn solve<T, const N: usize>(a: &mut [T], b: &mut [T]) -> bool {
+    if N < LAPACK_THRESHOLD {
+        return false;
+    }
+    let Ok(n) = i32::try_from(N) else {
+        return false;
+    };
+    let mut ipiv = [0; N];
+
+    if simd::is::<T, f32>() {
+        let (a, b): (&mut [f32], &mut [f32]) = (simd::cast_mut(a), simd::cast_mut(b));
+        let info = unsafe { lapacke_sys::LAPACKE_sgesv(LAPACK_ROW_MAJOR, n, n, a.as_mut_ptr(), n, ipiv.as_mut_ptr(), b.as_mut_ptr(), n) };
+        if info != 0 {
+            b.fill(f32::NAN);
+        }
+    } else if simd::is::<T, f64>() {
+        let (a, b): (&mut [f64], &mut [f64]) = (simd::cast_mut(a), simd::cast_mut(b));
+        let info = unsafe { lapacke_sys::LAPACKE_dgesv(LAPACK_ROW_MAJOR, n, n, a.as_mut_ptr(), n, ipiv.as_mut_ptr(), b.as_mut_ptr(), n) };
+        if info != 0 {
+            b.fill(f64::NAN);
+        }
+    } else {
+        return false;
+    }
+    true
+}
+
+/// Overwrites `x` with the solution of `A x = x` (or `A^T x = x` if `transpose` is set) with `?trtrs`,
+/// where `A` is the lower or upper triangle of the row-major `N x N` matrix `a`.
+///
+/// Returns `Some(false)` if a diagonal element is zero, and `None` if the system was not handed
+/// to LAPACK.
+#[cfg(feature = "blas")]
+pub(crate) fn solve_triangular<T, const N: usize>(a: &[T], lower: bool, transpose: bool, x: &mut [T]) -> Option<bool> {
+    if N < LAPACK_THRESHOLD {
+        return None;
+    }
+    let n = i32::try_from(N).ok()?;
+    let uplo = (if lower { b'L' } else { b'U' }) as c_char;
+    let trans = (if transpose { b'T' } else { b'N' }) as c_char;
+    let diag = b'N' as c_char;
+
+    let info = if simd::is::<T, f32>() {
+        let (a, x): (&[f32], &mut [f32]) = (simd::cast(a), simd::cast_mut(x));
+        unsafe { lapacke_sys::LAPACKE_strtrs(LAPACK_ROW_MAJOR, uplo, trans, diag, n, 1, a.as_ptr(), n, x.as_mut_ptr(), 1) }
+    } else if simd::is::<T, f64>() {
+        let (a, x): (&[f64], &mut [f64]) = (simd::cast(a), simd::cast_mut(x));
+        unsafe { lapacke_sys::LAPACKE_dtrtrs(LAPACK_ROW_MAJOR, uplo, trans, diag, n, 1, a.as_ptr(), n, x.as_mut_ptr(), 1) }
+    } else {
+        return None;
+    };
+    Some(info == 0)
+}
+
+/// Returns the determinant from the packed LU factors and pivots returned by `?getrf`.
+#[cfg(feature = "blas")]
+fn lu_det<F: Copy + Num + Neg<Output = F>>(lu: &[F], ipiv: &[i32], info: i32) -> F {
+    // A positive `info` is the index of an exactly zero pivot
+    if info != 0 {
+        return F::zero();
+    }
+    let n = ipiv.len();
+    (0..n).fold(F::one(), |det, k| {
+        // The pivots are one-based
+        let det = det * lu[k * n + k];
+        if ipiv[k] as usize != k + 1 { -det } else { det }
+    })
+}
+
+#[cfg(not(feature = "blas"))]
+#[inline(always)]
+pub(crate) fn det<T, const N: usize>(_a: &mut [T]) -> Option<T> {
+    None
+}
+
+#[cfg(all(not(feature = "blas"), any(feature = "std", feature = "libm")))]
+#[inline(always)]
+pub(crate) fn solve<T, const N: usize>(_a: &mut [T], _b: &mut [T]) -> bool {
+    false
+}
+
+#[cfg(not(feature = "blas"))]
+#[inline(always)]
+pub(crate) fn solve_triangular<T, const N: usize>(_a: &[T], _lower: bool, _transpose: bool, _x: &mut [T]) -> Option<bool> {
+    None
+}
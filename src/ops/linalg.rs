@@ -0,0 +1,154 @@
+//! Elimination and norms of `f32`/`f64` matrices.
+//!
+//! [`Matrix::det`](crate::Matrix::det) and the solves behind the matrix functions run the same
+//! partial pivoting elimination as their generic code here, on the row-major storage directly.
+//! With the `rayon` feature, the updates of the rows below each pivot are split across threads
+//! once they are large enough. Every row is updated exactly as it would be sequentially, so these
+//! results do not depend on the [`Parallelism`](crate::Parallelism) policy.
+//!
+//! The 1-norm used by the matrix functions sums the columns over bands of rows, which run in
+//! parallel and are then added in order, like the dot products.
+//!
+//! Other element types return `None` or `false` so that the caller runs its generic code.
+
+use core::ops::Range;
+use num_traits::Signed;
+
+use crate::ops::simd;
+use crate::parallel;
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::vector::Vector;
+
+/// Returns the determinant of the row-major `N x N` matrix `a`, overwriting it with its
+/// eliminated form. Returns `None` if `T` has no typed path.
+pub(crate) fn det<T, const N: usize>(a: &mut [T]) -> Option<T> {
+    if simd::is::<T, f32>() {
+        Some(simd::cast_value(det_rows::<f32, N>(simd::cast_mut(a))))
+    } else if simd::is::<T, f64>() {
+        Some(simd::cast_value(det_rows::<f64, N>(simd::cast_mut(a))))
+    } else {
+        None
+    }
+}
+
+/// Overwrites the row-major `N x N` matrix `b` with `X` such that `a * X == b`, destroying `a`.
+///
+/// A singular `a` gives non-finite elements. Returns `false` if `T` has no typed path.
+#[cfg(any(feature = "std", feature = "libm"))]
+pub(crate) fn solve<T, const N: usize>(a: &mut [T], b: &mut [T]) -> bool {
+    if simd::is::<T, f32>() {
+        solve_rows::<f32, N>(simd::cast_mut(a), simd::cast_mut(b));
+    } else if simd::is::<T, f64>() {
+        solve_rows::<f64, N>(simd::cast_mut(a), simd::cast_mut(b));
+    } else {
+        return false;
+    }
+    true
+}
+
+/// Returns the 1-norm of the row-major `N x N` matrix `a`, i.e. its maximum absolute column sum.
+/// Returns `None` if `T` has no typed path.
+#[cfg(any(feature = "std", feature = "libm"))]
+pub(crate) fn norm1<T, const N: usize>(a: &[T]) -> Option<T> {
+    if simd::is::<T, f32>() {
+        Some(simd::cast_value(column_norm1::<f32, N>(simd::cast(a))))
+    } else if simd::is::<T, f64>() {
+        Some(simd::cast_value(column_norm1::<f64, N>(simd::cast(a))))
+    } else {
+        None
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+fn column_norm1<F, const N: usize>(a: &[F]) -> F
+where
+    F: Copy + Send + Sync + Signed + PartialOrd,
+{
+    let sums = parallel::sum_ranges(N, N * N, |rows| {
+        Vector::<F, N>::from(core::array::from_fn(|j| rows.clone().fold(F::zero(), |sum, i| sum + a[i * N + j].abs())))
+    });
+    (0..N).fold(F::zero(), |max, j| if sums[j] > max { sums[j] } else { max })
+}
+
+fn det_rows<F, const N: usize>(a: &mut [F]) -> F
+where
+    F: Copy + Send + Sync + Signed + PartialOrd,
+{
+    let mut det = F::one();
+    for k in 0..N {
+        let pivot = (k + 1..N).fold(k, |p, i| if a[i * N + k].abs() > a[p * N + k].abs() { i } else { p });
+        if a[pivot * N + k].is_zero() {
+            return F::zero();
+        }
+        if pivot != k {
+            swap_rows(a, N, k, pivot);
+            det = -det;
+        }
+
+        let inv = F::one() / a[k * N + k];
+        det = det * a[k * N + k];
+        let (top, below) = a.split_at_mut((k + 1) * N);
+        eliminate(below, &top[k * N..], k + 1..N, |_, row| row[k] * inv);
+    }
+    det
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+fn solve_rows<F, const N: usize>(a: &mut [F], b: &mut [F])
+where
+    F: Copy + Send + Sync + Signed + PartialOrd,
+{
+    for k in 0..N {
+        let pivot = (k + 1..N).fold(k, |p, i| if a[i * N + k].abs() > a[p * N + k].abs() { i } else { p });
+        swap_rows(a, N, k, pivot);
+        swap_rows(b, N, k, pivot);
+
+        // The factors are kept below the diagonal of `a` to eliminate `b` with
+        let (top, below) = a.split_at_mut((k + 1) * N);
+        let pivot_row = &top[k * N..];
+        eliminate(below, pivot_row, k + 1..N, |_, row| {
+            row[k] = row[k] / pivot_row[k];
+            row[k]
+        });
+        let (top, below) = b.split_at_mut((k + 1) * N);
+        let factors = &a[(k + 1) * N..];
+        eliminate(below, &top[k * N..], 0..N, |i, _| factors[i * N + k]);
+    }
+
+    for k in (0..N).rev() {
+        let (top, below) = b.split_at_mut((k + 1) * N);
+        let upper = &a[k * N..(k + 1) * N];
+        parallel::for_each_chunk(&mut top[k * N..], 1, N * (N - k), |start, row| {
+            for (j, x) in (start..).zip(row) {
+                let sum = (k + 1..N).fold(*x, |sum, i| sum - upper[i] * below[(i - k - 1) * N + j]);
+                *x = sum / upper[k];
+            }
+        });
+    }
+}
+
+/// Subtracts `factor(i, row) * pivot[j]` from `row[j]` for every `j` in `cols` and every row `i`
+/// of the row-major `below`, which has as many columns as `pivot`.
+fn eliminate<F, G>(below: &mut [F], pivot: &[F], cols: Range<usize>, factor: G)
+where
+    F: Copy + Send + Sync + Signed,
+    G: Fn(usize, &mut [F]) -> F + Sync,
+{
+    let n = pivot.len();
+    let work = below.len() / n.max(1) * cols.len();
+    parallel::for_each_chunk(below, n, work, |start, rows| {
+        for (i, row) in (start / n..).zip(rows.chunks_exact_mut(n)) {
+            let factor = factor(i, row);
+            for j in cols.clone() {
+                row[j] = row[j] - factor * pivot[j];
+            }
+        }
+    });
+}
+
+fn swap_rows<F>(a: &mut [F], n: usize, i: usize, j: usize) {
+    if i != j {
+        let (top, bottom) = a.split_at_mut(i.max(j) * n);
+        top[i.min(j) * n..][..n].swap_with_slice(&mut bottom[..n]);
+    }
+}
@@ -1,39 +1,84 @@
+#[macro_use]
+mod simd_macros {
+    macro_rules! simd_op {
+        (Add) => { Some(simd::Op::Add) };
+        (Sub) => { Some(simd::Op::Sub) };
+        (Mul) => { Some(simd::Op::Mul) };
+        (Div) => { Some(simd::Op::Div) };
+        (AddAssign) => { Some(simd::Op::Add) };
+        (SubAssign) => { Some(simd::Op::Sub) };
+        (MulAssign) => { Some(simd::Op::Mul) };
+        (DivAssign) => { Some(simd::Op::Div) };
+        ($other:tt) => { None };
+    }
+}
+
 #[macro_use]
 mod op_macros {
     macro_rules! impl_inner {
         (mat, $lhs:ty, $rhs:ty, $trait:tt, $method:tt, $op:tt, $output:ty, $($generics:tt)*) => {
-            impl<T: Copy + $trait<T, Output=T>, $($generics)*> $trait<$rhs> for $lhs {
+            impl<T: Clone + $trait<T, Output=T>, $($generics)*> $trait<$rhs> for $lhs {
                 type Output = $output;
 
                 fn $method(self, other: $rhs) -> Self::Output {
-                    Self::Output::from(std::array::from_fn(|i| std::array::from_fn(|j| self[(i, j)] $op other[(i, j)])))
+                    Self::Output::from(core::array::from_fn(|i| core::array::from_fn(|j| self[(i, j)].clone() $op other[(i, j)].clone())))
                 }
             }
         };
         (mat, $lhs:ty, $trait:tt, $method:tt, $op:tt, $output:ty, $($generics:tt)*) => {
-            impl<T: Copy + $trait<T, Output=T>, $($generics)*> $trait<T> for $lhs {
+            impl<T: Clone + $trait<T, Output=T>, $($generics)*> $trait<T> for $lhs {
                 type Output = $output;
 
                 fn $method(self, scalar: T) -> Self::Output {
-                    Self::Output::from(std::array::from_fn(|i| std::array::from_fn(|j| self[(i, j)] $op scalar)))
+                    Self::Output::from(core::array::from_fn(|i| core::array::from_fn(|j| self[(i, j)].clone() $op scalar.clone())))
                 }
             }
         };
         (vec, $lhs:ty, $rhs:ty, $trait:tt, $method:tt, $op:tt, $output:ty, $($generics:tt)*) => {
-            impl<T: Copy + $trait<T, Output=T>, $($generics)*> $trait<$rhs> for $lhs {
+            impl<T: Clone + $trait<T, Output=T>, $($generics)*> $trait<$rhs> for $lhs {
                 type Output = $output;
 
                 fn $method(self, other: $rhs) -> Self::Output {
-                    Self::Output::from(std::array::from_fn(|i| self[i] $op other[i]))
+                    Self::Output::from(core::array::from_fn(|i| self[i].clone() $op other[i].clone()))
                 }
             }
         };
         (vec, $lhs:ty, $trait:tt, $method:tt, $op:tt, $output:ty, $($generics:tt)*) => {
-            impl<T: Copy + $trait<T, Output=T>, $($generics)*> $trait<T> for $lhs {
+            impl<T: Clone + $trait<T, Output=T>, $($generics)*> $trait<T> for $lhs {
                 type Output = $output;
 
                 fn $method(self, scalar: T) -> Self::Output {
-                    Self::Output::from(std::array::from_fn(|i| self[i] $op scalar))
+                    Self::Output::from(core::array::from_fn(|i| self[i].clone() $op scalar.clone()))
+                }
+            }
+        };
+        (contiguous, $lhs:ty, $rhs:ty, $trait:tt, $method:tt, $op:tt, $output:ty, $($generics:tt)*) => {
+            impl<T: Clone + $trait<T, Output=T>, $($generics)*> $trait<$rhs> for $lhs {
+                type Output = $output;
+
+                fn $method(self, other: $rhs) -> Self::Output {
+                    let (lhs, rhs) = (self.as_slice(), other.as_slice());
+                    if !simd::has_kernel::<T>(simd_op!($trait)) {
+                        return Self::Output::from_fn(|i| lhs[i].clone() $op rhs[i].clone());
+                    }
+                    let mut result = Self::Output::from_fn(|i| lhs[i].clone());
+                    simd::zip_assign(simd_op!($trait), result.as_mut_slice(), rhs);
+                    result
+                }
+            }
+        };
+        (contiguous, $lhs:ty, $trait:tt, $method:tt, $op:tt, $output:ty, $($generics:tt)*) => {
+            impl<T: Clone + $trait<T, Output=T>, $($generics)*> $trait<T> for $lhs {
+                type Output = $output;
+
+                fn $method(self, scalar: T) -> Self::Output {
+                    let lhs = self.as_slice();
+                    if !simd::has_kernel::<T>(simd_op!($trait)) {
+                        return Self::Output::from_fn(|i| lhs[i].clone() $op scalar.clone());
+                    }
+                    let mut result = Self::Output::from_fn(|i| lhs[i].clone());
+                    simd::scalar_assign(simd_op!($trait), result.as_mut_slice(), &scalar);
+                    result
                 }
             }
         };
@@ -56,10 +101,10 @@ mod op_macros {
         ($trait:tt, $method:tt, $op:tt) => {
             macro_rules! impl_vv_op {
                 ($lhs:ty, $rhs:ty) => {
-                    impl_combinations!(vec, $lhs, $rhs, $trait, $method, $op, Vector<T, N>, const N: usize);
+                    impl_combinations!(contiguous, $lhs, $rhs, $trait, $method, $op, Vector<T, N>, const N: usize);
                 };
                 ($lhs:ty) => {
-                    impl_combinations!(vec, $lhs, $trait, $method, $op, Vector<T, N>, const N: usize);
+                    impl_combinations!(contiguous, $lhs, $trait, $method, $op, Vector<T, N>, const N: usize);
                 }
             }
 
@@ -74,10 +119,10 @@ mod op_macros {
 
             macro_rules! impl_vv_op_row {
                 ($lhs:ty, $rhs:ty) => {
-                    impl_combinations!(vec, $lhs, $rhs, $trait, $method, $op, RowVector<T, N>, const N: usize);
+                    impl_combinations!(contiguous, $lhs, $rhs, $trait, $method, $op, RowVector<T, N>, const N: usize);
                 };
                 ($lhs:ty) => {
-                    impl_combinations!(vec, $lhs, $trait, $method, $op, RowVector<T, N>, const N: usize);
+                    impl_combinations!(contiguous, $lhs, $trait, $method, $op, RowVector<T, N>, const N: usize);
                 }
             }
 
@@ -92,10 +137,10 @@ mod op_macros {
 
             macro_rules! impl_mm_op {
                 ($lhs:ty, $rhs:ty) => {
-                    impl_combinations!(mat, $lhs, $rhs, $trait, $method, $op, Matrix<T, M, N>, const M: usize, const N: usize);
+                    impl_combinations!(contiguous, $lhs, $rhs, $trait, $method, $op, Matrix<T, M, N>, const M: usize, const N: usize);
                 };
                 ($lhs:ty) => {
-                    impl_combinations!(mat, $lhs, $trait, $method, $op, Matrix<T, M, N>, const M: usize, const N: usize);
+                    impl_combinations!(contiguous, $lhs, $trait, $method, $op, Matrix<T, M, N>, const M: usize, const N: usize);
                 }
             }
 
@@ -107,6 +152,15 @@ mod op_macros {
                     impl_combinations!(mat, $lhs, $trait, $method, $op, Matrix<T, M, N>, const A: usize, const B: usize, const M: usize, const N: usize);
                 }
             }
+
+            macro_rules! impl_mm_op_cm {
+                ($lhs:ty, $rhs:ty) => {
+                    impl_combinations!(contiguous, $lhs, $rhs, $trait, $method, $op, MatrixCM<T, M, N>, const M: usize, const N: usize);
+                };
+                ($lhs:ty) => {
+                    impl_combinations!(contiguous, $lhs, $trait, $method, $op, MatrixCM<T, M, N>, const M: usize, const N: usize);
+                }
+            }
         };
     }
 
@@ -141,21 +195,27 @@ mod op_macros {
                 }
             }
 
+            macro_rules! impl_mm_op_mixed {
+                ($lhs:ty, $rhs:ty) => {
+                    impl_combinations!(mat, $lhs, $rhs, $trait, $method, $op, Matrix<T, M, N>, const M: usize, const N: usize);
+                };
+            }
+
             macro_rules! impl_mv_op {
                 ($lhs:ty, $rhs:ty) => {
-                    impl_combinations!(mat, $lhs, $rhs, $trait, $method, $op, Matrix<T, N, 1>, const N: usize);
+                    impl_combinations!(contiguous, $lhs, $rhs, $trait, $method, $op, Matrix<T, N, 1>, const N: usize);
                 };
                 ($lhs:ty) => {
-                    impl_combinations!(mat, $lhs, $trait, $method, $op, Matrix<T, N, 1>, const N: usize);
+                    impl_combinations!(contiguous, $lhs, $trait, $method, $op, Matrix<T, N, 1>, const N: usize);
                 }
             }
 
             macro_rules! impl_mv_op_row {
                 ($lhs:ty, $rhs:ty) => {
-                    impl_combinations!(mat, $lhs, $rhs, $trait, $method, $op, Matrix<T, 1, N>, const N: usize);
+                    impl_combinations!(contiguous, $lhs, $rhs, $trait, $method, $op, Matrix<T, 1, N>, const N: usize);
                 };
                 ($lhs:ty) => {
-                    impl_combinations!(mat, $lhs, $trait, $method, $op, Matrix<T, 1, N>, const N: usize);
+                    impl_combinations!(contiguous, $lhs, $trait, $method, $op, Matrix<T, 1, N>, const N: usize);
                 }
             }
 
@@ -273,29 +333,49 @@ mod op_macros {
 #[macro_use]
 mod op_assign_macros {
     macro_rules! impl_assign_inner {
-        ($lhs:ty, $rhs:ty, $trait:tt, $method:tt, $op:tt, $output:ty, $($generics:tt)*) => {
-            impl<T: Copy + $trait<T>, $($generics)*> $trait<$rhs> for $lhs {
+        (index, $lhs:ty, $rhs:ty, $trait:tt, $method:tt, $op:tt, $output:ty, $($generics:tt)*) => {
+            impl<T: Clone + $trait<T>, $($generics)*> $trait<$rhs> for $lhs {
+                fn $method(&mut self, other: $rhs) {
+                    (0..self.capacity()).for_each(|i| self[i] $op other[i].clone());
+                }
+            }
+        };
+        (index, $lhs:ty, $trait:tt, $method:tt, $op:tt, $output:ty, $($generics:tt)*) => {
+            impl<T: Clone + $trait<T>, $($generics)*> $trait<T> for $lhs {
+                fn $method(&mut self, scalar: T) {
+                    (0..self.capacity()).for_each(|i| self[i] $op scalar.clone());
+                }
+            }
+        };
+        (contiguous, $lhs:ty, $rhs:ty, $trait:tt, $method:tt, $op:tt, $output:ty, $($generics:tt)*) => {
+            impl<T: Clone + $trait<T>, $($generics)*> $trait<$rhs> for $lhs {
                 fn $method(&mut self, other: $rhs) {
-                    (0..self.capacity()).for_each(|i| self[i] $op other[i]);
+                    let (lhs, rhs) = (self.as_mut_slice(), other.as_slice());
+                    if !simd::zip_assign(simd_op!($trait), lhs, rhs) {
+                        lhs.iter_mut().zip(rhs).for_each(|(a, b)| *a $op b.clone());
+                    }
                 }
             }
         };
-        ($lhs:ty, $trait:tt, $method:tt, $op:tt, $output:ty, $($generics:tt)*) => {
-            impl<T: Copy + $trait<T>, $($generics)*> $trait<T> for $lhs {
+        (contiguous, $lhs:ty, $trait:tt, $method:tt, $op:tt, $output:ty, $($generics:tt)*) => {
+            impl<T: Clone + $trait<T>, $($generics)*> $trait<T> for $lhs {
                 fn $method(&mut self, scalar: T) {
-                    (0..self.capacity()).for_each(|i| self[i] $op scalar);
+                    let lhs = self.as_mut_slice();
+                    if !simd::scalar_assign(simd_op!($trait), lhs, &scalar) {
+                        lhs.iter_mut().for_each(|a| *a $op scalar.clone());
+                    }
                 }
             }
         };
     }
 
     macro_rules! impl_assign_combinations {
-        ($lhs:ty, $rhs:ty, $trait:tt, $method:tt, $op:tt, $output:ty, $($generics:tt)*) => {
-            impl_assign_inner!($lhs, $rhs, $trait, $method, $op, $output, $($generics)*);
-            impl_assign_inner!($lhs, &$rhs, $trait, $method, $op, $output, $($generics)*);
+        ($type:tt, $lhs:ty, $rhs:ty, $trait:tt, $method:tt, $op:tt, $output:ty, $($generics:tt)*) => {
+            impl_assign_inner!($type, $lhs, $rhs, $trait, $method, $op, $output, $($generics)*);
+            impl_assign_inner!($type, $lhs, &$rhs, $trait, $method, $op, $output, $($generics)*);
         };
-        ($lhs:ty, $trait:tt, $method:tt, $op:tt, $output:ty, $($generics:tt)*) => {
-            impl_assign_inner!($lhs, $trait, $method, $op, $output, $($generics)*);
+        ($type:tt, $lhs:ty, $trait:tt, $method:tt, $op:tt, $output:ty, $($generics:tt)*) => {
+            impl_assign_inner!($type, $lhs, $trait, $method, $op, $output, $($generics)*);
         };
     }
 
@@ -303,37 +383,37 @@ mod op_assign_macros {
         ($trait:tt, $method:tt, $op:tt) => {
             macro_rules! impl_vv_op_assign {
                 ($lhs:ty, $rhs:ty) => {
-                    impl_assign_combinations!($lhs, $rhs, $trait, $method, $op, Vector<T, N>, const N: usize);
+                    impl_assign_combinations!(contiguous, $lhs, $rhs, $trait, $method, $op, Vector<T, N>, const N: usize);
                 };
                 ($lhs:ty) => {
-                    impl_assign_combinations!($lhs, $trait, $method, $op, Vector<T, N>, const N: usize);
+                    impl_assign_combinations!(contiguous, $lhs, $trait, $method, $op, Vector<T, N>, const N: usize);
                 }
             }
 
             macro_rules! impl_vv_op_assign_view {
                 ($lhs:ty, $rhs:ty) => {
-                    impl_assign_combinations!($lhs, $rhs, $trait, $method, $op, Vector<T, M>, V: IndexMut<usize, Output = T>, const N: usize, const M: usize);
+                    impl_assign_combinations!(index, $lhs, $rhs, $trait, $method, $op, Vector<T, M>, V: IndexMut<usize, Output = T>, const N: usize, const M: usize);
                 };
                 ($lhs:ty) => {
-                    impl_assign_combinations!($lhs, $trait, $method, $op, Vector<T, M>, V: IndexMut<usize, Output = T>, const N: usize, const M: usize);
+                    impl_assign_combinations!(index, $lhs, $trait, $method, $op, Vector<T, M>, V: IndexMut<usize, Output = T>, const N: usize, const M: usize);
                 }
             }
 
             macro_rules! impl_mm_op_assign {
                 ($lhs:ty, $rhs:ty) => {
-                    impl_assign_combinations!($lhs, $rhs, $trait, $method, $op, Matrix<T, M, N>, const M: usize, const N: usize);
+                    impl_assign_combinations!(contiguous, $lhs, $rhs, $trait, $method, $op, Matrix<T, M, N>, const M: usize, const N: usize);
                 };
                 ($lhs:ty) => {
-                    impl_assign_combinations!($lhs, $trait, $method, $op, Matrix<T, M, N>, const M: usize, const N: usize);
+                    impl_assign_combinations!(contiguous, $lhs, $trait, $method, $op, Matrix<T, M, N>, const M: usize, const N: usize);
                 }
             }
 
             macro_rules! impl_mm_op_assign_view {
                 ($lhs:ty, $rhs:ty) => {
-                    impl_assign_combinations!($lhs, $rhs, $trait, $method, $op, Matrix<T, M, N>, const A: usize, const B: usize, const M: usize, const N: usize);
+                    impl_assign_combinations!(index, $lhs, $rhs, $trait, $method, $op, Matrix<T, M, N>, const A: usize, const B: usize, const M: usize, const N: usize);
                 };
                 ($lhs:ty) => {
-                    impl_assign_combinations!($lhs, $trait, $method, $op, Matrix<T, M, N>, const A: usize, const B: usize, const M: usize, const N: usize);
+                    impl_assign_combinations!(index, $lhs, $trait, $method, $op, Matrix<T, M, N>, const A: usize, const B: usize, const M: usize, const N: usize);
                 }
             }
         };
@@ -345,46 +425,139 @@ mod op_assign_macros {
 
             macro_rules! impl_vv_op_assign_view_view {
                 ($lhs:ty, $rhs:ty) => {
-                    impl_assign_combinations!($lhs, $rhs, $trait, $method, $op, Vector<T, M>, V1: IndexMut<usize, Output = T>, V2: Index<usize, Output = T>, const A: usize, const N: usize, const M: usize);
+                    impl_assign_combinations!(index, $lhs, $rhs, $trait, $method, $op, Vector<T, M>, V1: IndexMut<usize, Output = T>, V2: Index<usize, Output = T>, const A: usize, const N: usize, const M: usize);
                 };
                 ($lhs:ty) => {
-                    impl_assign_combinations!($lhs, $trait, $method, $op, Vector<T, M>, V1: IndexMut<usize, Output = T>, V2: Index<usize, Output = T>, const A: usize, const N: usize, const M: usize);
+                    impl_assign_combinations!(index, $lhs, $trait, $method, $op, Vector<T, M>, V1: IndexMut<usize, Output = T>, V2: Index<usize, Output = T>, const A: usize, const N: usize, const M: usize);
                 }
             }
 
             macro_rules! impl_mm_op_assign_view_view {
                 ($lhs:ty, $rhs:ty) => {
-                    impl_assign_combinations!($lhs, $rhs, $trait, $method, $op, Matrix<T, M, N>, const A: usize, const B: usize, const C: usize, const D: usize, const M: usize, const N: usize);
+                    impl_assign_combinations!(index, $lhs, $rhs, $trait, $method, $op, Matrix<T, M, N>, const A: usize, const B: usize, const C: usize, const D: usize, const M: usize, const N: usize);
                 };
                 ($lhs:ty) => {
-                    impl_assign_combinations!($lhs, $trait, $method, $op, Matrix<T, M, N>, const A: usize, const B: usize, const C: usize, const D: usize, const M: usize, const N: usize);
+                    impl_assign_combinations!(index, $lhs, $trait, $method, $op, Matrix<T, M, N>, const A: usize, const B: usize, const C: usize, const D: usize, const M: usize, const N: usize);
                 }
             }
 
+            macro_rules! impl_mm_op_assign_mixed {
+                ($lhs:ty, $rhs:ty) => {
+                    impl_assign_combinations!(index, $lhs, $rhs, $trait, $method, $op, Matrix<T, M, N>, const M: usize, const N: usize);
+                };
+            }
+
             macro_rules! impl_vm_op_assign_mat_view {
                 ($lhs:ty, $rhs:ty) => {
-                    impl_assign_combinations!($lhs, $rhs, $trait, $method, $op, Vector<T, M>, const A: usize, const B: usize, const M: usize);
+                    impl_assign_combinations!(index, $lhs, $rhs, $trait, $method, $op, Vector<T, M>, const A: usize, const B: usize, const M: usize);
                 };
                 ($lhs:ty) => {
-                    impl_assign_combinations!($lhs, $trait, $method, $op, Vector<T, M>, const A: usize, const B: usize, const M: usize);
+                    impl_assign_combinations!(index, $lhs, $trait, $method, $op, Vector<T, M>, const A: usize, const B: usize, const M: usize);
                 }
             }
 
             macro_rules! impl_vm_op_assign_vec_view {
                 ($lhs:ty, $rhs:ty) => {
-                    impl_assign_combinations!($lhs, $rhs, $trait, $method, $op, Vector<T, M>, V: IndexMut<usize, Output = T>, const M: usize, const N: usize);
+                    impl_assign_combinations!(index, $lhs, $rhs, $trait, $method, $op, Vector<T, M>, V: IndexMut<usize, Output = T>, const M: usize, const N: usize);
                 };
                 ($lhs:ty) => {
-                    impl_assign_combinations!($lhs, $trait, $method, $op, Vector<T, M>, V: IndexMut<usize, Output = T>, const M: usize, const N: usize);
+                    impl_assign_combinations!(index, $lhs, $trait, $method, $op, Vector<T, M>, V: IndexMut<usize, Output = T>, const M: usize, const N: usize);
                 }
             }
 
             macro_rules! impl_vm_op_assign_view_view {
                 ($lhs:ty, $rhs:ty) => {
-                    impl_assign_combinations!($lhs, $rhs, $trait, $method, $op, Vector<T, M>, V: IndexMut<usize, Output = T>, const A: usize, const B: usize, const M: usize, const N: usize);
+                    impl_assign_combinations!(index, $lhs, $rhs, $trait, $method, $op, Vector<T, M>, V: IndexMut<usize, Output = T>, const A: usize, const B: usize, const M: usize, const N: usize);
                 };
                 ($lhs:ty) => {
-                    impl_assign_combinations!($lhs, $trait, $method, $op, Vector<T, M>, V: IndexMut<usize, Output = T>, const A: usize, const B: usize, const M: usize, const N: usize);
+                    impl_assign_combinations!(index, $lhs, $trait, $method, $op, Vector<T, M>, V: IndexMut<usize, Output = T>, const A: usize, const B: usize, const M: usize, const N: usize);
+                }
+            }
+        };
+    }
+}
+
+#[macro_use]
+mod unary_op_macros {
+    macro_rules! impl_unary_inner {
+        (mat, $lhs:ty, $trait:tt, $method:tt, $op:tt, $output:ty, $($generics:tt)*) => {
+            impl<T: Clone + $trait<Output=T>, $($generics)*> $trait for $lhs {
+                type Output = $output;
+
+                fn $method(self) -> Self::Output {
+                    Self::Output::from(core::array::from_fn(|i| core::array::from_fn(|j| $op self[(i, j)].clone())))
+                }
+            }
+        };
+        (vec, $lhs:ty, $trait:tt, $method:tt, $op:tt, $output:ty, $($generics:tt)*) => {
+            impl<T: Clone + $trait<Output=T>, $($generics)*> $trait for $lhs {
+                type Output = $output;
+
+                fn $method(self) -> Self::Output {
+                    Self::Output::from(core::array::from_fn(|i| $op self[i].clone()))
+                }
+            }
+        };
+        (contiguous, $lhs:ty, $trait:tt, $method:tt, $op:tt, $output:ty, $($generics:tt)*) => {
+            impl<T: Clone + $trait<Output=T>, $($generics)*> $trait for $lhs {
+                type Output = $output;
+
+                fn $method(self) -> Self::Output {
+                    let lhs = self.as_slice();
+                    Self::Output::from_fn(|i| $op lhs[i].clone())
+                }
+            }
+        };
+    }
+
+    macro_rules! impl_unary_combinations {
+        ($type:tt, $lhs:ty, $trait:tt, $method:tt, $op:tt, $output:ty, $($generics:tt)*) => {
+            impl_unary_inner!($type, $lhs, $trait, $method, $op, $output, $($generics)*);
+            impl_unary_inner!($type, &$lhs, $trait, $method, $op, $output, $($generics)*);
+        };
+    }
+
+    macro_rules! generate_op_unary_macros {
+        ($trait:tt, $method:tt, $op:tt) => {
+            macro_rules! impl_vv_op {
+                ($lhs:ty) => {
+                    impl_unary_combinations!(contiguous, $lhs, $trait, $method, $op, Vector<T, N>, const N: usize);
+                }
+            }
+
+            macro_rules! impl_vv_op_view {
+                ($lhs:ty) => {
+                    impl_unary_combinations!(vec, $lhs, $trait, $method, $op, Vector<T, M>, V: Index<usize, Output = T>, const N: usize, const M: usize);
+                }
+            }
+
+            macro_rules! impl_vv_op_row {
+                ($lhs:ty) => {
+                    impl_unary_combinations!(contiguous, $lhs, $trait, $method, $op, RowVector<T, N>, const N: usize);
+                }
+            }
+
+            macro_rules! impl_vv_op_view_row {
+                ($lhs:ty) => {
+                    impl_unary_combinations!(vec, $lhs, $trait, $method, $op, RowVector<T, M>, V: Index<usize, Output = T>, const N: usize, const M: usize);
+                }
+            }
+
+            macro_rules! impl_mm_op {
+                ($lhs:ty) => {
+                    impl_unary_combinations!(contiguous, $lhs, $trait, $method, $op, Matrix<T, M, N>, const M: usize, const N: usize);
+                }
+            }
+
+            macro_rules! impl_mm_op_view {
+                ($lhs:ty) => {
+                    impl_unary_combinations!(mat, $lhs, $trait, $method, $op, Matrix<T, M, N>, const A: usize, const B: usize, const M: usize, const N: usize);
+                }
+            }
+
+            macro_rules! impl_mm_op_cm {
+                ($lhs:ty) => {
+                    impl_unary_combinations!(contiguous, $lhs, $trait, $method, $op, MatrixCM<T, M, N>, const M: usize, const N: usize);
                 }
             }
         };
@@ -394,27 +567,63 @@ mod op_assign_macros {
 #[macro_use]
 mod dot_macros {
     macro_rules! impl_dot_inner {
-        ($lhs:ty, $rhs:ty, $($generics:tt)*) => {
-            impl<T: Copy + Zero + Mul<T, Output = T> + Add<T, Output = T>, $($generics)*> DotProduct<$rhs> for $lhs {
+        (index, $lhs:ty, $rhs:ty, $($generics:tt)*) => {
+            impl<T: Clone + Zero + Mul<T, Output = T> + Add<T, Output = T>, $($generics)*> DotProduct<$rhs> for $lhs {
                 type Output = T;
 
                 fn dot(self, other: $rhs) -> Self::Output {
                     let mut result = T::zero();
                     for i in 0..M {
-                        result = result + (self[i] * other[i]);
+                        result = result + (self[i].clone() * other[i].clone());
                     }
                     result
                 }
+
+                fn dotc(self, other: $rhs) -> Self::Output
+                where
+                    Self::Output: Conjugate,
+                {
+                    let mut result = T::zero();
+                    for i in 0..M {
+                        result = result + (self[i].clone().conj() * other[i].clone());
+                    }
+                    result
+                }
+            }
+        };
+        (contiguous, $lhs:ty, $rhs:ty, $($generics:tt)*) => {
+            impl<T: Clone + Zero + Mul<T, Output = T> + Add<T, Output = T>, $($generics)*> DotProduct<$rhs> for $lhs {
+                type Output = T;
+
+                fn dot(self, other: $rhs) -> Self::Output {
+                    let (lhs, rhs) = (self.as_slice(), other.as_slice());
+                    if let Some(result) = simd::dot(lhs, rhs) {
+                        return result;
+                    }
+                    lhs.iter().zip(rhs).fold(T::zero(), |acc, (a, b)| acc + (a.clone() * b.clone()))
+                }
+
+                fn dotc(self, other: $rhs) -> Self::Output
+                where
+                    Self::Output: Conjugate,
+                {
+                    let (lhs, rhs) = (self.as_slice(), other.as_slice());
+                    // The kernels only take real types, which are their own conjugate
+                    if let Some(result) = simd::dot(lhs, rhs) {
+                        return result;
+                    }
+                    lhs.iter().zip(rhs).fold(T::zero(), |acc, (a, b)| acc + (a.clone().conj() * b.clone()))
+                }
             }
         };
     }
 
     macro_rules! impl_dot_combinations {
-        ($lhs:ty, $rhs:ty, $($generics:tt)*) => {
-            impl_dot_inner!($lhs, $rhs, $($generics)*);
-            impl_dot_inner!(&$lhs, $rhs, $($generics)*);
-            impl_dot_inner!($lhs, &$rhs, $($generics)*);
-            impl_dot_inner!(&$lhs, &$rhs, $($generics)*);
+        ($type:tt, $lhs:ty, $rhs:ty, $($generics:tt)*) => {
+            impl_dot_inner!($type, $lhs, $rhs, $($generics)*);
+            impl_dot_inner!($type, &$lhs, $rhs, $($generics)*);
+            impl_dot_inner!($type, $lhs, &$rhs, $($generics)*);
+            impl_dot_inner!($type, &$lhs, &$rhs, $($generics)*);
         };
     }
 
@@ -422,31 +631,37 @@ mod dot_macros {
         () => {
             macro_rules! impl_dot {
                 ($lhs:ty, $rhs:ty) => {
-                    impl_dot_combinations!($lhs, $rhs, const M: usize);
+                    impl_dot_combinations!(contiguous, $lhs, $rhs, const M: usize);
                 };
             }
 
             macro_rules! impl_dot_view {
                 ($lhs:ty, $rhs:ty) => {
-                    impl_dot_combinations!($lhs, $rhs, V: Index<usize, Output = T>, const N: usize, const M: usize);
+                    impl_dot_combinations!(index, $lhs, $rhs, V: Index<usize, Output = T>, const N: usize, const M: usize);
                 };
             }
 
             macro_rules! impl_dot_view_view {
                 ($lhs:ty, $rhs:ty) => {
-                    impl_dot_combinations!($lhs, $rhs, V1: Index<usize, Output = T>, V2: Index<usize, Output = T>, const A: usize, const N: usize, const M: usize);
+                    impl_dot_combinations!(index, $lhs, $rhs, V1: Index<usize, Output = T>, V2: Index<usize, Output = T>, const A: usize, const N: usize, const M: usize);
                 };
             }
 
             macro_rules! impl_dot_mat_view {
                 ($lhs:ty, $rhs:ty) => {
-                    impl_dot_combinations!($lhs, $rhs, const A: usize, const B: usize, const M: usize);
+                    impl_dot_combinations!(index, $lhs, $rhs, const A: usize, const B: usize, const M: usize);
                 };
             }
 
             macro_rules! impl_dot_mat_view_view {
                 ($lhs:ty, $rhs:ty) => {
-                    impl_dot_combinations!($lhs, $rhs, V: Index<usize, Output = T>, const A: usize, const B: usize, const N: usize, const M: usize);
+                    impl_dot_combinations!(index, $lhs, $rhs, V: Index<usize, Output = T>, const A: usize, const B: usize, const N: usize, const M: usize);
+                };
+            }
+
+            macro_rules! impl_dot_mat_view_mat_view {
+                ($lhs:ty, $rhs:ty) => {
+                    impl_dot_combinations!(index, $lhs, $rhs, const A: usize, const B: usize, const P: usize, const Q: usize, const M: usize);
                 };
             }
         };
@@ -457,21 +672,21 @@ mod dot_macros {
 mod matmul_macros {
     macro_rules! impl_matmul_inner {
         (scalar, $lhs:ty, $rhs:ty, $output:ty, $($generics:tt)*) => {
-            impl<T: Copy + Zero + Mul<T, Output = T> + Add<T, Output = T>, $($generics)*> Mul<$rhs> for $lhs
+            impl<T: Clone + Zero + Mul<T, Output = T> + Add<T, Output = T>, $($generics)*> Mul<$rhs> for $lhs
             {
                 type Output = $output;
 
                 fn mul(self, other: $rhs) -> Self::Output {
                     let mut result = T::zero();
                     for i in 0..M {
-                        result = result + (self[i] * other[i]);
+                        result = result + (self[i].clone() * other[i].clone());
                     }
                     Self::Output::from([[result]])
                 }
             }
         };
         (vecvec, $lhs:ty, $rhs:ty, $output:ty, $($generics:tt)*) => {
-            impl<T: Copy + Zero + Mul<T, Output = T> + Add<T, Output = T>, $($generics)*> Mul<$rhs> for $lhs
+            impl<T: Clone + Zero + Mul<T, Output = T> + Add<T, Output = T>, $($generics)*> Mul<$rhs> for $lhs
             {
                 type Output = $output;
 
@@ -479,7 +694,7 @@ mod matmul_macros {
                     let mut result = Self::Output::zeros();
                     for i in 0..M {
                         for j in 0..N {
-                            result[(i, j)] = result[(i, j)] + (self[i] * other[j]);
+                            result[(i, j)] = result[(i, j)].clone() + (self[i].clone() * other[j].clone());
                         }
                     }
                     result
@@ -488,7 +703,7 @@ mod matmul_macros {
         };
         (vecmat, $lhs:ty, $rhs:ty, $output:ty, $($generics:tt)*) => {
 
-            impl<T: Copy + Zero + Mul<T, Output = T> + Add<T, Output = T>, $($generics)*> Mul<$rhs> for $lhs
+            impl<T: Clone + Zero + Mul<T, Output = T> + Add<T, Output = T>, $($generics)*> Mul<$rhs> for $lhs
             {
                 type Output = $output;
 
@@ -496,7 +711,26 @@ mod matmul_macros {
                     let mut result = Self::Output::zeros();
                     for j in 0..N {
                         for i in 0..M {
-                            result[i] = result[i] + (self[j] * other[(j, i)]);
+                            result[i] = result[i].clone() + (self[j].clone() * other[(j, i)].clone());
+                        }
+                    }
+                    result
+                }
+            }
+        };
+        (matvec_contiguous, $lhs:ty, $rhs:ty, $output:ty, $($generics:tt)*) => {
+            impl<T: Clone + Zero + Mul<T, Output = T> + Add<T, Output = T>, $($generics)*> Mul<$rhs> for $lhs {
+                type Output = $output;
+
+                fn mul(self, other: $rhs) -> Self::Output {
+                    let mut result = Self::Output::zeros();
+                    if !blas::matvec(self.as_slice(), other.as_slice(), result.as_mut_slice(), N)
+                        && !simd::matvec(self.as_slice(), other.as_slice(), result.as_mut_slice(), N)
+                    {
+                        for i in 0..M {
+                            for j in 0..N {
+                                result[(i, 0)] = result[(i, 0)].clone() + (self[(i, j)].clone() * other[j].clone());
+                            }
                         }
                     }
                     result
@@ -504,14 +738,14 @@ mod matmul_macros {
             }
         };
         (matvec, $lhs:ty, $rhs:ty, $output:ty, $($generics:tt)*) => {
-            impl<T: Copy + Zero + Mul<T, Output = T> + Add<T, Output = T>, $($generics)*> Mul<$rhs> for $lhs {
+            impl<T: Clone + Zero + Mul<T, Output = T> + Add<T, Output = T>, $($generics)*> Mul<$rhs> for $lhs {
                 type Output = $output;
 
                 fn mul(self, other: $rhs) -> Self::Output {
                     let mut result = Self::Output::zeros();
                     for i in 0..M {
                         for j in 0..N {
-                            result[(i, 0)] = result[(i, 0)] + (self[(i, j)] * other[j]);
+                            result[(i, 0)] = result[(i, 0)].clone() + (self[(i, j)].clone() * other[j].clone());
                         }
                     }
                     result
@@ -519,15 +753,16 @@ mod matmul_macros {
             }
         };
         (matmat, $lhs:ty, $rhs:ty, $output:ty, $($generics:tt)*) => {
-            impl<T: Copy + Zero + Mul<T, Output = T> + Add<T, Output = T>, $($generics)*> Mul<$rhs> for $lhs {
+            impl<T: Clone + Zero + Mul<T, Output = T> + Add<T, Output = T>, $($generics)*> Mul<$rhs> for $lhs {
                 type Output = $output;
 
                 fn mul(self, other: $rhs) -> Self::Output {
                     let mut result = Self::Output::zeros();
                     for i in 0..M {
                         for k in 0..N {
+                            let a = &self[(i, k)];
                             for j in 0..P {
-                                result[(i, j)] = result[(i, j)] + (self[(i, k)] * other[(k, j)]);
+                                result[(i, j)] = result[(i, j)].clone() + (a.clone() * other[(k, j)].clone());
                             }
                         }
                     }
@@ -535,6 +770,29 @@ mod matmul_macros {
                 }
             }
         };
+        (matmat_strided, $lhs:ty, $rhs:ty, $output:ty, $($generics:tt)*) => {
+            impl<T: Clone + Zero + Mul<T, Output = T> + Add<T, Output = T>, $($generics)*> Mul<$rhs> for $lhs {
+                type Output = $output;
+
+                fn mul(self, other: $rhs) -> Self::Output {
+                    let mut result = Self::Output::zeros();
+                    gemm::matmul(self.strided(), other.strided(), result.as_mut_slice(), M, N, P);
+                    result
+                }
+            }
+        };
+        (matmat_strided_cm, $lhs:ty, $rhs:ty, $output:ty, $($generics:tt)*) => {
+            impl<T: Clone + Zero + Mul<T, Output = T> + Add<T, Output = T>, $($generics)*> Mul<$rhs> for $lhs {
+                type Output = $output;
+
+                fn mul(self, other: $rhs) -> Self::Output {
+                    // A column-major A * B has the same layout as the row-major B^T * A^T
+                    let mut result = Self::Output::zeros();
+                    gemm::matmul(other.strided().t(), self.strided().t(), result.as_mut_slice(), P, N, M);
+                    result
+                }
+            }
+        };
     }
 
     macro_rules! impl_matmul_combinations {
@@ -622,7 +880,7 @@ mod matmul_macros {
 
             macro_rules! impl_matmul_vec {
                 ($lhs:ty, $rhs:ty) => {
-                    impl_matmul_combinations!(matvec, $lhs, $rhs, Matrix<T, M, 1>, const M: usize, const N: usize);
+                    impl_matmul_combinations!(matvec_contiguous, $lhs, $rhs, Matrix<T, M, 1>, const M: usize, const N: usize);
                 };
             }
 
@@ -632,6 +890,12 @@ mod matmul_macros {
                 };
             }
 
+            macro_rules! impl_matmul_vec_indexed {
+                ($lhs:ty, $rhs:ty) => {
+                    impl_matmul_combinations!(matvec, $lhs, $rhs, Matrix<T, M, 1>, const M: usize, const N: usize);
+                };
+            }
+
             macro_rules! impl_matmul_matvec {
                 ($lhs:ty, $rhs:ty) => {
                     impl_matmul_combinations!(vecvec, $lhs, $rhs, Matrix<T, M, N>, const M: usize, const N: usize);
@@ -670,17 +934,35 @@ mod matmul_macros {
 
             macro_rules! impl_matmul {
                 ($lhs:ty, $rhs:ty) => {
-                    impl_matmul_combinations!(matmat, $lhs, $rhs, Matrix<T, M, P>, const M: usize, const N: usize, const P: usize);
+                    impl_matmul_combinations!(matmat_strided, $lhs, $rhs, Matrix<T, M, P>, const M: usize, const N: usize, const P: usize);
+                };
+            }
+
+            macro_rules! impl_matmul_cm {
+                ($lhs:ty, $rhs:ty) => {
+                    impl_matmul_combinations!(matmat_strided_cm, $lhs, $rhs, MatrixCM<T, M, P>, const M: usize, const N: usize, const P: usize);
                 };
             }
 
             macro_rules! impl_matmul_view {
                 ($lhs:ty, $rhs:ty) => {
-                    impl_matmul_combinations!(matmat, $lhs, $rhs, Matrix<T, M, P>, const A: usize, const B: usize, const M: usize, const N: usize, const P: usize);
+                    impl_matmul_combinations!(matmat_strided, $lhs, $rhs, Matrix<T, M, P>, const A: usize, const B: usize, const M: usize, const N: usize, const P: usize);
                 };
             }
 
             macro_rules! impl_matmul_view_view {
+                ($lhs:ty, $rhs:ty) => {
+                    impl_matmul_combinations!(matmat_strided, $lhs, $rhs, Matrix<T, M, P>, const A: usize, const B: usize, const C: usize, const D: usize, const M: usize, const N: usize, const P: usize);
+                };
+            }
+
+            macro_rules! impl_matmul_view_indexed {
+                ($lhs:ty, $rhs:ty) => {
+                    impl_matmul_combinations!(matmat, $lhs, $rhs, Matrix<T, M, P>, const A: usize, const B: usize, const M: usize, const N: usize, const P: usize);
+                };
+            }
+
+            macro_rules! impl_matmul_view_view_indexed {
                 ($lhs:ty, $rhs:ty) => {
                     impl_matmul_combinations!(matmat, $lhs, $rhs, Matrix<T, M, P>, const A: usize, const B: usize, const C: usize, const D: usize, const M: usize, const N: usize, const P: usize);
                 };
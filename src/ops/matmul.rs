@@ -1,16 +1,21 @@
 use crate::matrix::Matrix;
+use crate::matrix_cm::MatrixCM;
+use crate::matrix_strided_view::MatrixStridedView;
+use crate::matrix_strided_view_mut::MatrixStridedViewMut;
 use crate::matrix_transpose_view::MatrixTransposeView;
 use crate::matrix_transpose_view_mut::MatrixTransposeViewMut;
 use crate::matrix_view::MatrixView;
 use crate::matrix_view_mut::MatrixViewMut;
+use crate::ops::{blas, gemm, simd};
 use crate::row_vector::RowVector;
 use crate::row_vector_view::RowVectorView;
 use crate::row_vector_view_mut::RowVectorViewMut;
+use crate::traits::{Contiguous, Strided};
 use crate::vector::Vector;
 use crate::vector_view::VectorView;
 use crate::vector_view_mut::VectorViewMut;
 use num_traits::Zero;
-use std::ops::{Add, Index, Mul};
+use core::ops::{Add, Index, Mul};
 
 // Generate macros
 generate_matmul_macros!();
@@ -26,10 +31,13 @@ impl_vecmul_view!(Vector<T, M>, RowVectorViewMut<'_, V, T, A, N>);
 
 // M x 1 * 1 x N -> M x N
 impl_vecmul!(Vector<T, M>, Matrix<T, 1, N>);
+impl_vecmul!(Vector<T, M>, MatrixCM<T, 1, N>);
 impl_vecmul_mat_row_view!(Vector<T, M>, MatrixView<'_, T, A, B, 1, N>);
 impl_vecmul_mat_row_view!(Vector<T, M>, MatrixViewMut<'_, T, A, B, 1, N>);
 impl_vecmul_mat_row_view!(Vector<T, M>, MatrixTransposeView<'_, T, A, B, 1, N>);
 impl_vecmul_mat_row_view!(Vector<T, M>, MatrixTransposeViewMut<'_, T, A, B, 1, N>);
+impl_vecmul_mat_row_view!(Vector<T, M>, MatrixStridedView<'_, T, A, B, 1, N>);
+impl_vecmul_mat_row_view!(Vector<T, M>, MatrixStridedViewMut<'_, T, A, B, 1, N>);
 
 //////////////////
 //  VectorView  //
@@ -42,10 +50,13 @@ impl_vecmul_view_view!(VectorView<'_, V1, T, A, M>, RowVectorViewMut<'_, V2, T,
 
 // M x 1 * 1 x N -> M x N
 impl_vecmul_view!(VectorView<'_, V, T, A, M>, Matrix<T, 1, N>);
+impl_vecmul_view!(VectorView<'_, V, T, A, M>, MatrixCM<T, 1, N>);
 impl_vecmul_mat_row_view_view!(VectorView<'_, V, T, A, M>, MatrixView<'_, T, B, C, 1, N>);
 impl_vecmul_mat_row_view_view!(VectorView<'_, V, T, A, M>, MatrixViewMut<'_, T, B, C, 1, N>);
 impl_vecmul_mat_row_view_view!(VectorView<'_, V, T, A, M>, MatrixTransposeView<'_, T, B, C, 1, N>);
 impl_vecmul_mat_row_view_view!(VectorView<'_, V, T, A, M>, MatrixTransposeViewMut<'_, T, B, C, 1, N>);
+impl_vecmul_mat_row_view_view!(VectorView<'_, V, T, A, M>, MatrixStridedView<'_, T, B, C, 1, N>);
+impl_vecmul_mat_row_view_view!(VectorView<'_, V, T, A, M>, MatrixStridedViewMut<'_, T, B, C, 1, N>);
 
 /////////////////////
 //  VectorViewMut  //
@@ -58,10 +69,13 @@ impl_vecmul_view_view!(VectorViewMut<'_, V1, T, A, M>, RowVectorViewMut<'_, V2,
 
 // N x 1 * 1 x M -> N x M
 impl_vecmul_view!(VectorViewMut<'_, V, T, A, M>, Matrix<T, 1, N>);
+impl_vecmul_view!(VectorViewMut<'_, V, T, A, M>, MatrixCM<T, 1, N>);
 impl_vecmul_mat_row_view_view!(VectorViewMut<'_, V, T, A, M>, MatrixView<'_, T, B, C, 1, N>);
 impl_vecmul_mat_row_view_view!(VectorViewMut<'_, V, T, A, M>, MatrixViewMut<'_, T, B, C, 1, N>);
 impl_vecmul_mat_row_view_view!(VectorViewMut<'_, V, T, A, M>, MatrixTransposeView<'_, T, B, C, 1, N>);
 impl_vecmul_mat_row_view_view!(VectorViewMut<'_, V, T, A, M>, MatrixTransposeViewMut<'_, T, B, C, 1, N>);
+impl_vecmul_mat_row_view_view!(VectorViewMut<'_, V, T, A, M>, MatrixStridedView<'_, T, B, C, 1, N>);
+impl_vecmul_mat_row_view_view!(VectorViewMut<'_, V, T, A, M>, MatrixStridedViewMut<'_, T, B, C, 1, N>);
 
 /////////////////
 //  RowVector  //
@@ -74,10 +88,13 @@ impl_vecmul_scalar_view!(RowVector<T, M>, VectorViewMut<'_, V, T, N, M>);
 
 // 1 x N * N x M -> 1 x M
 impl_vecmul_mat!(RowVector<T, N>, Matrix<T, N, M>);
+impl_vecmul_mat!(RowVector<T, N>, MatrixCM<T, N, M>);
 impl_vecmul_vecmat_view!(RowVector<T, N>, MatrixView<'_, T, A, B, N, M>);
 impl_vecmul_vecmat_view!(RowVector<T, N>, MatrixViewMut<'_, T, A, B, N, M>);
 impl_vecmul_vecmat_view!(RowVector<T, N>, MatrixTransposeView<'_, T, A, B, N, M>);
 impl_vecmul_vecmat_view!(RowVector<T, N>, MatrixTransposeViewMut<'_, T, A, B, N, M>);
+impl_vecmul_vecmat_view!(RowVector<T, N>, MatrixStridedView<'_, T, A, B, N, M>);
+impl_vecmul_vecmat_view!(RowVector<T, N>, MatrixStridedViewMut<'_, T, A, B, N, M>);
 
 /////////////////////
 //  RowVectorView  //
@@ -90,10 +107,13 @@ impl_vecmul_scalar_view_view!(RowVectorView<'_, V1, T, A, M>, VectorViewMut<'_,
 
 // 1 x N * N x M -> 1 x M
 impl_vecmul_mat_view!(RowVectorView<'_, V, T, A, N>, Matrix<T, N, M>);
+impl_vecmul_mat_view!(RowVectorView<'_, V, T, A, N>, MatrixCM<T, N, M>);
 impl_vecmul_vecmat_view_view!(RowVectorView<'_, V, T, A, N>, MatrixView<'_, T, B, C, N, M>);
 impl_vecmul_vecmat_view_view!(RowVectorView<'_, V, T, A, N>, MatrixViewMut<'_, T, B, C, N, M>);
 impl_vecmul_vecmat_view_view!(RowVectorView<'_, V, T, A, N>, MatrixTransposeView<'_, T, B, C, N, M>);
 impl_vecmul_vecmat_view_view!(RowVectorView<'_, V, T, A, N>, MatrixTransposeViewMut<'_, T, B, C, N, M>);
+impl_vecmul_vecmat_view_view!(RowVectorView<'_, V, T, A, N>, MatrixStridedView<'_, T, B, C, N, M>);
+impl_vecmul_vecmat_view_view!(RowVectorView<'_, V, T, A, N>, MatrixStridedViewMut<'_, T, B, C, N, M>);
 
 ////////////////////////
 //  RowVectorViewMut  //
@@ -106,10 +126,13 @@ impl_vecmul_scalar_view_view!(RowVectorViewMut<'_, V1, T, A, M>, VectorViewMut<'
 
 // 1 x N * N x M -> 1 x M
 impl_vecmul_mat_view!(RowVectorViewMut<'_, V, T, A, N>, Matrix<T, N, M>);
+impl_vecmul_mat_view!(RowVectorViewMut<'_, V, T, A, N>, MatrixCM<T, N, M>);
 impl_vecmul_vecmat_view_view!(RowVectorViewMut<'_, V, T, A, N>, MatrixView<'_, T, B, C, N, M>);
 impl_vecmul_vecmat_view_view!(RowVectorViewMut<'_, V, T, A, N>, MatrixViewMut<'_, T, B, C, N, M>);
 impl_vecmul_vecmat_view_view!(RowVectorViewMut<'_, V, T, A, N>, MatrixTransposeView<'_, T, B, C, N, M>);
 impl_vecmul_vecmat_view_view!(RowVectorViewMut<'_, V, T, A, N>, MatrixTransposeViewMut<'_, T, B, C, N, M>);
+impl_vecmul_vecmat_view_view!(RowVectorViewMut<'_, V, T, A, N>, MatrixStridedView<'_, T, B, C, N, M>);
+impl_vecmul_vecmat_view_view!(RowVectorViewMut<'_, V, T, A, N>, MatrixStridedViewMut<'_, T, B, C, N, M>);
 
 //////////////
 //  Matrix  //
@@ -127,10 +150,37 @@ impl_matmul_matvec_vec_view!(Matrix<T, M, 1>, RowVectorViewMut<'_, V, T, A, N>);
 
 // M x N * N x P -> M x P
 impl_matmul!(Matrix<T, M, N>, Matrix<T, N, P>);
+impl_matmul!(Matrix<T, M, N>, MatrixCM<T, N, P>);
 impl_matmul_view!(Matrix<T, M, N>, MatrixView<'_, T, A, B, N, P>);
 impl_matmul_view!(Matrix<T, M, N>, MatrixViewMut<'_, T, A, B, N, P>);
 impl_matmul_view!(Matrix<T, M, N>, MatrixTransposeView<'_, T, A, B, N, P>);
 impl_matmul_view!(Matrix<T, M, N>, MatrixTransposeViewMut<'_, T, A, B, N, P>);
+impl_matmul_view_indexed!(Matrix<T, M, N>, MatrixStridedView<'_, T, A, B, N, P>);
+impl_matmul_view_indexed!(Matrix<T, M, N>, MatrixStridedViewMut<'_, T, A, B, N, P>);
+
+////////////////
+//  MatrixCM  //
+////////////////
+
+// M x N * N x 1 -> M x 1
+impl_matmul_vec_indexed!(MatrixCM<T, M, N>, Vector<T, N>);
+impl_matmul_vec_view!(MatrixCM<T, M, N>, VectorView<'_, V, T, A, N>);
+impl_matmul_vec_view!(MatrixCM<T, M, N>, VectorViewMut<'_, V, T, A, N>);
+
+// M x 1 * 1 x N -> M x N
+impl_matmul_matvec!(MatrixCM<T, M, 1>, RowVector<T, N>);
+impl_matmul_matvec_vec_view!(MatrixCM<T, M, 1>, RowVectorView<'_, V, T, A, N>);
+impl_matmul_matvec_vec_view!(MatrixCM<T, M, 1>, RowVectorViewMut<'_, V, T, A, N>);
+
+// M x N * N x P -> M x P
+impl_matmul_cm!(MatrixCM<T, M, N>, MatrixCM<T, N, P>);
+impl_matmul!(MatrixCM<T, M, N>, Matrix<T, N, P>);
+impl_matmul_view!(MatrixCM<T, M, N>, MatrixView<'_, T, A, B, N, P>);
+impl_matmul_view!(MatrixCM<T, M, N>, MatrixViewMut<'_, T, A, B, N, P>);
+impl_matmul_view!(MatrixCM<T, M, N>, MatrixTransposeView<'_, T, A, B, N, P>);
+impl_matmul_view!(MatrixCM<T, M, N>, MatrixTransposeViewMut<'_, T, A, B, N, P>);
+impl_matmul_view_indexed!(MatrixCM<T, M, N>, MatrixStridedView<'_, T, A, B, N, P>);
+impl_matmul_view_indexed!(MatrixCM<T, M, N>, MatrixStridedViewMut<'_, T, A, B, N, P>);
 
 //////////////////
 //  MatrixView  //
@@ -148,10 +198,13 @@ impl_matmul_matvec_view_view!(MatrixView<'_, T, A, B, M, 1>, RowVectorViewMut<'_
 
 // M x N * N x P -> M x P
 impl_matmul_view!(MatrixView<'_, T, A, B, M, N>, Matrix<T, N, P>);
+impl_matmul_view!(MatrixView<'_, T, A, B, M, N>, MatrixCM<T, N, P>);
 impl_matmul_view_view!(MatrixView<'_, T, A, B, M, N>, MatrixView<'_, T, C, D, N, P>);
 impl_matmul_view_view!(MatrixView<'_, T, A, B, M, N>, MatrixViewMut<'_, T, C, D, N, P>);
 impl_matmul_view_view!(MatrixView<'_, T, A, B, M, N>, MatrixTransposeView<'_, T, C, D, N, P>);
 impl_matmul_view_view!(MatrixView<'_, T, A, B, M, N>, MatrixTransposeViewMut<'_, T, C, D, N, P>);
+impl_matmul_view_view_indexed!(MatrixView<'_, T, A, B, M, N>, MatrixStridedView<'_, T, C, D, N, P>);
+impl_matmul_view_view_indexed!(MatrixView<'_, T, A, B, M, N>, MatrixStridedViewMut<'_, T, C, D, N, P>);
 
 /////////////////////
 //  MatrixViewMut  //
@@ -169,10 +222,13 @@ impl_matmul_matvec_view_view!(MatrixViewMut<'_, T, A, B, M, 1>, RowVectorViewMut
 
 // M x N * N x P -> M x P
 impl_matmul_view!(MatrixViewMut<'_, T, A, B, M, N>, Matrix<T, N, P>);
+impl_matmul_view!(MatrixViewMut<'_, T, A, B, M, N>, MatrixCM<T, N, P>);
 impl_matmul_view_view!(MatrixViewMut<'_, T, A, B, M, N>, MatrixView<'_, T, C, D, N, P>);
 impl_matmul_view_view!(MatrixViewMut<'_, T, A, B, M, N>, MatrixViewMut<'_, T, C, D, N, P>);
 impl_matmul_view_view!(MatrixViewMut<'_, T, A, B, M, N>, MatrixTransposeView<'_, T, C, D, N, P>);
 impl_matmul_view_view!(MatrixViewMut<'_, T, A, B, M, N>, MatrixTransposeViewMut<'_, T, C, D, N, P>);
+impl_matmul_view_view_indexed!(MatrixViewMut<'_, T, A, B, M, N>, MatrixStridedView<'_, T, C, D, N, P>);
+impl_matmul_view_view_indexed!(MatrixViewMut<'_, T, A, B, M, N>, MatrixStridedViewMut<'_, T, C, D, N, P>);
 
 ///////////////////////////
 //  MatrixTransposeView  //
@@ -190,10 +246,13 @@ impl_matmul_matvec_view_view!(MatrixTransposeView<'_, T, A, B, M, 1>, RowVectorV
 
 // M x N * N x P -> M x P
 impl_matmul_view!(MatrixTransposeView<'_, T, A, B, M, N>, Matrix<T, N, P>);
+impl_matmul_view!(MatrixTransposeView<'_, T, A, B, M, N>, MatrixCM<T, N, P>);
 impl_matmul_view_view!(MatrixTransposeView<'_, T, A, B, M, N>, MatrixView<'_, T, C, D, N, P>);
 impl_matmul_view_view!(MatrixTransposeView<'_, T, A, B, M, N>, MatrixViewMut<'_, T, C, D, N, P>);
 impl_matmul_view_view!(MatrixTransposeView<'_, T, A, B, M, N>, MatrixTransposeView<'_, T, C, D, N, P>);
 impl_matmul_view_view!(MatrixTransposeView<'_, T, A, B, M, N>, MatrixTransposeViewMut<'_, T, C, D, N, P>);
+impl_matmul_view_view_indexed!(MatrixTransposeView<'_, T, A, B, M, N>, MatrixStridedView<'_, T, C, D, N, P>);
+impl_matmul_view_view_indexed!(MatrixTransposeView<'_, T, A, B, M, N>, MatrixStridedViewMut<'_, T, C, D, N, P>);
 
 //////////////////////////////
 //  MatrixTransposeViewMut  //
@@ -211,7 +270,58 @@ impl_matmul_matvec_view_view!(MatrixTransposeViewMut<'_, T, A, B, M, 1>, RowVect
 
 // M x N * N x P -> M x P
 impl_matmul_view!(MatrixTransposeViewMut<'_, T, A, B, M, N>, Matrix<T, N, P>);
+impl_matmul_view!(MatrixTransposeViewMut<'_, T, A, B, M, N>, MatrixCM<T, N, P>);
 impl_matmul_view_view!(MatrixTransposeViewMut<'_, T, A, B, M, N>, MatrixView<'_, T, C, D, N, P>);
 impl_matmul_view_view!(MatrixTransposeViewMut<'_, T, A, B, M, N>, MatrixViewMut<'_, T, C, D, N, P>);
 impl_matmul_view_view!(MatrixTransposeViewMut<'_, T, A, B, M, N>, MatrixTransposeView<'_, T, C, D, N, P>);
 impl_matmul_view_view!(MatrixTransposeViewMut<'_, T, A, B, M, N>, MatrixTransposeViewMut<'_, T, C, D, N, P>);
+impl_matmul_view_view_indexed!(MatrixTransposeViewMut<'_, T, A, B, M, N>, MatrixStridedView<'_, T, C, D, N, P>);
+impl_matmul_view_view_indexed!(MatrixTransposeViewMut<'_, T, A, B, M, N>, MatrixStridedViewMut<'_, T, C, D, N, P>);
+
+/////////////////////////
+//  MatrixStridedView  //
+/////////////////////////
+
+// M x N * N x 1 -> M x 1
+impl_matmul_mat_view!(MatrixStridedView<'_, T, A, B, M, N>, Vector<T, N>);
+impl_matmul_mat_view_view!(MatrixStridedView<'_, T, A, B, M, N>, VectorView<'_, V, T, C, N>);
+impl_matmul_mat_view_view!(MatrixStridedView<'_, T, A, B, M, N>, VectorViewMut<'_, V, T, C, N>);
+
+// M x 1 * 1 x N -> M x N
+impl_matmul_matvec_mat_view!(MatrixStridedView<'_, T, A, B, M, 1>, RowVector<T, N>);
+impl_matmul_matvec_view_view!(MatrixStridedView<'_, T, A, B, M, 1>, RowVectorView<'_, V, T, C, N>);
+impl_matmul_matvec_view_view!(MatrixStridedView<'_, T, A, B, M, 1>, RowVectorViewMut<'_, V, T, C, N>);
+
+// M x N * N x P -> M x P
+impl_matmul_view_indexed!(MatrixStridedView<'_, T, A, B, M, N>, Matrix<T, N, P>);
+impl_matmul_view_indexed!(MatrixStridedView<'_, T, A, B, M, N>, MatrixCM<T, N, P>);
+impl_matmul_view_view_indexed!(MatrixStridedView<'_, T, A, B, M, N>, MatrixView<'_, T, C, D, N, P>);
+impl_matmul_view_view_indexed!(MatrixStridedView<'_, T, A, B, M, N>, MatrixViewMut<'_, T, C, D, N, P>);
+impl_matmul_view_view_indexed!(MatrixStridedView<'_, T, A, B, M, N>, MatrixTransposeView<'_, T, C, D, N, P>);
+impl_matmul_view_view_indexed!(MatrixStridedView<'_, T, A, B, M, N>, MatrixTransposeViewMut<'_, T, C, D, N, P>);
+impl_matmul_view_view_indexed!(MatrixStridedView<'_, T, A, B, M, N>, MatrixStridedView<'_, T, C, D, N, P>);
+impl_matmul_view_view_indexed!(MatrixStridedView<'_, T, A, B, M, N>, MatrixStridedViewMut<'_, T, C, D, N, P>);
+
+////////////////////////////
+//  MatrixStridedViewMut  //
+////////////////////////////
+
+// M x N * N x 1 -> M x 1
+impl_matmul_mat_view!(MatrixStridedViewMut<'_, T, A, B, M, N>, Vector<T, N>);
+impl_matmul_mat_view_view!(MatrixStridedViewMut<'_, T, A, B, M, N>, VectorView<'_, V, T, C, N>);
+impl_matmul_mat_view_view!(MatrixStridedViewMut<'_, T, A, B, M, N>, VectorViewMut<'_, V, T, C, N>);
+
+// M x 1 * 1 x N -> M x N
+impl_matmul_matvec_mat_view!(MatrixStridedViewMut<'_, T, A, B, M, 1>, RowVector<T, N>);
+impl_matmul_matvec_view_view!(MatrixStridedViewMut<'_, T, A, B, M, 1>, RowVectorView<'_, V, T, C, N>);
+impl_matmul_matvec_view_view!(MatrixStridedViewMut<'_, T, A, B, M, 1>, RowVectorViewMut<'_, V, T, C, N>);
+
+// M x N * N x P -> M x P
+impl_matmul_view_indexed!(MatrixStridedViewMut<'_, T, A, B, M, N>, Matrix<T, N, P>);
+impl_matmul_view_indexed!(MatrixStridedViewMut<'_, T, A, B, M, N>, MatrixCM<T, N, P>);
+impl_matmul_view_view_indexed!(MatrixStridedViewMut<'_, T, A, B, M, N>, MatrixView<'_, T, C, D, N, P>);
+impl_matmul_view_view_indexed!(MatrixStridedViewMut<'_, T, A, B, M, N>, MatrixViewMut<'_, T, C, D, N, P>);
+impl_matmul_view_view_indexed!(MatrixStridedViewMut<'_, T, A, B, M, N>, MatrixTransposeView<'_, T, C, D, N, P>);
+impl_matmul_view_view_indexed!(MatrixStridedViewMut<'_, T, A, B, M, N>, MatrixTransposeViewMut<'_, T, C, D, N, P>);
+impl_matmul_view_view_indexed!(MatrixStridedViewMut<'_, T, A, B, M, N>, MatrixStridedView<'_, T, C, D, N, P>);
+impl_matmul_view_view_indexed!(MatrixStridedViewMut<'_, T, A, B, M, N>, MatrixStridedViewMut<'_, T, C, D, N, P>);
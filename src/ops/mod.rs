@@ -1,12 +1,31 @@
 #[macro_use]
 mod macros;
+mod blas;
+mod gemm;
+pub(crate) mod lapack;
+pub(crate) mod linalg;
+mod simd;
 
 pub mod add;
 pub mod add_assign;
+pub mod bitand;
+pub mod bitand_assign;
+pub mod bitor;
+pub mod bitor_assign;
+pub mod bitxor;
+pub mod bitxor_assign;
 pub mod div;
 pub mod div_assign;
 pub mod mul;
 pub mod mul_assign;
+pub mod neg;
+pub mod not;
+pub mod rem;
+pub mod rem_assign;
+pub mod shl;
+pub mod shl_assign;
+pub mod shr;
+pub mod shr_assign;
 pub mod sub;
 pub mod sub_assign;
 
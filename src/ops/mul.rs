@@ -1,13 +1,18 @@
-use std::ops::{Index, Mul};
+use core::ops::{Index, Mul};
 
 use crate::matrix::Matrix;
+use crate::matrix_cm::MatrixCM;
+use crate::matrix_strided_view::MatrixStridedView;
+use crate::matrix_strided_view_mut::MatrixStridedViewMut;
 use crate::matrix_transpose_view::MatrixTransposeView;
 use crate::matrix_transpose_view_mut::MatrixTransposeViewMut;
 use crate::matrix_view::MatrixView;
 use crate::matrix_view_mut::MatrixViewMut;
+use crate::ops::simd;
 use crate::row_vector::RowVector;
 use crate::row_vector_view::RowVectorView;
 use crate::row_vector_view_mut::RowVectorViewMut;
+use crate::traits::Contiguous;
 use crate::vector::Vector;
 use crate::vector_view::VectorView;
 use crate::vector_view_mut::VectorViewMut;
@@ -24,7 +29,10 @@ impl_vv_op_view_row!(RowVectorView<'_, V, T, N, M>);
 impl_vv_op_view_row!(RowVectorViewMut<'_, V, T, N, M>);
 
 impl_mm_op!(Matrix<T, M, N>);
+impl_mm_op_cm!(MatrixCM<T, M, N>);
 impl_mm_op_view!(MatrixView<'_, T, A, B, M, N>);
 impl_mm_op_view!(MatrixViewMut<'_, T, A, B, M, N>);
 impl_mm_op_view!(MatrixTransposeView<'_, T, A, B, M, N>);
 impl_mm_op_view!(MatrixTransposeViewMut<'_, T, A, B, M, N>);
+impl_mm_op_view!(MatrixStridedView<'_, T, A, B, M, N>);
+impl_mm_op_view!(MatrixStridedViewMut<'_, T, A, B, M, N>);
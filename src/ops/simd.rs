@@ -0,0 +1,464 @@
+//! Explicit SIMD kernels for `f32` and `f64`.
+//!
+//! The operator macros call the generic entry points at the bottom of this file.
+//! They return `false` (or `None`) when the element type has no kernel, in which
+//! case the caller falls back to its scalar loop.
+//!
+//! On x86 the AVX2 and SSE2 paths are selected at runtime when `std` is enabled
+//! and at compile time (via `target_feature`) otherwise. All other targets use the
+//! portable kernels, which are written so that LLVM can auto-vectorize them.
+//!
+//! Large inputs are split across threads by [`crate::parallel`] when the `rayon`
+//! feature is enabled.
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+use crate::parallel;
+
+/// Element-wise operation performed by a kernel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Level {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    Avx2,
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    Sse2,
+    Portable,
+}
+
+/// Returns the best instruction set available on this CPU.
+#[inline]
+pub(super) fn level() -> Level {
+    #[cfg(all(feature = "std", any(target_arch = "x86", target_arch = "x86_64")))]
+    {
+        if std::is_x86_feature_detected!("avx2") {
+            return Level::Avx2;
+        }
+        if std::is_x86_feature_detected!("sse2") {
+            return Level::Sse2;
+        }
+    }
+    #[cfg(all(not(feature = "std"), any(target_arch = "x86", target_arch = "x86_64")))]
+    {
+        if cfg!(target_feature = "avx2") {
+            return Level::Avx2;
+        }
+        if cfg!(target_feature = "sse2") {
+            return Level::Sse2;
+        }
+    }
+    Level::Portable
+}
+
+////////////////////////
+//  Portable Kernels  //
+////////////////////////
+
+macro_rules! portable_kernels {
+    ($t:ty) => {
+        pub(super) mod portable {
+            use super::super::Op;
+
+            const LANES: usize = 8;
+
+            pub fn zip_assign(op: Op, lhs: &mut [$t], rhs: &[$t]) {
+                let pairs = lhs.iter_mut().zip(rhs);
+                match op {
+                    Op::Add => pairs.for_each(|(a, b)| *a += *b),
+                    Op::Sub => pairs.for_each(|(a, b)| *a -= *b),
+                    Op::Mul => pairs.for_each(|(a, b)| *a *= *b),
+                    Op::Div => pairs.for_each(|(a, b)| *a /= *b),
+                }
+            }
+
+            pub fn scalar_assign(op: Op, lhs: &mut [$t], scalar: $t) {
+                match op {
+                    Op::Add => lhs.iter_mut().for_each(|a| *a += scalar),
+                    Op::Sub => lhs.iter_mut().for_each(|a| *a -= scalar),
+                    Op::Mul => lhs.iter_mut().for_each(|a| *a *= scalar),
+                    Op::Div => lhs.iter_mut().for_each(|a| *a /= scalar),
+                }
+            }
+
+            pub fn dot(a: &[$t], b: &[$t]) -> $t {
+                let mut acc = [0.0; LANES];
+                let chunks = a.chunks_exact(LANES).zip(b.chunks_exact(LANES));
+                for (x, y) in chunks {
+                    for l in 0..LANES {
+                        acc[l] += x[l] * y[l];
+                    }
+                }
+                let tail = a.len() - a.len() % LANES;
+                let mut sum = acc.iter().sum::<$t>();
+                for i in tail..a.len() {
+                    sum += a[i] * b[i];
+                }
+                sum
+            }
+
+            pub fn axpy(alpha: $t, x: &[$t], y: &mut [$t]) {
+                y.iter_mut().zip(x).for_each(|(y, x)| *y += alpha * *x);
+            }
+        }
+    };
+}
+
+///////////////////
+//  x86 Kernels  //
+///////////////////
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+macro_rules! x86_kernels {
+    (
+        $isa:ident, $feature:literal, $t:ty, $lanes:expr,
+        $load:ident, $store:ident, $set1:ident, $setzero:ident,
+        $add:ident, $sub:ident, $mul:ident, $div:ident
+    ) => {
+        pub(super) mod $isa {
+            use super::super::*;
+
+            const LANES: usize = $lanes;
+
+            #[target_feature(enable = $feature)]
+            pub unsafe fn zip_assign(op: Op, lhs: &mut [$t], rhs: &[$t]) {
+                let len = lhs.len().min(rhs.len());
+                let tail = len - len % LANES;
+                let (l, r) = (lhs.as_mut_ptr(), rhs.as_ptr());
+                macro_rules! run {
+                    ($vop:ident, $sop:tt) => {{
+                        let mut i = 0;
+                        while i < tail {
+                            $store(l.add(i), $vop($load(l.add(i)), $load(r.add(i))));
+                            i += LANES;
+                        }
+                        for i in tail..len {
+                            lhs[i] $sop rhs[i];
+                        }
+                    }};
+                }
+                match op {
+                    Op::Add => run!($add, +=),
+                    Op::Sub => run!($sub, -=),
+                    Op::Mul => run!($mul, *=),
+                    Op::Div => run!($div, /=),
+                }
+            }
+
+            #[target_feature(enable = $feature)]
+            pub unsafe fn scalar_assign(op: Op, lhs: &mut [$t], scalar: $t) {
+                let len = lhs.len();
+                let tail = len - len % LANES;
+                let l = lhs.as_mut_ptr();
+                let s = $set1(scalar);
+                macro_rules! run {
+                    ($vop:ident, $sop:tt) => {{
+                        let mut i = 0;
+                        while i < tail {
+                            $store(l.add(i), $vop($load(l.add(i)), s));
+                            i += LANES;
+                        }
+                        for x in &mut lhs[tail..] {
+                            *x $sop scalar;
+                        }
+                    }};
+                }
+                match op {
+                    Op::Add => run!($add, +=),
+                    Op::Sub => run!($sub, -=),
+                    Op::Mul => run!($mul, *=),
+                    Op::Div => run!($div, /=),
+                }
+            }
+
+            #[target_feature(enable = $feature)]
+            pub unsafe fn dot(a: &[$t], b: &[$t]) -> $t {
+                let len = a.len().min(b.len());
+                let (pa, pb) = (a.as_ptr(), b.as_ptr());
+
+                // Two independent accumulators hide the add latency
+                let mut acc0 = $setzero();
+                let mut acc1 = $setzero();
+                let mut i = 0;
+                while i + 2 * LANES <= len {
+                    acc0 = $add(acc0, $mul($load(pa.add(i)), $load(pb.add(i))));
+                    acc1 = $add(acc1, $mul($load(pa.add(i + LANES)), $load(pb.add(i + LANES))));
+                    i += 2 * LANES;
+                }
+                if i + LANES <= len {
+                    acc0 = $add(acc0, $mul($load(pa.add(i)), $load(pb.add(i))));
+                    i += LANES;
+                }
+
+                let mut lanes = [0.0; LANES];
+                $store(lanes.as_mut_ptr(), $add(acc0, acc1));
+                let mut sum = lanes.iter().sum::<$t>();
+                for j in i..len {
+                    sum += a[j] * b[j];
+                }
+                sum
+            }
+
+            #[target_feature(enable = $feature)]
+            pub unsafe fn axpy(alpha: $t, x: &[$t], y: &mut [$t]) {
+                let len = x.len().min(y.len());
+                let tail = len - len % LANES;
+                let (px, py) = (x.as_ptr(), y.as_mut_ptr());
+                let a = $set1(alpha);
+                let mut i = 0;
+                while i < tail {
+                    $store(py.add(i), $add($load(py.add(i)), $mul(a, $load(px.add(i)))));
+                    i += LANES;
+                }
+                for j in tail..len {
+                    y[j] += alpha * x[j];
+                }
+            }
+        }
+    };
+}
+
+macro_rules! dispatch_kernels {
+    ($t:ty) => {
+        pub(super) fn zip_assign(op: Op, lhs: &mut [$t], rhs: &[$t]) {
+            match level() {
+                #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                Level::Avx2 => unsafe { avx2::zip_assign(op, lhs, rhs) },
+                #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                Level::Sse2 => unsafe { sse2::zip_assign(op, lhs, rhs) },
+                Level::Portable => portable::zip_assign(op, lhs, rhs),
+            }
+        }
+
+        pub(super) fn scalar_assign(op: Op, lhs: &mut [$t], scalar: $t) {
+            match level() {
+                #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                Level::Avx2 => unsafe { avx2::scalar_assign(op, lhs, scalar) },
+                #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                Level::Sse2 => unsafe { sse2::scalar_assign(op, lhs, scalar) },
+                Level::Portable => portable::scalar_assign(op, lhs, scalar),
+            }
+        }
+
+        pub(super) fn dot(a: &[$t], b: &[$t]) -> $t {
+            match level() {
+                #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                Level::Avx2 => unsafe { avx2::dot(a, b) },
+                #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                Level::Sse2 => unsafe { sse2::dot(a, b) },
+                Level::Portable => portable::dot(a, b),
+            }
+        }
+
+        pub(super) fn axpy(alpha: $t, x: &[$t], y: &mut [$t]) {
+            match level() {
+                #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                Level::Avx2 => unsafe { avx2::axpy(alpha, x, y) },
+                #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                Level::Sse2 => unsafe { sse2::axpy(alpha, x, y) },
+                Level::Portable => portable::axpy(alpha, x, y),
+            }
+        }
+
+        /// Computes `out = a * x` where `a` is row-major with `n` columns.
+        pub(super) fn matvec(a: &[$t], x: &[$t], out: &mut [$t], n: usize) {
+            for (o, row) in out.iter_mut().zip(a.chunks_exact(n)) {
+                *o = dot(row, x);
+            }
+        }
+
+        /// Computes `out = a * b` where `a` has `n` columns and `b` has `p` columns.
+        ///
+        /// The `i-k-j` loop order matches the scalar fallback, so every element is
+        /// accumulated in the same order and the results are identical.
+        pub(super) fn matmul(a: &[$t], b: &[$t], out: &mut [$t], n: usize, p: usize) {
+            for (out_row, a_row) in out.chunks_exact_mut(p).zip(a.chunks_exact(n)) {
+                for (&a_ik, b_row) in a_row.iter().zip(b.chunks_exact(p)) {
+                    axpy(a_ik, b_row, out_row);
+                }
+            }
+        }
+    };
+}
+
+mod f32s {
+    use super::*;
+
+    portable_kernels!(f32);
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    x86_kernels!(
+        avx2, "avx2", f32, 8,
+        _mm256_loadu_ps, _mm256_storeu_ps, _mm256_set1_ps, _mm256_setzero_ps,
+        _mm256_add_ps, _mm256_sub_ps, _mm256_mul_ps, _mm256_div_ps
+    );
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    x86_kernels!(
+        sse2, "sse2", f32, 4,
+        _mm_loadu_ps, _mm_storeu_ps, _mm_set1_ps, _mm_setzero_ps,
+        _mm_add_ps, _mm_sub_ps, _mm_mul_ps, _mm_div_ps
+    );
+
+    dispatch_kernels!(f32);
+}
+
+mod f64s {
+    use super::*;
+
+    portable_kernels!(f64);
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    x86_kernels!(
+        avx2, "avx2", f64, 4,
+        _mm256_loadu_pd, _mm256_storeu_pd, _mm256_set1_pd, _mm256_setzero_pd,
+        _mm256_add_pd, _mm256_sub_pd, _mm256_mul_pd, _mm256_div_pd
+    );
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    x86_kernels!(
+        sse2, "sse2", f64, 2,
+        _mm_loadu_pd, _mm_storeu_pd, _mm_set1_pd, _mm_setzero_pd,
+        _mm_add_pd, _mm_sub_pd, _mm_mul_pd, _mm_div_pd
+    );
+
+    dispatch_kernels!(f64);
+}
+
+/////////////////////////////
+//  Generic Entry Points  //
+/////////////////////////////
+
+/// Returns `true` if `T` and `U` are the same type.
+///
+/// Lifetimes are erased, which is sound here because `U` is always `f32` or `f64`.
+#[inline]
+pub(super) fn is<T, U>() -> bool {
+    typeid::of::<T>() == typeid::of::<U>()
+}
+
+/// Returns `true` if `op` has a kernel for `T`.
+#[inline]
+pub(crate) fn has_kernel<T>(op: Option<Op>) -> bool {
+    op.is_some() && (is::<T, f32>() || is::<T, f64>())
+}
+
+#[inline]
+pub(super) fn cast<T, U>(s: &[T]) -> &[U] {
+    debug_assert!(is::<T, U>());
+    // SAFETY: `T` and `U` are the same type
+    unsafe { core::slice::from_raw_parts(s.as_ptr() as *const U, s.len()) }
+}
+
+#[inline]
+pub(super) fn cast_mut<T, U>(s: &mut [T]) -> &mut [U] {
+    debug_assert!(is::<T, U>());
+    // SAFETY: `T` and `U` are the same type
+    unsafe { core::slice::from_raw_parts_mut(s.as_mut_ptr() as *mut U, s.len()) }
+}
+
+#[inline]
+pub(super) fn cast_value<T, U>(v: T) -> U {
+    debug_assert!(is::<T, U>());
+    let v = core::mem::ManuallyDrop::new(v);
+    // SAFETY: `T` and `U` are the same type, and `v` is not dropped
+    unsafe { core::mem::transmute_copy(&*v) }
+}
+
+/// Computes `lhs[i] = lhs[i] op rhs[i]`. Returns `false` if `op` or `T` has no kernel.
+pub(crate) fn zip_assign<T>(op: Option<Op>, lhs: &mut [T], rhs: &[T]) -> bool {
+    let Some(op) = op else {
+        return false;
+    };
+    let work = lhs.len();
+    if is::<T, f32>() {
+        let rhs: &[f32] = cast(rhs);
+        parallel::for_each_chunk(cast_mut(lhs), 1, work, |s, c| f32s::zip_assign(op, c, &rhs[s..s + c.len()]));
+    } else if is::<T, f64>() {
+        let rhs: &[f64] = cast(rhs);
+        parallel::for_each_chunk(cast_mut(lhs), 1, work, |s, c| f64s::zip_assign(op, c, &rhs[s..s + c.len()]));
+    } else {
+        return false;
+    }
+    true
+}
+
+/// Computes `lhs[i] = lhs[i] op scalar`. Returns `false` if `op` or `T` has no kernel.
+pub(crate) fn scalar_assign<T>(op: Option<Op>, lhs: &mut [T], scalar: &T) -> bool {
+    let Some(op) = op else {
+        return false;
+    };
+    let work = lhs.len();
+    if is::<T, f32>() {
+        let scalar: f32 = cast(core::slice::from_ref(scalar))[0];
+        parallel::for_each_chunk(cast_mut(lhs), 1, work, |_, c| f32s::scalar_assign(op, c, scalar));
+    } else if is::<T, f64>() {
+        let scalar: f64 = cast(core::slice::from_ref(scalar))[0];
+        parallel::for_each_chunk(cast_mut(lhs), 1, work, |_, c| f64s::scalar_assign(op, c, scalar));
+    } else {
+        return false;
+    }
+    true
+}
+
+/// Computes the dot product of `a` and `b`. Returns `None` if `T` has no kernel.
+pub(crate) fn dot<T>(a: &[T], b: &[T]) -> Option<T> {
+    let len = a.len();
+    if is::<T, f32>() {
+        let (a, b): (&[f32], &[f32]) = (cast(a), cast(b));
+        Some(cast_value(parallel::sum_ranges(len, len, |r| f32s::dot(&a[r.clone()], &b[r]))))
+    } else if is::<T, f64>() {
+        let (a, b): (&[f64], &[f64]) = (cast(a), cast(b));
+        Some(cast_value(parallel::sum_ranges(len, len, |r| f64s::dot(&a[r.clone()], &b[r]))))
+    } else {
+        None
+    }
+}
+
+/// Computes `out = a * x` for a row-major `a` with `n` columns.
+/// Returns `false` if `T` has no kernel.
+pub(crate) fn matvec<T>(a: &[T], x: &[T], out: &mut [T], n: usize) -> bool {
+    if n == 0 {
+        return false;
+    }
+    let work = a.len();
+    if is::<T, f32>() {
+        let (a, x): (&[f32], &[f32]) = (cast(a), cast(x));
+        parallel::for_each_chunk(cast_mut(out), 1, work, |s, c| {
+            f32s::matvec(&a[s * n..(s + c.len()) * n], x, c, n)
+        });
+    } else if is::<T, f64>() {
+        let (a, x): (&[f64], &[f64]) = (cast(a), cast(x));
+        parallel::for_each_chunk(cast_mut(out), 1, work, |s, c| {
+            f64s::matvec(&a[s * n..(s + c.len()) * n], x, c, n)
+        });
+    } else {
+        return false;
+    }
+    true
+}
+
+/// Computes `out = a * b` for row-major `a` (`n` columns) and `b` (`p` columns).
+/// `out` must be zeroed. Returns `false` if `T` has no kernel.
+pub(crate) fn matmul<T>(a: &[T], b: &[T], out: &mut [T], n: usize, p: usize) -> bool {
+    if n == 0 || p == 0 {
+        return false;
+    }
+    if is::<T, f32>() {
+        f32s::matmul(cast(a), cast(b), cast_mut(out), n, p);
+    } else if is::<T, f64>() {
+        f64s::matmul(cast(a), cast(b), cast_mut(out), n, p);
+    } else {
+        return false;
+    }
+    true
+}
@@ -0,0 +1,35 @@
+//! Overflow-aware integer arithmetic.
+//!
+//! The operators panic on overflow in debug builds and wrap in release builds, like the element
+//! types themselves. [`MatrixRef`](crate::MatrixRef) and [`VectorRef`](crate::VectorRef) provide
+//! `checked_*` methods, which return `None` on overflow, and `wrapping_*` and `saturating_*`
+//! methods, which behave the same in every build.
+
+use num_traits::Zero;
+
+use crate::matrix::Matrix;
+use crate::traits::MatrixRef;
+
+/// Returns the product of `a` and `b`, accumulating each element with `mul_add(acc, a_ik, b_kj)`.
+/// Returns `None` as soon as `mul_add` does.
+pub(crate) fn try_matmul<T, A, B, F, const R: usize, const C: usize, const P: usize>(
+    a: &A,
+    b: &B,
+    mul_add: F,
+) -> Option<Matrix<T, R, P>>
+where
+    T: Clone + Zero,
+    A: MatrixRef<T, R, C> + ?Sized,
+    B: MatrixRef<T, C, P> + ?Sized,
+    F: Fn(T, T, T) -> Option<T>,
+{
+    let mut result = Matrix::<T, R, P>::zeros();
+    for i in 0..R {
+        for k in 0..C {
+            for j in 0..P {
+                result[(i, j)] = mul_add(result[(i, j)].clone(), a[(i, k)].clone(), b[(k, j)].clone())?;
+            }
+        }
+    }
+    Some(result)
+}
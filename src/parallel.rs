@@ -0,0 +1,241 @@
+//! Thread policy for large operations.
+//!
+//! With the `rayon` feature enabled, large `f32`/`f64` matrix products, matrix-vector
+//! products, element-wise operations and dot products are split across the rayon
+//! thread pool. Without it, the helpers in this module simply run sequentially.
+//!
+//! Reductions are split into consecutive ranges whose partial results are added in
+//! order: vector magnitudes through their dot product, and the matrix 1-norm used by
+//! the matrix functions through its column sums. Their results may differ from the
+//! sequential ones by rounding, but not from run to run.
+//!
+//! The elimination behind [`Matrix::det`](crate::Matrix::det) and the inverses taken by
+//! the matrix functions splits the row updates below each pivot. These give the same
+//! results under every policy.
+
+use core::ops::{Add, Range};
+#[cfg(feature = "rayon")]
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+#[cfg(feature = "rayon")]
+use std::cell::Cell;
+#[cfg(feature = "rayon")]
+use std::vec::Vec;
+
+/// Controls whether large operations run on multiple threads.
+///
+/// The policy can be set globally with [`set_parallelism`] or for the duration of a
+/// closure with [`with_parallelism`]. Only operations with at least
+/// [`parallel_threshold`] multiply-adds are split.
+///
+/// # Examples
+///
+/// ```
+/// use ferrix::{with_parallelism, Matrix, Parallelism};
+///
+/// let a = Matrix::<f64, 64, 64>::ones();
+/// let b = Matrix::<f64, 64, 64>::ones();
+///
+/// // Run this product on the calling thread only
+/// let c = with_parallelism(Parallelism::Sequential, || &a * &b);
+/// assert_eq!(c[(0, 0)], 64.0);
+/// ```
+#[cfg(feature = "rayon")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Parallelism {
+    /// Run on the calling thread.
+    Sequential,
+    /// Split the work into at most `n` tasks on the rayon thread pool.
+    ///
+    /// `Rayon(0)` uses one task per thread in the current pool.
+    Rayon(usize),
+}
+
+#[cfg(feature = "rayon")]
+const SEQUENTIAL: usize = usize::MAX;
+
+#[cfg(feature = "rayon")]
+static GLOBAL: AtomicUsize = AtomicUsize::new(0);
+
+#[cfg(feature = "rayon")]
+static THRESHOLD: AtomicUsize = AtomicUsize::new(1 << 17);
+
+#[cfg(feature = "rayon")]
+std::thread_local! {
+    static OVERRIDE: Cell<Option<Parallelism>> = const { Cell::new(None) };
+}
+
+#[cfg(feature = "rayon")]
+impl Parallelism {
+    fn encode(self) -> usize {
+        match self {
+            Parallelism::Sequential => SEQUENTIAL,
+            Parallelism::Rayon(n) => n.min(SEQUENTIAL - 1),
+        }
+    }
+
+    fn decode(value: usize) -> Self {
+        match value {
+            SEQUENTIAL => Parallelism::Sequential,
+            n => Parallelism::Rayon(n),
+        }
+    }
+}
+
+/// Sets the global [`Parallelism`] policy.
+///
+/// The default is `Parallelism::Rayon(0)`.
+///
+/// # Examples
+///
+/// ```
+/// use ferrix::{parallelism, set_parallelism, Parallelism};
+///
+/// set_parallelism(Parallelism::Rayon(4));
+/// assert_eq!(parallelism(), Parallelism::Rayon(4));
+/// # set_parallelism(Parallelism::Rayon(0));
+/// ```
+#[cfg(feature = "rayon")]
+pub fn set_parallelism(policy: Parallelism) {
+    GLOBAL.store(policy.encode(), Ordering::Relaxed);
+}
+
+/// Returns the [`Parallelism`] policy in effect on the calling thread.
+///
+/// This is the policy passed to an enclosing [`with_parallelism`] call, if any,
+/// and the global policy otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use ferrix::{parallelism, with_parallelism, Parallelism};
+///
+/// with_parallelism(Parallelism::Sequential, || {
+///     assert_eq!(parallelism(), Parallelism::Sequential);
+/// });
+/// ```
+#[cfg(feature = "rayon")]
+pub fn parallelism() -> Parallelism {
+    OVERRIDE
+        .with(Cell::get)
+        .unwrap_or_else(|| Parallelism::decode(GLOBAL.load(Ordering::Relaxed)))
+}
+
+/// Runs `f` with `policy` overriding the global [`Parallelism`] on the calling thread.
+///
+/// The override is carried into the tasks that operations inside `f` split their
+/// work into, so it also applies on the rayon workers running them. It does not
+/// apply to rayon tasks that `f` spawns itself: to set the policy there, call
+/// [`with_parallelism`] inside each task or use [`set_parallelism`].
+///
+/// # Examples
+///
+/// ```
+/// use ferrix::{with_parallelism, DotProduct, Parallelism, Vector};
+///
+/// let a = Vector::<f64, 1000>::ones();
+/// let dot = with_parallelism(Parallelism::Rayon(2), || (&a).dot(&a));
+/// assert_eq!(dot, 1000.0);
+/// ```
+#[cfg(feature = "rayon")]
+pub fn with_parallelism<R>(policy: Parallelism, f: impl FnOnce() -> R) -> R {
+    struct Restore(Option<Parallelism>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            OVERRIDE.with(|o| o.set(self.0));
+        }
+    }
+
+    let _restore = Restore(OVERRIDE.with(|o| o.replace(Some(policy))));
+    f()
+}
+
+/// Sets the minimum number of multiply-adds for an operation to run in parallel.
+///
+/// The default is `131072`, i.e. a `64 x 64` by `64 x 32` matrix product.
+///
+/// # Examples
+///
+/// ```
+/// use ferrix::{parallel_threshold, set_parallel_threshold};
+///
+/// set_parallel_threshold(1 << 20);
+/// assert_eq!(parallel_threshold(), 1 << 20);
+/// # set_parallel_threshold(1 << 17);
+/// ```
+#[cfg(feature = "rayon")]
+pub fn set_parallel_threshold(work: usize) {
+    THRESHOLD.store(work, Ordering::Relaxed);
+}
+
+/// Returns the minimum number of multiply-adds for an operation to run in parallel.
+#[cfg(feature = "rayon")]
+pub fn parallel_threshold() -> usize {
+    THRESHOLD.load(Ordering::Relaxed)
+}
+
+/// Returns the number of tasks to split `work` multiply-adds into, if more than one.
+#[cfg(feature = "rayon")]
+fn tasks(work: usize) -> Option<usize> {
+    if work < parallel_threshold() {
+        return None;
+    }
+    let n = match parallelism() {
+        Parallelism::Sequential => return None,
+        Parallelism::Rayon(0) => rayon::current_num_threads(),
+        Parallelism::Rayon(n) => n,
+    };
+    (n > 1).then_some(n)
+}
+
+/// Calls `f(start, chunk)` on consecutive chunks of `data`, each a multiple of
+/// `unit` elements long. The chunks run in parallel if `work` is large enough.
+pub(crate) fn for_each_chunk<U: Send>(
+    data: &mut [U],
+    unit: usize,
+    work: usize,
+    f: impl Fn(usize, &mut [U]) + Sync,
+) {
+    #[cfg(feature = "rayon")]
+    if let Some(tasks) = tasks(work) {
+        let units = data.len() / unit;
+        let chunk = units.div_ceil(tasks).max(1) * unit;
+        let policy = parallelism();
+        data.par_chunks_mut(chunk)
+            .enumerate()
+            .for_each(|(i, c)| with_parallelism(policy, || f(i * chunk, c)));
+        return;
+    }
+
+    #[cfg(not(feature = "rayon"))]
+    let _ = (unit, work);
+    f(0, data)
+}
+
+/// Returns the sum of `f(range)` over consecutive ranges covering `0..len`.
+/// The ranges run in parallel if `work` is large enough; the partial results are
+/// always combined in order, so the result does not depend on scheduling.
+pub(crate) fn sum_ranges<U: Send + Add<Output = U>>(
+    len: usize,
+    work: usize,
+    f: impl Fn(Range<usize>) -> U + Sync,
+) -> U {
+    #[cfg(feature = "rayon")]
+    if let Some(tasks) = tasks(work) {
+        let chunk = len.div_ceil(tasks).max(1);
+        let policy = parallelism();
+        let partials: Vec<U> = (0..len.div_ceil(chunk))
+            .into_par_iter()
+            .map(|i| with_parallelism(policy, || f(i * chunk..((i + 1) * chunk).min(len))))
+            .collect();
+        if let Some(sum) = partials.into_iter().reduce(|a, b| a + b) {
+            return sum;
+        }
+    }
+
+    #[cfg(not(feature = "rayon"))]
+    let _ = work;
+    f(0..len)
+}
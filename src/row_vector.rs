@@ -1,9 +1,19 @@
-use num_traits::{Float, One, PrimInt, Zero};
+#[cfg(feature = "fmt")]
+use core::fmt;
+#[cfg(any(feature = "std", feature = "libm"))]
+use core::ops::{Add, Mul};
+use core::ops::{Index, IndexMut};
+#[cfg(any(feature = "std", feature = "libm"))]
+use num_traits::Float;
+#[cfg(feature = "rand")]
+use num_traits::PrimInt;
+use num_traits::{One, Zero};
+#[cfg(feature = "rand")]
 use rand::distributions::uniform::SampleUniform;
+#[cfg(feature = "rand")]
 use rand::distributions::{Distribution, Standard, Uniform};
+#[cfg(feature = "rand")]
 use rand::Rng;
-use std::fmt;
-use std::ops::{Index, IndexMut};
 
 use crate::matrix::Matrix;
 use crate::matrix_transpose_view::MatrixTransposeView;
@@ -12,12 +22,18 @@ use crate::matrix_view::MatrixView;
 use crate::matrix_view_mut::MatrixViewMut;
 use crate::row_vector_view::RowVectorView;
 use crate::row_vector_view_mut::RowVectorViewMut;
-use crate::traits::{DotProduct, FloatRandom, IntRandom};
+use crate::traits::{Conjugate, Contiguous, VectorMut, VectorRef};
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::traits::DotProduct;
+#[cfg(feature = "rand")]
+use crate::traits::{FloatRandom, IntRandom};
+use crate::vector::Vector;
 use crate::vector_view::VectorView;
 use crate::vector_view_mut::VectorViewMut;
 
 /// A static row vector type.
 #[derive(Debug, Clone)]
+#[repr(transparent)]
 pub struct RowVector<T, const N: usize> {
     data: [T; N],
 }
@@ -113,12 +129,12 @@ impl<T, const N: usize> RowVector<T, N> {
 impl<T: Default, const N: usize> Default for RowVector<T, N> {
     fn default() -> Self {
         Self {
-            data: std::array::from_fn(|_| T::default()),
+            data: core::array::from_fn(|_| T::default()),
         }
     }
 }
 
-impl<T: Copy> RowVector<T, 1> {
+impl<T> RowVector<T, 1> {
     /// Converts a 1-dimensional [`RowVector`] into its scalar value.
     ///
     /// # Examples
@@ -130,11 +146,12 @@ impl<T: Copy> RowVector<T, 1> {
     /// assert_eq!(vec.into(), 42);
     /// ```
     pub fn into(self) -> T {
-        self[0]
+        let [value] = self.data;
+        value
     }
 }
 
-impl<T: Copy, const N: usize> RowVector<T, N> {
+impl<T: Clone, const N: usize> RowVector<T, N> {
     /// Creates a new [`RowVector`] filled with a specified value.
     ///
     /// This method initializes a new [`RowVector`] of size N, where each element is set to the provided value.
@@ -148,11 +165,13 @@ impl<T: Copy, const N: usize> RowVector<T, N> {
     /// assert_eq!(vec, RowVector::from([42, 42, 42]));
     /// ```
     pub fn fill(value: T) -> Self {
-        Self { data: [value; N] }
+        Self {
+            data: core::array::from_fn(|_| value.clone()),
+        }
     }
 }
 
-impl<T: Copy + Zero, const N: usize> RowVector<T, N> {
+impl<T: Clone + Zero, const N: usize> RowVector<T, N> {
     /// Creates a new [`RowVector`] filled with zeros.
     ///
     /// This method initializes a new [`RowVector`] of size N, where each element is set to zero.
@@ -170,7 +189,7 @@ impl<T: Copy + Zero, const N: usize> RowVector<T, N> {
     }
 }
 
-impl<T: Copy + One, const N: usize> RowVector<T, N> {
+impl<T: Clone + One, const N: usize> RowVector<T, N> {
     /// Creates a new [`RowVector`] filled with ones.
     ///
     /// This method initializes a new [`RowVector`] of size N, where each element is set to one.
@@ -188,6 +207,7 @@ impl<T: Copy + One, const N: usize> RowVector<T, N> {
     }
 }
 
+#[cfg(feature = "rand")]
 impl<T: PrimInt, const N: usize> IntRandom for RowVector<T, N>
 where
     Standard: Distribution<T>,
@@ -195,11 +215,12 @@ where
     fn random() -> Self {
         let mut rng = rand::thread_rng();
         Self {
-            data: std::array::from_fn(|_| rng.gen()),
+            data: core::array::from_fn(|_| rng.gen()),
         }
     }
 }
 
+#[cfg(feature = "rand")]
 impl<T: Float + SampleUniform, const N: usize> FloatRandom for RowVector<T, N>
 where
     Standard: Distribution<T>,
@@ -208,12 +229,12 @@ where
         let mut rng = rand::thread_rng();
         let dist = Uniform::new_inclusive(-T::one(), T::one());
         Self {
-            data: std::array::from_fn(|_| dist.sample(&mut rng)),
+            data: core::array::from_fn(|_| dist.sample(&mut rng)),
         }
     }
 }
 
-impl<T: Copy + Zero, const N: usize> RowVector<T, N> {
+impl<T: Clone + Zero, const N: usize> RowVector<T, N> {
     /// Creates a diagonal matrix from the [`RowVector`].
     ///
     /// This method returns a new NxN [`Matrix`] where the diagonal elements are set to the values of the [`RowVector`],
@@ -231,7 +252,7 @@ impl<T: Copy + Zero, const N: usize> RowVector<T, N> {
     pub fn diag(&self) -> Matrix<T, N, N> {
         let mut m = Matrix::<T, N, N>::zeros();
         for i in 0..N {
-            m[(i, i)] = self[i];
+            m[(i, i)] = self[i].clone();
         }
         m
     }
@@ -325,7 +346,51 @@ impl<T, const N: usize> RowVector<T, N> {
     }
 }
 
-impl<T: Float, const N: usize> RowVector<T, N> {
+impl<T: Conjugate, const N: usize> RowVector<T, N> {
+    /// Returns the element-wise complex conjugate of the [`RowVector`].
+    ///
+    /// Real numbers are their own conjugate, so for real element types this is a copy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "complex")]
+    /// # {
+    /// use ferrix::{Complex, RowVector};
+    ///
+    /// let vec = RowVector::from([Complex::new(1.0, 2.0), Complex::new(3.0, -4.0)]);
+    /// assert_eq!(vec.conj(), RowVector::from([Complex::new(1.0, -2.0), Complex::new(3.0, 4.0)]));
+    /// # }
+    /// ```
+    pub fn conj(&self) -> Self {
+        Self {
+            data: core::array::from_fn(|i| self.data[i].clone().conj()),
+        }
+    }
+
+    /// Returns the conjugate transpose of the [`RowVector`] as a new [`Vector`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "complex")]
+    /// # {
+    /// use ferrix::{Complex, RowVector, Vector};
+    ///
+    /// let vec = RowVector::from([Complex::new(1.0, 2.0), Complex::new(3.0, -4.0)]);
+    /// assert_eq!(vec.h(), Vector::from([Complex::new(1.0, -2.0), Complex::new(3.0, 4.0)]));
+    /// # }
+    /// ```
+    pub fn h(&self) -> Vector<T, N> {
+        Vector::from(core::array::from_fn(|i| self.data[i].clone().conj()))
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T: Conjugate + Zero + Mul<T, Output = T> + Add<T, Output = T>, const N: usize> RowVector<T, N>
+where
+    T::Real: Float,
+{
     /// Calculates the magnitude of the [`RowVector`].
     ///
     /// # Examples
@@ -336,8 +401,8 @@ impl<T: Float, const N: usize> RowVector<T, N> {
     /// let vec = RowVector::from([3.0, 4.0]);
     /// assert_eq!(vec.magnitude(), 5.0);
     /// ```
-    pub fn magnitude(&self) -> T {
-        self.dot(self).sqrt()
+    pub fn magnitude(&self) -> T::Real {
+        self.dotc(self).re().sqrt()
     }
 }
 
@@ -376,6 +441,7 @@ impl<T: Eq, const N: usize> Eq for RowVector<T, N> {}
 //  Display Trait Implementations  //
 /////////////////////////////////////
 
+#[cfg(feature = "fmt")]
 impl<T: fmt::Display, const N: usize> fmt::Display for RowVector<T, N> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if f.alternate() {
@@ -389,13 +455,21 @@ impl<T: fmt::Display, const N: usize> fmt::Display for RowVector<T, N> {
         write!(f, "{}]", self[N - 1])?;
 
         if f.alternate() {
-            write!(f, ", dtype={})", std::any::type_name::<T>())?;
+            write!(f, ", dtype={})", core::any::type_name::<T>())?;
         }
 
         Ok(())
     }
 }
 
+///////////////////////////////////////
+//  VectorRef Trait Implementations  //
+///////////////////////////////////////
+
+impl<T, const N: usize> VectorRef<T, N> for RowVector<T, N> {}
+
+impl<T, const N: usize> VectorMut<T, N> for RowVector<T, N> {}
+
 ///////////////////////////////////
 //  Index Trait Implementations  //
 ///////////////////////////////////
@@ -428,6 +502,26 @@ impl<T, const N: usize> IndexMut<(usize, usize)> for RowVector<T, N> {
     }
 }
 
+////////////////////////////////////////
+//  Contiguous Trait Implementations  //
+////////////////////////////////////////
+
+impl<T, const N: usize> Contiguous<T> for RowVector<T, N> {
+    fn from_fn<F: FnMut(usize) -> T>(f: F) -> Self {
+        Self {
+            data: core::array::from_fn(f),
+        }
+    }
+
+    fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+}
+
 //////////////////////////////////
 //  From Trait Implementations  //
 //////////////////////////////////
@@ -438,78 +532,127 @@ impl<T, const N: usize> From<[T; N]> for RowVector<T, N> {
     }
 }
 
-impl<T: Copy, const N: usize> From<[[T; N]; 1]> for RowVector<T, N> {
+impl<T: Clone, const N: usize> From<[[T; N]; 1]> for RowVector<T, N> {
     fn from(data: [[T; N]; 1]) -> Self {
         Self {
-            data: std::array::from_fn(|i| data[0][i]),
+            data: core::array::from_fn(|i| data[0][i].clone()),
         }
     }
 }
 
-impl<V: Index<usize, Output = T>, T: Copy, const A: usize, const N: usize>
+impl<V: Index<usize, Output = T>, T: Clone, const A: usize, const N: usize>
     From<RowVectorView<'_, V, T, A, N>> for RowVector<T, N>
 {
     fn from(vector: RowVectorView<'_, V, T, A, N>) -> Self {
         Self {
-            data: std::array::from_fn(|i| vector[i]),
+            data: core::array::from_fn(|i| vector[i].clone()),
         }
     }
 }
 
-impl<V: IndexMut<usize, Output = T>, T: Copy, const A: usize, const N: usize>
+impl<V: IndexMut<usize, Output = T>, T: Clone, const A: usize, const N: usize>
     From<RowVectorViewMut<'_, V, T, A, N>> for RowVector<T, N>
 {
     fn from(vector: RowVectorViewMut<'_, V, T, A, N>) -> Self {
         Self {
-            data: std::array::from_fn(|i| vector[i]),
+            data: core::array::from_fn(|i| vector[i].clone()),
         }
     }
 }
 
-impl<T: Copy, const N: usize> From<Matrix<T, 1, N>> for RowVector<T, N> {
+impl<T: Clone, const N: usize> From<Matrix<T, 1, N>> for RowVector<T, N> {
     fn from(matrix: Matrix<T, 1, N>) -> Self {
         Self {
-            data: std::array::from_fn(|i| matrix[(0, i)]),
+            data: core::array::from_fn(|i| matrix[(0, i)].clone()),
         }
     }
 }
 
-impl<T: Copy, const A: usize, const B: usize, const N: usize> From<MatrixView<'_, T, A, B, 1, N>>
+impl<T: Clone, const A: usize, const B: usize, const N: usize> From<MatrixView<'_, T, A, B, 1, N>>
     for RowVector<T, N>
 {
     fn from(matrix: MatrixView<'_, T, A, B, 1, N>) -> Self {
         Self {
-            data: std::array::from_fn(|i| matrix[(0, i)]),
+            data: core::array::from_fn(|i| matrix[(0, i)].clone()),
         }
     }
 }
 
-impl<T: Copy, const A: usize, const B: usize, const N: usize> From<MatrixViewMut<'_, T, A, B, 1, N>>
+impl<T: Clone, const A: usize, const B: usize, const N: usize> From<MatrixViewMut<'_, T, A, B, 1, N>>
     for RowVector<T, N>
 {
     fn from(matrix: MatrixViewMut<'_, T, A, B, 1, N>) -> Self {
         Self {
-            data: std::array::from_fn(|i| matrix[(0, i)]),
+            data: core::array::from_fn(|i| matrix[(0, i)].clone()),
         }
     }
 }
 
-impl<T: Copy, const A: usize, const B: usize, const N: usize>
+impl<T: Clone, const A: usize, const B: usize, const N: usize>
     From<MatrixTransposeView<'_, T, A, B, 1, N>> for RowVector<T, N>
 {
     fn from(matrix: MatrixTransposeView<'_, T, A, B, 1, N>) -> Self {
         Self {
-            data: std::array::from_fn(|i| matrix[(0, i)]),
+            data: core::array::from_fn(|i| matrix[(0, i)].clone()),
         }
     }
 }
 
-impl<T: Copy, const A: usize, const B: usize, const N: usize>
+impl<T: Clone, const A: usize, const B: usize, const N: usize>
     From<MatrixTransposeViewMut<'_, T, A, B, 1, N>> for RowVector<T, N>
 {
     fn from(matrix: MatrixTransposeViewMut<'_, T, A, B, 1, N>) -> Self {
         Self {
-            data: std::array::from_fn(|i| matrix[(0, i)]),
+            data: core::array::from_fn(|i| matrix[(0, i)].clone()),
         }
     }
 }
+
+#[cfg(feature = "bytemuck")]
+impl<T: bytemuck::Pod, const N: usize> RowVector<T, N> {
+    /// Returns the elements of the [`RowVector`] as bytes, without copying.
+    ///
+    /// Use this in place of `bytemuck::bytes_of`, e.g. to upload the [`RowVector`] to a GPU buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::RowVector;
+    ///
+    /// let vec = RowVector::from([1u16, 2, 3, 4]);
+    /// assert_eq!(vec.as_bytes(), bytemuck::cast_slice::<u16, u8>(&[1, 2, 3, 4]));
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(&self.data)
+    }
+
+    /// Returns the elements of a slice of [`RowVector`] values as bytes, without copying.
+    ///
+    /// The vectors are laid out one after another. Use this in place of
+    /// `bytemuck::cast_slice`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::RowVector;
+    ///
+    /// let vectors = [RowVector::from([1u16, 2, 3, 4]), RowVector::from([5u16, 6, 7, 8])];
+    /// let bytes = RowVector::slice_as_bytes(&vectors);
+    /// assert_eq!(bytes, bytemuck::cast_slice::<u16, u8>(&[1, 2, 3, 4, 5, 6, 7, 8]));
+    /// ```
+    pub fn slice_as_bytes(vectors: &[Self]) -> &[u8] {
+        bytemuck::cast_slice(<Self as bytemuck::TransparentWrapper<_>>::peel_slice(vectors).as_flattened())
+    }
+}
+
+//////////////////////////////////////
+//  Bytemuck Trait Implementations  //
+//////////////////////////////////////
+
+// SAFETY: `RowVector` is `repr(transparent)` over `[T; N]`, which is zeroable when `T` is
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Zeroable, const N: usize> bytemuck::Zeroable for RowVector<T, N> {}
+
+// SAFETY: `RowVector` is `repr(transparent)` over `[T; N]`
+#[cfg(feature = "bytemuck")]
+unsafe impl<T, const N: usize> bytemuck::TransparentWrapper<[T; N]> for RowVector<T, N> {}
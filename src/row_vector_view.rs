@@ -1,11 +1,18 @@
-use num_traits::Float;
-use std::fmt;
-use std::marker::PhantomData;
-use std::ops::Index;
+#[cfg(any(feature = "std", feature = "libm"))]
+use num_traits::{Float, Zero};
+#[cfg(feature = "fmt")]
+use core::fmt;
+use core::marker::PhantomData;
+#[cfg(any(feature = "std", feature = "libm"))]
+use core::ops::{Add, Mul};
+use core::ops::Index;
 
 use crate::row_vector::RowVector;
 use crate::row_vector_view_mut::RowVectorViewMut;
+#[cfg(any(feature = "std", feature = "libm"))]
 use crate::traits::DotProduct;
+use crate::traits::{Conjugate, VectorRef};
+use crate::vector::Vector;
 use crate::vector_view::VectorView;
 
 /// A row vector view of a [`Vector`](crate::vector::Vector) (transposed view) or a [`RowVector`].
@@ -114,10 +121,40 @@ impl<'a, V, T, const N: usize, const M: usize> RowVectorView<'a, V, T, N, M> {
     pub fn t(&'a self) -> VectorView<'a, V, T, N, M> {
         VectorView::new(self.data, self.start)
     }
+
+    /// Returns a view of the [`RowVectorView`].
+    ///
+    /// This method returns a [`RowVectorView`] of size `K` starting from the given index, relative to the
+    /// [`RowVectorView`]. Returns `None` if the requested view is out of bounds or if `K` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::RowVector;
+    ///
+    /// let vec = RowVector::from([1, 2, 3, 4, 5]);
+    /// let view = vec.view::<4>(1).unwrap();
+    /// let inner = view.view::<2>(1).unwrap();
+    /// assert_eq!(inner, RowVector::from([3, 4]));
+    /// ```
+    pub fn view<const K: usize>(&self, start: usize) -> Option<RowVectorView<'a, V, T, N, K>> {
+        if start + K > M || K == 0 {
+            return None;
+        }
+        Some(RowVectorView::new(self.data, self.start + start))
+    }
 }
 
-impl<'a, V: Index<usize, Output = T>, T: Float, const N: usize, const M: usize>
-    RowVectorView<'a, V, T, N, M>
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<
+        'a,
+        V: Index<usize, Output = T>,
+        T: Conjugate + Zero + Mul<T, Output = T> + Add<T, Output = T>,
+        const N: usize,
+        const M: usize,
+    > RowVectorView<'a, V, T, N, M>
+where
+    T::Real: Float,
 {
     /// Calculates the magnitude of the [`RowVectorView`].
     ///
@@ -130,8 +167,40 @@ impl<'a, V: Index<usize, Output = T>, T: Float, const N: usize, const M: usize>
     /// let view = vec.view::<2>(2).unwrap();
     /// assert_eq!(view.magnitude(), 5.0);
     /// ```
-    pub fn magnitude(&self) -> T {
-        self.dot(self).sqrt()
+    pub fn magnitude(&self) -> T::Real {
+        self.dotc(self).re().sqrt()
+    }
+}
+
+impl<V: Index<usize, Output = T>, T: Conjugate, const N: usize, const M: usize> RowVectorView<'_, V, T, N, M> {
+    /// Returns the element-wise complex conjugate of the [`RowVectorView`] as a new [`RowVector`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::RowVector;
+    ///
+    /// let vec = RowVector::from([1, 2, 3, 4, 5]);
+    /// let view = vec.view::<3>(1).unwrap();
+    /// assert_eq!(view.conj(), RowVector::from([2, 3, 4]));
+    /// ```
+    pub fn conj(&self) -> RowVector<T, M> {
+        RowVector::from(core::array::from_fn(|i| self[i].clone().conj()))
+    }
+
+    /// Returns the conjugate transpose of the [`RowVectorView`] as a new [`Vector`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{RowVector, Vector};
+    ///
+    /// let vec = RowVector::from([1, 2, 3, 4, 5]);
+    /// let view = vec.view::<3>(1).unwrap();
+    /// assert_eq!(view.h(), Vector::from([2, 3, 4]));
+    /// ```
+    pub fn h(&self) -> Vector<T, M> {
+        Vector::from(core::array::from_fn(|i| self[i].clone().conj()))
     }
 }
 
@@ -187,6 +256,7 @@ impl<'a, V: Index<usize, Output = T>, T: Eq, const N: usize, const M: usize> Eq
 //  Display Trait Implementations  //
 /////////////////////////////////////
 
+#[cfg(feature = "fmt")]
 impl<'a, V: Index<usize, Output = T>, T: fmt::Display, const N: usize, const M: usize> fmt::Display
     for RowVectorView<'a, V, T, N, M>
 {
@@ -202,13 +272,22 @@ impl<'a, V: Index<usize, Output = T>, T: fmt::Display, const N: usize, const M:
         write!(f, "{}]", self[M - 1])?;
 
         if f.alternate() {
-            write!(f, ", dtype={})", std::any::type_name::<T>())?;
+            write!(f, ", dtype={})", core::any::type_name::<T>())?;
         }
 
         Ok(())
     }
 }
 
+///////////////////////////////////////
+//  VectorRef Trait Implementations  //
+///////////////////////////////////////
+
+impl<V: Index<usize, Output = T>, T, const N: usize, const M: usize> VectorRef<T, M>
+    for RowVectorView<'_, V, T, N, M>
+{
+}
+
 ///////////////////////////////////
 //  Index Trait Implementations  //
 ///////////////////////////////////
@@ -1,12 +1,19 @@
 use crate::row_vector::RowVector;
 use crate::row_vector_view::RowVectorView;
+#[cfg(any(feature = "std", feature = "libm"))]
 use crate::traits::DotProduct;
+use crate::traits::{Conjugate, VectorMut, VectorRef};
+use crate::vector::Vector;
 use crate::vector_view::VectorView;
 use crate::vector_view_mut::VectorViewMut;
-use num_traits::Float;
-use std::fmt;
-use std::marker::PhantomData;
-use std::ops::{Index, IndexMut};
+#[cfg(any(feature = "std", feature = "libm"))]
+use num_traits::{Float, Zero};
+#[cfg(feature = "fmt")]
+use core::fmt;
+use core::marker::PhantomData;
+#[cfg(any(feature = "std", feature = "libm"))]
+use core::ops::{Add, Mul};
+use core::ops::{Index, IndexMut};
 
 /// A row vector view of a [`Vector`](crate::vector::Vector) (transposed view) or a [`RowVector`].
 #[derive(Debug)]
@@ -133,10 +140,63 @@ impl<'a, V, T, const N: usize, const M: usize> RowVectorViewMut<'a, V, T, N, M>
     pub fn t_mut(&'a mut self) -> VectorViewMut<'a, V, T, N, M> {
         VectorViewMut::new(self.data, self.start)
     }
+
+    /// Returns a view of the [`RowVectorViewMut`].
+    ///
+    /// This method returns a [`RowVectorView`] of size `K` starting from the given index, relative to the
+    /// [`RowVectorViewMut`]. Returns `None` if the requested view is out of bounds or if `K` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::RowVector;
+    ///
+    /// let mut vec = RowVector::from([1, 2, 3, 4, 5]);
+    /// let view = vec.view_mut::<4>(1).unwrap();
+    /// let inner = view.view::<2>(1).unwrap();
+    /// assert_eq!(inner, RowVector::from([3, 4]));
+    /// ```
+    pub fn view<const K: usize>(&self, start: usize) -> Option<RowVectorView<'_, V, T, N, K>> {
+        if start + K > M || K == 0 {
+            return None;
+        }
+        Some(RowVectorView::new(self.data, self.start + start))
+    }
+
+    /// Returns a mutable view of the [`RowVectorViewMut`].
+    ///
+    /// This method returns a [`RowVectorViewMut`] of size `K` starting from the given index, relative to the
+    /// [`RowVectorViewMut`]. Returns `None` if the requested view is out of bounds or if `K` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::RowVector;
+    ///
+    /// let mut vec = RowVector::from([1, 2, 3, 4, 5]);
+    /// let mut view = vec.view_mut::<4>(1).unwrap();
+    /// let mut inner = view.view_mut::<2>(1).unwrap();
+    /// inner[0] = 10;
+    /// assert_eq!(vec, RowVector::from([1, 2, 10, 4, 5]));
+    /// ```
+    pub fn view_mut<const K: usize>(&mut self, start: usize) -> Option<RowVectorViewMut<'_, V, T, N, K>> {
+        if start + K > M || K == 0 {
+            return None;
+        }
+        Some(RowVectorViewMut::new(self.data, self.start + start))
+    }
 }
 
-impl<'a, V: Index<usize, Output = T>, T: Float, const N: usize, const M: usize>
-    RowVectorViewMut<'a, V, T, N, M>
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<
+        'a,
+        V: Index<usize, Output = T>,
+        T: Conjugate + Zero + Mul<T, Output = T> + Add<T, Output = T>,
+        const N: usize,
+        const M: usize,
+    > RowVectorViewMut<'a, V, T, N, M>
+where
+    T::Real: Float,
 {
     /// Calculates the magnitude of the [`RowVectorViewMut`].
     ///
@@ -149,8 +209,40 @@ impl<'a, V: Index<usize, Output = T>, T: Float, const N: usize, const M: usize>
     /// let view = vec.view_mut::<2>(2).unwrap();
     /// assert_eq!(view.magnitude(), 5.0);
     /// ```
-    pub fn magnitude(&self) -> T {
-        self.dot(self).sqrt()
+    pub fn magnitude(&self) -> T::Real {
+        self.dotc(self).re().sqrt()
+    }
+}
+
+impl<V: Index<usize, Output = T>, T: Conjugate, const N: usize, const M: usize> RowVectorViewMut<'_, V, T, N, M> {
+    /// Returns the element-wise complex conjugate of the [`RowVectorViewMut`] as a new [`RowVector`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::RowVector;
+    ///
+    /// let mut vec = RowVector::from([1, 2, 3, 4, 5]);
+    /// let view = vec.view_mut::<3>(1).unwrap();
+    /// assert_eq!(view.conj(), RowVector::from([2, 3, 4]));
+    /// ```
+    pub fn conj(&self) -> RowVector<T, M> {
+        RowVector::from(core::array::from_fn(|i| self[i].clone().conj()))
+    }
+
+    /// Returns the conjugate transpose of the [`RowVectorViewMut`] as a new [`Vector`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{RowVector, Vector};
+    ///
+    /// let mut vec = RowVector::from([1, 2, 3, 4, 5]);
+    /// let view = vec.view_mut::<3>(1).unwrap();
+    /// assert_eq!(view.h(), Vector::from([2, 3, 4]));
+    /// ```
+    pub fn h(&self) -> Vector<T, M> {
+        Vector::from(core::array::from_fn(|i| self[i].clone().conj()))
     }
 }
 
@@ -206,6 +298,7 @@ impl<'a, V: Index<usize, Output = T>, T: Eq, const N: usize, const M: usize> Eq
 //  Display Trait Implementations  //
 /////////////////////////////////////
 
+#[cfg(feature = "fmt")]
 impl<'a, V: Index<usize, Output = T>, T: fmt::Display, const N: usize, const M: usize> fmt::Display
     for RowVectorViewMut<'a, V, T, N, M>
 {
@@ -221,13 +314,27 @@ impl<'a, V: Index<usize, Output = T>, T: fmt::Display, const N: usize, const M:
         write!(f, "{}]", self[M - 1])?;
 
         if f.alternate() {
-            write!(f, ", dtype={})", std::any::type_name::<T>())?;
+            write!(f, ", dtype={})", core::any::type_name::<T>())?;
         }
 
         Ok(())
     }
 }
 
+///////////////////////////////////////
+//  VectorRef Trait Implementations  //
+///////////////////////////////////////
+
+impl<V: Index<usize, Output = T>, T, const N: usize, const M: usize> VectorRef<T, M>
+    for RowVectorViewMut<'_, V, T, N, M>
+{
+}
+
+impl<V: IndexMut<usize, Output = T>, T, const N: usize, const M: usize> VectorMut<T, M>
+    for RowVectorViewMut<'_, V, T, N, M>
+{
+}
+
 ///////////////////////////////////
 //  Index Trait Implementations  //
 ///////////////////////////////////
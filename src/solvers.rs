@@ -0,0 +1,363 @@
+//! Iterative Krylov solvers for `A x = b`.
+//!
+//! The solvers only need to apply `A` to vectors, so `A` can be a [`Matrix`](crate::Matrix),
+//! a [`SparseMatrix`](crate::SparseMatrix) or any closure implementing [`LinearOperator`].
+//! Each solver starts from the initial guess `x0` and stops once the residual norm
+//! `‖b - A x‖` falls below `tol * ‖b‖`, or after `max_iter` iterations.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use num_traits::Float;
+
+use crate::traits::{DotProduct, LinearOperator};
+use crate::vector::Vector;
+
+/// Summary of an iterative solve.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConvergenceReport<T> {
+    /// Whether the residual reached the requested tolerance.
+    pub converged: bool,
+    /// The number of iterations performed.
+    pub iterations: usize,
+    /// The residual norm `‖b - A x‖` before the first iteration and after every iteration.
+    pub residuals: Vec<T>,
+}
+
+impl<T> ConvergenceReport<T> {
+    fn new() -> Self {
+        Self {
+            converged: false,
+            iterations: 0,
+            residuals: Vec::new(),
+        }
+    }
+}
+
+#[inline]
+fn norm<T: Float, const N: usize>(v: &Vector<T, N>) -> T {
+    v.dot(v).sqrt()
+}
+
+/// Solves `A x = b` for a symmetric positive definite `A` with the conjugate gradient method.
+///
+/// # Examples
+///
+/// ```
+/// use ferrix::{cg, Matrix, Vector};
+///
+/// let a = Matrix::from([[4.0, 1.0], [1.0, 3.0]]);
+/// let b = Vector::<f64, 2>::from([1.0, 2.0]);
+///
+/// let (x, report) = cg(&a, &b, Vector::zeros(), 1e-10, 10);
+/// assert!(report.converged);
+/// assert!((x[0] - 1.0 / 11.0).abs() < 1e-10);
+/// assert!((x[1] - 7.0 / 11.0).abs() < 1e-10);
+/// ```
+pub fn cg<T, A, const N: usize>(
+    a: &A,
+    b: &Vector<T, N>,
+    x0: Vector<T, N>,
+    tol: T,
+    max_iter: usize,
+) -> (Vector<T, N>, ConvergenceReport<T>)
+where
+    T: Float,
+    A: LinearOperator<T, N, N> + ?Sized,
+{
+    pcg(a, &|r: &Vector<T, N>| r.clone(), b, x0, tol, max_iter)
+}
+
+/// Solves `A x = b` for a symmetric positive definite `A` with the preconditioned conjugate
+/// gradient method.
+///
+/// `m` applies the inverse of the preconditioner, i.e. it maps a residual `r` to an
+/// approximation of `A⁻¹ r`. It should itself be symmetric positive definite.
+///
+/// # Examples
+///
+/// ```
+/// use ferrix::{pcg, Matrix, Vector};
+///
+/// let a = Matrix::from([[4.0, 1.0], [1.0, 3.0]]);
+/// let b = Vector::<f64, 2>::from([1.0, 2.0]);
+///
+/// // Jacobi preconditioner
+/// let m = |r: &Vector<f64, 2>| Vector::from([r[0] / 4.0, r[1] / 3.0]);
+///
+/// let (x, report) = pcg(&a, &m, &b, Vector::zeros(), 1e-10, 10);
+/// assert!(report.converged);
+/// assert!((x[0] - 1.0 / 11.0).abs() < 1e-10);
+/// ```
+pub fn pcg<T, A, P, const N: usize>(
+    a: &A,
+    m: &P,
+    b: &Vector<T, N>,
+    x0: Vector<T, N>,
+    tol: T,
+    max_iter: usize,
+) -> (Vector<T, N>, ConvergenceReport<T>)
+where
+    T: Float,
+    A: LinearOperator<T, N, N> + ?Sized,
+    P: LinearOperator<T, N, N> + ?Sized,
+{
+    let mut report = ConvergenceReport::new();
+    let threshold = tol * norm(b);
+
+    let mut x = x0;
+    let mut r = b - a.apply(&x);
+    let mut residual = norm(&r);
+    report.residuals.push(residual);
+    if residual <= threshold {
+        report.converged = true;
+        return (x, report);
+    }
+
+    let mut z = m.apply(&r);
+    let mut p = z.clone();
+    let mut rz = (&r).dot(&z);
+    while report.iterations < max_iter {
+        let ap = a.apply(&p);
+        let pap = (&p).dot(&ap);
+        if pap == T::zero() {
+            break;
+        }
+        let alpha = rz / pap;
+        x = x + &p * alpha;
+        r = r - &ap * alpha;
+
+        report.iterations += 1;
+        residual = norm(&r);
+        report.residuals.push(residual);
+        if residual <= threshold {
+            report.converged = true;
+            break;
+        }
+
+        z = m.apply(&r);
+        let rz_next = (&r).dot(&z);
+        let beta = rz_next / rz;
+        rz = rz_next;
+        p = &z + &p * beta;
+    }
+    (x, report)
+}
+
+/// Solves `A x = b` with the restarted generalized minimal residual method, GMRES(`restart`).
+///
+/// The Krylov basis is rebuilt from the current residual every `restart` iterations. Larger
+/// values converge in fewer iterations but store more vectors. The residuals recorded within
+/// a cycle are the estimates from the Arnoldi process. If `A` turns out to be singular on the
+/// Krylov subspace, the solve stops early, unconverged, with the best solution found so far.
+///
+/// # Panics
+///
+/// Panics if `restart` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use ferrix::{gmres, Matrix, Vector};
+///
+/// // Non-symmetric system
+/// let a = Matrix::from([[3.0, 1.0, 0.0], [-1.0, 2.0, 1.0], [0.0, 1.0, 4.0]]);
+/// let b = Vector::<f64, 3>::from([4.0, 2.0, 5.0]);
+///
+/// let (x, report) = gmres(&a, &b, Vector::zeros(), 1e-10, 3, 20);
+/// assert!(report.converged);
+/// assert!((x[0] - 1.0).abs() < 1e-8);
+/// assert!((x[1] - 1.0).abs() < 1e-8);
+/// assert!((x[2] - 1.0).abs() < 1e-8);
+/// ```
+pub fn gmres<T, A, const N: usize>(
+    a: &A,
+    b: &Vector<T, N>,
+    x0: Vector<T, N>,
+    tol: T,
+    restart: usize,
+    max_iter: usize,
+) -> (Vector<T, N>, ConvergenceReport<T>)
+where
+    T: Float,
+    A: LinearOperator<T, N, N> + ?Sized,
+{
+    if restart == 0 {
+        panic!("Restart must be greater than zero");
+    }
+
+    let mut report = ConvergenceReport::new();
+    let threshold = tol * norm(b);
+    let mut x = x0;
+    let mut singular = false;
+
+    loop {
+        let r = b - a.apply(&x);
+        let beta = norm(&r);
+        if report.residuals.is_empty() {
+            report.residuals.push(beta);
+        }
+        if beta <= threshold {
+            report.converged = true;
+            break;
+        }
+        // Restarting from the same residual would run into the same singular column
+        if singular || report.iterations >= max_iter {
+            break;
+        }
+
+        // Arnoldi process with modified Gram-Schmidt. The Hessenberg matrix is reduced to
+        // upper triangular form by Givens rotations as it is built, so that `|g[j + 1]|` is
+        // the residual norm after `j + 1` steps.
+        let mut basis = Vec::with_capacity(restart + 1);
+        basis.push(&r * (T::one() / beta));
+        let mut h = vec![vec![T::zero(); restart]; restart + 1];
+        let mut rotations: Vec<(T, T)> = Vec::with_capacity(restart);
+        let mut g = vec![T::zero(); restart + 1];
+        g[0] = beta;
+
+        let mut k = 0;
+        while k < restart && report.iterations < max_iter {
+            let mut w = a.apply(&basis[k]);
+            let scale = norm(&w);
+            for (i, v) in basis.iter().enumerate() {
+                h[i][k] = (&w).dot(v);
+                w = w - v * h[i][k];
+            }
+            h[k + 1][k] = norm(&w);
+
+            for (i, &(c, s)) in rotations.iter().enumerate() {
+                let (hi, hj) = (h[i][k], h[i + 1][k]);
+                h[i][k] = c * hi + s * hj;
+                h[i + 1][k] = c * hj - s * hi;
+            }
+            let (hk, hk1) = (h[k][k], h[k + 1][k]);
+            let denom = hk.hypot(hk1);
+            if denom <= T::epsilon() * scale {
+                // The new column would put a zero on the diagonal: `A` is singular on the Krylov
+                // subspace, so the residual cannot be reduced further. The column is dropped and
+                // the solution is taken from the previous ones.
+                singular = true;
+                report.iterations += 1;
+                report.residuals.push(g[k].abs());
+                break;
+            }
+            let (c, s) = (hk / denom, hk1 / denom);
+            rotations.push((c, s));
+            h[k][k] = c * hk + s * hk1;
+            h[k + 1][k] = T::zero();
+            g[k + 1] = -s * g[k];
+            g[k] = c * g[k];
+
+            let breakdown = hk1 == T::zero();
+            if !breakdown {
+                basis.push(&w * (T::one() / hk1));
+            }
+            k += 1;
+            report.iterations += 1;
+            report.residuals.push(g[k].abs());
+            if g[k].abs() <= threshold || breakdown {
+                break;
+            }
+        }
+
+        // Solve the k x k upper triangular system H y = g by back substitution. Its diagonal is
+        // nonzero, since a column that would make it zero ends the loop above without being kept.
+        let mut y = vec![T::zero(); k];
+        for i in (0..k).rev() {
+            let sum = ((i + 1)..k).fold(g[i], |acc, j| acc - h[i][j] * y[j]);
+            y[i] = sum / h[i][i];
+        }
+        for (v, &yi) in basis.iter().zip(&y) {
+            x = x + v * yi;
+        }
+    }
+    (x, report)
+}
+
+/// Solves `A x = b` with the biconjugate gradient stabilized method.
+///
+/// BiCGSTAB handles non-symmetric systems with a fixed amount of memory. The solve stops
+/// early, unconverged, if the method breaks down.
+///
+/// # Examples
+///
+/// ```
+/// use ferrix::{bicgstab, Matrix, Vector};
+///
+/// let a = Matrix::from([[3.0, 1.0, 0.0], [-1.0, 2.0, 1.0], [0.0, 1.0, 4.0]]);
+/// let b = Vector::<f64, 3>::from([4.0, 2.0, 5.0]);
+///
+/// let (x, report) = bicgstab(&a, &b, Vector::zeros(), 1e-10, 20);
+/// assert!(report.converged);
+/// assert!((x[0] - 1.0).abs() < 1e-8);
+/// ```
+pub fn bicgstab<T, A, const N: usize>(
+    a: &A,
+    b: &Vector<T, N>,
+    x0: Vector<T, N>,
+    tol: T,
+    max_iter: usize,
+) -> (Vector<T, N>, ConvergenceReport<T>)
+where
+    T: Float,
+    A: LinearOperator<T, N, N> + ?Sized,
+{
+    let mut report = ConvergenceReport::new();
+    let threshold = tol * norm(b);
+
+    let mut x = x0;
+    let mut r = b - a.apply(&x);
+    let mut residual = norm(&r);
+    report.residuals.push(residual);
+    if residual <= threshold {
+        report.converged = true;
+        return (x, report);
+    }
+
+    let r_hat = r.clone();
+    let (mut rho, mut alpha, mut omega) = (T::one(), T::one(), T::one());
+    let mut v = Vector::<T, N>::zeros();
+    let mut p = Vector::<T, N>::zeros();
+    while report.iterations < max_iter {
+        let rho_next = (&r_hat).dot(&r);
+        if rho_next == T::zero() || omega == T::zero() {
+            break;
+        }
+        let beta = (rho_next / rho) * (alpha / omega);
+        rho = rho_next;
+        p = &r + (&p - &v * omega) * beta;
+        v = a.apply(&p);
+        let r_hat_v = (&r_hat).dot(&v);
+        if r_hat_v == T::zero() {
+            break;
+        }
+        alpha = rho / r_hat_v;
+        let s = &r - &v * alpha;
+
+        report.iterations += 1;
+        if norm(&s) <= threshold {
+            x = x + &p * alpha;
+            report.residuals.push(norm(&s));
+            report.converged = true;
+            break;
+        }
+
+        let t = a.apply(&s);
+        let tt = (&t).dot(&t);
+        omega = if tt == T::zero() {
+            T::zero()
+        } else {
+            (&t).dot(&s) / tt
+        };
+        x = x + &p * alpha + &s * omega;
+        r = &s - &t * omega;
+
+        residual = norm(&r);
+        report.residuals.push(residual);
+        if residual <= threshold {
+            report.converged = true;
+            break;
+        }
+    }
+    (x, report)
+}
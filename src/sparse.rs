@@ -0,0 +1,935 @@
+use alloc::vec;
+use alloc::vec::Vec;
+#[cfg(feature = "fmt")]
+use core::fmt;
+use core::ops::{Add, Mul};
+use num_traits::Zero;
+
+use crate::matrix::Matrix;
+use crate::traits::{Contiguous, LinearOperator, TransposeOperator};
+use crate::vector::Vector;
+
+/// Storage format of a [`SparseMatrix`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SparseFormat {
+    /// Coordinate format: unordered `(row, col, value)` triplets. Duplicates are allowed
+    /// and summed when converting to another format.
+    Coo,
+    /// Compressed sparse row format.
+    Csr,
+    /// Compressed sparse column format.
+    Csc,
+}
+
+#[derive(Debug, Clone)]
+enum Storage<T> {
+    Coo {
+        row_indices: Vec<usize>,
+        col_indices: Vec<usize>,
+        values: Vec<T>,
+    },
+    Csr {
+        row_ptr: Vec<usize>,
+        col_indices: Vec<usize>,
+        values: Vec<T>,
+    },
+    Csc {
+        col_ptr: Vec<usize>,
+        row_indices: Vec<usize>,
+        values: Vec<T>,
+    },
+}
+
+/// A dynamically sized sparse matrix.
+///
+/// Only the non-zero entries are stored, in [`Coo`](SparseFormat::Coo),
+/// [`Csr`](SparseFormat::Csr) or [`Csc`](SparseFormat::Csc) format.
+/// Unlike the dense types, the shape is only known at runtime.
+///
+/// COO is the natural format for assembly, CSR for matrix-vector and matrix-matrix
+/// products, and CSC for column access. Compressed formats always have their indices
+/// sorted and free of duplicates.
+///
+/// Because shapes are only checked at runtime, the `*` operator panics on a dimension
+/// mismatch, while the named methods ([`mul_vec`](SparseMatrix::mul_vec) and
+/// [`checked_matmul`](SparseMatrix::checked_matmul)) return `None`.
+///
+/// # Examples
+///
+/// ```
+/// use ferrix::{Matrix, SparseMatrix, Vector};
+///
+/// // Assemble a 3x3 matrix; duplicate entries are summed
+/// let mut a = SparseMatrix::new(3, 3);
+/// a.push(0, 0, 2.0);
+/// a.push(1, 1, 1.0);
+/// a.push(1, 1, 1.0);
+/// a.push(2, 0, -1.0);
+///
+/// let a = a.to_csr();
+/// assert_eq!(a.nnz(), 3);
+///
+/// let x = Vector::from([1.0, 2.0, 3.0]);
+/// let y: Vector<f64, 3> = a.mul_vec(&x).unwrap();
+/// assert_eq!(y, Vector::from([2.0, 4.0, -1.0]));
+///
+/// let dense: Matrix<f64, 3, 3> = a.to_dense().unwrap();
+/// assert_eq!(dense, Matrix::from([[2.0, 0.0, 0.0], [0.0, 2.0, 0.0], [-1.0, 0.0, 0.0]]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SparseMatrix<T> {
+    rows: usize,
+    cols: usize,
+    storage: Storage<T>,
+}
+
+impl<T> SparseMatrix<T> {
+    /// Creates an empty `rows x cols` [`SparseMatrix`] in COO format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{SparseFormat, SparseMatrix};
+    ///
+    /// let a = SparseMatrix::<f64>::new(1000, 1000);
+    /// assert_eq!(a.nnz(), 0);
+    /// assert_eq!(a.format(), SparseFormat::Coo);
+    /// ```
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            storage: Storage::Coo {
+                row_indices: Vec::new(),
+                col_indices: Vec::new(),
+                values: Vec::new(),
+            },
+        }
+    }
+
+    /// Returns the shape of the [`SparseMatrix`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::SparseMatrix;
+    ///
+    /// let a = SparseMatrix::<f64>::new(2, 3);
+    /// assert_eq!(a.shape(), (2, 3));
+    /// ```
+    #[inline]
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Returns the number of rows in the [`SparseMatrix`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::SparseMatrix;
+    ///
+    /// let a = SparseMatrix::<f64>::new(2, 3);
+    /// assert_eq!(a.rows(), 2);
+    /// ```
+    #[inline]
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns in the [`SparseMatrix`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::SparseMatrix;
+    ///
+    /// let a = SparseMatrix::<f64>::new(2, 3);
+    /// assert_eq!(a.cols(), 3);
+    /// ```
+    #[inline]
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the number of stored entries in the [`SparseMatrix`].
+    ///
+    /// In COO format, duplicate entries are counted separately.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::SparseMatrix;
+    ///
+    /// let a = SparseMatrix::from_triplets(2, 2, &[(0, 0, 1), (0, 0, 2), (1, 0, 3)]).unwrap();
+    /// assert_eq!(a.nnz(), 3);
+    /// assert_eq!(a.to_csr().nnz(), 2);
+    /// ```
+    #[inline]
+    pub fn nnz(&self) -> usize {
+        match &self.storage {
+            Storage::Coo { values, .. } | Storage::Csr { values, .. } | Storage::Csc { values, .. } => {
+                values.len()
+            }
+        }
+    }
+
+    /// Returns the storage format of the [`SparseMatrix`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{SparseFormat, SparseMatrix};
+    ///
+    /// let a = SparseMatrix::<f64>::new(2, 2);
+    /// assert_eq!(a.to_csc().format(), SparseFormat::Csc);
+    /// ```
+    #[inline]
+    pub fn format(&self) -> SparseFormat {
+        match &self.storage {
+            Storage::Coo { .. } => SparseFormat::Coo,
+            Storage::Csr { .. } => SparseFormat::Csr,
+            Storage::Csc { .. } => SparseFormat::Csc,
+        }
+    }
+
+    /// Returns an iterator over the stored `(row, col, value)` entries in storage order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::SparseMatrix;
+    ///
+    /// let a = SparseMatrix::from_triplets(2, 2, &[(1, 0, 2), (0, 1, 1)]).unwrap().to_csr();
+    /// let entries: Vec<_> = a.iter().collect();
+    /// assert_eq!(entries, vec![(0, 1, &1), (1, 0, &2)]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> + Clone + '_ {
+        Entries {
+            storage: &self.storage,
+            position: 0,
+            major: 0,
+        }
+    }
+}
+
+impl<T: Clone> SparseMatrix<T> {
+    /// Creates a `rows x cols` [`SparseMatrix`] in COO format from `(row, col, value)` triplets.
+    ///
+    /// Duplicate entries are kept and summed when converting to another format.
+    /// Returns `None` if any index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::SparseMatrix;
+    ///
+    /// let a = SparseMatrix::from_triplets(2, 2, &[(0, 1, 1.0), (1, 0, 2.0)]).unwrap();
+    /// assert_eq!(a.get((0, 1)), 1.0);
+    /// assert_eq!(a.get((0, 0)), 0.0);
+    ///
+    /// assert!(SparseMatrix::from_triplets(2, 2, &[(2, 0, 1.0)]).is_none());
+    /// ```
+    pub fn from_triplets(rows: usize, cols: usize, triplets: &[(usize, usize, T)]) -> Option<Self> {
+        if triplets.iter().any(|&(i, j, _)| i >= rows || j >= cols) {
+            return None;
+        }
+        Some(Self {
+            rows,
+            cols,
+            storage: Storage::Coo {
+                row_indices: triplets.iter().map(|t| t.0).collect(),
+                col_indices: triplets.iter().map(|t| t.1).collect(),
+                values: triplets.iter().map(|t| t.2.clone()).collect(),
+            },
+        })
+    }
+
+    /// Appends the entry `(row, col, value)`, summing it with any existing entry at that position.
+    ///
+    /// The [`SparseMatrix`] is converted to COO format first if necessary.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `(row, col)` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::SparseMatrix;
+    ///
+    /// let mut a = SparseMatrix::new(2, 2);
+    /// a.push(0, 1, 1.0);
+    /// a.push(0, 1, 2.5);
+    /// assert_eq!(a.get((0, 1)), 3.5);
+    /// ```
+    pub fn push(&mut self, row: usize, col: usize, value: T) {
+        if row >= self.rows || col >= self.cols {
+            panic!("Index out of bounds");
+        }
+        if !matches!(self.storage, Storage::Coo { .. }) {
+            *self = self.to_coo();
+        }
+        if let Storage::Coo {
+            row_indices,
+            col_indices,
+            values,
+        } = &mut self.storage
+        {
+            row_indices.push(row);
+            col_indices.push(col);
+            values.push(value);
+        }
+    }
+
+    /// Returns the transpose of the [`SparseMatrix`].
+    ///
+    /// CSR and CSC are swapped without moving any entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{SparseFormat, SparseMatrix};
+    ///
+    /// let a = SparseMatrix::from_triplets(2, 3, &[(0, 2, 5)]).unwrap().to_csr();
+    /// let t = a.t();
+    /// assert_eq!(t.shape(), (3, 2));
+    /// assert_eq!(t.format(), SparseFormat::Csc);
+    /// assert_eq!(t.get((2, 0)), 5);
+    /// ```
+    pub fn t(&self) -> Self {
+        let storage = match &self.storage {
+            Storage::Coo {
+                row_indices,
+                col_indices,
+                values,
+            } => Storage::Coo {
+                row_indices: col_indices.clone(),
+                col_indices: row_indices.clone(),
+                values: values.clone(),
+            },
+            Storage::Csr {
+                row_ptr,
+                col_indices,
+                values,
+            } => Storage::Csc {
+                col_ptr: row_ptr.clone(),
+                row_indices: col_indices.clone(),
+                values: values.clone(),
+            },
+            Storage::Csc {
+                col_ptr,
+                row_indices,
+                values,
+            } => Storage::Csr {
+                row_ptr: col_ptr.clone(),
+                col_indices: row_indices.clone(),
+                values: values.clone(),
+            },
+        };
+        Self {
+            rows: self.cols,
+            cols: self.rows,
+            storage,
+        }
+    }
+
+    /// Returns the [`SparseMatrix`] in COO format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{SparseFormat, SparseMatrix};
+    ///
+    /// let a = SparseMatrix::from_triplets(2, 2, &[(0, 0, 1)]).unwrap().to_csr();
+    /// assert_eq!(a.to_coo().format(), SparseFormat::Coo);
+    /// ```
+    pub fn to_coo(&self) -> Self {
+        let (row_indices, (col_indices, values)) = self.iter().map(|(i, j, v)| (i, (j, v.clone()))).unzip();
+        Self {
+            rows: self.rows,
+            cols: self.cols,
+            storage: Storage::Coo {
+                row_indices,
+                col_indices,
+                values,
+            },
+        }
+    }
+}
+
+impl<T: Clone + Zero + Add<T, Output = T>> SparseMatrix<T> {
+    /// Returns the [`SparseMatrix`] in CSR format, summing duplicate entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{SparseFormat, SparseMatrix};
+    ///
+    /// let a = SparseMatrix::from_triplets(2, 2, &[(1, 1, 1), (1, 1, 2)]).unwrap();
+    /// let csr = a.to_csr();
+    /// assert_eq!(csr.format(), SparseFormat::Csr);
+    /// assert_eq!(csr.nnz(), 1);
+    /// assert_eq!(csr.get((1, 1)), 3);
+    /// ```
+    pub fn to_csr(&self) -> Self {
+        if let Storage::Csr { .. } = self.storage {
+            return self.clone();
+        }
+        let (row_ptr, col_indices, values) = compress(self.rows, self.iter());
+        Self {
+            rows: self.rows,
+            cols: self.cols,
+            storage: Storage::Csr {
+                row_ptr,
+                col_indices,
+                values,
+            },
+        }
+    }
+
+    /// Returns the [`SparseMatrix`] in CSC format, summing duplicate entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{SparseFormat, SparseMatrix};
+    ///
+    /// let a = SparseMatrix::from_triplets(2, 2, &[(0, 1, 1), (1, 0, 2)]).unwrap();
+    /// let csc = a.to_csc();
+    /// assert_eq!(csc.format(), SparseFormat::Csc);
+    /// assert_eq!(csc.get((1, 0)), 2);
+    /// ```
+    pub fn to_csc(&self) -> Self {
+        if let Storage::Csc { .. } = self.storage {
+            return self.clone();
+        }
+        let (col_ptr, row_indices, values) = compress(self.cols, self.iter().map(|(i, j, v)| (j, i, v)));
+        Self {
+            rows: self.rows,
+            cols: self.cols,
+            storage: Storage::Csc {
+                col_ptr,
+                row_indices,
+                values,
+            },
+        }
+    }
+
+    /// Returns the element at `index`, or zero if no entry is stored there.
+    ///
+    /// This is a binary search in CSR and CSC format, and a linear scan over the stored entries in COO
+    /// format.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::SparseMatrix;
+    ///
+    /// let a = SparseMatrix::from_triplets(2, 2, &[(0, 1, 4)]).unwrap();
+    /// assert_eq!(a.get((0, 1)), 4);
+    /// assert_eq!(a.get((1, 1)), 0);
+    /// ```
+    pub fn get(&self, index: (usize, usize)) -> T {
+        let (i, j) = index;
+        if i >= self.rows || j >= self.cols {
+            panic!("Index out of bounds");
+        }
+        match &self.storage {
+            Storage::Coo {
+                row_indices,
+                col_indices,
+                values,
+            } => row_indices
+                .iter()
+                .zip(col_indices)
+                .zip(values)
+                .filter(|((&r, &c), _)| r == i && c == j)
+                .fold(T::zero(), |acc, (_, v)| acc + v.clone()),
+            Storage::Csr {
+                row_ptr,
+                col_indices,
+                values,
+            } => find(&col_indices[row_ptr[i]..row_ptr[i + 1]], &values[row_ptr[i]..], j),
+            Storage::Csc {
+                col_ptr,
+                row_indices,
+                values,
+            } => find(&row_indices[col_ptr[j]..col_ptr[j + 1]], &values[col_ptr[j]..], i),
+        }
+    }
+
+    /// Converts the [`SparseMatrix`] into a dense [`Matrix`].
+    ///
+    /// Returns `None` if the shape does not match `(R, C)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{Matrix, SparseMatrix};
+    ///
+    /// let a = SparseMatrix::from_triplets(2, 2, &[(0, 1, 1), (1, 0, 2)]).unwrap();
+    /// let dense: Matrix<i32, 2, 2> = a.to_dense().unwrap();
+    /// assert_eq!(dense, Matrix::from([[0, 1], [2, 0]]));
+    ///
+    /// assert!(a.to_dense::<3, 2>().is_none());
+    /// ```
+    pub fn to_dense<const R: usize, const C: usize>(&self) -> Option<Matrix<T, R, C>> {
+        if (R, C) != self.shape() {
+            return None;
+        }
+        let mut result = Matrix::<T, R, C>::zeros();
+        for (i, j, v) in self.iter() {
+            result[(i, j)] = result[(i, j)].clone() + v.clone();
+        }
+        Some(result)
+    }
+}
+
+impl<T: Clone + Zero + Add<T, Output = T> + Mul<T, Output = T>> SparseMatrix<T> {
+    /// Multiplies the [`SparseMatrix`] by a dense [`Vector`].
+    ///
+    /// Returns `None` if the shape of the [`SparseMatrix`] is not `(M, N)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{SparseMatrix, Vector};
+    ///
+    /// let a = SparseMatrix::from_triplets(2, 3, &[(0, 0, 1), (0, 2, 2), (1, 1, 3)]).unwrap();
+    /// let x = Vector::from([1, 2, 3]);
+    /// let y: Vector<i32, 2> = a.mul_vec(&x).unwrap();
+    /// assert_eq!(y, Vector::from([7, 6]));
+    /// ```
+    pub fn mul_vec<const M: usize, const N: usize>(&self, other: &Vector<T, N>) -> Option<Vector<T, M>> {
+        if (M, N) != self.shape() {
+            return None;
+        }
+        let mut result = Vector::<T, M>::zeros();
+        self.mul_into(other.as_slice(), result.as_mut_slice());
+        Some(result)
+    }
+
+    /// Multiplies the [`SparseMatrix`] by another [`SparseMatrix`].
+    ///
+    /// The product is returned in CSR format. Returns `None` if the number of columns of `self`
+    /// does not match the number of rows of `other`; the `*` operator panics instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::SparseMatrix;
+    ///
+    /// let a = SparseMatrix::from_triplets(2, 3, &[(0, 0, 1), (0, 2, 2), (1, 1, 3)]).unwrap();
+    /// let b = SparseMatrix::from_triplets(3, 1, &[(0, 0, 1), (2, 0, 1)]).unwrap();
+    /// let product = a.checked_matmul(&b).unwrap();
+    /// assert_eq!(product.to_dense::<2, 1>().unwrap().as_slice(), &[3, 0]);
+    /// assert!(a.checked_matmul(&a).is_none());
+    /// ```
+    pub fn checked_matmul(&self, other: &Self) -> Option<Self> {
+        (self.cols == other.rows).then(|| self.matmul(other))
+    }
+
+    /// Computes `y = A x`, where `x` has one element per column and `y` one zeroed element per row.
+    fn mul_into(&self, x: &[T], y: &mut [T]) {
+        match &self.storage {
+            Storage::Csr {
+                row_ptr,
+                col_indices,
+                values,
+            } => {
+                for (i, yi) in y.iter_mut().enumerate() {
+                    let (start, end) = (row_ptr[i], row_ptr[i + 1]);
+                    *yi = col_indices[start..end]
+                        .iter()
+                        .zip(&values[start..end])
+                        .fold(T::zero(), |acc, (&j, v)| acc + (v.clone() * x[j].clone()));
+                }
+            }
+            _ => {
+                for (i, j, v) in self.iter() {
+                    y[i] = y[i].clone() + (v.clone() * x[j].clone());
+                }
+            }
+        }
+    }
+}
+
+impl<T: Clone + Zero + PartialEq> SparseMatrix<T> {
+    /// Creates a [`SparseMatrix`] in CSR format from the non-zero elements of a dense [`Matrix`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{Matrix, SparseMatrix};
+    ///
+    /// let a = SparseMatrix::from_dense(&Matrix::from([[0.0, 1.0], [2.0, 0.0]]));
+    /// assert_eq!(a.nnz(), 2);
+    /// ```
+    pub fn from_dense<const R: usize, const C: usize>(matrix: &Matrix<T, R, C>) -> Self {
+        let mut row_ptr = Vec::with_capacity(R + 1);
+        let mut col_indices = Vec::new();
+        let mut values = Vec::new();
+        row_ptr.push(0);
+        for i in 0..R {
+            for j in 0..C {
+                let v = &matrix[(i, j)];
+                if !v.is_zero() {
+                    col_indices.push(j);
+                    values.push(v.clone());
+                }
+            }
+            row_ptr.push(values.len());
+        }
+        Self {
+            rows: R,
+            cols: C,
+            storage: Storage::Csr {
+                row_ptr,
+                col_indices,
+                values,
+            },
+        }
+    }
+}
+
+/// Iterator over the stored entries of a [`SparseMatrix`], reading the storage arrays in place.
+struct Entries<'a, T> {
+    storage: &'a Storage<T>,
+    position: usize,
+    // Major index of the entry at `position` in a compressed format
+    major: usize,
+}
+
+// Derived `Clone` would require `T: Clone`
+impl<T> Clone for Entries<'_, T> {
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+impl<'a, T> Iterator for Entries<'a, T> {
+    type Item = (usize, usize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let k = self.position;
+        let entry = match self.storage {
+            Storage::Coo {
+                row_indices,
+                col_indices,
+                values,
+            } => (row_indices.get(k).copied()?, col_indices[k], &values[k]),
+            Storage::Csr {
+                row_ptr,
+                col_indices,
+                values,
+            } => {
+                let v = values.get(k)?;
+                while row_ptr[self.major + 1] <= k {
+                    self.major += 1;
+                }
+                (self.major, col_indices[k], v)
+            }
+            Storage::Csc {
+                col_ptr,
+                row_indices,
+                values,
+            } => {
+                let v = values.get(k)?;
+                while col_ptr[self.major + 1] <= k {
+                    self.major += 1;
+                }
+                (row_indices[k], self.major, v)
+            }
+        };
+        self.position += 1;
+        Some(entry)
+    }
+}
+
+/// Compresses `(major, minor, value)` entries, sorting each major slice by minor index and
+/// summing duplicates.
+fn compress<'a, T: 'a + Clone + Zero + Add<T, Output = T>>(
+    n: usize,
+    entries: impl Iterator<Item = (usize, usize, &'a T)> + Clone,
+) -> (Vec<usize>, Vec<usize>, Vec<T>) {
+    // Bucket the entries by major index
+    let mut ptr = vec![0; n + 1];
+    for (a, _, _) in entries.clone() {
+        ptr[a + 1] += 1;
+    }
+    for k in 0..n {
+        ptr[k + 1] += ptr[k];
+    }
+    let mut next = ptr.clone();
+    let mut bucketed = vec![(0, T::zero()); ptr[n]];
+    for (a, b, v) in entries {
+        bucketed[next[a]] = (b, v.clone());
+        next[a] += 1;
+    }
+
+    // Sort and merge each bucket
+    let mut minor = Vec::with_capacity(bucketed.len());
+    let mut values: Vec<T> = Vec::with_capacity(bucketed.len());
+    let mut compressed = vec![0; n + 1];
+    for k in 0..n {
+        let bucket = &mut bucketed[ptr[k]..ptr[k + 1]];
+        bucket.sort_by_key(|&(b, _)| b);
+        for (b, v) in bucket.iter_mut() {
+            let (b, v) = (*b, core::mem::replace(v, T::zero()));
+            if minor.len() > compressed[k] && minor.last() == Some(&b) {
+                let last = values.len() - 1;
+                values[last] = values[last].clone() + v;
+            } else {
+                minor.push(b);
+                values.push(v);
+            }
+        }
+        compressed[k + 1] = minor.len();
+    }
+    (compressed, minor, values)
+}
+
+/// Returns the value stored at minor index `target`, or zero.
+fn find<T: Clone + Zero>(indices: &[usize], values: &[T], target: usize) -> T {
+    match indices.binary_search(&target) {
+        Ok(k) => values[k].clone(),
+        Err(_) => T::zero(),
+    }
+}
+
+////////////////////////////////////////////
+//  Multiplication Trait Implementations  //
+////////////////////////////////////////////
+
+impl<T: Clone + Zero + Add<T, Output = T> + Mul<T, Output = T>> SparseMatrix<T> {
+    /// Computes the product of two matrices of matching shapes in CSR format with Gustavson's algorithm.
+    fn matmul(&self, other: &Self) -> Self {
+        let (a, b) = (self.to_csr(), other.to_csr());
+        let (
+            Storage::Csr {
+                row_ptr: a_ptr,
+                col_indices: a_cols,
+                values: a_values,
+            },
+            Storage::Csr {
+                row_ptr: b_ptr,
+                col_indices: b_cols,
+                values: b_values,
+            },
+        ) = (&a.storage, &b.storage)
+        else {
+            unreachable!()
+        };
+
+        let n = other.cols;
+        let mut row_ptr = Vec::with_capacity(self.rows + 1);
+        let mut col_indices = Vec::new();
+        let mut values = Vec::new();
+        row_ptr.push(0);
+
+        // Dense accumulator for one output row, and the columns it touches
+        let mut acc = vec![T::zero(); n];
+        let mut touched = vec![false; n];
+        let mut pattern = Vec::new();
+        for i in 0..self.rows {
+            for k in a_ptr[i]..a_ptr[i + 1] {
+                let (a_col, a_val) = (a_cols[k], &a_values[k]);
+                for l in b_ptr[a_col]..b_ptr[a_col + 1] {
+                    let j = b_cols[l];
+                    if !touched[j] {
+                        touched[j] = true;
+                        pattern.push(j);
+                    }
+                    acc[j] = acc[j].clone() + (a_val.clone() * b_values[l].clone());
+                }
+            }
+            pattern.sort_unstable();
+            for &j in &pattern {
+                col_indices.push(j);
+                values.push(core::mem::replace(&mut acc[j], T::zero()));
+                touched[j] = false;
+            }
+            pattern.clear();
+            row_ptr.push(values.len());
+        }
+
+        Self {
+            rows: self.rows,
+            cols: n,
+            storage: Storage::Csr {
+                row_ptr,
+                col_indices,
+                values,
+            },
+        }
+    }
+}
+
+macro_rules! impl_sparse_matmul {
+    ($lhs:ty, $rhs:ty) => {
+        impl<T: Clone + Zero + Add<T, Output = T> + Mul<T, Output = T>> Mul<$rhs> for $lhs {
+            type Output = SparseMatrix<T>;
+
+            /// Returns the product in CSR format.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the number of columns of `self` does not match the number of rows of `other`.
+            fn mul(self, other: $rhs) -> Self::Output {
+                match self.checked_matmul(&other) {
+                    Some(result) => result,
+                    None => panic!("Dimension mismatch"),
+                }
+            }
+        }
+    };
+}
+
+impl_sparse_matmul!(SparseMatrix<T>, SparseMatrix<T>);
+impl_sparse_matmul!(&SparseMatrix<T>, SparseMatrix<T>);
+impl_sparse_matmul!(SparseMatrix<T>, &SparseMatrix<T>);
+impl_sparse_matmul!(&SparseMatrix<T>, &SparseMatrix<T>);
+
+macro_rules! impl_sparse_matvec {
+    ($lhs:ty, $rhs:ty) => {
+        impl<T: Clone + Zero + Add<T, Output = T> + Mul<T, Output = T>, const N: usize> Mul<$rhs> for $lhs {
+            type Output = Vec<T>;
+
+            /// Returns the product as a [`Vec`] with one element per row, since the number of rows
+            /// is only known at runtime. Use [`SparseMatrix::mul_vec`] for a [`Vector`].
+            ///
+            /// # Panics
+            ///
+            /// Panics if the number of columns of `self` is not `N`.
+            fn mul(self, other: $rhs) -> Self::Output {
+                if self.cols != N {
+                    panic!("Dimension mismatch");
+                }
+                let mut result = vec![T::zero(); self.rows];
+                self.mul_into(other.as_slice(), &mut result);
+                result
+            }
+        }
+    };
+}
+
+impl_sparse_matvec!(SparseMatrix<T>, Vector<T, N>);
+impl_sparse_matvec!(&SparseMatrix<T>, Vector<T, N>);
+impl_sparse_matvec!(SparseMatrix<T>, &Vector<T, N>);
+impl_sparse_matvec!(&SparseMatrix<T>, &Vector<T, N>);
+
+//////////////////////////////////////
+//  Equality Trait Implementations  //
+//////////////////////////////////////
+
+// Compares the represented matrices, regardless of format and explicitly stored zeros
+impl<T: Clone + Zero + Add<T, Output = T> + PartialEq> PartialEq for SparseMatrix<T> {
+    fn eq(&self, other: &Self) -> bool {
+        if self.shape() != other.shape() {
+            return false;
+        }
+        let (a, b) = (self.to_csr(), other.to_csr());
+        let nonzero = |(_, _, v): &(usize, usize, &T)| !v.is_zero();
+        a.iter().filter(nonzero).eq(b.iter().filter(nonzero))
+    }
+}
+
+////////////////////////////////////////////
+//  LinearOperator Trait Implementations  //
+////////////////////////////////////////////
+
+impl<T: Clone + Zero + Add<T, Output = T> + Mul<T, Output = T>, const N: usize, const M: usize> LinearOperator<T, N, M>
+    for SparseMatrix<T>
+{
+    /// Multiplies the [`SparseMatrix`] by `x`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shape of the [`SparseMatrix`] is not `(N, M)`.
+    fn apply(&self, x: &Vector<T, M>) -> Vector<T, N> {
+        match self.mul_vec(x) {
+            Some(result) => result,
+            None => panic!("Dimension mismatch"),
+        }
+    }
+}
+
+impl<T: Clone + Zero + Add<T, Output = T> + Mul<T, Output = T>, const N: usize, const M: usize> TransposeOperator<T, N, M>
+    for SparseMatrix<T>
+{
+    /// Multiplies the transpose of the [`SparseMatrix`] by `x`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shape of the [`SparseMatrix`] is not `(N, M)`.
+    fn apply_transpose(&self, x: &Vector<T, N>) -> Vector<T, M> {
+        if (N, M) != self.shape() {
+            panic!("Dimension mismatch");
+        }
+        let mut result = Vector::<T, M>::zeros();
+        for (i, j, v) in self.iter() {
+            result[j] = result[j].clone() + (v.clone() * x[i].clone());
+        }
+        result
+    }
+}
+
+//////////////////////////////////
+//  From Trait Implementations  //
+//////////////////////////////////
+
+impl<T: Clone + Zero + PartialEq, const R: usize, const C: usize> From<Matrix<T, R, C>> for SparseMatrix<T> {
+    fn from(matrix: Matrix<T, R, C>) -> Self {
+        Self::from_dense(&matrix)
+    }
+}
+
+impl<T: Clone + Zero + PartialEq, const R: usize, const C: usize> From<&Matrix<T, R, C>> for SparseMatrix<T> {
+    fn from(matrix: &Matrix<T, R, C>) -> Self {
+        Self::from_dense(matrix)
+    }
+}
+
+/////////////////////////////////////
+//  Display Trait Implementations  //
+/////////////////////////////////////
+
+#[cfg(feature = "fmt")]
+impl<T: fmt::Display> fmt::Display for SparseMatrix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "SparseMatrix(")?;
+        }
+
+        write!(f, "[")?;
+        for (k, (i, j, v)) in self.iter().enumerate() {
+            if k > 0 {
+                writeln!(f)?;
+                write!(f, " ")?;
+                if f.alternate() {
+                    write!(f, "             ")?;
+                }
+            }
+            write!(f, "({}, {}): {}", i, j, v)?;
+        }
+        write!(f, "]")?;
+
+        if f.alternate() {
+            write!(
+                f,
+                ", shape=({}, {}), format={:?}, dtype={})",
+                self.rows,
+                self.cols,
+                self.format(),
+                core::any::type_name::<T>()
+            )?;
+        }
+
+        Ok(())
+    }
+}
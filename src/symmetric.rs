@@ -0,0 +1,248 @@
+//! Symmetric matrices.
+//!
+//! A [`Symmetric`] matrix is equal to its transpose. Products with a dense right-hand side, whether
+//! a [`Vector`], a [`Matrix`], a reference to either or one of their views, read each element above
+//! the diagonal once and use it for both of its mirror images.
+
+use core::ops::{Add, Index, Mul, Sub};
+use num_traits::Zero;
+
+use crate::matrix::Matrix;
+use crate::matrix_cm::MatrixCM;
+use crate::matrix_strided_view::MatrixStridedView;
+use crate::matrix_strided_view_mut::MatrixStridedViewMut;
+use crate::matrix_transpose_view::MatrixTransposeView;
+use crate::matrix_transpose_view_mut::MatrixTransposeViewMut;
+use crate::matrix_view::MatrixView;
+use crate::matrix_view_mut::MatrixViewMut;
+use crate::traits::{MatrixRef, VectorRef};
+use crate::vector::Vector;
+use crate::vector_view::VectorView;
+use crate::vector_view_mut::VectorViewMut;
+
+/// A static symmetric matrix type.
+///
+/// The [`Matrix`] is always equal to its transpose. Sums, differences and scalar multiples keep the
+/// invariant and so return a [`Symmetric`] matrix.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Symmetric<T, const N: usize> {
+    matrix: Matrix<T, N, N>,
+}
+
+impl<T: PartialEq, const N: usize> Symmetric<T, N> {
+    /// Creates a new [`Symmetric`] matrix from a [`Matrix`].
+    ///
+    /// Returns `None` if the [`Matrix`] is not equal to its transpose.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{Matrix, Symmetric};
+    ///
+    /// assert!(Symmetric::new(Matrix::from([[1, 2], [2, 3]])).is_some());
+    /// assert!(Symmetric::new(Matrix::from([[1, 2], [4, 3]])).is_none());
+    /// ```
+    pub fn new(matrix: Matrix<T, N, N>) -> Option<Self> {
+        let symmetric = (0..N).all(|i| (0..i).all(|j| matrix[(i, j)] == matrix[(j, i)]));
+        symmetric.then_some(Self { matrix })
+    }
+}
+
+impl<T: Clone, const N: usize> Symmetric<T, N> {
+    /// Creates a new [`Symmetric`] matrix from the upper triangle of a [`Matrix`].
+    ///
+    /// The elements below the diagonal are replaced with their mirror images above it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{Matrix, Symmetric};
+    ///
+    /// let s = Symmetric::from_upper(&Matrix::from([[1, 2], [0, 3]]));
+    /// assert_eq!(s.matrix(), &Matrix::from([[1, 2], [2, 3]]));
+    /// ```
+    pub fn from_upper(matrix: &Matrix<T, N, N>) -> Self {
+        let matrix = Matrix::from(core::array::from_fn(|i| {
+            core::array::from_fn(|j| matrix[(i.min(j), i.max(j))].clone())
+        }));
+        Self { matrix }
+    }
+}
+
+impl<T, const N: usize> Symmetric<T, N> {
+    /// Returns a reference to the underlying [`Matrix`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{Matrix, Symmetric};
+    ///
+    /// let s = Symmetric::new(Matrix::from([[1, 2], [2, 3]])).unwrap();
+    /// assert_eq!(s.matrix()[(1, 0)], 2);
+    /// ```
+    pub fn matrix(&self) -> &Matrix<T, N, N> {
+        &self.matrix
+    }
+}
+
+//////////////////////////////////
+//  From Trait Implementations  //
+//////////////////////////////////
+
+impl<T, const N: usize> From<Symmetric<T, N>> for Matrix<T, N, N> {
+    fn from(symmetric: Symmetric<T, N>) -> Self {
+        symmetric.matrix
+    }
+}
+
+///////////////////////////////////
+//  Index Trait Implementations  //
+///////////////////////////////////
+
+impl<T, const N: usize> Index<(usize, usize)> for Symmetric<T, N> {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        &self.matrix[index]
+    }
+}
+
+////////////////////////////////////////
+//  Arithmetic Trait Implementations  //
+////////////////////////////////////////
+
+impl<T: Clone + Zero + Mul<T, Output = T>, const N: usize> Symmetric<T, N> {
+    fn mul_vector<V: VectorRef<T, N> + ?Sized>(&self, other: &V) -> Matrix<T, N, 1> {
+        let mut product = Matrix::<T, N, 1>::zeros();
+        for i in 0..N {
+            product[(i, 0)] = product[(i, 0)].clone() + self.matrix[(i, i)].clone() * other[i].clone();
+            for k in i + 1..N {
+                let a = &self.matrix[(i, k)];
+                product[(i, 0)] = product[(i, 0)].clone() + a.clone() * other[k].clone();
+                product[(k, 0)] = product[(k, 0)].clone() + a.clone() * other[i].clone();
+            }
+        }
+        product
+    }
+
+    fn mul_matrix<M: MatrixRef<T, N, C> + ?Sized, const C: usize>(&self, other: &M) -> Matrix<T, N, C> {
+        let mut product = Matrix::<T, N, C>::zeros();
+        for i in 0..N {
+            for j in 0..C {
+                product[(i, j)] = product[(i, j)].clone() + self.matrix[(i, i)].clone() * other[(i, j)].clone();
+            }
+            for k in i + 1..N {
+                let a = &self.matrix[(i, k)];
+                for j in 0..C {
+                    product[(i, j)] = product[(i, j)].clone() + a.clone() * other[(k, j)].clone();
+                    product[(k, j)] = product[(k, j)].clone() + a.clone() * other[(i, j)].clone();
+                }
+            }
+        }
+        product
+    }
+}
+
+// `$arg` borrows `$other` for `mul_vector` or `mul_matrix`, which read it in place
+macro_rules! impl_symmetric_dense_mul {
+    ($method:ident, $output:ty, $rhs:ty, [$($generics:tt)*], |$other:ident| $arg:expr) => {
+        impl<T: Clone + Zero + Mul<T, Output = T>, const N: usize, $($generics)*> Mul<$rhs> for Symmetric<T, N> {
+            type Output = $output;
+
+            fn mul(self, $other: $rhs) -> Self::Output {
+                self.$method($arg)
+            }
+        }
+
+        impl<T: Clone + Zero + Mul<T, Output = T>, const N: usize, $($generics)*> Mul<$rhs> for &Symmetric<T, N> {
+            type Output = $output;
+
+            fn mul(self, $other: $rhs) -> Self::Output {
+                self.$method($arg)
+            }
+        }
+    };
+}
+
+impl_symmetric_dense_mul!(mul_vector, Matrix<T, N, 1>, Vector<T, N>, [], |other| &other);
+impl_symmetric_dense_mul!(mul_vector, Matrix<T, N, 1>, &Vector<T, N>, [], |other| other);
+impl_symmetric_dense_mul!(mul_vector, Matrix<T, N, 1>, VectorView<'_, V, T, A, N>, [V: Index<usize, Output = T>, const A: usize], |other| &other);
+impl_symmetric_dense_mul!(mul_vector, Matrix<T, N, 1>, VectorViewMut<'_, V, T, A, N>, [V: Index<usize, Output = T>, const A: usize], |other| &other);
+
+impl_symmetric_dense_mul!(mul_matrix, Matrix<T, N, C>, Matrix<T, N, C>, [const C: usize], |other| &other);
+impl_symmetric_dense_mul!(mul_matrix, Matrix<T, N, C>, &Matrix<T, N, C>, [const C: usize], |other| other);
+impl_symmetric_dense_mul!(mul_matrix, Matrix<T, N, C>, MatrixCM<T, N, C>, [const C: usize], |other| &other);
+impl_symmetric_dense_mul!(mul_matrix, Matrix<T, N, C>, &MatrixCM<T, N, C>, [const C: usize], |other| other);
+impl_symmetric_dense_mul!(mul_matrix, Matrix<T, N, C>, MatrixView<'_, T, A, B, N, C>, [const A: usize, const B: usize, const C: usize], |other| &other);
+impl_symmetric_dense_mul!(mul_matrix, Matrix<T, N, C>, MatrixViewMut<'_, T, A, B, N, C>, [const A: usize, const B: usize, const C: usize], |other| &other);
+impl_symmetric_dense_mul!(mul_matrix, Matrix<T, N, C>, MatrixTransposeView<'_, T, A, B, N, C>, [const A: usize, const B: usize, const C: usize], |other| &other);
+impl_symmetric_dense_mul!(mul_matrix, Matrix<T, N, C>, MatrixTransposeViewMut<'_, T, A, B, N, C>, [const A: usize, const B: usize, const C: usize], |other| &other);
+impl_symmetric_dense_mul!(mul_matrix, Matrix<T, N, C>, MatrixStridedView<'_, T, A, B, N, C>, [const A: usize, const B: usize, const C: usize], |other| &other);
+impl_symmetric_dense_mul!(mul_matrix, Matrix<T, N, C>, MatrixStridedViewMut<'_, T, A, B, N, C>, [const A: usize, const B: usize, const C: usize], |other| &other);
+
+macro_rules! impl_symmetric_ops {
+    ($($lhs:ty),*) => {
+        $(
+            impl<T: Clone + Mul<T, Output = T>, const N: usize> Mul<T> for $lhs {
+                type Output = Symmetric<T, N>;
+
+                fn mul(self, scalar: T) -> Self::Output {
+                    let matrix = Matrix::from(core::array::from_fn(|i| {
+                        core::array::from_fn(|j| self.matrix[(i, j)].clone() * scalar.clone())
+                    }));
+                    Symmetric { matrix }
+                }
+            }
+
+            impl<T: Clone + Add<T, Output = T>, const N: usize> Add<Symmetric<T, N>> for $lhs {
+                type Output = Symmetric<T, N>;
+
+                fn add(self, other: Symmetric<T, N>) -> Self::Output {
+                    let matrix = Matrix::from(core::array::from_fn(|i| {
+                        core::array::from_fn(|j| self.matrix[(i, j)].clone() + other.matrix[(i, j)].clone())
+                    }));
+                    Symmetric { matrix }
+                }
+            }
+
+            impl<T: Clone + Sub<T, Output = T>, const N: usize> Sub<Symmetric<T, N>> for $lhs {
+                type Output = Symmetric<T, N>;
+
+                fn sub(self, other: Symmetric<T, N>) -> Self::Output {
+                    let matrix = Matrix::from(core::array::from_fn(|i| {
+                        core::array::from_fn(|j| self.matrix[(i, j)].clone() - other.matrix[(i, j)].clone())
+                    }));
+                    Symmetric { matrix }
+                }
+            }
+        )*
+    };
+}
+
+impl_symmetric_ops!(Symmetric<T, N>, &Symmetric<T, N>);
+
+macro_rules! impl_matrix_symmetric_mul {
+    ($($lhs:ty, $rhs:ty);*) => {
+        $(
+            impl<T: Clone + Zero + Mul<T, Output = T>, const R: usize, const N: usize> Mul<$rhs> for $lhs {
+                type Output = Matrix<T, R, N>;
+
+                fn mul(self, other: $rhs) -> Self::Output {
+                    // Only the upper triangle of `other` is read
+                    Matrix::from(core::array::from_fn(|i| {
+                        core::array::from_fn(|j| {
+                            (0..N).fold(T::zero(), |sum, k| sum + self[(i, k)].clone() * other.matrix[(k.min(j), k.max(j))].clone())
+                        })
+                    }))
+                }
+            }
+        )*
+    };
+}
+
+impl_matrix_symmetric_mul!(
+    Matrix<T, R, N>, Symmetric<T, N>;
+    &Matrix<T, R, N>, Symmetric<T, N>;
+    Matrix<T, R, N>, &Symmetric<T, N>;
+    &Matrix<T, R, N>, &Symmetric<T, N>
+);
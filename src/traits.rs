@@ -1,6 +1,31 @@
+use crate::cast;
+use crate::compare;
+use crate::overflow;
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use core::marker::PhantomData;
+#[cfg(feature = "complex")]
+use core::ops::Neg;
+use core::ops::{Add, Index, IndexMut, Mul};
+#[cfg(feature = "complex")]
+use num_complex::Complex;
+#[cfg(feature = "complex")]
+use num_traits::Num;
+#[cfg(feature = "rational")]
+use num_integer::Integer;
+#[cfg(feature = "rational")]
+use num_rational::Ratio;
+use num_traits::float::FloatCore;
+use num_traits::{
+    AsPrimitive, Bounded, CheckedAdd, CheckedMul, CheckedSub, NumCast, SaturatingAdd, SaturatingMul, SaturatingSub,
+    ToPrimitive, WrappingAdd, WrappingMul, WrappingSub, Zero,
+};
+
 /// Trait for the dot product operation.
 ///
 /// The [dot product](https://en.wikipedia.org/wiki/Dot_product) is the sum of the element-wise products of two vectors.
+/// For complex vectors, [`DotProduct::dotc`] conjugates the elements of `self` first, giving the
+/// [Hermitian inner product](https://en.wikipedia.org/wiki/Inner_product_space) `Σ conj(aᵢ) bᵢ`.
 ///
 /// The `dot` method is only defined for [`Vector`](crate::vector::Vector) and [`RowVector`](crate::row_vector::RowVector).
 /// However, the `dot` method accepts as an argument a [`Matrix`](crate::matrix::Matrix) or any of its views,
@@ -41,6 +66,695 @@ pub trait DotProduct<V> {
 
     /// Computes the dot product of two vectors.
     fn dot(self, other: V) -> Self::Output;
+
+    /// Computes the Hermitian inner product of two vectors, conjugating the elements of `self`.
+    ///
+    /// For real element types this is the same as [`DotProduct::dot`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "complex")]
+    /// # {
+    /// use ferrix::{Complex, DotProduct, Vector};
+    ///
+    /// let a = Vector::from([Complex::new(0.0, 1.0), Complex::new(2.0, 0.0)]);
+    /// assert_eq!((&a).dot(&a), Complex::new(3.0, 0.0));
+    /// assert_eq!((&a).dotc(&a), Complex::new(5.0, 0.0));
+    /// # }
+    /// ```
+    fn dotc(self, other: V) -> Self::Output
+    where
+        Self::Output: Conjugate;
+}
+
+/// Trait for complex conjugation.
+///
+/// Real numbers are their own conjugate. With the `complex` feature, this trait is also implemented for
+/// [`Complex`](num_complex::Complex), which is what makes [`DotProduct::dotc`] and `magnitude` Hermitian.
+/// With the `rational` feature, it is also implemented for [`Ratio`](num_rational::Ratio), and with the
+/// `half` feature for [`f16`](half::f16) and [`bf16`](half::bf16). [`DotProduct::dot`] does not
+/// require it.
+///
+/// # Example
+///
+/// ```
+/// use ferrix::Conjugate;
+///
+/// assert_eq!(3.0.conj(), 3.0);
+/// assert_eq!((-2).re(), -2);
+/// ```
+pub trait Conjugate: Clone {
+    /// The type of the real part.
+    type Real;
+
+    /// Returns the complex conjugate.
+    fn conj(self) -> Self;
+
+    /// Returns the real part.
+    fn re(self) -> Self::Real;
+}
+
+macro_rules! impl_conjugate_real {
+    ($($t:ty),*) => {
+        $(
+            impl Conjugate for $t {
+                type Real = $t;
+
+                #[inline]
+                fn conj(self) -> Self {
+                    self
+                }
+
+                #[inline]
+                fn re(self) -> Self::Real {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_conjugate_real!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+#[cfg(feature = "half")]
+impl_conjugate_real!(half::f16, half::bf16);
+
+#[cfg(feature = "complex")]
+impl<T: Clone + Num + Neg<Output = T>> Conjugate for Complex<T> {
+    type Real = T;
+
+    #[inline]
+    fn conj(self) -> Self {
+        Complex::new(self.re, -self.im)
+    }
+
+    #[inline]
+    fn re(self) -> Self::Real {
+        self.re
+    }
+}
+
+#[cfg(feature = "rational")]
+impl<T: Clone + Integer> Conjugate for Ratio<T> {
+    type Real = Self;
+
+    #[inline]
+    fn conj(self) -> Self {
+        self
+    }
+
+    #[inline]
+    fn re(self) -> Self::Real {
+        self
+    }
+}
+
+/// Trait for linear maps from `M`-dimensional to `N`-dimensional vectors.
+///
+/// Implemented for [`Matrix`](crate::matrix::Matrix), every matrix view,
+/// [`SparseMatrix`](crate::SparseMatrix) and closures `Fn(&Vector<T, M>) -> Vector<T, N>`, so that
+/// generic algorithms such as the iterative solvers in this crate also accept matrix-free operators.
+/// Operators that can also apply their transpose implement [`TransposeOperator`].
+///
+/// # Example
+///
+/// ```
+/// use ferrix::{LinearOperator, Matrix, Vector};
+///
+/// let a = Matrix::from([[2.0, 1.0], [0.0, 3.0]]);
+/// let x = Vector::from([1.0, 1.0]);
+/// assert_eq!(a.apply(&x), Vector::from([3.0, 3.0]));
+///
+/// // The same map without storing the matrix
+/// let f = |x: &Vector<f64, 2>| Vector::from([2.0 * x[0] + x[1], 3.0 * x[1]]);
+/// assert_eq!(f.apply(&x), Vector::from([3.0, 3.0]));
+/// ```
+pub trait LinearOperator<T, const N: usize, const M: usize> {
+    /// Applies the operator to `x`.
+    fn apply(&self, x: &Vector<T, M>) -> Vector<T, N>;
+}
+
+/// Trait for [`LinearOperator`]s that can also apply their transpose.
+///
+/// Implemented for [`Matrix`](crate::matrix::Matrix), every matrix view,
+/// [`SparseMatrix`](crate::SparseMatrix) and pairs of closures `(f, f_t)`, where `f_t` applies the
+/// transpose of `f`. A single closure does not know its transpose and so only implements
+/// [`LinearOperator`].
+///
+/// # Example
+///
+/// ```
+/// use ferrix::{LinearOperator, Matrix, TransposeOperator, Vector};
+///
+/// let a = Matrix::from([[2.0, 1.0], [0.0, 3.0]]);
+/// let x = Vector::from([1.0, 1.0]);
+/// assert_eq!(a.apply_transpose(&x), Vector::from([2.0, 4.0]));
+///
+/// // The same map without storing the matrix
+/// let f = |x: &Vector<f64, 2>| Vector::from([2.0 * x[0] + x[1], 3.0 * x[1]]);
+/// let f_t = |x: &Vector<f64, 2>| Vector::from([2.0 * x[0], x[0] + 3.0 * x[1]]);
+/// assert_eq!((f, f_t).apply(&x), Vector::from([3.0, 3.0]));
+/// assert_eq!((f, f_t).apply_transpose(&x), Vector::from([2.0, 4.0]));
+/// ```
+pub trait TransposeOperator<T, const N: usize, const M: usize>: LinearOperator<T, N, M> {
+    /// Applies the transpose of the operator to `x`.
+    fn apply_transpose(&self, x: &Vector<T, N>) -> Vector<T, M>;
+}
+
+impl<T, F: Fn(&Vector<T, M>) -> Vector<T, N>, const N: usize, const M: usize> LinearOperator<T, N, M> for F {
+    fn apply(&self, x: &Vector<T, M>) -> Vector<T, N> {
+        self(x)
+    }
+}
+
+impl<T, F, G, const N: usize, const M: usize> LinearOperator<T, N, M> for (F, G)
+where
+    F: Fn(&Vector<T, M>) -> Vector<T, N>,
+    G: Fn(&Vector<T, N>) -> Vector<T, M>,
+{
+    fn apply(&self, x: &Vector<T, M>) -> Vector<T, N> {
+        (self.0)(x)
+    }
+}
+
+impl<T, F, G, const N: usize, const M: usize> TransposeOperator<T, N, M> for (F, G)
+where
+    F: Fn(&Vector<T, M>) -> Vector<T, N>,
+    G: Fn(&Vector<T, N>) -> Vector<T, M>,
+{
+    fn apply_transpose(&self, x: &Vector<T, N>) -> Vector<T, M> {
+        (self.1)(x)
+    }
+}
+
+/// Trait for read access to an `R x C` matrix.
+///
+/// Implemented for [`Matrix`](crate::matrix::Matrix), [`MatrixView`](crate::MatrixView),
+/// [`MatrixViewMut`](crate::MatrixViewMut), [`MatrixTransposeView`](crate::MatrixTransposeView) and
+/// [`MatrixTransposeViewMut`](crate::MatrixTransposeViewMut), so that functions can accept any of them.
+/// The provided methods always return an owned [`Matrix`](crate::matrix::Matrix) or [`Vector`].
+///
+/// # Example
+///
+/// ```
+/// use ferrix::{Matrix, MatrixRef};
+///
+/// fn trace<const N: usize>(m: &impl MatrixRef<i32, N, N>) -> i32 {
+///     (0..N).map(|i| m[(i, i)]).sum()
+/// }
+///
+/// let a = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+/// assert_eq!(trace(&a.view::<2, 2>((0, 0)).unwrap()), 6);
+/// assert_eq!(trace(&a.view::<2, 2>((1, 1)).unwrap()), 14);
+/// assert_eq!(trace(&a.t()), 15);
+/// ```
+pub trait MatrixRef<T, const R: usize, const C: usize>: Index<(usize, usize), Output = T> {
+    /// Returns the shape of the matrix, i.e. `(R, C)`.
+    fn shape(&self) -> (usize, usize) {
+        (R, C)
+    }
+
+    /// Returns a reference to the element at `index`, or `None` if it is out of bounds.
+    fn get(&self, index: (usize, usize)) -> Option<&T> {
+        (index.0 < R && index.1 < C).then(|| &self[index])
+    }
+
+    /// Copies the elements into a new [`Matrix`](crate::matrix::Matrix).
+    fn to_matrix(&self) -> Matrix<T, R, C>
+    where
+        T: Clone,
+    {
+        self.map(|x| x)
+    }
+
+    /// Returns a [`Matrix`](crate::matrix::Matrix) with `f` applied to each element.
+    fn map<U, F: Fn(T) -> U>(&self, f: F) -> Matrix<U, R, C>
+    where
+        T: Clone,
+    {
+        Matrix::from(core::array::from_fn(|i| core::array::from_fn(|j| f(self[(i, j)].clone()))))
+    }
+
+    /// Returns a [`Matrix`](crate::matrix::Matrix) with `f` applied to each pair of elements of
+    /// `self` and `other`.
+    fn zip_map<U, M: MatrixRef<T, R, C> + ?Sized, F: Fn(T, T) -> U>(&self, other: &M, f: F) -> Matrix<U, R, C>
+    where
+        T: Clone,
+    {
+        Matrix::from(core::array::from_fn(|i| core::array::from_fn(|j| f(self[(i, j)].clone(), other[(i, j)].clone()))))
+    }
+
+    /// Returns the matrix product of `self` and `other`.
+    fn matmul<M: MatrixRef<T, C, P> + ?Sized, const P: usize>(&self, other: &M) -> Matrix<T, R, P>
+    where
+        T: Clone + Zero + Add<T, Output = T> + Mul<T, Output = T>,
+    {
+        Matrix::from(core::array::from_fn(|i| {
+            core::array::from_fn(|j| (0..C).fold(T::zero(), |acc, k| acc + (self[(i, k)].clone() * other[(k, j)].clone())))
+        }))
+    }
+
+    /// Returns the product of `self` and the vector `x`.
+    fn matvec<V: VectorRef<T, C> + ?Sized>(&self, x: &V) -> Vector<T, R>
+    where
+        T: Clone + Zero + Add<T, Output = T> + Mul<T, Output = T>,
+    {
+        Vector::from(core::array::from_fn(|i| (0..C).fold(T::zero(), |acc, j| acc + (self[(i, j)].clone() * x[j].clone()))))
+    }
+
+    /// Returns the matrix product of `self` and `other`, computed in the accumulator type `A`.
+    ///
+    /// Each element is converted to `A` as it is read, so that e.g. an `f32` product is summed in
+    /// `f64`, or an `f16` product in `f32`, without copying either operand.
+    fn matmul_acc<A, M: MatrixRef<T, C, P> + ?Sized, const P: usize>(&self, other: &M) -> Matrix<A, R, P>
+    where
+        T: Clone,
+        A: From<T> + Zero + Add<A, Output = A> + Mul<A, Output = A>,
+    {
+        Matrix::from(core::array::from_fn(|i| {
+            core::array::from_fn(|j| {
+                (0..C).fold(A::zero(), |acc, k| acc + A::from(self[(i, k)].clone()) * A::from(other[(k, j)].clone()))
+            })
+        }))
+    }
+
+    /// Returns the product of `self` and the vector `x`, computed in the accumulator type `A`.
+    fn matvec_acc<A, V: VectorRef<T, C> + ?Sized>(&self, x: &V) -> Vector<A, R>
+    where
+        T: Clone,
+        A: From<T> + Zero + Add<A, Output = A> + Mul<A, Output = A>,
+    {
+        Vector::from(core::array::from_fn(|i| {
+            (0..C).fold(A::zero(), |acc, j| acc + A::from(self[(i, j)].clone()) * A::from(x[j].clone()))
+        }))
+    }
+
+    /// Returns `self + other`, or `None` if any element overflows.
+    fn checked_add<M: MatrixRef<T, R, C> + ?Sized>(&self, other: &M) -> Option<Matrix<T, R, C>>
+    where
+        T: Clone + CheckedAdd,
+    {
+        cast::transpose_matrix(self.zip_map(other, |a, b| a.checked_add(&b)))
+    }
+
+    /// Returns `self - other`, or `None` if any element overflows.
+    fn checked_sub<M: MatrixRef<T, R, C> + ?Sized>(&self, other: &M) -> Option<Matrix<T, R, C>>
+    where
+        T: Clone + CheckedSub,
+    {
+        cast::transpose_matrix(self.zip_map(other, |a, b| a.checked_sub(&b)))
+    }
+
+    /// Returns `self * scalar`, or `None` if any element overflows.
+    fn checked_mul(&self, scalar: T) -> Option<Matrix<T, R, C>>
+    where
+        T: Clone + CheckedMul,
+    {
+        cast::transpose_matrix(self.map(|a| a.checked_mul(&scalar)))
+    }
+
+    /// Returns the matrix product of `self` and `other`, or `None` if any product or partial sum
+    /// overflows.
+    fn checked_matmul<M: MatrixRef<T, C, P> + ?Sized, const P: usize>(&self, other: &M) -> Option<Matrix<T, R, P>>
+    where
+        T: Clone + Zero + CheckedAdd + CheckedMul,
+    {
+        overflow::try_matmul(self, other, |acc, a, b| acc.checked_add(&a.checked_mul(&b)?))
+    }
+
+    /// Returns `self + other`, wrapping around at the bounds of `T`.
+    fn wrapping_add<M: MatrixRef<T, R, C> + ?Sized>(&self, other: &M) -> Matrix<T, R, C>
+    where
+        T: Clone + WrappingAdd,
+    {
+        self.zip_map(other, |a, b| a.wrapping_add(&b))
+    }
+
+    /// Returns `self - other`, wrapping around at the bounds of `T`.
+    fn wrapping_sub<M: MatrixRef<T, R, C> + ?Sized>(&self, other: &M) -> Matrix<T, R, C>
+    where
+        T: Clone + WrappingSub,
+    {
+        self.zip_map(other, |a, b| a.wrapping_sub(&b))
+    }
+
+    /// Returns `self * scalar`, wrapping around at the bounds of `T`.
+    fn wrapping_mul(&self, scalar: T) -> Matrix<T, R, C>
+    where
+        T: Clone + WrappingMul,
+    {
+        self.map(|a| a.wrapping_mul(&scalar))
+    }
+
+    /// Returns the matrix product of `self` and `other`, wrapping around at the bounds of `T`.
+    fn wrapping_matmul<M: MatrixRef<T, C, P> + ?Sized, const P: usize>(&self, other: &M) -> Matrix<T, R, P>
+    where
+        T: Clone + Zero + WrappingAdd + WrappingMul,
+    {
+        overflow::try_matmul(self, other, |acc, a, b| Some(acc.wrapping_add(&a.wrapping_mul(&b)))).unwrap()
+    }
+
+    /// Returns `self + other`, clamping each element to the bounds of `T`.
+    fn saturating_add<M: MatrixRef<T, R, C> + ?Sized>(&self, other: &M) -> Matrix<T, R, C>
+    where
+        T: Clone + SaturatingAdd,
+    {
+        self.zip_map(other, |a, b| a.saturating_add(&b))
+    }
+
+    /// Returns `self - other`, clamping each element to the bounds of `T`.
+    fn saturating_sub<M: MatrixRef<T, R, C> + ?Sized>(&self, other: &M) -> Matrix<T, R, C>
+    where
+        T: Clone + SaturatingSub,
+    {
+        self.zip_map(other, |a, b| a.saturating_sub(&b))
+    }
+
+    /// Returns `self * scalar`, clamping each element to the bounds of `T`.
+    fn saturating_mul(&self, scalar: T) -> Matrix<T, R, C>
+    where
+        T: Clone + SaturatingMul,
+    {
+        self.map(|a| a.saturating_mul(&scalar))
+    }
+
+    /// Returns the matrix product of `self` and `other`, clamping each product and partial sum to
+    /// the bounds of `T`. The partial sums are accumulated in order of increasing `k`.
+    fn saturating_matmul<M: MatrixRef<T, C, P> + ?Sized, const P: usize>(&self, other: &M) -> Matrix<T, R, P>
+    where
+        T: Clone + Zero + SaturatingAdd + SaturatingMul,
+    {
+        overflow::try_matmul(self, other, |acc, a, b| Some(acc.saturating_add(&a.saturating_mul(&b)))).unwrap()
+    }
+
+    /// Returns a [`Matrix`](crate::matrix::Matrix) with each element converted to `U`, or `None` if
+    /// any element is out of range of `U`. A finite float that would round to infinity is out of range.
+    fn cast<U: NumCast>(&self) -> Option<Matrix<U, R, C>>
+    where
+        T: Clone + ToPrimitive,
+    {
+        cast::transpose_matrix(self.map(cast::checked))
+    }
+
+    /// Returns a [`Matrix`](crate::matrix::Matrix) with each element converted to `U`. Elements that
+    /// are out of range are clamped to the bounds of `U`. NaN becomes zero if `U` is an integer type
+    /// and stays NaN if `U` is a float type.
+    fn cast_saturating<U: NumCast + Bounded + Zero>(&self) -> Matrix<U, R, C>
+    where
+        T: Clone + ToPrimitive + PartialOrd + Zero,
+    {
+        self.map(cast::saturating)
+    }
+
+    /// Returns a [`Matrix`](crate::matrix::Matrix) with each element converted to `U` as if by `as`,
+    /// which truncates, wraps or rounds instead of failing.
+    fn cast_lossy<U: 'static + Copy>(&self) -> Matrix<U, R, C>
+    where
+        T: AsPrimitive<U>,
+    {
+        self.map(|x| x.as_())
+    }
+
+    /// Returns `true` if each element of `self` is within `epsilon` of the corresponding
+    /// element of `other`.
+    fn approx_eq_abs<M: MatrixRef<T, R, C> + ?Sized>(&self, other: &M, epsilon: T) -> bool
+    where
+        T: FloatCore,
+    {
+        (0..R).all(|i| (0..C).all(|j| compare::approx_eq_abs(self[(i, j)], other[(i, j)], epsilon)))
+    }
+
+    /// Returns `true` if each element of `self` is within `epsilon` of the corresponding
+    /// element of `other`, or within `max_relative` times the larger of their magnitudes.
+    fn approx_eq_rel<M: MatrixRef<T, R, C> + ?Sized>(&self, other: &M, epsilon: T, max_relative: T) -> bool
+    where
+        T: FloatCore,
+    {
+        (0..R).all(|i| (0..C).all(|j| compare::approx_eq_rel(self[(i, j)], other[(i, j)], epsilon, max_relative)))
+    }
+}
+
+/// Trait for write access to an `R x C` matrix.
+///
+/// Implemented for [`Matrix`](crate::matrix::Matrix), [`MatrixViewMut`](crate::MatrixViewMut) and
+/// [`MatrixTransposeViewMut`](crate::MatrixTransposeViewMut).
+///
+/// # Example
+///
+/// ```
+/// use ferrix::{Matrix, MatrixMut};
+///
+/// fn double<const R: usize, const C: usize>(m: &mut impl MatrixMut<i32, R, C>) {
+///     m.map_inplace(|x| 2 * x);
+/// }
+///
+/// let mut a = Matrix::from([[1, 2], [3, 4]]);
+/// double(&mut a.t_mut());
+/// assert_eq!(a, Matrix::from([[2, 4], [6, 8]]));
+/// ```
+pub trait MatrixMut<T, const R: usize, const C: usize>: MatrixRef<T, R, C> + IndexMut<(usize, usize)> {
+    /// Returns a mutable reference to the element at `index`, or `None` if it is out of bounds.
+    fn get_mut(&mut self, index: (usize, usize)) -> Option<&mut T> {
+        (index.0 < R && index.1 < C).then(|| &mut self[index])
+    }
+
+    /// Copies the elements of `other` into `self`.
+    fn copy_from<M: MatrixRef<T, R, C> + ?Sized>(&mut self, other: &M)
+    where
+        T: Clone,
+    {
+        for i in 0..R {
+            for j in 0..C {
+                self[(i, j)] = other[(i, j)].clone();
+            }
+        }
+    }
+
+    /// Replaces each element `x` with `f(x)`.
+    fn map_inplace<F: Fn(T) -> T>(&mut self, f: F)
+    where
+        T: Clone,
+    {
+        for i in 0..R {
+            for j in 0..C {
+                self[(i, j)] = f(self[(i, j)].clone());
+            }
+        }
+    }
+}
+
+/// Trait for read access to a vector of length `N`.
+///
+/// Implemented for [`Vector`], [`RowVector`](crate::row_vector::RowVector) and their views.
+/// The provided methods always return an owned [`Vector`].
+///
+/// # Example
+///
+/// ```
+/// use ferrix::{RowVector, Vector, VectorRef};
+///
+/// fn sum<const N: usize>(v: &impl VectorRef<i32, N>) -> i32 {
+///     (0..N).map(|i| v[i]).sum()
+/// }
+///
+/// let a = Vector::from([1, 2, 3, 4]);
+/// assert_eq!(sum(&a), 10);
+/// assert_eq!(sum(&a.view::<2>(1).unwrap()), 5);
+/// assert_eq!(sum(&RowVector::from([5, 6])), 11);
+/// ```
+pub trait VectorRef<T, const N: usize>: Index<usize, Output = T> {
+    /// Returns the length of the vector, i.e. `N`.
+    fn shape(&self) -> usize {
+        N
+    }
+
+    /// Returns a reference to the element at `index`, or `None` if it is out of bounds.
+    fn get(&self, index: usize) -> Option<&T> {
+        (index < N).then(|| &self[index])
+    }
+
+    /// Copies the elements into a new [`Vector`].
+    fn to_vector(&self) -> Vector<T, N>
+    where
+        T: Clone,
+    {
+        self.map(|x| x)
+    }
+
+    /// Returns a [`Vector`] with `f` applied to each element.
+    fn map<U, F: Fn(T) -> U>(&self, f: F) -> Vector<U, N>
+    where
+        T: Clone,
+    {
+        Vector::from(core::array::from_fn(|i| f(self[i].clone())))
+    }
+
+    /// Returns a [`Vector`] with `f` applied to each pair of elements of `self` and `other`.
+    fn zip_map<U, V: VectorRef<T, N> + ?Sized, F: Fn(T, T) -> U>(&self, other: &V, f: F) -> Vector<U, N>
+    where
+        T: Clone,
+    {
+        Vector::from(core::array::from_fn(|i| f(self[i].clone(), other[i].clone())))
+    }
+
+    /// Returns `self + other`, or `None` if any element overflows.
+    fn checked_add<V: VectorRef<T, N> + ?Sized>(&self, other: &V) -> Option<Vector<T, N>>
+    where
+        T: Clone + CheckedAdd,
+    {
+        cast::transpose_vector(self.zip_map(other, |a, b| a.checked_add(&b)))
+    }
+
+    /// Returns `self - other`, or `None` if any element overflows.
+    fn checked_sub<V: VectorRef<T, N> + ?Sized>(&self, other: &V) -> Option<Vector<T, N>>
+    where
+        T: Clone + CheckedSub,
+    {
+        cast::transpose_vector(self.zip_map(other, |a, b| a.checked_sub(&b)))
+    }
+
+    /// Returns `self * scalar`, or `None` if any element overflows.
+    fn checked_mul(&self, scalar: T) -> Option<Vector<T, N>>
+    where
+        T: Clone + CheckedMul,
+    {
+        cast::transpose_vector(self.map(|a| a.checked_mul(&scalar)))
+    }
+
+    /// Returns `self + other`, wrapping around at the bounds of `T`.
+    fn wrapping_add<V: VectorRef<T, N> + ?Sized>(&self, other: &V) -> Vector<T, N>
+    where
+        T: Clone + WrappingAdd,
+    {
+        self.zip_map(other, |a, b| a.wrapping_add(&b))
+    }
+
+    /// Returns `self - other`, wrapping around at the bounds of `T`.
+    fn wrapping_sub<V: VectorRef<T, N> + ?Sized>(&self, other: &V) -> Vector<T, N>
+    where
+        T: Clone + WrappingSub,
+    {
+        self.zip_map(other, |a, b| a.wrapping_sub(&b))
+    }
+
+    /// Returns `self * scalar`, wrapping around at the bounds of `T`.
+    fn wrapping_mul(&self, scalar: T) -> Vector<T, N>
+    where
+        T: Clone + WrappingMul,
+    {
+        self.map(|a| a.wrapping_mul(&scalar))
+    }
+
+    /// Returns `self + other`, clamping each element to the bounds of `T`.
+    fn saturating_add<V: VectorRef<T, N> + ?Sized>(&self, other: &V) -> Vector<T, N>
+    where
+        T: Clone + SaturatingAdd,
+    {
+        self.zip_map(other, |a, b| a.saturating_add(&b))
+    }
+
+    /// Returns `self - other`, clamping each element to the bounds of `T`.
+    fn saturating_sub<V: VectorRef<T, N> + ?Sized>(&self, other: &V) -> Vector<T, N>
+    where
+        T: Clone + SaturatingSub,
+    {
+        self.zip_map(other, |a, b| a.saturating_sub(&b))
+    }
+
+    /// Returns `self * scalar`, clamping each element to the bounds of `T`.
+    fn saturating_mul(&self, scalar: T) -> Vector<T, N>
+    where
+        T: Clone + SaturatingMul,
+    {
+        self.map(|a| a.saturating_mul(&scalar))
+    }
+
+    /// Returns a [`Vector`] with each element converted to `U`, or `None` if any element is out of
+    /// range of `U`. A finite float that would round to infinity is out of range.
+    fn cast<U: NumCast>(&self) -> Option<Vector<U, N>>
+    where
+        T: Clone + ToPrimitive,
+    {
+        cast::transpose_vector(self.map(cast::checked))
+    }
+
+    /// Returns a [`Vector`] with each element converted to `U`. Elements that are out of range are
+    /// clamped to the bounds of `U`. NaN becomes zero if `U` is an integer type and stays NaN if `U`
+    /// is a float type.
+    fn cast_saturating<U: NumCast + Bounded + Zero>(&self) -> Vector<U, N>
+    where
+        T: Clone + ToPrimitive + PartialOrd + Zero,
+    {
+        self.map(cast::saturating)
+    }
+
+    /// Returns a [`Vector`] with each element converted to `U` as if by `as`, which truncates, wraps
+    /// or rounds instead of failing.
+    fn cast_lossy<U: 'static + Copy>(&self) -> Vector<U, N>
+    where
+        T: AsPrimitive<U>,
+    {
+        self.map(|x| x.as_())
+    }
+
+    /// Returns `true` if each element of `self` is within `epsilon` of the corresponding
+    /// element of `other`.
+    fn approx_eq_abs<V: VectorRef<T, N> + ?Sized>(&self, other: &V, epsilon: T) -> bool
+    where
+        T: FloatCore,
+    {
+        (0..N).all(|i| compare::approx_eq_abs(self[i], other[i], epsilon))
+    }
+
+    /// Returns `true` if each element of `self` is within `epsilon` of the corresponding
+    /// element of `other`, or within `max_relative` times the larger of their magnitudes.
+    fn approx_eq_rel<V: VectorRef<T, N> + ?Sized>(&self, other: &V, epsilon: T, max_relative: T) -> bool
+    where
+        T: FloatCore,
+    {
+        (0..N).all(|i| compare::approx_eq_rel(self[i], other[i], epsilon, max_relative))
+    }
+}
+
+/// Trait for write access to a vector of length `N`.
+///
+/// Implemented for [`Vector`], [`RowVector`](crate::row_vector::RowVector) and their mutable views.
+///
+/// # Example
+///
+/// ```
+/// use ferrix::{Vector, VectorMut};
+///
+/// let mut a = Vector::from([1, 2, 3, 4]);
+/// a.view_mut::<2>(2).unwrap().copy_from(&Vector::from([0, 0]));
+/// assert_eq!(a, Vector::from([1, 2, 0, 0]));
+/// ```
+pub trait VectorMut<T, const N: usize>: VectorRef<T, N> + IndexMut<usize> {
+    /// Returns a mutable reference to the element at `index`, or `None` if it is out of bounds.
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        (index < N).then(|| &mut self[index])
+    }
+
+    /// Copies the elements of `other` into `self`.
+    fn copy_from<V: VectorRef<T, N> + ?Sized>(&mut self, other: &V)
+    where
+        T: Clone,
+    {
+        for i in 0..N {
+            self[i] = other[i].clone();
+        }
+    }
+
+    /// Replaces each element `x` with `f(x)`.
+    fn map_inplace<F: Fn(T) -> T>(&mut self, f: F)
+    where
+        T: Clone,
+    {
+        for i in 0..N {
+            self[i] = f(self[i].clone());
+        }
+    }
 }
 
 /// Trait for integer random number generation.
@@ -56,6 +770,7 @@ pub trait DotProduct<V> {
 ///
 /// let a = Vector::<i32, 3>::random();
 /// ```
+#[cfg(feature = "rand")]
 pub trait IntRandom {
     /// Will generate random integers uniformly across all values of the type.
     fn random() -> Self;
@@ -74,7 +789,148 @@ pub trait IntRandom {
 ///
 /// let a = Vector::<f32, 3>::random();
 /// ```
+#[cfg(feature = "rand")]
 pub trait FloatRandom {
     /// Will generate random floating-point numbers uniformly in the range `[-1, 1]`.
     fn random() -> Self;
 }
+
+/// Trait for types backed by a single contiguous array.
+///
+/// Used internally to hand the storage of [`Vector`](crate::vector::Vector), [`RowVector`](crate::row_vector::RowVector),
+/// [`Matrix`](crate::matrix::Matrix) and [`MatrixCM`](crate::matrix_cm::MatrixCM) to the optimized kernels.
+///
+/// Linear indices follow the storage order, which is column-major for `MatrixCM`. The element-wise
+/// SIMD paths only pair up operands of the same type, so they do not depend on it. The matrix-vector
+/// product is only routed through here for the row-major `Matrix`.
+pub(crate) trait Contiguous<T>: Sized {
+    /// Creates a new instance where each element is initialized from its linear index.
+    fn from_fn<F: FnMut(usize) -> T>(f: F) -> Self;
+
+    /// Returns the elements in storage order.
+    fn as_slice(&self) -> &[T];
+
+    /// Returns the elements in storage order.
+    fn as_mut_slice(&mut self) -> &mut [T];
+}
+
+/// Borrowed, strided description of a matrix-like operand.
+///
+/// Element `(i, j)` lives at `ptr.add(offset + i * rs + j * cs)`. The description holds a
+/// raw pointer rather than a slice so that a view only ever reads the elements inside its
+/// own block, while other views of the same matrix may be writing to theirs.
+#[derive(Debug)]
+pub(crate) struct StridedRef<'a, T> {
+    pub(crate) ptr: *const T,
+    pub(crate) offset: usize,
+    pub(crate) rs: usize,
+    pub(crate) cs: usize,
+    pub(crate) _marker: PhantomData<&'a T>,
+}
+
+// SAFETY: a `StridedRef` behaves like a `&'a` to the elements it describes.
+unsafe impl<T: Sync> Send for StridedRef<'_, T> {}
+
+// SAFETY: a `StridedRef` only ever reads the elements it describes.
+unsafe impl<T: Sync> Sync for StridedRef<'_, T> {}
+
+// Implemented by hand so that the description is `Copy` even when `T` is not
+impl<T> Clone for StridedRef<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for StridedRef<'_, T> {}
+
+impl<'a, T> StridedRef<'a, T> {
+    /// Describes the elements of `data` with the given strides.
+    #[inline]
+    pub(crate) fn new(data: &'a [T], rs: usize, cs: usize) -> Self {
+        Self {
+            ptr: data.as_ptr(),
+            offset: 0,
+            rs,
+            cs,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Describes the elements starting at `ptr.add(offset)` with the given strides.
+    ///
+    /// # Safety
+    ///
+    /// Every element the description is used to read must be valid for reads for `'a`, and
+    /// must not be written to during `'a`.
+    #[inline]
+    pub(crate) unsafe fn from_raw(ptr: *const T, offset: usize, rs: usize, cs: usize) -> Self {
+        Self {
+            ptr,
+            offset,
+            rs,
+            cs,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns a pointer to element `(0, 0)`.
+    #[inline]
+    pub(crate) fn as_ptr(&self) -> *const T {
+        self.ptr.wrapping_add(self.offset)
+    }
+
+    /// Returns the `len` elements starting at element `(0, 0)`.
+    ///
+    /// # Safety
+    ///
+    /// The described operand must store those `len` elements contiguously.
+    #[inline]
+    pub(crate) unsafe fn as_slice(&self, len: usize) -> &'a [T] {
+        core::slice::from_raw_parts(self.as_ptr(), len)
+    }
+
+    /// Returns the description of the same elements reinterpreted as `U`.
+    ///
+    /// # Safety
+    ///
+    /// `T` and `U` must be the same type.
+    #[inline]
+    pub(crate) unsafe fn cast<U>(self) -> StridedRef<'a, U> {
+        StridedRef::from_raw(self.ptr.cast(), self.offset, self.rs, self.cs)
+    }
+}
+
+impl<T: Clone> StridedRef<'_, T> {
+    /// Returns a clone of element `(i, j)`.
+    ///
+    /// # Safety
+    ///
+    /// `(i, j)` must lie inside the described operand. The description does not know its own
+    /// shape, so this cannot be checked here.
+    #[inline]
+    pub(crate) unsafe fn at(&self, i: usize, j: usize) -> T {
+        (*self.ptr.add(self.offset + i * self.rs + j * self.cs)).clone()
+    }
+}
+
+impl<T> StridedRef<'_, T> {
+    /// Returns the description of the transposed operand.
+    #[inline]
+    pub(crate) fn t(self) -> Self {
+        Self {
+            rs: self.cs,
+            cs: self.rs,
+            ..self
+        }
+    }
+}
+
+/// Trait for matrix types that can describe their elements with fixed strides.
+///
+/// Used internally so that [`MatrixView`](crate::matrix_view::MatrixView) and
+/// [`MatrixTransposeView`](crate::matrix_transpose_view::MatrixTransposeView) operands
+/// can be packed by the blocked matrix multiplication instead of being indexed.
+pub(crate) trait Strided<T> {
+    /// Returns the strided description of `self`.
+    fn strided(&self) -> StridedRef<'_, T>;
+}
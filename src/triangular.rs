@@ -0,0 +1,373 @@
+//! Upper and lower triangular matrices.
+//!
+//! [`UpperTriangular`] and [`LowerTriangular`] keep the elements outside their triangle at zero, so
+//! products only visit the triangle and `solve` is a single back or forward substitution. Dense
+//! right-hand sides may be a [`Vector`], a [`Matrix`], a reference to either or one of their views.
+
+use core::ops::{Index, Mul};
+use num_traits::{Num, Zero};
+
+use crate::matrix::Matrix;
+use crate::matrix_cm::MatrixCM;
+use crate::matrix_strided_view::MatrixStridedView;
+use crate::matrix_strided_view_mut::MatrixStridedViewMut;
+use crate::matrix_transpose_view::MatrixTransposeView;
+use crate::matrix_transpose_view_mut::MatrixTransposeViewMut;
+use crate::matrix_view::MatrixView;
+use crate::matrix_view_mut::MatrixViewMut;
+use crate::traits::{MatrixRef, VectorRef};
+use crate::vector::Vector;
+use crate::vector_view::VectorView;
+use crate::vector_view_mut::VectorViewMut;
+
+/// A static upper triangular matrix type.
+///
+/// The elements below the diagonal are always zero, so products skip them and
+/// [`UpperTriangular::solve`] is a back substitution.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpperTriangular<T, const N: usize> {
+    matrix: Matrix<T, N, N>,
+}
+
+/// A static lower triangular matrix type.
+///
+/// The elements above the diagonal are always zero, so products skip them and
+/// [`LowerTriangular::solve`] is a forward substitution.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LowerTriangular<T, const N: usize> {
+    matrix: Matrix<T, N, N>,
+}
+
+impl<T: Zero, const N: usize> UpperTriangular<T, N> {
+    /// Creates a new [`UpperTriangular`] matrix from a [`Matrix`].
+    ///
+    /// Returns `None` if any element below the diagonal is nonzero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{Matrix, UpperTriangular};
+    ///
+    /// assert!(UpperTriangular::new(Matrix::from([[1, 2], [0, 3]])).is_some());
+    /// assert!(UpperTriangular::new(Matrix::from([[1, 2], [4, 3]])).is_none());
+    /// ```
+    pub fn new(matrix: Matrix<T, N, N>) -> Option<Self> {
+        let triangular = (0..N).all(|i| (0..i).all(|j| matrix[(i, j)].is_zero()));
+        triangular.then_some(Self { matrix })
+    }
+}
+
+impl<T: Zero, const N: usize> LowerTriangular<T, N> {
+    /// Creates a new [`LowerTriangular`] matrix from a [`Matrix`].
+    ///
+    /// Returns `None` if any element above the diagonal is nonzero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{LowerTriangular, Matrix};
+    ///
+    /// assert!(LowerTriangular::new(Matrix::from([[1, 0], [2, 3]])).is_some());
+    /// assert!(LowerTriangular::new(Matrix::from([[1, 4], [2, 3]])).is_none());
+    /// ```
+    pub fn new(matrix: Matrix<T, N, N>) -> Option<Self> {
+        let triangular = (0..N).all(|i| (i + 1..N).all(|j| matrix[(i, j)].is_zero()));
+        triangular.then_some(Self { matrix })
+    }
+}
+
+impl<T: Clone + Zero, const N: usize> UpperTriangular<T, N> {
+    /// Creates a new [`UpperTriangular`] matrix from the upper triangle of a [`Matrix`].
+    ///
+    /// The elements below the diagonal are replaced with zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{Matrix, UpperTriangular};
+    ///
+    /// let u = UpperTriangular::from_upper(&Matrix::from([[1, 2], [4, 3]]));
+    /// assert_eq!(u.matrix(), &Matrix::from([[1, 2], [0, 3]]));
+    /// ```
+    pub fn from_upper(matrix: &Matrix<T, N, N>) -> Self {
+        let matrix = Matrix::from(core::array::from_fn(|i| {
+            core::array::from_fn(|j| if i <= j { matrix[(i, j)].clone() } else { T::zero() })
+        }));
+        Self { matrix }
+    }
+}
+
+impl<T: Clone + Zero, const N: usize> LowerTriangular<T, N> {
+    /// Creates a new [`LowerTriangular`] matrix from the lower triangle of a [`Matrix`].
+    ///
+    /// The elements above the diagonal are replaced with zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{LowerTriangular, Matrix};
+    ///
+    /// let l = LowerTriangular::from_lower(&Matrix::from([[1, 4], [2, 3]]));
+    /// assert_eq!(l.matrix(), &Matrix::from([[1, 0], [2, 3]]));
+    /// ```
+    pub fn from_lower(matrix: &Matrix<T, N, N>) -> Self {
+        let matrix = Matrix::from(core::array::from_fn(|i| {
+            core::array::from_fn(|j| if i >= j { matrix[(i, j)].clone() } else { T::zero() })
+        }));
+        Self { matrix }
+    }
+}
+
+impl<T, const N: usize> UpperTriangular<T, N> {
+    /// Returns a reference to the underlying [`Matrix`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{Matrix, UpperTriangular};
+    ///
+    /// let u = UpperTriangular::new(Matrix::from([[1, 2], [0, 3]])).unwrap();
+    /// assert_eq!(u.matrix()[(0, 1)], 2);
+    /// ```
+    pub fn matrix(&self) -> &Matrix<T, N, N> {
+        &self.matrix
+    }
+}
+
+impl<T, const N: usize> LowerTriangular<T, N> {
+    /// Returns a reference to the underlying [`Matrix`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{LowerTriangular, Matrix};
+    ///
+    /// let l = LowerTriangular::new(Matrix::from([[1, 0], [2, 3]])).unwrap();
+    /// assert_eq!(l.matrix()[(1, 0)], 2);
+    /// ```
+    pub fn matrix(&self) -> &Matrix<T, N, N> {
+        &self.matrix
+    }
+}
+
+impl<T: Clone + Num, const N: usize> UpperTriangular<T, N> {
+    /// Solves `U x = b` by back substitution.
+    ///
+    /// Returns `None` if a diagonal element is zero. For integer elements the divisions truncate, so
+    /// the solution is only exact when every division is; convert them to a rational type first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{Matrix, UpperTriangular, Vector};
+    ///
+    /// let u = UpperTriangular::new(Matrix::from([[2.0, 1.0], [0.0, 4.0]])).unwrap();
+    /// assert_eq!(u.solve(&Vector::from([4.0, 8.0])), Some(Vector::from([1.0, 2.0])));
+    /// ```
+    pub fn solve<V: VectorRef<T, N> + ?Sized>(&self, b: &V) -> Option<Vector<T, N>> {
+        self.matrix.solve_upper(b)
+    }
+
+    /// Solves `U^T x = b` by forward substitution.
+    ///
+    /// Returns `None` if a diagonal element is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{Matrix, UpperTriangular, Vector};
+    ///
+    /// let u = UpperTriangular::new(Matrix::from([[2.0, 1.0], [0.0, 4.0]])).unwrap();
+    /// assert_eq!(u.solve_transpose(&Vector::from([2.0, 9.0])), Some(Vector::from([1.0, 2.0])));
+    /// ```
+    pub fn solve_transpose<V: VectorRef<T, N> + ?Sized>(&self, b: &V) -> Option<Vector<T, N>> {
+        self.matrix.solve_upper_transpose(b)
+    }
+}
+
+impl<T: Clone + Num, const N: usize> LowerTriangular<T, N> {
+    /// Solves `L x = b` by forward substitution.
+    ///
+    /// Returns `None` if a diagonal element is zero. For integer elements the divisions truncate, so
+    /// the solution is only exact when every division is; convert them to a rational type first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{LowerTriangular, Matrix, Vector};
+    ///
+    /// let l = LowerTriangular::new(Matrix::from([[2.0, 0.0], [1.0, 4.0]])).unwrap();
+    /// assert_eq!(l.solve(&Vector::from([2.0, 9.0])), Some(Vector::from([1.0, 2.0])));
+    /// ```
+    pub fn solve<V: VectorRef<T, N> + ?Sized>(&self, b: &V) -> Option<Vector<T, N>> {
+        self.matrix.solve_lower(b)
+    }
+
+    /// Solves `L^T x = b` by back substitution.
+    ///
+    /// Returns `None` if a diagonal element is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::{LowerTriangular, Matrix, Vector};
+    ///
+    /// let l = LowerTriangular::new(Matrix::from([[2.0, 0.0], [1.0, 4.0]])).unwrap();
+    /// assert_eq!(l.solve_transpose(&Vector::from([4.0, 8.0])), Some(Vector::from([1.0, 2.0])));
+    /// ```
+    pub fn solve_transpose<V: VectorRef<T, N> + ?Sized>(&self, b: &V) -> Option<Vector<T, N>> {
+        self.matrix.solve_lower_transpose(b)
+    }
+}
+
+//////////////////////////////////
+//  From Trait Implementations  //
+//////////////////////////////////
+
+impl<T, const N: usize> From<UpperTriangular<T, N>> for Matrix<T, N, N> {
+    fn from(triangular: UpperTriangular<T, N>) -> Self {
+        triangular.matrix
+    }
+}
+
+impl<T, const N: usize> From<LowerTriangular<T, N>> for Matrix<T, N, N> {
+    fn from(triangular: LowerTriangular<T, N>) -> Self {
+        triangular.matrix
+    }
+}
+
+///////////////////////////////////
+//  Index Trait Implementations  //
+///////////////////////////////////
+
+impl<T, const N: usize> Index<(usize, usize)> for UpperTriangular<T, N> {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        &self.matrix[index]
+    }
+}
+
+impl<T, const N: usize> Index<(usize, usize)> for LowerTriangular<T, N> {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        &self.matrix[index]
+    }
+}
+
+////////////////////////////////////////
+//  Arithmetic Trait Implementations  //
+////////////////////////////////////////
+
+// `$row` returns the columns of row `i` inside the triangle, and `$col` the rows of column `j`
+macro_rules! impl_triangular_mul {
+    ($type:ident, $row:expr, $col:expr) => {
+        impl<T: Clone + Zero + Mul<T, Output = T>, const N: usize> $type<T, N> {
+            fn mul_vector<V: VectorRef<T, N> + ?Sized>(&self, other: &V) -> Matrix<T, N, 1> {
+                Matrix::from(core::array::from_fn(|i| {
+                    [$row(i, N).fold(T::zero(), |sum: T, k| sum + self.matrix[(i, k)].clone() * other[k].clone())]
+                }))
+            }
+
+            fn mul_matrix<M: MatrixRef<T, N, C> + ?Sized, const C: usize>(&self, other: &M) -> Matrix<T, N, C> {
+                Matrix::from(core::array::from_fn(|i| {
+                    core::array::from_fn(|j| {
+                        $row(i, N).fold(T::zero(), |sum: T, k| sum + self.matrix[(i, k)].clone() * other[(k, j)].clone())
+                    })
+                }))
+            }
+        }
+
+        impl_triangular_mul!(@dense $type, mul_vector, Matrix<T, N, 1>, Vector<T, N>, [], |other| &other);
+        impl_triangular_mul!(@dense $type, mul_vector, Matrix<T, N, 1>, &Vector<T, N>, [], |other| other);
+        impl_triangular_mul!(@dense $type, mul_vector, Matrix<T, N, 1>, VectorView<'_, V, T, A, N>, [V: Index<usize, Output = T>, const A: usize], |other| &other);
+        impl_triangular_mul!(@dense $type, mul_vector, Matrix<T, N, 1>, VectorViewMut<'_, V, T, A, N>, [V: Index<usize, Output = T>, const A: usize], |other| &other);
+
+        impl_triangular_mul!(@dense $type, mul_matrix, Matrix<T, N, C>, Matrix<T, N, C>, [const C: usize], |other| &other);
+        impl_triangular_mul!(@dense $type, mul_matrix, Matrix<T, N, C>, &Matrix<T, N, C>, [const C: usize], |other| other);
+        impl_triangular_mul!(@dense $type, mul_matrix, Matrix<T, N, C>, MatrixCM<T, N, C>, [const C: usize], |other| &other);
+        impl_triangular_mul!(@dense $type, mul_matrix, Matrix<T, N, C>, &MatrixCM<T, N, C>, [const C: usize], |other| other);
+        impl_triangular_mul!(@dense $type, mul_matrix, Matrix<T, N, C>, MatrixView<'_, T, A, B, N, C>, [const A: usize, const B: usize, const C: usize], |other| &other);
+        impl_triangular_mul!(@dense $type, mul_matrix, Matrix<T, N, C>, MatrixViewMut<'_, T, A, B, N, C>, [const A: usize, const B: usize, const C: usize], |other| &other);
+        impl_triangular_mul!(@dense $type, mul_matrix, Matrix<T, N, C>, MatrixTransposeView<'_, T, A, B, N, C>, [const A: usize, const B: usize, const C: usize], |other| &other);
+        impl_triangular_mul!(@dense $type, mul_matrix, Matrix<T, N, C>, MatrixTransposeViewMut<'_, T, A, B, N, C>, [const A: usize, const B: usize, const C: usize], |other| &other);
+        impl_triangular_mul!(@dense $type, mul_matrix, Matrix<T, N, C>, MatrixStridedView<'_, T, A, B, N, C>, [const A: usize, const B: usize, const C: usize], |other| &other);
+        impl_triangular_mul!(@dense $type, mul_matrix, Matrix<T, N, C>, MatrixStridedViewMut<'_, T, A, B, N, C>, [const A: usize, const B: usize, const C: usize], |other| &other);
+
+        impl_triangular_mul!(@structured $type, $row, $type<T, N>, &$type<T, N>);
+        impl_triangular_mul!(@matrix $type, $col, Matrix<T, R, N>, $type<T, N>; &Matrix<T, R, N>, $type<T, N>; Matrix<T, R, N>, &$type<T, N>; &Matrix<T, R, N>, &$type<T, N>);
+    };
+    // `$arg` borrows `$other` for `mul_vector` or `mul_matrix`, which read it in place
+    (@dense $type:ident, $method:ident, $output:ty, $rhs:ty, [$($generics:tt)*], |$other:ident| $arg:expr) => {
+        impl<T: Clone + Zero + Mul<T, Output = T>, const N: usize, $($generics)*> Mul<$rhs> for $type<T, N> {
+            type Output = $output;
+
+            fn mul(self, $other: $rhs) -> Self::Output {
+                self.$method($arg)
+            }
+        }
+
+        impl<T: Clone + Zero + Mul<T, Output = T>, const N: usize, $($generics)*> Mul<$rhs> for &$type<T, N> {
+            type Output = $output;
+
+            fn mul(self, $other: $rhs) -> Self::Output {
+                self.$method($arg)
+            }
+        }
+    };
+    (@structured $type:ident, $row:expr, $($lhs:ty),*) => {
+        $(
+            impl<T: Clone + Zero + Mul<T, Output = T>, const N: usize> Mul<$type<T, N>> for $lhs {
+                type Output = $type<T, N>;
+
+                fn mul(self, other: $type<T, N>) -> Self::Output {
+                    // Only the products with `k` between `i` and `j` are inside both triangles
+                    let matrix = Matrix::from(core::array::from_fn(|i| {
+                        core::array::from_fn(|j| {
+                            $row(i, N).filter(|&k| $row(k, N).contains(&j)).fold(T::zero(), |sum: T, k| {
+                                sum + self.matrix[(i, k)].clone() * other.matrix[(k, j)].clone()
+                            })
+                        })
+                    }));
+                    $type { matrix }
+                }
+            }
+
+            impl<T: Clone + Zero + Mul<T, Output = T>, const N: usize> Mul<T> for $lhs {
+                type Output = $type<T, N>;
+
+                fn mul(self, scalar: T) -> Self::Output {
+                    let matrix = Matrix::from(core::array::from_fn(|i| {
+                        core::array::from_fn(|j| {
+                            if $row(i, N).contains(&j) {
+                                self.matrix[(i, j)].clone() * scalar.clone()
+                            } else {
+                                T::zero()
+                            }
+                        })
+                    }));
+                    $type { matrix }
+                }
+            }
+        )*
+    };
+    (@matrix $type:ident, $col:expr, $($lhs:ty, $rhs:ty);*) => {
+        $(
+            impl<T: Clone + Zero + Mul<T, Output = T>, const R: usize, const N: usize> Mul<$rhs> for $lhs {
+                type Output = Matrix<T, R, N>;
+
+                fn mul(self, other: $rhs) -> Self::Output {
+                    Matrix::from(core::array::from_fn(|i| {
+                        core::array::from_fn(|j| {
+                            $col(j, N).fold(T::zero(), |sum: T, k| sum + self[(i, k)].clone() * other.matrix[(k, j)].clone())
+                        })
+                    }))
+                }
+            }
+        )*
+    };
+}
+
+impl_triangular_mul!(UpperTriangular, |i, n| i..n, |j, _| 0..j + 1);
+impl_triangular_mul!(LowerTriangular, |i, _| 0..i + 1, |j, n| j..n);
@@ -1,24 +1,39 @@
-use num_traits::{Float, One, PrimInt, Zero};
+#[cfg(feature = "fmt")]
+use core::fmt;
+#[cfg(any(feature = "std", feature = "libm"))]
+use core::ops::{Add, Mul};
+use core::ops::{Index, IndexMut};
+#[cfg(any(feature = "std", feature = "libm"))]
+use num_traits::Float;
+#[cfg(feature = "rand")]
+use num_traits::PrimInt;
+use num_traits::{One, Zero};
+#[cfg(feature = "rand")]
 use rand::distributions::uniform::SampleUniform;
+#[cfg(feature = "rand")]
 use rand::distributions::{Distribution, Standard, Uniform};
+#[cfg(feature = "rand")]
 use rand::Rng;
-use std::default::Default;
-use std::fmt;
-use std::ops::{Index, IndexMut};
 
 use crate::matrix::Matrix;
 use crate::matrix_transpose_view::MatrixTransposeView;
 use crate::matrix_transpose_view_mut::MatrixTransposeViewMut;
 use crate::matrix_view::MatrixView;
 use crate::matrix_view_mut::MatrixViewMut;
+use crate::row_vector::RowVector;
 use crate::row_vector_view::RowVectorView;
 use crate::row_vector_view_mut::RowVectorViewMut;
-use crate::traits::{DotProduct, FloatRandom, IntRandom};
+use crate::traits::{Conjugate, Contiguous, VectorMut, VectorRef};
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::traits::DotProduct;
+#[cfg(feature = "rand")]
+use crate::traits::{FloatRandom, IntRandom};
 use crate::vector_view::VectorView;
 use crate::vector_view_mut::VectorViewMut;
 
 /// A static column vector type.
 #[derive(Debug, Clone)]
+#[repr(transparent)]
 pub struct Vector<T, const N: usize> {
     data: [T; N],
 }
@@ -44,7 +59,7 @@ impl<T: Default, const N: usize> Default for Vector<T, N> {
     /// ```
     fn default() -> Self {
         Self {
-            data: std::array::from_fn(|_| T::default()),
+            data: core::array::from_fn(|_| T::default()),
         }
     }
 }
@@ -137,6 +152,7 @@ impl<T, const N: usize> Vector<T, N> {
     }
 }
 
+#[cfg(feature = "rand")]
 impl<T: PrimInt, const N: usize> IntRandom for Vector<T, N>
 where
     Standard: Distribution<T>,
@@ -144,11 +160,12 @@ where
     fn random() -> Self {
         let mut rng = rand::thread_rng();
         Self {
-            data: std::array::from_fn(|_| rng.gen()),
+            data: core::array::from_fn(|_| rng.gen()),
         }
     }
 }
 
+#[cfg(feature = "rand")]
 impl<T: Float + SampleUniform, const N: usize> FloatRandom for Vector<T, N>
 where
     Standard: Distribution<T>,
@@ -157,12 +174,12 @@ where
         let mut rng = rand::thread_rng();
         let dist = Uniform::new_inclusive(-T::one(), T::one());
         Self {
-            data: std::array::from_fn(|_| dist.sample(&mut rng)),
+            data: core::array::from_fn(|_| dist.sample(&mut rng)),
         }
     }
 }
 
-impl<T: Copy> Vector<T, 1> {
+impl<T> Vector<T, 1> {
     /// Converts a 1-dimensional [`Vector`] into its scalar value.
     ///
     /// # Examples
@@ -174,11 +191,12 @@ impl<T: Copy> Vector<T, 1> {
     /// assert_eq!(vec.into(), 42);
     /// ```
     pub fn into(self) -> T {
-        self[0]
+        let [value] = self.data;
+        value
     }
 }
 
-impl<T: Copy, const N: usize> Vector<T, N> {
+impl<T: Clone, const N: usize> Vector<T, N> {
     /// Creates a new [`Vector`] filled with a specified value.
     ///
     /// This method initializes a new [`Vector`] of size N, where each element is set to the provided value.
@@ -193,12 +211,12 @@ impl<T: Copy, const N: usize> Vector<T, N> {
     /// ```
     pub fn fill(value: T) -> Self {
         Self {
-            data: std::array::from_fn(|_| value),
+            data: core::array::from_fn(|_| value.clone()),
         }
     }
 }
 
-impl<T: Copy + Zero, const N: usize> Vector<T, N> {
+impl<T: Clone + Zero, const N: usize> Vector<T, N> {
     /// Creates a new [`Vector`] filled with zeros.
     ///
     /// This method initializes a new [`Vector`] of size N, where each element is set to zero.
@@ -216,7 +234,7 @@ impl<T: Copy + Zero, const N: usize> Vector<T, N> {
     }
 }
 
-impl<T: Copy + One, const N: usize> Vector<T, N> {
+impl<T: Clone + One, const N: usize> Vector<T, N> {
     /// Creates a new Vector filled with ones.
     ///
     /// This method initializes a new [`Vector`] of size N, where each element is set to one.
@@ -234,12 +252,14 @@ impl<T: Copy + One, const N: usize> Vector<T, N> {
     }
 }
 
-impl<T: Copy + Zero, const N: usize> Vector<T, N> {
+impl<T: Clone + Zero, const N: usize> Vector<T, N> {
     /// Creates a diagonal matrix from the [`Vector`].
     ///
     /// This method returns a new NxN [`Matrix`] where the diagonal elements are set to the values of the [`Vector`],
     /// and all other elements are zero.
     ///
+    /// To scale by the diagonal without building a dense [`Matrix`], use a [`Diagonal`](crate::Diagonal) instead.
+    ///
     /// # Examples
     ///
     /// ```
@@ -252,7 +272,7 @@ impl<T: Copy + Zero, const N: usize> Vector<T, N> {
     pub fn diag(&self) -> Matrix<T, N, N> {
         let mut m = Matrix::<T, N, N>::zeros();
         for i in 0..N {
-            m[(i, i)] = self[i];
+            m[(i, i)] = self[i].clone();
         }
         m
     }
@@ -346,7 +366,51 @@ impl<T, const N: usize> Vector<T, N> {
     }
 }
 
-impl<T: Float, const N: usize> Vector<T, N> {
+impl<T: Conjugate, const N: usize> Vector<T, N> {
+    /// Returns the element-wise complex conjugate of the [`Vector`].
+    ///
+    /// Real numbers are their own conjugate, so for real element types this is a copy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "complex")]
+    /// # {
+    /// use ferrix::{Complex, Vector};
+    ///
+    /// let vec = Vector::from([Complex::new(1.0, 2.0), Complex::new(3.0, -4.0)]);
+    /// assert_eq!(vec.conj(), Vector::from([Complex::new(1.0, -2.0), Complex::new(3.0, 4.0)]));
+    /// # }
+    /// ```
+    pub fn conj(&self) -> Self {
+        Self {
+            data: core::array::from_fn(|i| self.data[i].clone().conj()),
+        }
+    }
+
+    /// Returns the conjugate transpose of the [`Vector`] as a new [`RowVector`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "complex")]
+    /// # {
+    /// use ferrix::{Complex, RowVector, Vector};
+    ///
+    /// let vec = Vector::from([Complex::new(1.0, 2.0), Complex::new(3.0, -4.0)]);
+    /// assert_eq!(vec.h(), RowVector::from([Complex::new(1.0, -2.0), Complex::new(3.0, 4.0)]));
+    /// # }
+    /// ```
+    pub fn h(&self) -> RowVector<T, N> {
+        RowVector::from(core::array::from_fn(|i| self.data[i].clone().conj()))
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T: Conjugate + Zero + Mul<T, Output = T> + Add<T, Output = T>, const N: usize> Vector<T, N>
+where
+    T::Real: Float,
+{
     /// Calculates the magnitude (Euclidean norm) of the [`Vector`].
     ///
     /// # Examples
@@ -357,8 +421,8 @@ impl<T: Float, const N: usize> Vector<T, N> {
     /// let vec = Vector::from([3.0, 4.0]);
     /// assert_eq!(vec.magnitude(), 5.0);
     /// ```
-    pub fn magnitude(&self) -> T {
-        self.dot(self).sqrt()
+    pub fn magnitude(&self) -> T::Real {
+        self.dotc(self).re().sqrt()
     }
 }
 
@@ -397,6 +461,7 @@ impl<T: Eq, const N: usize> Eq for Vector<T, N> {}
 //  Display Trait Implementations  //
 /////////////////////////////////////
 
+#[cfg(feature = "fmt")]
 impl<T: fmt::Display, const N: usize> fmt::Display for Vector<T, N> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if f.alternate() {
@@ -420,13 +485,21 @@ impl<T: fmt::Display, const N: usize> fmt::Display for Vector<T, N> {
         write!(f, " {}]", self[N - 1])?;
 
         if f.alternate() {
-            write!(f, ", dtype={})", std::any::type_name::<T>())?;
+            write!(f, ", dtype={})", core::any::type_name::<T>())?;
         }
 
         Ok(())
     }
 }
 
+///////////////////////////////////////
+//  VectorRef Trait Implementations  //
+///////////////////////////////////////
+
+impl<T, const N: usize> VectorRef<T, N> for Vector<T, N> {}
+
+impl<T, const N: usize> VectorMut<T, N> for Vector<T, N> {}
+
 ///////////////////////////////////
 //  Index Trait Implementations  //
 ///////////////////////////////////
@@ -459,6 +532,26 @@ impl<T, const N: usize> IndexMut<(usize, usize)> for Vector<T, N> {
     }
 }
 
+////////////////////////////////////////
+//  Contiguous Trait Implementations  //
+////////////////////////////////////////
+
+impl<T, const N: usize> Contiguous<T> for Vector<T, N> {
+    fn from_fn<F: FnMut(usize) -> T>(f: F) -> Self {
+        Self {
+            data: core::array::from_fn(f),
+        }
+    }
+
+    fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+}
+
 //////////////////////////////////
 //  From Trait Implementations  //
 //////////////////////////////////
@@ -469,78 +562,127 @@ impl<T, const N: usize> From<[T; N]> for Vector<T, N> {
     }
 }
 
-impl<T: Copy, const N: usize> From<[[T; 1]; N]> for Vector<T, N> {
+impl<T: Clone, const N: usize> From<[[T; 1]; N]> for Vector<T, N> {
     fn from(data: [[T; 1]; N]) -> Self {
         Self {
-            data: std::array::from_fn(|i| data[i][0]),
+            data: core::array::from_fn(|i| data[i][0].clone()),
         }
     }
 }
 
-impl<V: Index<usize, Output = T>, T: Copy, const A: usize, const N: usize>
+impl<V: Index<usize, Output = T>, T: Clone, const A: usize, const N: usize>
     From<VectorView<'_, V, T, A, N>> for Vector<T, N>
 {
     fn from(vector: VectorView<'_, V, T, A, N>) -> Self {
         Self {
-            data: std::array::from_fn(|i| vector[i]),
+            data: core::array::from_fn(|i| vector[i].clone()),
         }
     }
 }
 
-impl<V: IndexMut<usize, Output = T>, T: Copy, const A: usize, const N: usize>
+impl<V: IndexMut<usize, Output = T>, T: Clone, const A: usize, const N: usize>
     From<VectorViewMut<'_, V, T, A, N>> for Vector<T, N>
 {
     fn from(vector: VectorViewMut<'_, V, T, A, N>) -> Self {
         Self {
-            data: std::array::from_fn(|i| vector[i]),
+            data: core::array::from_fn(|i| vector[i].clone()),
         }
     }
 }
 
-impl<T: Copy, const N: usize> From<Matrix<T, N, 1>> for Vector<T, N> {
+impl<T: Clone, const N: usize> From<Matrix<T, N, 1>> for Vector<T, N> {
     fn from(matrix: Matrix<T, N, 1>) -> Self {
         Self {
-            data: std::array::from_fn(|i| matrix[(i, 0)]),
+            data: core::array::from_fn(|i| matrix[(i, 0)].clone()),
         }
     }
 }
 
-impl<T: Copy, const A: usize, const B: usize, const N: usize> From<MatrixView<'_, T, A, B, N, 1>>
+impl<T: Clone, const A: usize, const B: usize, const N: usize> From<MatrixView<'_, T, A, B, N, 1>>
     for Vector<T, N>
 {
     fn from(matrix: MatrixView<'_, T, A, B, N, 1>) -> Self {
         Self {
-            data: std::array::from_fn(|i| matrix[(i, 0)]),
+            data: core::array::from_fn(|i| matrix[(i, 0)].clone()),
         }
     }
 }
 
-impl<T: Copy, const A: usize, const B: usize, const N: usize> From<MatrixViewMut<'_, T, A, B, N, 1>>
+impl<T: Clone, const A: usize, const B: usize, const N: usize> From<MatrixViewMut<'_, T, A, B, N, 1>>
     for Vector<T, N>
 {
     fn from(matrix: MatrixViewMut<'_, T, A, B, N, 1>) -> Self {
         Self {
-            data: std::array::from_fn(|i| matrix[(i, 0)]),
+            data: core::array::from_fn(|i| matrix[(i, 0)].clone()),
         }
     }
 }
 
-impl<T: Copy, const A: usize, const B: usize, const N: usize>
+impl<T: Clone, const A: usize, const B: usize, const N: usize>
     From<MatrixTransposeView<'_, T, A, B, N, 1>> for Vector<T, N>
 {
     fn from(matrix: MatrixTransposeView<'_, T, A, B, N, 1>) -> Self {
         Self {
-            data: std::array::from_fn(|i| matrix[(i, 0)]),
+            data: core::array::from_fn(|i| matrix[(i, 0)].clone()),
         }
     }
 }
 
-impl<T: Copy, const A: usize, const B: usize, const N: usize>
+impl<T: Clone, const A: usize, const B: usize, const N: usize>
     From<MatrixTransposeViewMut<'_, T, A, B, N, 1>> for Vector<T, N>
 {
     fn from(matrix: MatrixTransposeViewMut<'_, T, A, B, N, 1>) -> Self {
         Self {
-            data: std::array::from_fn(|i| matrix[(i, 0)]),
+            data: core::array::from_fn(|i| matrix[(i, 0)].clone()),
         }
     }
 }
+
+#[cfg(feature = "bytemuck")]
+impl<T: bytemuck::Pod, const N: usize> Vector<T, N> {
+    /// Returns the elements of the [`Vector`] as bytes, without copying.
+    ///
+    /// Use this in place of `bytemuck::bytes_of`, e.g. to upload the [`Vector`] to a GPU buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Vector;
+    ///
+    /// let vec = Vector::from([1u16, 2, 3, 4]);
+    /// assert_eq!(vec.as_bytes(), bytemuck::cast_slice::<u16, u8>(&[1, 2, 3, 4]));
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(&self.data)
+    }
+
+    /// Returns the elements of a slice of [`Vector`] values as bytes, without copying.
+    ///
+    /// The vectors are laid out one after another. Use this in place of
+    /// `bytemuck::cast_slice`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ferrix::Vector;
+    ///
+    /// let vectors = [Vector::from([1u16, 2, 3, 4]), Vector::from([5u16, 6, 7, 8])];
+    /// let bytes = Vector::slice_as_bytes(&vectors);
+    /// assert_eq!(bytes, bytemuck::cast_slice::<u16, u8>(&[1, 2, 3, 4, 5, 6, 7, 8]));
+    /// ```
+    pub fn slice_as_bytes(vectors: &[Self]) -> &[u8] {
+        bytemuck::cast_slice(<Self as bytemuck::TransparentWrapper<_>>::peel_slice(vectors).as_flattened())
+    }
+}
+
+//////////////////////////////////////
+//  Bytemuck Trait Implementations  //
+//////////////////////////////////////
+
+// SAFETY: `Vector` is `repr(transparent)` over `[T; N]`, which is zeroable when `T` is
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Zeroable, const N: usize> bytemuck::Zeroable for Vector<T, N> {}
+
+// SAFETY: `Vector` is `repr(transparent)` over `[T; N]`
+#[cfg(feature = "bytemuck")]
+unsafe impl<T, const N: usize> bytemuck::TransparentWrapper<[T; N]> for Vector<T, N> {}
@@ -0,0 +1,71 @@
+#[cfg(all(test, feature = "approx"))]
+mod tests {
+    use approx::{assert_abs_diff_eq, assert_abs_diff_ne, assert_relative_eq, assert_ulps_eq, relative_ne};
+    use ferrix::{Matrix, MatrixCM, RowVector, Vector};
+
+    #[test]
+    fn test_matrix_abs_diff_eq() {
+        let a = Matrix::from([[1.0, 2.0], [3.0, 4.0]]);
+        let b = Matrix::from([[1.0, 2.0 + 1e-10], [3.0, 4.0]]);
+        assert_abs_diff_eq!(a, b, epsilon = 1e-9);
+        assert_abs_diff_ne!(a, b, epsilon = 1e-11);
+        assert_abs_diff_eq!(a, MatrixCM::from(b), epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_matrix_relative_eq() {
+        let a = Matrix::from([[1e8, 2.0], [3.0, 4.0]]);
+        let b = &a * (1.0 + 1e-14);
+        assert_relative_eq!(a, b, max_relative = 1e-12);
+        assert_ulps_eq!(a, b, max_ulps = 128);
+        assert!(relative_ne!(a, &a + 1e-3));
+    }
+
+    #[test]
+    fn test_view_combinations() {
+        let a = Matrix::from([[1.0f32, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let b = a.t().t() + 1e-7;
+        let view = a.view::<2, 2>((0, 1)).unwrap();
+        assert_relative_eq!(view, b.view::<2, 2>((0, 1)).unwrap());
+        assert_relative_eq!(view, Matrix::from([[2.0, 3.0], [5.0, 6.0]]));
+        assert_relative_eq!(a.t(), b.t());
+        assert_relative_eq!(a.t(), Matrix::from([[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]));
+        assert_relative_eq!(b, a.t().t());
+        assert_relative_eq!(a.strided_view::<2, 2>((0, 0), (1, 2)).unwrap(), Matrix::from([[1.0, 3.0], [4.0, 6.0]]));
+    }
+
+    #[test]
+    fn test_mutable_views() {
+        let mut a = Matrix::from([[1.0, 2.0], [3.0, 4.0]]);
+        let b = &a + 1e-12;
+        assert_relative_eq!(a.view_mut::<2, 2>((0, 0)).unwrap(), b, max_relative = 1e-9);
+        assert_relative_eq!(a.t_mut(), b.t(), max_relative = 1e-9);
+    }
+
+    #[test]
+    fn test_vectors() {
+        let a = Vector::from([1.0, 2.0, 3.0]);
+        let b = &a + 1e-12;
+        assert_relative_eq!(a, b, max_relative = 1e-9);
+        assert_relative_eq!(a.view::<2>(1).unwrap(), Vector::from([2.0, 3.0]));
+        assert_relative_eq!(RowVector::from([1.0, 2.0]), RowVector::from([1.0, 2.0 + 1e-12]), epsilon = 1e-9);
+        assert_abs_diff_ne!(a, Vector::from([1.0, 2.0, 3.1]));
+    }
+
+    #[test]
+    fn test_traits_in_scope() {
+        use approx::{AbsDiffEq, RelativeEq};
+        use ferrix::{MatrixRef, VectorRef};
+
+        let a = Matrix::from([[1.0, 2.0], [3.0, 4.0]]);
+        let b = &a + 1e-12;
+        assert!(a.abs_diff_eq(&b, 1e-9));
+        assert!(a.approx_eq_abs(&b, 1e-9));
+        assert!(a.relative_eq(&b, 0.0, 1e-9));
+        assert!(a.approx_eq_rel(&b, 0.0, 1e-9));
+
+        let v = Vector::from([1.0, 2.0]);
+        assert!(v.abs_diff_eq(&v, 0.0));
+        assert!(v.approx_eq_abs(&v, 0.0));
+    }
+}
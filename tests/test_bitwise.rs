@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod tests {
+    use ferrix::{Matrix, MatrixCM, RowVector, Vector};
+
+    #[test]
+    fn test_vector_bitwise() {
+        let a = Vector::<u8, 3>::from([0b1100, 0b1010, 0xFF]);
+        let b = Vector::<u8, 3>::from([0b1010, 0b0110, 0x0F]);
+        assert_eq!(&a & &b, Vector::from([0b1000, 0b0010, 0x0F]));
+        assert_eq!(&a | &b, Vector::from([0b1110, 0b1110, 0xFF]));
+        assert_eq!(&a ^ &b, Vector::from([0b0110, 0b1100, 0xF0]));
+        assert_eq!(&a & 0b0100, Vector::from([0b0100, 0, 0b0100]));
+        assert_eq!(a.view::<2>(0).unwrap() | b.view::<2>(1).unwrap(), Vector::from([0b1110, 0b1111]));
+        assert_eq!(a ^ RowVector::from([1, 1, 1]).t(), Vector::from([0b1101, 0b1011, 0xFE]));
+
+        let flags = Vector::from([true, false, true]);
+        assert_eq!(flags & Vector::from([true, true, false]), Vector::from([true, false, false]));
+    }
+
+    #[test]
+    fn test_vector_shift() {
+        let v = Vector::<u32, 3>::from([1, 2, 3]);
+        assert_eq!(&v << 2, Vector::from([4, 8, 12]));
+        assert_eq!(&v << Vector::from([0, 1, 2]), Vector::from([1, 4, 12]));
+        assert_eq!((&v << 4) >> 3, Vector::from([2, 4, 6]));
+        assert_eq!(RowVector::from([-8i32, 8]) >> 2, RowVector::from([-2, 2]));
+        assert_eq!(v.view::<2>(1).unwrap() >> 1, Vector::from([1, 1]));
+    }
+
+    #[test]
+    fn test_matrix_bitwise() {
+        let a = Matrix::<i32, 2, 2>::from([[0b1100, 0b1010], [-1, 0]]);
+        let b = Matrix::<i32, 2, 2>::from([[0b1010, 0b0110], [0b0101, -1]]);
+        assert_eq!(&a & &b, Matrix::from([[0b1000, 0b0010], [0b0101, 0]]));
+        assert_eq!(&a | &b, Matrix::from([[0b1110, 0b1110], [-1, -1]]));
+        assert_eq!(&a ^ &b, Matrix::from([[0b0110, 0b1100], [!0b0101, -1]]));
+        assert_eq!(a.t() & b.t(), (&a & &b).t().t().t());
+        assert_eq!(a.view::<1, 2>((0, 0)).unwrap() ^ 0b1111, Matrix::from([[0b0011, 0b0101]]));
+        assert_eq!(MatrixCM::from(a.clone()) | MatrixCM::from(b.clone()), MatrixCM::from(&a | b));
+        assert_eq!(&a << 1, Matrix::from([[0b11000, 0b10100], [-2, 0]]));
+        assert_eq!(a.t() >> Matrix::from([[2, 0], [1, 0]]), Matrix::from([[0b11, -1], [0b101, 0]]));
+    }
+
+    #[test]
+    fn test_bitwise_assign() {
+        let mut v = Vector::<u16, 3>::from([0b1100, 0b1010, 0xFF]);
+        v &= 0x0E;
+        assert_eq!(v, Vector::from([0b1100, 0b1010, 0x0E]));
+        v |= Vector::from([1, 1, 1]);
+        assert_eq!(v, Vector::from([0b1101, 0b1011, 0x0F]));
+        v ^= &Vector::from([0x0F, 0x0F, 0x0F]);
+        assert_eq!(v, Vector::from([0b0010, 0b0100, 0]));
+        v <<= 2;
+        assert_eq!(v, Vector::from([0b1000, 0b10000, 0]));
+        v >>= Vector::from([3, 4, 0]);
+        assert_eq!(v, Vector::from([1, 1, 0]));
+
+        let mut m = Matrix::<u8, 2, 2>::from([[1, 2], [4, 8]]);
+        let mut row = m.view_mut::<1, 2>((1, 0)).unwrap();
+        row <<= 1;
+        assert_eq!(m, Matrix::from([[1, 2], [8, 16]]));
+        let mut transposed = m.t_mut();
+        transposed |= Matrix::from([[0x10, 0], [0, 0x20]]);
+        assert_eq!(m, Matrix::from([[0x11, 2], [8, 0x30]]));
+    }
+}
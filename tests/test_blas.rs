@@ -0,0 +1,200 @@
+#[cfg(all(test, feature = "blas"))]
+mod tests {
+    use ferrix::{FloatRandom, LowerTriangular, Matrix, MatrixCM, MatrixRef, Vector};
+
+    fn reference<const M: usize, const N: usize, const P: usize>(
+        a: impl Fn(usize, usize) -> f64,
+        b: impl Fn(usize, usize) -> f64,
+    ) -> Matrix<f64, M, P> {
+        let mut result = Matrix::<f64, M, P>::zeros();
+        for i in 0..M {
+            for j in 0..P {
+                for k in 0..N {
+                    result[(i, j)] += a(i, k) * b(k, j);
+                }
+            }
+        }
+        result
+    }
+
+    fn assert_close<const M: usize, const N: usize>(a: &Matrix<f64, M, N>, b: &Matrix<f64, M, N>, tol: f64) {
+        for i in 0..M {
+            for j in 0..N {
+                assert!((a[(i, j)] - b[(i, j)]).abs() < tol, "({}, {}): {} != {}", i, j, a[(i, j)], b[(i, j)]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_matmul_f64() {
+        let a = Matrix::<f64, 50, 70>::random();
+        let b = Matrix::<f64, 70, 40>::random();
+        let expected = reference::<50, 70, 40>(|i, j| a[(i, j)], |i, j| b[(i, j)]);
+        assert_close(&(a * b), &expected, 1e-10);
+    }
+
+    #[test]
+    fn test_matmul_f32() {
+        let a = Matrix::<f32, 64, 64>::random();
+        let b = Matrix::<f32, 64, 64>::random();
+        let expected = reference::<64, 64, 64>(|i, j| a[(i, j)] as f64, |i, j| b[(i, j)] as f64);
+        let result = a * b;
+        for i in 0..64 {
+            for j in 0..64 {
+                assert!((result[(i, j)] as f64 - expected[(i, j)]).abs() < 1e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn test_matmul_transposed() {
+        let a = Matrix::<f64, 70, 50>::random();
+        let b = Matrix::<f64, 40, 70>::random();
+        let expected = reference::<50, 70, 40>(|i, j| a[(j, i)], |i, j| b[(j, i)]);
+        assert_close(&(a.t() * b.t()), &expected, 1e-10);
+        assert_close(&(a.t() * b.t().t().t()), &expected, 1e-10);
+    }
+
+    #[test]
+    fn test_matmul_views() {
+        let a = Matrix::<f64, 80, 80>::random();
+        let b = Matrix::<f64, 80, 80>::random();
+        let lhs = a.view::<50, 60>((3, 7)).unwrap();
+        let rhs = b.view::<45, 60>((20, 1)).unwrap();
+        let expected = reference::<50, 60, 45>(|i, j| a[(i + 3, j + 7)], |i, j| b[(j + 20, i + 1)]);
+        assert_close(&(lhs * rhs.t()), &expected, 1e-10);
+    }
+
+    #[test]
+    fn test_matmul_strided() {
+        // Neither operand has a unit stride, so the native kernel is used
+        let a = Matrix::<f64, 100, 100>::random();
+        let lhs = a.strided_view::<50, 50>((0, 0), (2, 2)).unwrap();
+        let expected = reference::<50, 50, 50>(|i, j| a[(2 * i, 2 * j)], |i, j| a[(2 * i, 2 * j)]);
+        assert_close(&(&lhs * &lhs), &expected, 1e-10);
+    }
+
+    #[test]
+    fn test_matmul_column_major() {
+        let a = Matrix::<f64, 50, 70>::random();
+        let b = Matrix::<f64, 70, 40>::random();
+        let expected = reference::<50, 70, 40>(|i, j| a[(i, j)], |i, j| b[(i, j)]);
+        let result = MatrixCM::from(a.clone()) * MatrixCM::from(b.clone());
+        assert_close(&Matrix::from(result), &expected, 1e-10);
+        assert_close(&(a * MatrixCM::from(b)), &expected, 1e-10);
+    }
+
+    #[test]
+    fn test_matvec() {
+        let a = Matrix::<f64, 150, 130>::random();
+        let x = Vector::<f64, 130>::random();
+        let expected = reference::<150, 130, 1>(|i, j| a[(i, j)], |i, _| x[i]);
+        assert_close(&(a * x), &expected, 1e-10);
+
+        let a = Matrix::<f32, 130, 150>::random();
+        let x = Vector::<f32, 150>::random();
+        let result = &a * &x;
+        let expected = reference::<130, 150, 1>(|i, j| a[(i, j)] as f64, |i, _| x[i] as f64);
+        for i in 0..130 {
+            assert!((result[(i, 0)] as f64 - expected[(i, 0)]).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_integer_matmul() {
+        let a = Matrix::<i64, 60, 60>::fill(2);
+        let b = Matrix::<i64, 60, 60>::fill(3);
+        assert_eq!(a * b, Matrix::fill(360));
+    }
+
+    /// Returns the determinant by the same partial pivoting elimination as the native `det`.
+    fn reference_det<const N: usize>(a: &Matrix<f64, N, N>) -> f64 {
+        let mut a = a.clone();
+        let mut det = 1.0;
+        for k in 0..N {
+            let pivot = (k..N).fold(k, |p, i| if a[(i, k)].abs() > a[(p, k)].abs() { i } else { p });
+            if pivot != k {
+                for j in 0..N {
+                    let tmp = a[(k, j)];
+                    a[(k, j)] = a[(pivot, j)];
+                    a[(pivot, j)] = tmp;
+                }
+                det = -det;
+            }
+            det *= a[(k, k)];
+            for i in k + 1..N {
+                let factor = a[(i, k)] / a[(k, k)];
+                for j in k + 1..N {
+                    a[(i, j)] -= factor * a[(k, j)];
+                }
+            }
+        }
+        det
+    }
+
+    /// Returns the solution of `L x = b` by forward substitution, like the native solvers.
+    fn reference_lower<const N: usize>(l: impl Fn(usize, usize) -> f64, b: &Vector<f64, N>) -> Vector<f64, N> {
+        let mut x = b.clone();
+        for i in 0..N {
+            x[i] = (0..i).fold(x[i], |sum, j| sum - l(i, j) * x[j]) / l(i, i);
+        }
+        x
+    }
+
+    /// Returns a random `N x N` matrix whose lower triangle is well conditioned.
+    fn diagonally_dominant<const N: usize>() -> Matrix<f64, N, N> {
+        Matrix::<f64, N, N>::random() + Matrix::eye() * N as f64
+    }
+
+    #[test]
+    fn test_det() {
+        let a = diagonally_dominant::<40>() - Matrix::fill(20.0);
+        let expected = reference_det(&a);
+        assert!((a.det() - expected).abs() < 1e-10 * expected.abs());
+
+        // Scaled down so that the determinant fits in an `f32`
+        let a32 = a.map(|x| (x / 10.0) as f32);
+        let expected32 = expected * 1e-40;
+        assert!(((a32.det() as f64) - expected32).abs() < 1e-3 * expected32.abs());
+
+        // Two equal rows
+        let mut singular = a;
+        for j in 0..40 {
+            singular[(7, j)] = singular[(3, j)];
+        }
+        assert_eq!(singular.det(), 0.0);
+    }
+
+    #[test]
+    fn test_inverse() {
+        let a = diagonally_dominant::<40>();
+        assert_close(&(a.powi(-1) * &a), &Matrix::eye(), 1e-12);
+        assert_close(&a.powi(-2), &(a.powi(-1) * a.powi(-1)), 1e-12);
+        assert!(Matrix::<f64, 40, 40>::ones().powi(-1).as_slice().iter().all(|x| !x.is_finite()));
+    }
+
+    #[test]
+    fn test_triangular_solve() {
+        let a = diagonally_dominant::<40>();
+        let b = Vector::<f64, 40>::random();
+        let close = |x: Vector<f64, 40>, y: Vector<f64, 40>| (0..40).all(|i| (x[i] - y[i]).abs() < 1e-12);
+
+        assert!(close(a.solve_lower(&b).unwrap(), reference_lower(|i, j| a[(i, j)], &b)));
+        assert!(close(a.solve_upper_transpose(&b).unwrap(), reference_lower(|i, j| a[(j, i)], &b)));
+
+        // Upper systems are lower systems with the indices reversed
+        let rev = |v: &Vector<f64, 40>| Vector::from(core::array::from_fn(|i| v[39 - i]));
+        let upper = reference_lower(|i, j| a[(39 - i, 39 - j)], &rev(&b));
+        assert!(close(a.solve_upper(&b).unwrap(), rev(&upper)));
+        let lower_t = reference_lower(|i, j| a[(39 - j, 39 - i)], &rev(&b));
+        assert!(close(a.solve_lower_transpose(&b).unwrap(), rev(&lower_t)));
+
+        let l = LowerTriangular::new(Matrix::from(core::array::from_fn(|i| core::array::from_fn(|j| if j <= i { a[(i, j)] } else { 0.0 })))).unwrap();
+        assert!(close(l.solve(&b).unwrap(), reference_lower(|i, j| a[(i, j)], &b)));
+
+        let mut singular = a;
+        singular[(20, 20)] = 0.0;
+        assert!(singular.solve_lower(&b).is_none());
+        assert!(singular.solve_upper_transpose(&b).is_none());
+    }
+}
@@ -0,0 +1,99 @@
+// Products above the blocking threshold take the packed GEBP kernel. The sizes
+// below are chosen to leave remainders in every blocking dimension.
+#[cfg(test)]
+mod tests {
+    use ferrix::Matrix;
+
+    fn matrix<const R: usize, const C: usize>(seed: f64) -> Matrix<f64, R, C> {
+        Matrix::from(core::array::from_fn(|i| {
+            core::array::from_fn(|j| ((i * C + j) as f64 * seed).sin())
+        }))
+    }
+
+    fn naive<const M: usize, const N: usize, const P: usize>(
+        a: impl Fn(usize, usize) -> f64,
+        b: impl Fn(usize, usize) -> f64,
+    ) -> Matrix<f64, M, P> {
+        Matrix::from(core::array::from_fn(|i| {
+            core::array::from_fn(|j| (0..N).fold(0.0, |acc, k| acc + a(i, k) * b(k, j)))
+        }))
+    }
+
+    fn assert_close<const R: usize, const C: usize>(a: &Matrix<f64, R, C>, b: &Matrix<f64, R, C>) {
+        for i in 0..R {
+            for j in 0..C {
+                assert!((a[(i, j)] - b[(i, j)]).abs() < 1e-9, "mismatch at ({i}, {j})");
+            }
+        }
+    }
+
+    #[test]
+    fn test_blocked_matmul() {
+        let a = matrix::<67, 53>(0.3);
+        let b = matrix::<53, 71>(0.7);
+        let expected = naive::<67, 53, 71>(|i, k| a[(i, k)], |k, j| b[(k, j)]);
+        assert_close(&(&a * &b), &expected);
+    }
+
+    #[test]
+    fn test_blocked_matmul_block_edges() {
+        // Inner dimension larger than one KC panel
+        let a = matrix::<21, 300>(0.1);
+        let b = matrix::<300, 19>(0.2);
+        let expected = naive::<21, 300, 19>(|i, k| a[(i, k)], |k, j| b[(k, j)]);
+        assert_close(&(&a * &b), &expected);
+
+        // Output wider than one NC panel
+        let a = matrix::<5, 50>(0.1);
+        let b = matrix::<50, 517>(0.2);
+        let expected = naive::<5, 50, 517>(|i, k| a[(i, k)], |k, j| b[(k, j)]);
+        assert_close(&(&a * &b), &expected);
+    }
+
+    #[test]
+    fn test_blocked_matmul_f32() {
+        let a = Matrix::<f32, 49, 51>::from(core::array::from_fn(|i| {
+            core::array::from_fn(|j| ((i * 51 + j) as f32 * 0.3).sin())
+        }));
+        let b = Matrix::<f32, 51, 50>::from(core::array::from_fn(|i| {
+            core::array::from_fn(|j| ((i * 50 + j) as f32 * 0.7).cos())
+        }));
+        let result = &a * &b;
+        for i in 0..49 {
+            for j in 0..50 {
+                let expected = (0..51).fold(0.0, |acc, k| acc + a[(i, k)] * b[(k, j)]);
+                assert!((result[(i, j)] - expected).abs() < 1e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn test_blocked_matmul_transpose() {
+        let a = matrix::<60, 70>(0.3);
+        let b = matrix::<65, 70>(0.7);
+
+        // Matrix * MatrixTransposeView
+        let expected = naive::<60, 70, 65>(|i, k| a[(i, k)], |k, j| b[(j, k)]);
+        assert_close(&(&a * b.t()), &expected);
+
+        // MatrixTransposeView * MatrixTransposeView
+        let expected = naive::<70, 60, 70>(|i, k| a[(k, i)], |k, j| a[(k, j)]);
+        assert_close(&(a.t() * a.view::<60, 70>((0, 0)).unwrap()), &expected);
+
+        // MatrixTransposeView * Matrix
+        let c = matrix::<60, 55>(0.5);
+        let expected = naive::<70, 60, 55>(|i, k| a[(k, i)], |k, j| c[(k, j)]);
+        assert_close(&(a.t() * &c), &expected);
+    }
+
+    #[test]
+    fn test_blocked_matmul_view() {
+        let a = matrix::<70, 70>(0.3);
+        let b = matrix::<70, 70>(0.7);
+
+        let va = a.view::<60, 50>((3, 7)).unwrap();
+        let vb = b.view::<50, 65>((11, 2)).unwrap();
+        let expected = naive::<60, 50, 65>(|i, k| a[(i + 3, k + 7)], |k, j| b[(k + 11, j + 2)]);
+        assert_close(&(va * vb), &expected);
+    }
+}
@@ -0,0 +1,97 @@
+#[cfg(all(test, feature = "bytemuck"))]
+mod tests {
+    use bytemuck::TransparentWrapper;
+    use ferrix::{Matrix, MatrixCM, RowVector, Vector};
+
+    #[test]
+    fn test_matrix_cast_slice() {
+        let matrices = [
+            Matrix::from([[1.0f32, 2.0], [3.0, 4.0]]),
+            Matrix::from([[5.0f32, 6.0], [7.0, 8.0]]),
+        ];
+        let arrays: &[[[f32; 2]; 2]] = Matrix::peel_slice(&matrices);
+        let floats: &[f32] = bytemuck::cast_slice(arrays);
+        assert_eq!(floats, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+
+        let bytes: &[u8] = bytemuck::cast_slice(arrays);
+        assert_eq!(bytes.len(), 32);
+        let back: &[Matrix<f32, 2, 2>] = Matrix::wrap_slice(bytemuck::cast_slice(bytes));
+        assert_eq!(back[1], matrices[1]);
+
+        assert_eq!(bytemuck::cast_slice::<f32, u8>(matrices[0].as_slice()), &bytes[..16]);
+    }
+
+    #[test]
+    fn test_matrix_wrap() {
+        let matrix: Matrix<u32, 2, 3> = Matrix::wrap(bytemuck::cast([1u32, 2, 3, 4, 5, 6]));
+        assert_eq!(matrix, Matrix::from([[1, 2, 3], [4, 5, 6]]));
+        let array: [[u32; 3]; 2] = Matrix::peel(matrix);
+        assert_eq!(array, [[1, 2, 3], [4, 5, 6]]);
+
+        let mut matrix = Matrix::from([[1, 2], [3, 4]]);
+        Matrix::peel_mut(&mut matrix)[1][0] = 0;
+        assert_eq!(matrix, Matrix::from([[1, 2], [0, 4]]));
+    }
+
+    #[test]
+    fn test_zeroed() {
+        assert_eq!(<Matrix<f64, 3, 2> as bytemuck::Zeroable>::zeroed(), Matrix::zeros());
+        assert_eq!(<Vector<i16, 4> as bytemuck::Zeroable>::zeroed(), Vector::zeros());
+        assert_eq!(<RowVector<u8, 4> as bytemuck::Zeroable>::zeroed(), RowVector::zeros());
+    }
+
+    #[test]
+    fn test_vector_wrap() {
+        let vector = Vector::from([1u16, 2, 3, 4]);
+        let pairs: &[[u16; 2]] = bytemuck::cast_slice(Vector::peel_ref(&vector));
+        assert_eq!(pairs, &[[1, 2], [3, 4]]);
+        let row: RowVector<u16, 4> = RowVector::wrap(Vector::peel(vector));
+        assert_eq!(row, RowVector::from([1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn test_matrix_cm_wrap() {
+        let matrix: MatrixCM<u32, 2, 3> = MatrixCM::wrap(bytemuck::cast([1u32, 2, 3, 4, 5, 6]));
+        assert_eq!(matrix, Matrix::from([[1, 3, 5], [2, 4, 6]]));
+        let transposed: Matrix<u32, 3, 2> = Matrix::wrap(MatrixCM::peel(matrix));
+        assert_eq!(transposed, Matrix::from([[1, 2], [3, 4], [5, 6]]));
+    }
+
+    #[test]
+    fn test_as_bytes() {
+        let matrix = Matrix::from([[1.0f32, 2.0], [3.0, 4.0]]);
+        assert_eq!(matrix.as_bytes(), bytemuck::cast_slice::<f32, u8>(matrix.as_slice()));
+        assert_eq!(matrix.as_bytes().as_ptr(), matrix.as_ptr() as *const u8);
+
+        // Column-major storage order
+        let matrix_cm = MatrixCM::from(matrix.clone());
+        assert_eq!(matrix_cm.as_bytes(), bytemuck::cast_slice::<f32, u8>(&[1.0, 3.0, 2.0, 4.0]));
+
+        let vector = Vector::from([1u32, 2, 3]);
+        assert_eq!(vector.as_bytes(), bytemuck::bytes_of(&[1u32, 2, 3]));
+        let row = RowVector::from([1u32, 2, 3]);
+        assert_eq!(row.as_bytes(), vector.as_bytes());
+    }
+
+    #[test]
+    fn test_slice_as_bytes() {
+        let matrices = [
+            Matrix::from([[1.0f32, 2.0], [3.0, 4.0]]),
+            Matrix::from([[5.0f32, 6.0], [7.0, 8.0]]),
+        ];
+        let bytes = Matrix::slice_as_bytes(&matrices);
+        assert_eq!(bytes.len(), 32);
+        assert_eq!(bytes.as_ptr(), matrices.as_ptr() as *const u8);
+        let back: &[Matrix<f32, 2, 2>] = Matrix::wrap_slice(bytemuck::cast_slice(bytes));
+        assert_eq!(back, &matrices);
+
+        let columns = [MatrixCM::from(matrices[0].clone()), MatrixCM::from(matrices[1].clone())];
+        let floats: &[f32] = bytemuck::cast_slice(MatrixCM::slice_as_bytes(&columns));
+        assert_eq!(floats, &[1.0, 3.0, 2.0, 4.0, 5.0, 7.0, 6.0, 8.0]);
+
+        let vectors = [Vector::from([1u16, 2]), Vector::from([3u16, 4])];
+        assert_eq!(Vector::slice_as_bytes(&vectors), bytemuck::bytes_of(&[1u16, 2, 3, 4]));
+        let rows = [RowVector::from([1u16, 2]), RowVector::from([3u16, 4])];
+        assert_eq!(RowVector::slice_as_bytes(&rows), Vector::slice_as_bytes(&vectors));
+    }
+}
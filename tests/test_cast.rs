@@ -0,0 +1,80 @@
+#[cfg(test)]
+mod tests {
+    use ferrix::{Matrix, MatrixCM, MatrixRef, RowVector, Vector, VectorRef};
+
+    #[test]
+    fn test_matrix_cast() {
+        let m = Matrix::from([[1.5, -2.0], [255.0, 3.9]]);
+        assert_eq!(m.cast::<u8>(), None);
+        assert_eq!(m.cast::<i32>(), Some(Matrix::from([[1, -2], [255, 3]])));
+        assert_eq!(m.t().cast::<i16>(), Some(Matrix::from([[1, 255], [-2, 3]])));
+        assert_eq!(Matrix::from([[1u64, 2], [3, 4]]).cast::<f32>(), Some(Matrix::from([[1.0, 2.0], [3.0, 4.0]])));
+        assert_eq!(MatrixCM::from(Matrix::from([[300, 2]])).cast::<u8>(), None);
+    }
+
+    #[test]
+    fn test_matrix_cast_saturating() {
+        let m = Matrix::from([[1.5, -2.0], [300.0, f64::NAN]]);
+        assert_eq!(m.cast_saturating::<u8>(), Matrix::from([[1, 0], [255, 0]]));
+        assert_eq!(Matrix::from([[i64::MAX, i64::MIN]]).cast_saturating::<i32>(), Matrix::from([[i32::MAX, i32::MIN]]));
+        assert_eq!(m.view::<1, 2>((0, 0)).unwrap().cast_saturating::<i8>(), Matrix::from([[1, -2]]));
+    }
+
+    #[test]
+    fn test_matrix_cast_lossy() {
+        let m = Matrix::from([[1.5, -2.0], [300.0, f64::NAN]]);
+        assert_eq!(m.cast_lossy::<u8>(), Matrix::from([[1, 0], [255, 0]]));
+        assert_eq!(Matrix::from([[300i32, -1]]).cast_lossy::<u8>(), Matrix::from([[44, 255]]));
+        assert_eq!(Matrix::from([[0.1f64]]).cast_lossy::<f32>(), Matrix::from([[0.1f32]]));
+    }
+
+    #[test]
+    fn test_vector_cast() {
+        let v = Vector::from([1.0f32, -1.0, 1e10]);
+        assert_eq!(v.cast::<i32>(), None);
+        assert_eq!(v.cast::<f64>(), Some(Vector::from([1.0, -1.0, 1e10f32 as f64])));
+        assert_eq!(v.cast_saturating::<i32>(), Vector::from([1, -1, i32::MAX]));
+        assert_eq!(v.cast_lossy::<u16>(), Vector::from([1, 0, u16::MAX]));
+        assert_eq!(RowVector::from([1u8, 2]).cast::<i8>(), Some(Vector::from([1, 2])));
+        assert_eq!(v.view::<2>(0).unwrap().cast::<i64>(), Some(Vector::from([1, -1])));
+    }
+
+    #[test]
+    fn test_float_cast() {
+        let m = Matrix::from([[1.5f64, 1e300], [-1e300, 0.1]]);
+        assert_eq!(m.cast::<f32>(), None);
+        assert_eq!(m.cast_saturating::<f32>(), Matrix::from([[1.5, f32::MAX], [f32::MIN, 0.1]]));
+        assert_eq!(Matrix::from([[f64::MAX]]).cast::<f32>(), None);
+        assert_eq!(Matrix::from([[f64::INFINITY, 1e-300]]).cast::<f32>(), Some(Matrix::from([[f32::INFINITY, 0.0]])));
+
+        let v = Vector::from([f64::NAN, 2.0]);
+        assert!(v.cast::<f32>().unwrap()[0].is_nan());
+        assert!(v.cast_saturating::<f32>()[0].is_nan());
+        assert_eq!(v.cast::<i32>(), None);
+        assert_eq!(v.cast_saturating::<i32>(), Vector::from([0, 2]));
+        assert_eq!(Vector::from([1e39f64, -1e39]).cast_saturating::<f32>(), Vector::from([f32::MAX, f32::MIN]));
+    }
+
+    #[test]
+    fn test_matmul_acc() {
+        // Summing in f32 loses the small terms, summing in f64 keeps them
+        let a = Matrix::from([[1.0f32, 1e-8, 1e-8, 1e-8]]);
+        let b = Matrix::<f32, 4, 1>::ones();
+        assert_eq!((&a * &b)[(0, 0)], 1.0);
+        let product: Matrix<f64, 1, 1> = a.matmul_acc(&b);
+        assert!((product[(0, 0)] - (1.0 + 3.0 * 1e-8f32 as f64)).abs() < 1e-15);
+
+        let a = Matrix::from([[i32::MAX, i32::MAX], [1, 2]]);
+        let product: Matrix<i64, 2, 2> = a.matmul_acc(&a.t());
+        assert_eq!(product[(0, 0)], 2 * (i32::MAX as i64).pow(2));
+        assert_eq!(product[(1, 1)], 5);
+    }
+
+    #[test]
+    fn test_matvec_acc() {
+        let a = Matrix::<u8, 2, 3>::fill(200);
+        let x = Vector::from([2u8, 3, 4]);
+        let result: Vector<u32, 2> = a.matvec_acc(&x);
+        assert_eq!(result, Vector::from([1800, 1800]));
+    }
+}
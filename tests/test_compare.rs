@@ -0,0 +1,92 @@
+#[cfg(test)]
+mod tests {
+    use ferrix::{assert_matrix_eq, assert_vector_eq, Matrix, MatrixCM, MatrixRef, RowVector, Vector, VectorRef};
+
+    #[test]
+    fn test_matrix_approx_eq_abs() {
+        let a = Matrix::from([[1.0, 2.0], [3.0, 4.0]]);
+        let b = Matrix::from([[1.0, 2.001], [3.0, 4.0]]);
+        assert!(a.approx_eq_abs(&b, 1e-2));
+        assert!(!a.approx_eq_abs(&b, 1e-4));
+        assert!(a.t().approx_eq_abs(&b.t(), 1e-2));
+        assert!(a.view::<1, 2>((0, 0)).unwrap().approx_eq_abs(&b.view::<1, 2>((0, 0)).unwrap(), 1e-2));
+        assert!(a.approx_eq_abs(&MatrixCM::from(b), 1e-2));
+    }
+
+    #[test]
+    fn test_matrix_approx_eq_rel() {
+        let a = Matrix::from([[1e6, 1e-6], [0.0, -1.0]]);
+        let b = Matrix::from([[1e6 + 1e-4, 1e-6 + 1e-16], [0.0, -1.0]]);
+        assert!(a.approx_eq_rel(&b, 0.0, 1e-9));
+        assert!(!a.approx_eq_rel(&b, 0.0, 1e-12));
+        assert!(!a.approx_eq_abs(&b, 1e-9));
+    }
+
+    #[test]
+    fn test_special_values() {
+        let inf = Matrix::from([[f64::INFINITY, f64::NEG_INFINITY]]);
+        assert!(inf.approx_eq_abs(&inf, 0.0));
+        assert!(inf.approx_eq_rel(&inf, 0.0, 0.0));
+        assert!(!inf.approx_eq_rel(&Matrix::from([[f64::MAX, f64::MIN]]), 1.0, 1.0));
+
+        let nan = Matrix::from([[f64::NAN]]);
+        assert!(!nan.approx_eq_abs(&nan, f64::INFINITY));
+        assert!(!nan.approx_eq_rel(&nan, f64::INFINITY, f64::INFINITY));
+    }
+
+    #[test]
+    fn test_vector_compare() {
+        let a = Vector::from([1.0f32, 2.0, 3.0]);
+        let b = RowVector::from([1.0f32, 2.0, 3.0001]);
+        assert!(a.approx_eq_abs(&b, 1e-3));
+        assert!(!a.approx_eq_abs(&b, 1e-5));
+        assert!(a.approx_eq_rel(&b, 0.0, 1e-4));
+        assert!(a.view::<2>(0).unwrap().approx_eq_abs(&b.view::<2>(0).unwrap(), 0.0));
+    }
+
+    #[test]
+    fn test_assert_matrix_eq() {
+        let a = Matrix::from([[1.0, 2.0], [3.0, 4.0]]);
+        let b = &a + 1e-12;
+        assert_matrix_eq!(a, a);
+        assert_matrix_eq!(a, a.t().t());
+        assert_matrix_eq!(a, b, epsilon = 1e-9);
+        assert_matrix_eq!(a.t(), MatrixCM::from(b.t()), epsilon = 1e-9, max_relative = 1e-9);
+        assert_matrix_eq!(Matrix::from([[1, 2]]), Matrix::from([[1, 2]]));
+    }
+
+    #[test]
+    fn test_assert_vector_eq() {
+        let a = Vector::from([1.0, 2.0, 3.0]);
+        assert_vector_eq!(a, RowVector::from([1.0, 2.0, 3.0]));
+        assert_vector_eq!(a, &a + 1e-12, epsilon = 1e-9);
+        assert_vector_eq!(a.view::<2>(1).unwrap(), Vector::from([2.0, 3.0]), epsilon = 0.0, max_relative = 1e-9);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion `left == right` failed\n1 of 4 elements differ:\n  (1, 0): left = 3, right = 5")]
+    fn test_assert_matrix_eq_exact_fails() {
+        assert_matrix_eq!(Matrix::from([[1, 2], [3, 4]]), Matrix::from([[1, 2], [5, 4]]));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion `left ≈ right` failed (epsilon = 0.001)\n2 of 4 elements differ:\n  (0, 1): left = 2.0, right = 2.5\n  (1, 0): left = 3.0, right = 2.0"
+    )]
+    fn test_assert_matrix_eq_epsilon_fails() {
+        let a = Matrix::from([[1.0, 2.0], [3.0, 4.0]]);
+        assert_matrix_eq!(a, Matrix::from([[1.0, 2.5], [2.0, 4.0]]), epsilon = 1e-3);
+    }
+
+    #[test]
+    #[should_panic(expected = "(epsilon = 0.0, max_relative = 1e-9)\n1 of 2 elements differ:\n  [1]: left = 2.0, right = 2.1")]
+    fn test_assert_vector_eq_relative_fails() {
+        assert_vector_eq!(Vector::from([1.0, 2.0]), Vector::from([1.0, 2.1]), epsilon = 0.0, max_relative = 1e-9);
+    }
+
+    #[test]
+    #[should_panic(expected = "400 of 400 elements differ:")]
+    fn test_assert_matrix_eq_truncates() {
+        assert_matrix_eq!(Matrix::<f64, 20, 20>::zeros(), Matrix::<f64, 20, 20>::ones(), epsilon = 0.5);
+    }
+}
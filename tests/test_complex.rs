@@ -0,0 +1,147 @@
+#[cfg(all(test, feature = "complex"))]
+mod tests {
+    use ferrix::{Complex, DotProduct, Matrix, MatrixRef, RowVector, Vector};
+
+    fn c(re: f64, im: f64) -> Complex<f64> {
+        Complex::new(re, im)
+    }
+
+    #[test]
+    fn test_complex_ops() {
+        let a = Matrix::from([[c(1.0, 2.0), c(0.0, -1.0)], [c(3.0, 0.0), c(2.0, 2.0)]]);
+        let b = Matrix::from([[c(0.0, 1.0), c(1.0, 0.0)], [c(1.0, 1.0), c(-1.0, 0.0)]]);
+
+        assert_eq!(
+            &a + &b,
+            Matrix::from([[c(1.0, 3.0), c(1.0, -1.0)], [c(4.0, 1.0), c(1.0, 2.0)]])
+        );
+        assert_eq!(
+            &a - &b,
+            Matrix::from([[c(1.0, 1.0), c(-1.0, -1.0)], [c(2.0, -1.0), c(3.0, 2.0)]])
+        );
+        assert_eq!(
+            &a * c(0.0, 1.0),
+            Matrix::from([[c(-2.0, 1.0), c(1.0, 0.0)], [c(0.0, 3.0), c(-2.0, 2.0)]])
+        );
+        assert_eq!(
+            &a * &b,
+            Matrix::from([[c(-1.0, 0.0), c(1.0, 3.0)], [c(0.0, 7.0), c(1.0, -2.0)]])
+        );
+
+        let v = Vector::from([c(1.0, 0.0), c(0.0, 1.0)]);
+        assert_eq!(&a * &v, Matrix::from([[c(2.0, 2.0)], [c(1.0, 2.0)]]));
+    }
+
+    #[test]
+    fn test_complex_conj() {
+        let m = Matrix::from([[c(1.0, 2.0), c(3.0, -4.0)], [c(-5.0, 0.0), c(0.0, 6.0)]]);
+        assert_eq!(
+            m.conj(),
+            Matrix::from([[c(1.0, -2.0), c(3.0, 4.0)], [c(-5.0, 0.0), c(0.0, -6.0)]])
+        );
+        assert_eq!(m.conj().conj(), m);
+
+        let v = Vector::from([c(1.0, 1.0), c(2.0, -2.0)]);
+        assert_eq!(v.conj(), Vector::from([c(1.0, -1.0), c(2.0, 2.0)]));
+        assert_eq!(v.h(), RowVector::from([c(1.0, -1.0), c(2.0, 2.0)]));
+        assert_eq!(v.view::<1>(1).unwrap().conj(), Vector::from([c(2.0, 2.0)]));
+        assert_eq!(v.t().conj(), RowVector::from([c(1.0, -1.0), c(2.0, 2.0)]));
+        assert_eq!(v.t().h(), v.conj());
+
+        let r = RowVector::from([c(1.0, 1.0), c(2.0, -2.0)]);
+        assert_eq!(r.conj(), RowVector::from([c(1.0, -1.0), c(2.0, 2.0)]));
+        assert_eq!(r.h(), Vector::from([c(1.0, -1.0), c(2.0, 2.0)]));
+        assert_eq!(r.view::<1>(0).unwrap().h(), Vector::from([c(1.0, -1.0)]));
+
+        // Real matrices are unchanged
+        let m = Matrix::from([[1.0, -2.0], [3.0, 4.0]]);
+        assert_eq!(m.conj(), m);
+    }
+
+    #[test]
+    fn test_complex_conjugate_transpose() {
+        let m = Matrix::from([
+            [c(1.0, 2.0), c(3.0, -4.0), c(0.0, 1.0)],
+            [c(-5.0, 0.0), c(0.0, 6.0), c(2.0, 2.0)],
+        ]);
+        let h = m.h();
+        assert_eq!(h.shape(), (3, 2));
+        assert_eq!(
+            h,
+            Matrix::from([
+                [c(1.0, -2.0), c(-5.0, 0.0)],
+                [c(3.0, 4.0), c(0.0, -6.0)],
+                [c(0.0, -1.0), c(2.0, -2.0)]
+            ])
+        );
+        assert_eq!(h[(1, 1)], c(0.0, -6.0));
+        assert_eq!(h.get((2, 2)), None);
+        assert_eq!(h.h(), m);
+        assert_eq!(m.h(), Matrix::from(m.t()).conj());
+
+        // Views
+        let view = m.view::<2, 2>((0, 1)).unwrap();
+        assert_eq!(
+            view.h(),
+            Matrix::from([[c(3.0, 4.0), c(0.0, -6.0)], [c(0.0, -1.0), c(2.0, -2.0)]])
+        );
+        assert_eq!(view.conj(), view.h().t());
+        assert_eq!(m.t().h(), m.conj());
+        assert_eq!(m.t().conj(), h);
+        let strided = m.strided_view::<2, 2>((0, 0), (1, 2)).unwrap();
+        assert_eq!(
+            strided.h(),
+            Matrix::from([[c(1.0, -2.0), c(-5.0, 0.0)], [c(0.0, -1.0), c(2.0, -2.0)]])
+        );
+
+        // A^H A is Hermitian
+        let gram = m.h() * &m;
+        assert_eq!(gram.h(), gram);
+        assert_eq!(gram[(0, 0)], c(30.0, 0.0));
+
+        // A A^H is Hermitian
+        let gram = &m * m.h();
+        assert_eq!(gram.h(), gram);
+        assert_eq!(gram[(0, 0)], c(31.0, 0.0));
+    }
+
+    #[test]
+    fn test_complex_dot() {
+        let a = Vector::from([c(1.0, 2.0), c(3.0, -1.0)]);
+        let b = Vector::from([c(0.0, 1.0), c(2.0, 2.0)]);
+
+        // a . b is not conjugated
+        assert_eq!((&a).dot(&b), c(6.0, 5.0));
+        assert_eq!((&b).dot(&a), c(6.0, 5.0));
+
+        // conj(a) . b
+        assert_eq!((&a).dotc(&b), c(6.0, 9.0));
+        assert_eq!((&b).dotc(&a), c(6.0, -9.0));
+
+        // The inner product of a vector with itself is real
+        assert_eq!((&a).dotc(&a), c(15.0, 0.0));
+
+        let view = a.view::<2>(0).unwrap();
+        assert_eq!(view.dotc(&b), c(6.0, 9.0));
+
+        let r = RowVector::from([c(1.0, 2.0), c(3.0, -1.0)]);
+        assert_eq!(
+            r.dotc(RowVector::from([c(0.0, 1.0), c(2.0, 2.0)])),
+            c(6.0, 9.0)
+        );
+    }
+
+    #[test]
+    fn test_complex_magnitude() {
+        let v = Vector::from([c(3.0, 4.0)]);
+        assert_eq!(v.magnitude(), 5.0);
+
+        let v = Vector::from([c(1.0, 1.0), c(1.0, -1.0), c(0.0, 2.0), c(2.0, 0.0)]);
+        assert_eq!(v.magnitude(), 12.0f64.sqrt());
+        assert_eq!(v.view::<2>(2).unwrap().magnitude(), 8.0f64.sqrt());
+        assert_eq!(v.t().magnitude(), 12.0f64.sqrt());
+
+        let r = RowVector::from([Complex::new(0.0f32, 3.0), Complex::new(4.0, 0.0)]);
+        assert_eq!(r.magnitude(), 5.0);
+    }
+}
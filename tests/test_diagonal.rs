@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod tests {
+    use ferrix::{Diagonal, Matrix, Vector};
+
+    #[test]
+    fn test_new() {
+        let d = Diagonal::new(Vector::from([1, 2, 3]));
+        assert_eq!(d, Diagonal::from([1, 2, 3]));
+        assert_eq!(d.diag(), &Vector::from([1, 2, 3]));
+        assert_eq!(Matrix::from(d.clone()), Vector::from([1, 2, 3]).diag());
+
+        let mut d = d;
+        d[2] = 5;
+        assert_eq!(d.to_matrix(), Matrix::from([[1, 0, 0], [0, 2, 0], [0, 0, 5]]));
+    }
+
+    #[test]
+    fn test_mul() {
+        let d = Diagonal::from([2, -1, 3]);
+        let dense = d.to_matrix();
+        let m = Matrix::from([[1, 2], [3, 4], [5, 6]]);
+        let v = Vector::from([1, 2, 3]);
+
+        assert_eq!(&d * &v, &dense * &v);
+        assert_eq!(&d * &m, &dense * &m);
+        assert_eq!(&d * v.view::<3>(0).unwrap(), &dense * &v);
+        assert_eq!(&d * m.t().t(), &dense * &m);
+        assert_eq!(d.clone() * m.strided_view::<3, 1>((0, 0), (1, 1)).unwrap(), Matrix::from([[2], [-3], [15]]));
+        let mt = Matrix::from([[1, 3, 5], [2, 4, 6]]);
+        assert_eq!(&mt * &d, &mt * &dense);
+        assert_eq!(Matrix::from([[1, 1, 1]]) * d.clone(), Matrix::from([[2, -1, 3]]));
+        assert_eq!(&d * d.clone(), Diagonal::from([4, 1, 9]));
+        assert_eq!(&d * 2, Diagonal::from([4, -2, 6]));
+        assert_eq!(&d + d.clone(), d * 2);
+    }
+
+    #[test]
+    fn test_sub_neg() {
+        let d = Diagonal::from([2, -1, 3]);
+        assert_eq!(&d - Diagonal::from([1, 1, 1]), Diagonal::from([1, -2, 2]));
+        assert_eq!(d.clone() - d.clone(), Diagonal::from([0, 0, 0]));
+        assert_eq!(-&d, Diagonal::from([-2, 1, -3]));
+        assert_eq!(-d.clone() + d, Diagonal::from([0, 0, 0]));
+    }
+
+    #[test]
+    fn test_solve() {
+        let d = Diagonal::from([2.0, 4.0, 0.5]);
+        let x = Vector::from([1.0, 2.0, 3.0]);
+        assert_eq!(d.solve(&Vector::from(&d * &x)), Some(x));
+        assert_eq!(Diagonal::from([1.0, 0.0]).solve(&Vector::from([1.0, 1.0])), None);
+
+        // Integer divisions truncate
+        let d = Diagonal::from([2, 3]);
+        assert_eq!(d.solve(&Vector::from([4, 9])), Some(Vector::from([2, 3])));
+        assert_eq!(d.solve(&Vector::from([5, 7])), Some(Vector::from([2, 2])));
+    }
+}
@@ -0,0 +1,85 @@
+#[cfg(test)]
+mod tests {
+    use ferrix::{assert_vector_eq, solve_tridiagonal, Matrix, Vector};
+
+    #[test]
+    fn test_solve_triangular() {
+        let a = Matrix::from([[2.0, 5.0, -1.0], [1.0, 3.0, 4.0], [-2.0, 0.5, 4.0]]);
+        let x = Vector::from([1.0, -2.0, 3.0]);
+        let lower = Matrix::from([[2.0, 0.0, 0.0], [1.0, 3.0, 0.0], [-2.0, 0.5, 4.0]]);
+        let upper = Matrix::from([[2.0, 5.0, -1.0], [0.0, 3.0, 4.0], [0.0, 0.0, 4.0]]);
+
+        // Only the relevant triangle of `a` is read
+        assert_eq!(a.solve_lower(&Vector::from(&lower * &x)), Some(x.clone()));
+        assert_eq!(a.solve_upper(&Vector::from(&upper * &x)), Some(x.clone()));
+        assert_eq!(a.solve_lower_transpose(&Vector::from(lower.t() * &x)), Some(x.clone()));
+        assert_eq!(a.solve_upper_transpose(&Vector::from(upper.t() * &x)), Some(x.clone()));
+        assert_eq!(a.solve_lower(&x.view::<3>(0).unwrap()), lower.solve_lower(&x));
+
+        let singular = Matrix::from([[1.0, 0.0], [1.0, 0.0]]);
+        assert_eq!(singular.solve_lower(&Vector::from([1.0, 1.0])), None);
+        assert_eq!(singular.solve_upper_transpose(&Vector::from([1.0, 1.0])), None);
+    }
+
+    #[test]
+    fn test_solve_unit_triangular() {
+        let a = Matrix::from([[9, 2, -3], [4, 9, 1], [-1, 2, 9]]);
+        let x = Vector::from([1, 2, -1]);
+        let lower = Matrix::from([[1, 0, 0], [4, 1, 0], [-1, 2, 1]]);
+        let upper = Matrix::from([[1, 2, -3], [0, 1, 1], [0, 0, 1]]);
+        assert_eq!(a.solve_lower_unit(&Vector::from(lower * &x)), x);
+        assert_eq!(a.solve_upper_unit(&Vector::from(upper * &x)), x);
+    }
+
+    #[test]
+    fn test_solve_tridiagonal() {
+        let lower = Vector::from([0.0, 1.0, -2.0, 1.0, 0.5]);
+        let diag = Vector::from([4.0, 5.0, 6.0, 4.0, 3.0]);
+        let upper = Vector::from([1.0, 2.0, 1.0, -1.0, 0.0]);
+        let a = Matrix::from(core::array::from_fn(|i| {
+            core::array::from_fn(|j| match j as isize - i as isize {
+                -1 => lower[i],
+                0 => diag[i],
+                1 => upper[i],
+                _ => 0.0,
+            })
+        }));
+        let x = Vector::from([1.0, -1.0, 2.0, 0.5, -3.0]);
+        let solution = solve_tridiagonal(&lower, &diag, &upper, &Vector::from(&a * &x)).unwrap();
+        assert_vector_eq!(solution, x, epsilon = 1e-14);
+
+        // The bands can be any vector-like type, e.g. views into one packed vector
+        let packed = Vector::from([0.0, 1.0, -2.0, 1.0, 0.5, 4.0, 5.0, 6.0, 4.0, 3.0, 1.0, 2.0, 1.0, -1.0, 0.0]);
+        let (lower, diag, upper) = (packed.view::<5>(0).unwrap(), packed.view::<5>(5).unwrap(), packed.view::<5>(10).unwrap());
+        assert_eq!(solve_tridiagonal(&lower, &diag, &upper, &Vector::from(&a * &x)), Some(solution));
+
+        let zero = Vector::from([0.0, 0.0]);
+        assert_eq!(solve_tridiagonal(&zero, &zero, &zero, &Vector::from([1.0, 1.0])), None);
+        assert_eq!(
+            solve_tridiagonal(&Vector::<f64, 0>::zeros(), &Vector::zeros(), &Vector::zeros(), &Vector::zeros()),
+            Some(Vector::zeros())
+        );
+    }
+
+    #[test]
+    fn test_solve_banded() {
+        let a = Matrix::from([
+            [5.0, 1.0, 2.0, 0.0, 0.0],
+            [1.0, 6.0, 1.0, 2.0, 0.0],
+            [0.0, 1.0, 7.0, 1.0, 2.0],
+            [0.0, 0.0, 1.0, 6.0, 1.0],
+            [0.0, 0.0, 0.0, 1.0, 5.0],
+        ]);
+        let x = Vector::from([1.0, 2.0, -1.0, 0.5, 3.0]);
+        let solution = a.solve_banded(1, 2, &Vector::from(&a * &x)).unwrap();
+        assert_vector_eq!(solution, x, epsilon = 1e-14);
+
+        // A diagonal band ignores the off-diagonal elements
+        assert_eq!(a.solve_banded(0, 0, &Vector::from([5.0, 6.0, 7.0, 6.0, 5.0])), Some(Vector::ones()));
+        assert_eq!(Matrix::<f64, 2, 2>::zeros().solve_banded(1, 1, &Vector::ones()), None);
+
+        // Bandwidths beyond the matrix cover all of it
+        let full = a.solve_banded(usize::MAX, usize::MAX, &Vector::from(&a * &x)).unwrap();
+        assert_vector_eq!(full, x, epsilon = 1e-14);
+    }
+}
@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod tests {
+    use ferrix::{assert_matrix_eq, Matrix, Matrix2, Matrix3};
+
+    #[test]
+    fn test_expm() {
+        let generator = Matrix2::from([[0.0, -0.7], [0.7, 0.0]]);
+        assert_matrix_eq!(generator.expm(), Matrix2::rot(0.7), epsilon = 1e-15);
+        assert_eq!(Matrix3::<f64>::zeros().expm(), Matrix3::eye());
+
+        // Moler and Van Loan's example, whose eigenvalues are -1 and -17
+        let a = Matrix::from([[-49.0, 24.0], [-64.0, 31.0]]);
+        let e1 = (-1.0f64).exp();
+        let e17 = (-17.0f64).exp();
+        let expected = Matrix::from([
+            [-2.0 * e1 + 3.0 * e17, 1.5 * e1 - 1.5 * e17],
+            [-4.0 * e1 + 4.0 * e17, 3.0 * e1 - 2.0 * e17],
+        ]);
+        assert_matrix_eq!(a.expm(), expected, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_expm_f32() {
+        let a = Matrix::from([[0.0f32, 1.0], [-2.0, -3.0]]);
+        let e1 = (-1.0f32).exp();
+        let e2 = (-2.0f32).exp();
+        let expected = Matrix::from([[2.0 * e1 - e2, e1 - e2], [-2.0 * e1 + 2.0 * e2, -e1 + 2.0 * e2]]);
+        assert_matrix_eq!(a.expm(), expected, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_sqrtm() {
+        let a = Matrix3::from([[4.0, 1.0, 0.5], [1.0, 3.0, 0.2], [0.5, 0.2, 2.0]]);
+        let root = a.sqrtm().unwrap();
+        assert_matrix_eq!(&root * &root, a, epsilon = 1e-13);
+        assert_matrix_eq!(root, root.t(), epsilon = 1e-13);
+
+        assert_eq!(Matrix3::<f64>::zeros().sqrtm(), None);
+        assert_eq!(Matrix::from([[1.0, 0.0], [0.0, -4.0]]).sqrtm(), None);
+    }
+
+    #[test]
+    fn test_logm() {
+        let generator = Matrix2::from([[0.0, -1.2], [1.2, 0.0]]);
+        assert_matrix_eq!(Matrix2::rot(1.2).logm().unwrap(), generator, epsilon = 1e-13);
+
+        let a = Matrix3::from([[5.0, 1.0, 0.0], [-2.0, 3.0, 1.0], [0.5, 0.0, 10.0]]);
+        assert_matrix_eq!(a.logm().unwrap().expm(), a, epsilon = 1e-11);
+        assert_matrix_eq!(a.expm().logm().unwrap(), a, epsilon = 1e-10);
+
+        assert_eq!(Matrix::from([[-1.0, 0.0], [0.0, 1.0]]).logm(), None);
+    }
+
+    #[test]
+    fn test_powi() {
+        let a = Matrix3::from([[0.9, 0.1, 0.0], [0.0, 0.8, 0.2], [0.3, 0.0, 0.7]]);
+        let mut expected = Matrix3::eye();
+        for k in 0..=13 {
+            assert_matrix_eq!(a.powi(k), expected, epsilon = 1e-14);
+            expected = expected * &a;
+        }
+        assert_matrix_eq!(a.powi(-5) * a.powi(5), Matrix3::eye(), epsilon = 1e-12);
+        assert_matrix_eq!(a.powi(-1) * a, Matrix3::eye(), epsilon = 1e-14);
+
+        let singular = Matrix::from([[1.0f64, 2.0], [2.0, 4.0]]);
+        assert!(!singular.powi(-1)[(1, 1)].is_finite());
+    }
+}
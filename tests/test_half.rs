@@ -0,0 +1,36 @@
+#[cfg(all(test, feature = "half"))]
+mod tests {
+    use ferrix::{bf16, f16, DotProduct, Matrix, MatrixRef, Vector, VectorRef};
+
+    #[test]
+    fn test_f16_ops() {
+        let a = Matrix::from([[f16::from_f32(1.0), f16::from_f32(2.0)], [f16::from_f32(3.0), f16::from_f32(4.0)]]);
+        let b = &a + f16::ONE;
+        assert_eq!(b.cast::<f32>(), Some(Matrix::from([[2.0, 3.0], [4.0, 5.0]])));
+        assert_eq!((&a * &a).cast::<f32>(), Some(Matrix::from([[7.0, 10.0], [15.0, 22.0]])));
+        assert_eq!((-&a).cast_lossy::<f32>(), Matrix::from([[-1.0, -2.0], [-3.0, -4.0]]));
+
+        let v = Vector::from([bf16::from_f32(1.5), bf16::from_f32(2.0)]);
+        assert_eq!((&v).dot(&v).to_f32(), 6.25);
+    }
+
+    #[test]
+    fn test_f16_accumulation() {
+        // 4096 products of 1 exceed the f16 spacing at 2048, so an f16 sum stalls
+        let a = Matrix::<f16, 1, 4096>::fill(f16::ONE);
+        let b = Matrix::<f16, 4096, 1>::fill(f16::ONE);
+        assert_eq!((&a * &b)[(0, 0)].to_f32(), 2048.0);
+        let product: Matrix<f32, 1, 1> = a.matmul_acc(&b);
+        assert_eq!(product[(0, 0)], 4096.0);
+    }
+
+    #[test]
+    fn test_f16_cast() {
+        let m = Matrix::from([[0.1f32, 70000.0], [-70000.0, 1.0]]);
+        let h = m.cast_lossy::<f16>();
+        assert_eq!(h[(0, 0)], f16::from_f32(0.1));
+        assert!(h[(0, 1)].is_infinite());
+        assert_eq!(m.cast_saturating::<f16>()[(1, 1)], f16::ONE);
+        assert_eq!(Vector::from([f16::MAX]).cast::<i32>(), Some(Vector::from([65504])));
+    }
+}
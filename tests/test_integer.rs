@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod tests {
+    use ferrix::{Matrix, Matrix3, MatrixRef};
+
+    #[test]
+    fn test_det_bareiss() {
+        assert_eq!(Matrix::<i64, 0, 0>::from([]).det_bareiss(), 1);
+        assert_eq!(Matrix::from([[7i32]]).det_bareiss(), 7);
+        assert_eq!(Matrix::from([[0, 1], [1, 0]]).det_bareiss(), -1);
+        assert_eq!(Matrix3::from([[0i64, 2, 1], [3, 0, 4], [5, 6, 0]]).det_bareiss(), 58);
+        assert_eq!(Matrix3::from([[1i64, 2, 3], [4, 5, 6], [7, 8, 9]]).det_bareiss(), 0);
+
+        let a = Matrix::from([[3i64, -2, 5, 1], [0, 4, -1, 2], [6, 1, 0, -3], [-2, 7, 2, 5]]);
+        assert_eq!(a.det_bareiss(), a.map(|x| x as f64).det().round() as i64);
+    }
+
+    #[test]
+    fn test_hermite_normal_form() {
+        let a = Matrix3::from([[3i64, 3, 1], [0, 4, 1], [2, 5, 2]]);
+        let (h, u) = a.hermite_normal_form();
+        assert_eq!(&u * &a, h);
+        assert_eq!(u.det_bareiss().abs(), 1);
+        for i in 0..3 {
+            assert!(h[(i, i)] > 0);
+            for j in 0..i {
+                assert_eq!(h[(i, j)], 0);
+                assert!((0..h[(i, i)]).contains(&h[(j, i)]));
+            }
+        }
+        assert_eq!(h[(0, 0)] * h[(1, 1)] * h[(2, 2)], a.det_bareiss().abs());
+    }
+
+    #[test]
+    fn test_hermite_normal_form_rectangular() {
+        let a = Matrix::from([[2i32, 4, 6, 8], [1, 2, 4, 3], [3, 6, 10, 11]]);
+        let (h, u) = a.hermite_normal_form();
+        assert_eq!(h, Matrix::from([[1, 2, 0, 7], [0, 0, 2, -2], [0, 0, 0, 0]]));
+        assert_eq!(&u * a, h);
+        assert_eq!(u.det_bareiss().abs(), 1);
+    }
+
+    #[test]
+    fn test_smith_normal_form() {
+        let a = Matrix3::from([[0i64, 4, 6], [8, 10, 12], [14, 16, 18]]);
+        let (d, u, v) = a.smith_normal_form();
+        assert_eq!(d, Matrix::from([[2, 0, 0], [0, 2, 0], [0, 0, 6]]));
+        assert_eq!(&u * a * &v, d);
+        assert_eq!(u.det_bareiss().abs(), 1);
+        assert_eq!(v.det_bareiss().abs(), 1);
+    }
+
+    #[test]
+    fn test_smith_normal_form_rectangular() {
+        let a = Matrix::from([[6i64, 4], [10, 8], [4, 2]]);
+        let (d, u, v) = a.smith_normal_form();
+        assert_eq!(d, Matrix::from([[2, 0], [0, 2], [0, 0]]));
+        assert_eq!(u * a * v, d);
+
+        let zero = Matrix::<i64, 2, 3>::zeros();
+        let (d, u, v) = zero.smith_normal_form();
+        assert_eq!(d, zero);
+        assert_eq!(u, Matrix::eye());
+        assert_eq!(v, Matrix::eye());
+    }
+}
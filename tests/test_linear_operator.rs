@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod tests {
+    use ferrix::{LinearOperator, Matrix, TransposeOperator, Vector};
+
+    fn matrix() -> Matrix<f64, 3, 4> {
+        Matrix::from([
+            [1.0, 2.0, 3.0, 4.0],
+            [5.0, 6.0, 7.0, 8.0],
+            [9.0, 10.0, 11.0, 12.0],
+        ])
+    }
+
+    // Generic caller, as a solver or power iteration would be
+    fn gram<A: TransposeOperator<f64, N, M>, const N: usize, const M: usize>(
+        a: &A,
+        x: &Vector<f64, M>,
+    ) -> Vector<f64, M> {
+        a.apply_transpose(&a.apply(x))
+    }
+
+    #[test]
+    fn test_linear_operator_matrix() {
+        let a = matrix();
+        let x = Vector::from([1.0, 0.0, -1.0, 2.0]);
+        assert_eq!(a.apply(&x), Vector::from([6.0, 14.0, 22.0]));
+
+        let y = Vector::from([1.0, -1.0, 2.0]);
+        assert_eq!(
+            a.apply_transpose(&y),
+            Vector::from([14.0, 16.0, 18.0, 20.0])
+        );
+    }
+
+    #[test]
+    fn test_linear_operator_views() {
+        let mut a = matrix();
+        let x = Vector::from([1.0, -1.0]);
+        let y = Vector::from([2.0, 1.0]);
+
+        // Rows 1..3, columns 1..3: [[6, 7], [10, 11]]
+        let view = a.view::<2, 2>((1, 1)).unwrap();
+        assert_eq!(view.apply(&x), Vector::from([-1.0, -1.0]));
+        assert_eq!(view.apply_transpose(&y), Vector::from([22.0, 25.0]));
+
+        let view_mut = a.view_mut::<2, 2>((1, 1)).unwrap();
+        assert_eq!(view_mut.apply(&x), Vector::from([-1.0, -1.0]));
+        assert_eq!(view_mut.apply_transpose(&y), Vector::from([22.0, 25.0]));
+
+        // Transposed: [[1, 5, 9], [2, 6, 10], [3, 7, 11], [4, 8, 12]]
+        let z = Vector::from([1.0, 0.0, -1.0]);
+        let w = Vector::from([1.0, 0.0, -1.0, 2.0]);
+        let t = a.t();
+        assert_eq!(t.apply(&z), Vector::from([-8.0, -8.0, -8.0, -8.0]));
+        assert_eq!(t.apply_transpose(&w), Vector::from([6.0, 14.0, 22.0]));
+
+        let t_mut = a.t_mut();
+        assert_eq!(t_mut.apply(&z), Vector::from([-8.0, -8.0, -8.0, -8.0]));
+        assert_eq!(t_mut.apply_transpose(&w), Vector::from([6.0, 14.0, 22.0]));
+
+        let h = a.h();
+        assert_eq!(h.apply(&z), Vector::from([-8.0, -8.0, -8.0, -8.0]));
+        assert_eq!(h.apply_transpose(&w), Vector::from([6.0, 14.0, 22.0]));
+    }
+
+    #[test]
+    fn test_linear_operator_closure() {
+        let a = matrix();
+        let f = |x: &Vector<f64, 4>| a.apply(x);
+        let f_t = |y: &Vector<f64, 3>| a.apply_transpose(y);
+        let x = Vector::from([1.0, 0.0, -1.0, 2.0]);
+        let y = Vector::from([1.0, -1.0, 2.0]);
+
+        assert_eq!(f.apply(&x), a.apply(&x));
+        assert_eq!((f, f_t).apply(&x), a.apply(&x));
+        assert_eq!((f, f_t).apply_transpose(&y), a.apply_transpose(&y));
+        assert_eq!(gram(&(f, f_t), &x), gram(&a, &x));
+    }
+
+    #[test]
+    fn test_linear_operator_sparse() {
+        let a = matrix();
+        let sparse = ferrix::SparseMatrix::from_dense(&a);
+        let y = Vector::from([1.0, -1.0, 2.0]);
+        assert_eq!(
+            TransposeOperator::<f64, 3, 4>::apply_transpose(&sparse, &y),
+            a.apply_transpose(&y)
+        );
+        assert_eq!(
+            TransposeOperator::<f64, 3, 4>::apply_transpose(&sparse.to_csc(), &y),
+            a.apply_transpose(&y)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Dimension mismatch")]
+    fn test_linear_operator_sparse_dimension_mismatch() {
+        let sparse = ferrix::SparseMatrix::from_dense(&matrix());
+        TransposeOperator::<f64, 4, 3>::apply_transpose(&sparse, &Vector::from([1.0, 2.0, 3.0, 4.0]));
+    }
+}
@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use ferrix::{FloatRandom, IntRandom, Matrix, RowVector, Vector};
+    use ferrix::{FloatRandom, IntRandom, Matrix, MatrixMut, MatrixRef, RowVector, Vector};
 
     #[test]
     fn test_default() {
@@ -78,6 +78,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::absurd_extreme_comparisons)]
     fn test_random_int() {
         let matrix = Matrix::<i32, 2, 3>::random();
         assert!(i32::MIN <= matrix[(0, 0)] && matrix[(0, 0)] <= i32::MAX);
@@ -509,6 +510,260 @@ mod tests {
         assert_eq!(new_matrix, Matrix::from([[1, 4], [2, 5], [3, 6]]));
     }
 
+    #[test]
+    fn test_split_at_mut_rows() {
+        let mut matrix = Matrix::from([[1, 2], [3, 4], [5, 6]]);
+        assert!(matrix.split_at_mut_rows::<1, 1>().is_none());
+
+        let (mut top, mut bottom) = matrix.split_at_mut_rows::<2, 1>().unwrap();
+        assert_eq!(top, Matrix::from([[1, 2], [3, 4]]));
+        assert_eq!(bottom, Matrix::from([[5, 6]]));
+        bottom[(0, 0)] = top[(1, 1)];
+        top[(0, 1)] = 0;
+        assert_eq!(matrix, Matrix::from([[1, 0], [3, 4], [4, 6]]));
+    }
+
+    #[test]
+    fn test_split_at_mut_cols() {
+        let mut matrix = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+        assert!(matrix.split_at_mut_cols::<2, 2>().is_none());
+
+        let (mut left, right) = matrix.split_at_mut_cols::<1, 2>().unwrap();
+        assert_eq!(right, Matrix::from([[2, 3], [5, 6]]));
+        left.copy_from(&(&right * Matrix::from([[1], [1]])));
+        assert_eq!(matrix, Matrix::from([[5, 2, 3], [11, 5, 6]]));
+    }
+
+    #[test]
+    fn test_split_quadrants_mut() {
+        let mut matrix = Matrix::from([[2.0, 1.0, 1.0], [4.0, 3.0, 3.0], [2.0, 1.0, 3.0]]);
+        assert!(matrix.split_quadrants_mut::<1, 2, 2, 2>().is_none());
+
+        // One step of an in-place LU factorisation: A22 -= A21 * A12 / a11
+        let (a11, a12, mut a21, mut a22) = matrix.split_quadrants_mut::<1, 2, 1, 2>().unwrap();
+        let pivot = a11[(0, 0)];
+        a21.map_inplace(|x| x / pivot);
+        let update = &a21 * &a12;
+        let schur = a22.zip_map(&update, |x, y| x - y);
+        a22.copy_from(&schur);
+        assert_eq!(
+            matrix,
+            Matrix::from([[2.0, 1.0, 1.0], [2.0, 1.0, 1.0], [1.0, 0.0, 2.0]])
+        );
+    }
+
+    #[test]
+    fn test_split_at_mut_rows_threads() {
+        let mut matrix = Matrix::from([[1, 2], [3, 4], [5, 6], [7, 8]]);
+        let (mut top, mut bottom) = matrix.split_at_mut_rows::<2, 2>().unwrap();
+        std::thread::scope(|s| {
+            s.spawn(|| top.map_inplace(|x| x * 10));
+            s.spawn(|| bottom.map_inplace(|x| -x));
+        });
+        assert_eq!(
+            matrix,
+            Matrix::from([[10, 20], [30, 40], [-5, -6], [-7, -8]])
+        );
+    }
+
+    #[test]
+    fn test_split_at_mut_rows_read_while_writing() {
+        let mut matrix = Matrix::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0], [7.0, 8.0]]);
+        let (top, mut bottom) = matrix.split_at_mut_rows::<2, 2>().unwrap();
+        std::thread::scope(|s| {
+            let reader = s.spawn(|| {
+                // Reads only the elements of `top` while `bottom` is being written
+                let gram = top.t() * top.view::<2, 2>((0, 0)).unwrap();
+                let product = top.view::<1, 2>((1, 0)).unwrap() * Vector::from([1.0, -1.0]);
+                let strided = Matrix::from(top.t()) * top.t().t();
+                (gram, product, strided)
+            });
+            s.spawn(|| {
+                for _ in 0..100 {
+                    bottom.map_inplace(|x| x + 1.0);
+                }
+            });
+            let (gram, product, strided) = reader.join().unwrap();
+            assert_eq!(gram, Matrix::from([[10.0, 14.0], [14.0, 20.0]]));
+            assert_eq!(product, Matrix::from([[-1.0]]));
+            assert_eq!(strided, gram);
+        });
+        assert_eq!(
+            matrix,
+            Matrix::from([[1.0, 2.0], [3.0, 4.0], [105.0, 106.0], [107.0, 108.0]])
+        );
+    }
+
+    #[test]
+    fn test_rows_mut() {
+        let mut matrix = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+        let mut rows: Vec<_> = matrix.rows_mut().collect();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[1], RowVector::from([4, 5, 6]));
+        let (first, second) = rows.split_at_mut(1);
+        second[0][2] = first[0][0];
+        first[0][0] = 0;
+        assert_eq!(matrix, Matrix::from([[0, 2, 3], [4, 5, 1]]));
+    }
+
+    #[test]
+    fn test_hstack() {
+        let a = Matrix::from([[1, 2], [3, 4]]);
+        let b = Matrix::from([[5, 6, 7], [8, 9, 10]]);
+        let stacked = Matrix::<i32, 2, 5>::hstack(&a, &b).unwrap();
+        assert_eq!(stacked, Matrix::from([[1, 2, 5, 6, 7], [3, 4, 8, 9, 10]]));
+        assert_eq!(
+            Matrix::<i32, 2, 4>::hstack(&a.t(), &b.view::<2, 2>((0, 1)).unwrap()).unwrap(),
+            Matrix::from([[1, 3, 6, 7], [2, 4, 9, 10]])
+        );
+        assert!(Matrix::<i32, 2, 4>::hstack(&a, &b).is_none());
+    }
+
+    #[test]
+    fn test_vstack() {
+        let a = Matrix::from([[1, 2], [3, 4]]);
+        let b = Matrix::from(RowVector::from([5, 6]));
+        let stacked = Matrix::<i32, 3, 2>::vstack(&a, &b).unwrap();
+        assert_eq!(stacked, Matrix::from([[1, 2], [3, 4], [5, 6]]));
+        assert_eq!(
+            Matrix::<i32, 4, 2>::vstack(&a, &a.t()).unwrap(),
+            Matrix::from([[1, 2], [3, 4], [1, 3], [2, 4]])
+        );
+        assert!(Matrix::<i32, 4, 2>::vstack(&a, &b).is_none());
+    }
+
+    #[test]
+    fn test_from_blocks() {
+        // Augmented state-space matrix [[A, B], [0, I]]
+        let a = Matrix::from([[1.0, 0.1], [0.0, 1.0]]);
+        let b = Matrix::from([[0.0], [0.1]]);
+        let zeros = Matrix::<f64, 1, 2>::zeros();
+        let eye = Matrix::<f64, 1, 1>::eye();
+        let augmented = Matrix::<f64, 3, 3>::from_blocks(&a, &b, &zeros, &eye).unwrap();
+        assert_eq!(
+            augmented,
+            Matrix::from([[1.0, 0.1, 0.0], [0.0, 1.0, 0.1], [0.0, 0.0, 1.0]])
+        );
+        assert!(Matrix::<f64, 3, 4>::from_blocks(&a, &b, &zeros, &eye).is_none());
+    }
+
+    #[test]
+    fn test_block_diag() {
+        let a = Matrix::from([[1, 2], [3, 4]]);
+        let b = Matrix::from([[5, 6]]);
+        let block = Matrix::<i32, 3, 4>::block_diag(&a, &b).unwrap();
+        assert_eq!(block, Matrix::from([[1, 2, 0, 0], [3, 4, 0, 0], [0, 0, 5, 6]]));
+        assert!(Matrix::<i32, 3, 3>::block_diag(&a, &b).is_none());
+    }
+
+    #[test]
+    fn test_kron() {
+        let a = Matrix::from([[1, 2], [3, 4], [5, 6]]);
+        let b = Matrix::from([[1, -1]]);
+        let product: Matrix<i32, 3, 4> = a.kron(&b).unwrap();
+        assert_eq!(
+            product,
+            Matrix::from([[1, -1, 2, -2], [3, -3, 4, -4], [5, -5, 6, -6]])
+        );
+        let block: Matrix<i32, 4, 4> = Matrix::<i32, 2, 2>::eye().kron(&Matrix::from([[1, 2], [3, 4]])).unwrap();
+        assert_eq!(
+            block,
+            Matrix::from([[1, 2, 0, 0], [3, 4, 0, 0], [0, 0, 1, 2], [0, 0, 3, 4]])
+        );
+        assert!(a.kron::<1, 2, 3, 3>(&b).is_none());
+    }
+
+    #[test]
+    fn test_from_row_slice() {
+        let data = [1, 2, 3, 4, 5, 6];
+        let matrix = Matrix::<i32, 3, 2>::from_row_slice(&data).unwrap();
+        assert_eq!(matrix, Matrix::from([[1, 2], [3, 4], [5, 6]]));
+        assert!(Matrix::<i32, 2, 2>::from_row_slice(&data).is_none());
+        assert!(Matrix::<i32, 4, 2>::from_row_slice(&data).is_none());
+    }
+
+    #[test]
+    fn test_from_column_slice() {
+        let data = [1, 2, 3, 4, 5, 6];
+        let matrix = Matrix::<i32, 3, 2>::from_column_slice(&data).unwrap();
+        assert_eq!(matrix, Matrix::from([[1, 4], [2, 5], [3, 6]]));
+        assert!(Matrix::<i32, 2, 2>::from_column_slice(&data).is_none());
+        assert_eq!(matrix.vec::<6>().unwrap(), Vector::from(data));
+    }
+
+    #[test]
+    fn test_reshape() {
+        let matrix = Matrix::from([[1, 2, 3, 4], [5, 6, 7, 8]]);
+        assert_eq!(
+            matrix.reshape::<4, 2>().unwrap(),
+            Matrix::from([[1, 2], [3, 4], [5, 6], [7, 8]])
+        );
+        assert_eq!(
+            matrix.reshape::<1, 8>().unwrap(),
+            Matrix::from([[1, 2, 3, 4, 5, 6, 7, 8]])
+        );
+        assert_eq!(matrix.reshape::<2, 4>().unwrap(), matrix);
+        assert!(matrix.reshape::<3, 3>().is_none());
+    }
+
+    #[test]
+    fn test_flatten() {
+        let matrix = Matrix::from([[1, 2], [3, 4], [5, 6]]);
+        assert_eq!(matrix.flatten::<6>().unwrap(), Vector::from([1, 2, 3, 4, 5, 6]));
+        assert!(matrix.flatten::<5>().is_none());
+    }
+
+    #[test]
+    fn test_vec() {
+        let matrix = Matrix::from([[1, 2], [3, 4], [5, 6]]);
+        assert_eq!(matrix.vec::<6>().unwrap(), Vector::from([1, 3, 5, 2, 4, 6]));
+        assert_eq!(matrix.vec::<6>().unwrap(), matrix.t().to_matrix().flatten::<6>().unwrap());
+        assert!(matrix.vec::<7>().is_none());
+    }
+
+    #[test]
+    fn test_as_slice() {
+        let matrix = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(matrix.as_slice(), &[1, 2, 3, 4, 5, 6]);
+        assert_eq!(matrix.as_slice()[4], matrix[4]);
+    }
+
+    #[test]
+    fn test_as_mut_slice() {
+        let mut matrix = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+        matrix.as_mut_slice().reverse();
+        assert_eq!(matrix, Matrix::from([[6, 5, 4], [3, 2, 1]]));
+    }
+
+    #[test]
+    fn test_as_ptr() {
+        let mut matrix = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+        let ptr = matrix.as_ptr();
+        let copied: Vec<i32> = (0..6).map(|i| unsafe { *ptr.add(i) }).collect();
+        assert_eq!(copied, vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(ptr, &matrix[(0, 0)] as *const i32);
+
+        let ptr = matrix.as_mut_ptr();
+        unsafe { *ptr.add(5) = 0 };
+        assert_eq!(matrix, Matrix::from([[1, 2, 3], [4, 5, 0]]));
+    }
+
+    #[test]
+    fn test_det() {
+        let a = Matrix::from([[4.0, 3.0, 2.0], [2.0, 1.0, 3.0], [3.0, 2.0, 1.0]]);
+        assert!((a.det() - 3.0f64).abs() < 1e-12);
+        assert!((Matrix::<f32, 3, 3>::eye() * 2.0).det() == 8.0);
+        assert_eq!(Matrix::from([[0.0, 1.0], [1.0, 0.0]]).det(), -1.0);
+        assert_eq!(Matrix::from([[1.0, 2.0], [2.0, 4.0]]).det(), 0.0);
+        assert_eq!(Matrix::<f64, 0, 0>::from([]).det(), 1.0);
+    }
+
+    #[test]
+    fn test_layout() {
+        assert_eq!(core::mem::size_of::<Matrix<f32, 3, 4>>(), core::mem::size_of::<[[f32; 4]; 3]>());
+        assert_eq!(core::mem::align_of::<Matrix<f64, 3, 4>>(), core::mem::align_of::<f64>());
+    }
+
     #[test]
     fn test_matrix_send() {
         fn assert_send<T: Send>() {}
@@ -0,0 +1,209 @@
+#[cfg(test)]
+mod tests {
+    use ferrix::{FloatRandom, LinearOperator, Matrix, MatrixCM, RowVector, TransposeOperator, Vector};
+
+    #[test]
+    fn test_new() {
+        let matrix = MatrixCM::<f64, 2, 3>::new();
+        assert_eq!(matrix.shape(), (2, 3));
+        assert_eq!(matrix.capacity(), 6);
+        assert_eq!(matrix.rows(), 2);
+        assert_eq!(matrix.cols(), 3);
+        assert_eq!(matrix, MatrixCM::zeros());
+    }
+
+    #[test]
+    fn test_constructors() {
+        assert_eq!(MatrixCM::<i32, 2, 2>::ones(), MatrixCM::from([[1, 1], [1, 1]]));
+        assert_eq!(MatrixCM::<i32, 2, 2>::fill(7), MatrixCM::from([[7, 7], [7, 7]]));
+        assert_eq!(MatrixCM::<i32, 3, 2>::eye(), MatrixCM::from([[1, 0], [0, 1], [0, 0]]));
+        assert_eq!(
+            MatrixCM::from_columns([[1, 2, 3], [4, 5, 6]]),
+            MatrixCM::from([[1, 4], [2, 5], [3, 6]])
+        );
+    }
+
+    #[test]
+    fn test_layout() {
+        let matrix = MatrixCM::from([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(matrix.as_slice(), &[1, 4, 2, 5, 3, 6]);
+        assert_eq!(MatrixCM::<i32, 2, 3>::from_slice(matrix.as_slice()).unwrap(), matrix);
+        assert!(MatrixCM::<i32, 3, 3>::from_slice(matrix.as_slice()).is_none());
+        assert_eq!(matrix.as_ptr(), &matrix[(0, 0)] as *const i32);
+        assert_eq!(
+            core::mem::size_of::<MatrixCM<f32, 3, 4>>(),
+            core::mem::size_of::<[[f32; 3]; 4]>()
+        );
+    }
+
+    #[test]
+    fn test_index() {
+        let mut matrix = MatrixCM::from([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(matrix[(0, 2)], 3);
+        assert_eq!(matrix[(1, 0)], 4);
+        assert_eq!(matrix[2], 3);
+        assert_eq!(matrix[3], 4);
+        matrix[(1, 2)] = 60;
+        matrix[1] = 20;
+        assert_eq!(matrix, MatrixCM::from([[1, 20, 3], [4, 5, 60]]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Index out of bounds")]
+    fn test_index_out_of_bounds() {
+        let matrix = MatrixCM::from([[1, 2, 3], [4, 5, 6]]);
+        let _ = matrix[(2, 0)];
+    }
+
+    #[test]
+    fn test_eq() {
+        let matrix = MatrixCM::from([[1, 2], [3, 4]]);
+        assert_eq!(matrix, Matrix::from([[1, 2], [3, 4]]));
+        assert_eq!(Matrix::from([[1, 2], [3, 4]]), matrix);
+        assert_ne!(matrix, Matrix::from([[1, 3], [2, 4]]));
+        assert_ne!(matrix, MatrixCM::from([[1, 3], [2, 4]]));
+    }
+
+    #[test]
+    fn test_conversions() {
+        let matrix = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+        let column_major = MatrixCM::from(matrix.clone());
+        assert_eq!(column_major, matrix);
+        assert_eq!(column_major.as_slice(), &[1, 4, 2, 5, 3, 6]);
+        assert_eq!(Matrix::from(column_major), matrix);
+        assert_eq!(MatrixCM::from(matrix.t()), Matrix::from([[1, 4], [2, 5], [3, 6]]));
+        assert_eq!(MatrixCM::from(matrix.t()).as_slice(), matrix.as_slice());
+    }
+
+    #[test]
+    fn test_as_transposed() {
+        let mut matrix = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+        let ptr = matrix.as_ptr();
+        let transposed = matrix.as_transposed_cm();
+        assert_eq!(transposed, &MatrixCM::from([[1, 4], [2, 5], [3, 6]]));
+        assert_eq!(transposed.as_ptr(), ptr);
+        assert_eq!(transposed.as_transposed(), &matrix);
+
+        matrix.as_transposed_cm_mut()[(0, 1)] = 40;
+        assert_eq!(matrix, Matrix::from([[1, 2, 3], [40, 5, 6]]));
+
+        let mut column_major = matrix.into_transposed_cm();
+        column_major.as_transposed_mut()[(1, 2)] = 0;
+        assert_eq!(column_major.into_transposed(), Matrix::from([[1, 2, 3], [40, 5, 0]]));
+    }
+
+    #[test]
+    fn test_add_sub() {
+        let a = MatrixCM::from([[1, 2, 3], [4, 5, 6]]);
+        let b = MatrixCM::from([[6, 5, 4], [3, 2, 1]]);
+        let row_major = Matrix::from([[1, 1, 1], [2, 2, 2]]);
+
+        let sum: MatrixCM<i32, 2, 3> = &a + b;
+        assert_eq!(sum, MatrixCM::from([[7, 7, 7], [7, 7, 7]]));
+        let difference: MatrixCM<i32, 2, 3> = &a - 1;
+        assert_eq!(difference, MatrixCM::from([[0, 1, 2], [3, 4, 5]]));
+
+        let mixed: Matrix<i32, 2, 3> = &a + &row_major;
+        assert_eq!(mixed, Matrix::from([[2, 3, 4], [6, 7, 8]]));
+        assert_eq!(&row_major - &a, Matrix::from([[0, -1, -2], [-2, -3, -4]]));
+        assert_eq!(a + row_major.t().t(), Matrix::from([[2, 3, 4], [6, 7, 8]]));
+        assert_eq!(row_major.view::<2, 2>((0, 0)).unwrap() + MatrixCM::from([[1, 2], [3, 4]]), Matrix::from([[2, 3], [5, 6]]));
+    }
+
+    #[test]
+    fn test_mul_div() {
+        let a = MatrixCM::from([[2, 4], [6, 8]]);
+        let product: MatrixCM<i32, 2, 2> = &a * 2;
+        assert_eq!(product, MatrixCM::from([[4, 8], [12, 16]]));
+        assert_eq!(a / 2, MatrixCM::from([[1, 2], [3, 4]]));
+    }
+
+    #[test]
+    fn test_assign() {
+        let mut a = MatrixCM::from([[1, 2], [3, 4]]);
+        a += MatrixCM::from([[1, 1], [1, 1]]);
+        a -= Matrix::from([[0, 1], [2, 3]]);
+        a *= 10;
+        a /= 2;
+        assert_eq!(a, MatrixCM::from([[10, 10], [10, 10]]));
+
+        let mut b = Matrix::from([[1, 2], [3, 4]]);
+        b += MatrixCM::from([[1, 2], [3, 4]]);
+        assert_eq!(b, Matrix::from([[2, 4], [6, 8]]));
+        let mut row = b.view_mut::<1, 2>((1, 0)).unwrap();
+        row -= MatrixCM::from([[6, 8]]);
+        assert_eq!(b, Matrix::from([[2, 4], [0, 0]]));
+
+        let mut c = MatrixCM::<i32, 2, 2>::zeros();
+        c += b.t();
+        assert_eq!(c, Matrix::from([[2, 0], [4, 0]]));
+    }
+
+    #[test]
+    fn test_matmul() {
+        let a = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+        let b = Matrix::from([[1, 0], [0, 1], [1, 1]]);
+        let expected = &a * &b;
+
+        let (a_cm, b_cm) = (MatrixCM::from(a.clone()), MatrixCM::from(b.clone()));
+        let product: MatrixCM<i32, 2, 2> = &a_cm * &b_cm;
+        assert_eq!(product, expected);
+        let mixed: Matrix<i32, 2, 2> = &a_cm * &b;
+        assert_eq!(mixed, expected);
+        assert_eq!(&a * &b_cm, expected);
+        assert_eq!(a.view::<2, 3>((0, 0)).unwrap() * b_cm, expected);
+        assert_eq!(&a_cm * b.t().t(), expected);
+        assert_eq!(b.t() * a_cm.as_transposed(), expected.t());
+    }
+
+    #[test]
+    fn test_matmul_large() {
+        let a = Matrix::<f64, 17, 33>::random();
+        let b = Matrix::<f64, 33, 9>::random();
+        let expected = &a * &b;
+        let product = MatrixCM::from(a) * MatrixCM::from(b);
+        for i in 0..17 {
+            for j in 0..9 {
+                assert!((product[(i, j)] - expected[(i, j)]).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_matmul_vector() {
+        let a = MatrixCM::from([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(&a * Vector::from([1, 0, -1]), Matrix::from([[-2], [-2]]));
+        assert_eq!(RowVector::from([1, -1]) * a, Matrix::from([[-3, -3, -3]]));
+        assert_eq!(MatrixCM::from([[1], [2]]) * RowVector::from([1, 2]), Matrix::from([[1, 2], [2, 4]]));
+        assert_eq!(Vector::from([1, 2]) * MatrixCM::from([[1, 2]]), Matrix::from([[1, 2], [2, 4]]));
+    }
+
+    #[test]
+    fn test_linear_operator() {
+        let a = MatrixCM::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        assert_eq!(a.apply(&Vector::from([1.0, 1.0, 1.0])), Vector::from([6.0, 15.0]));
+        assert_eq!(a.apply_transpose(&Vector::from([1.0, 1.0])), Vector::from([5.0, 7.0, 9.0]));
+    }
+
+    #[test]
+    fn test_display() {
+        let matrix = MatrixCM::from([[1, 2], [3, 4]]);
+        assert_eq!(format!("{}", matrix), "[[1, 2]\n [3, 4]]");
+        assert_eq!(
+            format!("{:#}", matrix),
+            "MatrixCM([[1, 2]\n          [3, 4]], dtype=i32)"
+        );
+    }
+
+    #[test]
+    fn test_matrix_cm_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<MatrixCM<i32, 3, 3>>();
+    }
+
+    #[test]
+    fn test_matrix_cm_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<MatrixCM<i32, 3, 3>>();
+    }
+}
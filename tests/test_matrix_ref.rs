@@ -0,0 +1,112 @@
+#[cfg(test)]
+mod tests {
+    use ferrix::{Matrix, MatrixMut, MatrixRef, RowVector, Vector, VectorMut, VectorRef};
+
+    // Generic over every matrix type
+    fn trace<T: Copy + core::ops::Add<Output = T>, const N: usize>(
+        m: &impl MatrixRef<T, N, N>,
+    ) -> T {
+        (1..N).fold(m[(0, 0)], |acc, i| acc + m[(i, i)])
+    }
+
+    fn sum<const N: usize>(v: &impl VectorRef<i32, N>) -> i32 {
+        (0..N).map(|i| v[i]).sum()
+    }
+
+    #[test]
+    fn test_matrix_ref() {
+        let mut a = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        assert_eq!(trace(&a), 15);
+        assert_eq!(trace(&a.view::<2, 2>((1, 0)).unwrap()), 12);
+        assert_eq!(trace(&a.t()), 15);
+        assert_eq!(trace(&a.view_mut::<2, 2>((0, 1)).unwrap()), 8);
+        assert_eq!(trace(&a.t_mut()), 15);
+
+        let view = a.view::<2, 3>((1, 0)).unwrap();
+        assert_eq!(MatrixRef::shape(&view), (2, 3));
+        assert_eq!(MatrixRef::get(&view, (1, 2)), Some(&9));
+        assert_eq!(MatrixRef::get(&view, (2, 0)), None);
+        assert_eq!(view.to_matrix(), Matrix::from([[4, 5, 6], [7, 8, 9]]));
+        assert_eq!(
+            view.map(|x| x % 2 == 0),
+            Matrix::from([[true, false, true], [false, true, false]])
+        );
+        assert_eq!(
+            view.zip_map(&a.view::<2, 3>((0, 0)).unwrap(), |x, y| x - y),
+            Matrix::from([[3, 3, 3], [3, 3, 3]])
+        );
+    }
+
+    #[test]
+    fn test_matrix_ref_products() {
+        let a = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+        let b = Matrix::from([[1, 0], [0, 1], [1, 1]]);
+        let expected = &a * &b;
+        assert_eq!(a.matmul(&b), expected);
+        assert_eq!(a.view::<2, 3>((0, 0)).unwrap().matmul(&b.t().t()), expected);
+        assert_eq!(b.t().matmul(&a.t()), expected.t());
+
+        let x = Vector::from([1, -1, 2]);
+        assert_eq!(a.matvec(&x), Vector::from([5, 11]));
+        assert_eq!(
+            a.matvec(&RowVector::from([1, -1, 2])),
+            Vector::from([5, 11])
+        );
+        assert_eq!(b.t().matvec(&x), Vector::from([3, 1]));
+        assert_eq!(
+            b.matvec(&x.view::<2>(1).unwrap()),
+            Vector::from([-1, 2, 1])
+        );
+    }
+
+    #[test]
+    fn test_matrix_mut() {
+        let mut a = Matrix::from([[1, 2], [3, 4]]);
+        *MatrixMut::get_mut(&mut a, (0, 1)).unwrap() = 5;
+        assert_eq!(MatrixMut::get_mut(&mut a, (2, 0)), None);
+        assert_eq!(a, Matrix::from([[1, 5], [3, 4]]));
+
+        a.t_mut().copy_from(&Matrix::from([[1, 2], [3, 4]]));
+        assert_eq!(a, Matrix::from([[1, 3], [2, 4]]));
+
+        a.view_mut::<1, 2>((1, 0)).unwrap().map_inplace(|x| x * 10);
+        assert_eq!(a, Matrix::from([[1, 3], [20, 40]]));
+    }
+
+    #[test]
+    fn test_vector_ref() {
+        let mut a = Vector::from([1, 2, 3, 4]);
+        let b = RowVector::from([5, 6, 7, 8]);
+        assert_eq!(sum(&a), 10);
+        assert_eq!(sum(&b), 26);
+        assert_eq!(sum(&a.view::<2>(2).unwrap()), 7);
+        assert_eq!(sum(&b.view::<3>(0).unwrap()), 18);
+        assert_eq!(sum(&a.t()), 10);
+        assert_eq!(sum(&a.view_mut::<3>(1).unwrap()), 9);
+
+        assert_eq!(VectorRef::shape(&b), 4);
+        assert_eq!(VectorRef::get(&b, 3), Some(&8));
+        assert_eq!(VectorRef::get(&b, 4), None);
+        assert_eq!(b.to_vector(), Vector::from([5, 6, 7, 8]));
+        assert_eq!(VectorRef::map(&a, |x| x * x), Vector::from([1, 4, 9, 16]));
+        assert_eq!(a.zip_map(&b, |x, y| x + y), Vector::from([6, 8, 10, 12]));
+    }
+
+    #[test]
+    fn test_vector_mut() {
+        let mut a = Vector::from([1, 2, 3, 4]);
+        *VectorMut::get_mut(&mut a, 0).unwrap() = 0;
+        assert_eq!(VectorMut::get_mut(&mut a, 4), None);
+
+        a.view_mut::<2>(1)
+            .unwrap()
+            .copy_from(&RowVector::from([7, 7]));
+        assert_eq!(a, Vector::from([0, 7, 7, 4]));
+
+        let mut b = RowVector::from([1, 2, 3]);
+        b.map_inplace(|x| -x);
+        assert_eq!(b, RowVector::from([-1, -2, -3]));
+        a.t_mut().map_inplace(|x| x + 1);
+        assert_eq!(a, Vector::from([1, 8, 8, 5]));
+    }
+}
@@ -0,0 +1,154 @@
+#[cfg(test)]
+mod tests {
+    use ferrix::{DotProduct, LinearOperator, Matrix, MatrixStridedView, RowVector, TransposeOperator, Vector, VectorRef};
+
+    #[test]
+    fn test_matrix_strided_view_shape() {
+        let matrix = Matrix::from([[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]]);
+        let view = matrix.strided_view::<2, 2>((0, 0), (2, 3)).unwrap();
+        assert_eq!(view.shape(), (2, 2));
+        assert_eq!(view.capacity(), 4);
+        assert_eq!(view.rows(), 2);
+        assert_eq!(view.cols(), 2);
+        assert_eq!(view, Matrix::from([[1, 4], [9, 12]]));
+    }
+
+    #[test]
+    fn test_matrix_strided_view_reversed() {
+        let matrix = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        let flipped = matrix.strided_view::<3, 3>((2, 2), (-1, -1)).unwrap();
+        assert_eq!(flipped, Matrix::from([[9, 8, 7], [6, 5, 4], [3, 2, 1]]));
+
+        let every_other = matrix.strided_view::<2, 3>((2, 2), (-2, -1)).unwrap();
+        assert_eq!(every_other, Matrix::from([[9, 8, 7], [3, 2, 1]]));
+        assert_eq!(every_other.t(), Matrix::from([[9, 3], [8, 2], [7, 1]]));
+    }
+
+    #[test]
+    fn test_matrix_strided_view_out_of_bounds() {
+        let matrix = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        assert!(matrix.strided_view::<2, 2>((0, 0), (3, 1)).is_none());
+        assert!(matrix.strided_view::<2, 2>((1, 0), (-2, 1)).is_none());
+        assert!(matrix.strided_view::<2, 2>((0, 0), (0, 1)).is_none());
+        assert!(matrix.strided_view::<1, 1>((3, 0), (1, 1)).is_none());
+        assert!(matrix.strided_view::<2, 2>((0, 0), (isize::MAX, 1)).is_none());
+    }
+
+    #[test]
+    fn test_matrix_strided_view_diagonal() {
+        let matrix = Matrix::from([[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]]);
+        assert_eq!(matrix.diagonal_view::<3>((0, 0)).unwrap(), Vector::from([1, 6, 11]));
+        assert_eq!(matrix.diagonal_view::<3>((0, 1)).unwrap(), Vector::from([2, 7, 12]));
+        assert_eq!(matrix.diagonal_view::<2>((1, 0)).unwrap(), Vector::from([5, 10]));
+        assert!(matrix.diagonal_view::<3>((1, 0)).is_none());
+        assert!(matrix.diagonal_view::<3>((0, 2)).is_none());
+
+        let anti = matrix.anti_diagonal_view::<3>((0, 3)).unwrap();
+        assert_eq!(anti, Vector::from([4, 7, 10]));
+        assert_eq!(anti.t(), Matrix::from([[4, 7, 10]]));
+        assert!(matrix.anti_diagonal_view::<3>((1, 3)).is_none());
+        assert!(matrix.anti_diagonal_view::<2>((0, 0)).is_none());
+    }
+
+    #[test]
+    fn test_matrix_strided_view_diagonal_vector() {
+        let matrix = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        let diagonal = matrix.diagonal_view::<3>((0, 0)).unwrap();
+        let anti = matrix.anti_diagonal_view::<3>((0, 2)).unwrap();
+        assert_eq!(diagonal[1], 5);
+        assert_eq!(diagonal.get(2), Some(&9));
+        assert_eq!(diagonal.get(3), None);
+        assert_eq!(diagonal.to_vector(), Vector::from([1, 5, 9]));
+        assert_eq!(anti.map(|x| x * 2), Vector::from([6, 10, 14]));
+
+        let v = Vector::from([1, 0, -1]);
+        assert_eq!((&diagonal).dot(&v), -8);
+        assert_eq!((&v).dot(&diagonal), -8);
+        assert_eq!((&diagonal).dot(&anti), 91);
+        assert_eq!(v.view::<2>(1).unwrap().dot(matrix.diagonal_view::<2>((1, 1)).unwrap()), -9);
+    }
+
+    #[test]
+    fn test_matrix_strided_view_eq() {
+        let matrix = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        let view = matrix.strided_view::<2, 2>((0, 0), (2, 2)).unwrap();
+        let other = Matrix::from([[1, 3], [7, 9]]);
+        assert_eq!(view, other);
+        assert_eq!(other, view);
+        assert_eq!(view, other.view::<2, 2>((0, 0)).unwrap());
+        assert_eq!(other.view::<2, 2>((0, 0)).unwrap(), view);
+        assert_eq!(view.t(), other.t());
+        assert_ne!(view, matrix.strided_view::<2, 2>((0, 0), (1, 1)).unwrap());
+    }
+
+    #[test]
+    fn test_matrix_strided_view_ops() {
+        let matrix = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        let view = matrix.strided_view::<2, 2>((2, 0), (-2, 2)).unwrap();
+        let other = Matrix::from([[1, 1], [2, 2]]);
+        assert_eq!(&view + &other, Matrix::from([[8, 10], [3, 5]]));
+        assert_eq!(&other - &view, Matrix::from([[-6, -8], [1, -1]]));
+        assert_eq!(&view * 2, Matrix::from([[14, 18], [2, 6]]));
+        assert_eq!(&view / 2, Matrix::from([[3, 4], [0, 1]]));
+        assert_eq!(&view + &view.t(), Matrix::from([[14, 10], [10, 6]]));
+
+        assert_eq!(&view * &other, Matrix::from([[25, 25], [7, 7]]));
+        assert_eq!(&other * &view, Matrix::from([[8, 12], [16, 24]]));
+        assert_eq!(&view * &Vector::from([1, -1]), Matrix::from([[-2], [-2]]));
+
+        let diagonal = matrix.diagonal_view::<3>((0, 0)).unwrap();
+        assert_eq!(&diagonal + &Vector::from([1, 1, 1]), Matrix::from([[2], [6], [10]]));
+        assert_eq!(Vector::from([1, 1, 1]) - &diagonal, Vector::from([0, -4, -8]));
+        assert_eq!(RowVector::from([1, 2, 3]) * &diagonal, Matrix::from([[38]]));
+    }
+
+    #[test]
+    fn test_matrix_strided_view_linear_operator() {
+        let matrix = Matrix::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let view = matrix.strided_view::<2, 2>((1, 2), (-1, -2)).unwrap();
+        let x = Vector::from([1.0, 1.0]);
+        assert_eq!(view.apply(&x), Vector::from([10.0, 4.0]));
+        assert_eq!(view.apply_transpose(&x), Vector::from([9.0, 5.0]));
+    }
+
+    #[test]
+    fn test_matrix_strided_view_display() {
+        let matrix = Matrix::from([[1, 2], [3, 4]]);
+        let view = matrix.strided_view::<2, 2>((1, 0), (-1, 1)).unwrap();
+        assert_eq!(format!("{}", view), "[[3, 4]\n [1, 2]]");
+        assert_eq!(
+            format!("{:#}", view),
+            "MatrixStridedView([[3, 4]\n                   [1, 2]], dtype=i32)"
+        );
+    }
+
+    #[test]
+    fn test_matrix_strided_view_index() {
+        let matrix = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        let view = matrix.strided_view::<2, 2>((2, 2), (-2, -2)).unwrap();
+        assert_eq!(view[(0, 0)], 9);
+        assert_eq!(view[(0, 1)], 7);
+        assert_eq!(view[(1, 0)], 3);
+        assert_eq!(view[3], 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index out of bounds")]
+    fn test_matrix_strided_view_index_out_of_bounds() {
+        let matrix = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        let view = matrix.strided_view::<2, 2>((0, 0), (2, 2)).unwrap();
+        let _ = view[4];
+    }
+
+    #[test]
+    fn test_matrix_strided_view_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<MatrixStridedView<'_, i32, 3, 3, 2, 2>>();
+    }
+
+    #[test]
+    fn test_matrix_strided_view_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<MatrixStridedView<'_, i32, 3, 3, 2, 2>>();
+    }
+}
@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod tests {
+    use ferrix::{DotProduct, Matrix, MatrixMut, MatrixStridedViewMut, Vector};
+
+    #[test]
+    fn test_matrix_strided_view_mut_shape() {
+        let mut matrix = Matrix::from([[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]]);
+        let view = matrix.strided_view_mut::<2, 2>((0, 0), (2, 3)).unwrap();
+        assert_eq!(view.shape(), (2, 2));
+        assert_eq!(view.capacity(), 4);
+        assert_eq!(view.rows(), 2);
+        assert_eq!(view.cols(), 2);
+        assert_eq!(view, Matrix::from([[1, 4], [9, 12]]));
+    }
+
+    #[test]
+    fn test_matrix_strided_view_mut_index_mut() {
+        let mut matrix = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        let mut view = matrix.strided_view_mut::<2, 3>((2, 2), (-2, -1)).unwrap();
+        view[(0, 0)] = 90;
+        view[4] = 20;
+        view.t_mut()[(2, 1)] = 10;
+        assert_eq!(
+            matrix,
+            Matrix::from([[10, 20, 3], [4, 5, 6], [7, 8, 90]])
+        );
+    }
+
+    #[test]
+    fn test_matrix_strided_view_mut_diagonal() {
+        let mut matrix = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        matrix.diagonal_view_mut::<3>((0, 0)).unwrap().copy_from(&Matrix::from([[0], [0], [0]]));
+        matrix.anti_diagonal_view_mut::<2>((0, 1)).unwrap().map_inplace(|x| -x);
+        assert!(matrix.diagonal_view_mut::<4>((0, 0)).is_none());
+        assert!(matrix.anti_diagonal_view_mut::<2>((2, 2)).is_none());
+        assert_eq!(matrix, Matrix::from([[0, -2, 3], [-4, 0, 6], [7, 8, 0]]));
+    }
+
+    #[test]
+    fn test_matrix_strided_view_mut_diagonal_vector() {
+        let mut matrix = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        let mut diagonal = matrix.diagonal_view_mut::<3>((0, 0)).unwrap();
+        // `MatrixMut` is in scope as well, so the vector methods are called by path
+        *ferrix::VectorMut::get_mut(&mut diagonal, 1).unwrap() = 0;
+        assert_eq!(ferrix::VectorMut::get_mut(&mut diagonal, 3), None);
+        assert_eq!((&diagonal).dot(Vector::from([1, 1, 1])), 10);
+        ferrix::VectorMut::copy_from(&mut diagonal, &Vector::from([-1, -2, -3]));
+        assert_eq!(matrix, Matrix::from([[-1, 2, 3], [4, -2, 6], [7, 8, -3]]));
+    }
+
+    #[test]
+    fn test_matrix_strided_view_mut_assign_ops() {
+        let mut matrix = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        let mut view = matrix.strided_view_mut::<2, 2>((0, 0), (2, 2)).unwrap();
+        view += Matrix::from([[10, 10], [10, 10]]);
+        view *= 2;
+        view -= 1;
+        assert_eq!(
+            matrix,
+            Matrix::from([[21, 2, 25], [4, 5, 6], [33, 8, 37]])
+        );
+
+        let other = Matrix::from([[1, 1], [1, 1]]);
+        let mut diagonal = matrix.diagonal_view_mut::<3>((0, 0)).unwrap();
+        diagonal += Vector::from([1, 2, 3]);
+        diagonal /= 2;
+        assert_eq!(diagonal, Vector::from([11, 3, 20]));
+
+        let mut result = other.clone();
+        result += matrix.strided_view::<2, 2>((2, 2), (-2, -2)).unwrap();
+        assert_eq!(result, Matrix::from([[21, 34], [26, 12]]));
+    }
+
+    #[test]
+    fn test_matrix_strided_view_mut_display() {
+        let mut matrix = Matrix::from([[1, 2], [3, 4]]);
+        let view = matrix.strided_view_mut::<2, 2>((0, 1), (1, -1)).unwrap();
+        assert_eq!(format!("{}", view), "[[2, 1]\n [4, 3]]");
+        assert_eq!(
+            format!("{:#}", view),
+            "MatrixStridedViewMut([[2, 1]\n                      [4, 3]], dtype=i32)"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Index out of bounds")]
+    fn test_matrix_strided_view_mut_index_out_of_bounds() {
+        let mut matrix = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        let mut view = matrix.strided_view_mut::<2, 2>((0, 0), (2, 2)).unwrap();
+        view[(2, 0)] = 0;
+    }
+
+    #[test]
+    fn test_matrix_strided_view_mut_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<MatrixStridedViewMut<'_, i32, 3, 3, 2, 2>>();
+    }
+
+    #[test]
+    fn test_matrix_strided_view_mut_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<MatrixStridedViewMut<'_, i32, 3, 3, 2, 2>>();
+    }
+}
@@ -164,6 +164,20 @@ mod tests {
         let _ = view[(0, 2)];
     }
 
+    #[test]
+    fn test_matrix_transpose_view_view() {
+        let matrix = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9], [10, 11, 12]]);
+        let transposed = matrix.t();
+        let view = transposed.view::<2, 3>((1, 1)).unwrap();
+        assert_eq!(view, Matrix::from([[5, 8, 11], [6, 9, 12]]));
+        let inner = view.view::<2, 2>((0, 1)).unwrap();
+        assert_eq!(inner, Matrix::from([[8, 11], [9, 12]]));
+        assert_eq!(inner.t(), Matrix::from([[8, 9], [11, 12]]));
+        assert_eq!(inner.t(), matrix.view::<2, 2>((2, 1)).unwrap());
+        assert!(transposed.view::<3, 1>((1, 0)).is_none());
+        assert!(view.view::<1, 4>((0, 0)).is_none());
+    }
+
     #[test]
     fn test_matrix_transpose_view_send() {
         fn assert_send<T: Send>() {}
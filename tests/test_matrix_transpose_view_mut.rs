@@ -224,6 +224,27 @@ mod tests {
         view[4] = 10; // This should panic
     }
 
+    #[test]
+    fn test_view() {
+        let mut matrix = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9], [10, 11, 12]]);
+        let mut transposed = matrix.t_mut();
+        assert_eq!(
+            transposed.view::<2, 2>((1, 2)).unwrap(),
+            Matrix::from([[8, 11], [9, 12]])
+        );
+        assert!(transposed.view::<2, 2>((2, 0)).is_none());
+
+        let mut view = transposed.view_mut::<2, 3>((1, 1)).unwrap();
+        view[(0, 0)] = 0;
+        let mut inner = view.view_mut::<1, 2>((1, 1)).unwrap();
+        inner[(0, 1)] = 0;
+        assert!(view.view_mut::<3, 1>((0, 0)).is_none());
+        assert_eq!(
+            matrix,
+            Matrix::from([[1, 2, 3], [4, 0, 6], [7, 8, 9], [10, 11, 0]])
+        );
+    }
+
     #[test]
     fn test_send() {
         fn assert_send<T: Send>() {}
@@ -166,6 +166,18 @@ mod tests {
         let _ = view[4]; // This should panic
     }
 
+    #[test]
+    fn test_matrix_view_view() {
+        let matrix = Matrix::from([[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]]);
+        let view = matrix.view::<2, 3>((1, 1)).unwrap();
+        let inner = view.view::<2, 2>((0, 1)).unwrap();
+        assert_eq!(inner, Matrix::from([[7, 8], [11, 12]]));
+        assert_eq!(inner.view::<1, 1>((1, 0)).unwrap(), Matrix::from([[11]]));
+        assert_eq!(inner.t(), Matrix::from([[7, 11], [8, 12]]));
+        assert!(view.view::<2, 2>((1, 0)).is_none());
+        assert!(view.view::<1, 3>((0, 1)).is_none());
+    }
+
     #[test]
     fn test_matrix_view_send() {
         fn assert_send<T: Send>() {}
@@ -220,6 +220,44 @@ mod tests {
         view[10] = 10; // This should panic
     }
 
+    #[test]
+    fn test_matrix_view_mut_view() {
+        let mut matrix = Matrix::from([[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]]);
+        let mut view = matrix.view_mut::<2, 3>((1, 1)).unwrap();
+        assert_eq!(view.view::<2, 2>((0, 1)).unwrap(), Matrix::from([[7, 8], [11, 12]]));
+        assert!(view.view::<3, 1>((0, 0)).is_none());
+
+        let mut inner = view.view_mut::<2, 2>((0, 1)).unwrap();
+        inner[(1, 0)] = 0;
+        let mut innermost = inner.view_mut::<1, 1>((0, 1)).unwrap();
+        innermost[(0, 0)] = 0;
+        assert!(view.view_mut::<1, 4>((0, 0)).is_none());
+        assert_eq!(
+            matrix,
+            Matrix::from([[1, 2, 3, 4], [5, 6, 7, 0], [9, 10, 0, 12]])
+        );
+    }
+
+    #[test]
+    fn test_matrix_view_mut_split() {
+        let mut matrix = Matrix::from([[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]]);
+        let mut view = matrix.view_mut::<2, 3>((1, 1)).unwrap();
+        assert!(view.split_at_mut_rows::<1, 2>().is_none());
+        assert!(view.split_at_mut_cols::<2, 2>().is_none());
+        assert!(view.split_quadrants_mut::<1, 1, 1, 1>().is_none());
+
+        let (mut top, bottom) = view.split_at_mut_rows::<1, 1>().unwrap();
+        top[(0, 2)] = bottom[(0, 0)];
+        let (left, mut right) = view.split_at_mut_cols::<1, 2>().unwrap();
+        right[(1, 0)] = left[(0, 0)];
+        let (_, _, mut a21, a22) = view.split_quadrants_mut::<1, 1, 2, 1>().unwrap();
+        a21[(0, 1)] = a22[(0, 0)];
+        assert_eq!(
+            matrix,
+            Matrix::from([[1, 2, 3, 4], [5, 6, 7, 10], [9, 10, 12, 12]])
+        );
+    }
+
     #[test]
     fn test_matrix_view_mut_send() {
         fn assert_send<T: Send>() {}
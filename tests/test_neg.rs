@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod tests {
+    use ferrix::{Matrix, MatrixCM, RowVector, Vector};
+
+    #[test]
+    fn test_vector_neg() {
+        let v = Vector::<f64, 3>::from([1.0, -2.0, 3.0]);
+        assert_eq!(-v.clone(), Vector::from([-1.0, 2.0, -3.0]));
+        assert_eq!(-&v, Vector::from([-1.0, 2.0, -3.0]));
+        assert_eq!(-v.view::<2>(1).unwrap(), Vector::from([2.0, -3.0]));
+
+        let mut v = Vector::<i32, 3>::from([1, -2, 3]);
+        assert_eq!(-v.view_mut::<2>(0).unwrap(), Vector::from([-1, 2]));
+        assert_eq!(-v.t(), RowVector::from([-1, 2, -3]));
+    }
+
+    #[test]
+    fn test_row_vector_neg() {
+        let v = RowVector::<f64, 3>::from([1.0, -2.0, 3.0]);
+        assert_eq!(-v.clone(), RowVector::from([-1.0, 2.0, -3.0]));
+        assert_eq!(-&v, RowVector::from([-1.0, 2.0, -3.0]));
+        assert_eq!(-v.view::<2>(0).unwrap(), RowVector::from([-1.0, 2.0]));
+
+        let mut v = RowVector::<i32, 3>::from([1, -2, 3]);
+        assert_eq!(-v.view_mut::<2>(1).unwrap(), RowVector::from([2, -3]));
+        assert_eq!(-v.t_mut(), Vector::from([-1, 2, -3]));
+    }
+
+    #[test]
+    fn test_matrix_neg() {
+        let m = Matrix::<f64, 2, 3>::from([[1.0, -2.0, 3.0], [-4.0, 5.0, -6.0]]);
+        let expected = Matrix::from([[-1.0, 2.0, -3.0], [4.0, -5.0, 6.0]]);
+        assert_eq!(-m.clone(), expected);
+        assert_eq!(-&m, expected);
+        assert_eq!(&m + -&m, Matrix::zeros());
+        assert_eq!(-MatrixCM::from(m.clone()), MatrixCM::from(expected.clone()));
+        assert_eq!(-m.t(), expected.t().t().t());
+        assert_eq!(-m.view::<2, 2>((0, 1)).unwrap(), Matrix::from([[2.0, -3.0], [-5.0, 6.0]]));
+        assert_eq!(-m.strided_view::<2, 2>((0, 0), (1, 2)).unwrap(), Matrix::from([[-1.0, -3.0], [4.0, 6.0]]));
+
+        let mut m = Matrix::<i32, 2, 2>::from([[1, 2], [3, 4]]);
+        assert_eq!(-m.view_mut::<1, 2>((1, 0)).unwrap(), Matrix::from([[-3, -4]]));
+        assert_eq!(-m.t_mut(), Matrix::from([[-1, -3], [-2, -4]]));
+        assert_eq!(-m.strided_view_mut::<2, 1>((0, 1), (1, 1)).unwrap(), Matrix::from([[-2], [-4]]));
+    }
+
+    #[test]
+    fn test_not() {
+        let v = Vector::<u8, 3>::from([0, 1, 0xF0]);
+        assert_eq!(!v, Vector::from([0xFF, 0xFE, 0x0F]));
+        assert_eq!(!RowVector::from([true, false]), RowVector::from([false, true]));
+
+        let m = Matrix::<i32, 2, 2>::from([[0, -1], [1, 2]]);
+        assert_eq!(!m.clone(), Matrix::from([[-1, 0], [-2, -3]]));
+        assert_eq!(!&m, Matrix::from([[-1, 0], [-2, -3]]));
+        assert_eq!(!m.t(), Matrix::from([[-1, -2], [0, -3]]));
+        assert_eq!(!MatrixCM::from(m.clone()), MatrixCM::from(Matrix::from([[-1, 0], [-2, -3]])));
+        assert_eq!(!m.view::<1, 2>((0, 0)).unwrap(), Matrix::from([[-1, 0]]));
+    }
+}
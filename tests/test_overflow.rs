@@ -0,0 +1,67 @@
+#[cfg(test)]
+mod tests {
+    use ferrix::{Matrix, MatrixRef, RowVector, Vector, VectorRef};
+
+    #[test]
+    fn test_checked_matrix() {
+        let a = Matrix::from([[i32::MAX, 1], [-1, 2]]);
+        let b = Matrix::from([[1, 1], [1, 1]]);
+        assert_eq!(a.checked_add(&b), None);
+        assert_eq!(a.checked_sub(&b), Some(Matrix::from([[i32::MAX - 1, 0], [-2, 1]])));
+        assert_eq!(a.t().checked_add(&b.t()), None);
+        assert_eq!(a.checked_mul(2), None);
+        assert_eq!(b.checked_mul(2), Some(Matrix::fill(2)));
+        assert_eq!(Matrix::from([[0u8]]).checked_sub(&Matrix::from([[1]])), None);
+    }
+
+    #[test]
+    fn test_checked_matmul() {
+        let a = Matrix::<i32, 2, 2>::fill(46341);
+        assert_eq!(a.checked_matmul(&Matrix::<i32, 2, 1>::fill(1)), Some(Matrix::fill(92682)));
+        assert_eq!(a.checked_matmul(&a), None);
+
+        // Each product fits, but their sum does not
+        let a = Matrix::from([[i32::MAX / 2 + 1, i32::MAX / 2 + 1]]);
+        assert_eq!(a.checked_matmul(&Matrix::<i32, 2, 1>::ones()), None);
+        assert_eq!(a.view::<1, 1>((0, 0)).unwrap().checked_matmul(&Matrix::from([[1]])), Some(Matrix::from([[i32::MAX / 2 + 1]])));
+    }
+
+    #[test]
+    fn test_wrapping_matrix() {
+        let a = Matrix::from([[u8::MAX, 1], [0, 2]]);
+        let b = Matrix::<u8, 2, 2>::ones();
+        assert_eq!(a.wrapping_add(&b), Matrix::from([[0, 2], [1, 3]]));
+        assert_eq!(a.wrapping_sub(&b), Matrix::from([[254, 0], [255, 1]]));
+        assert_eq!(a.wrapping_mul(2), Matrix::from([[254, 2], [0, 4]]));
+        assert_eq!(a.wrapping_matmul(&a), Matrix::from([[1, 1], [0, 4]]));
+
+        let a = Matrix::<i32, 2, 2>::fill(i32::MAX);
+        assert_eq!(a.wrapping_matmul(&a), Matrix::fill(i32::MAX.wrapping_mul(i32::MAX).wrapping_mul(2)));
+    }
+
+    #[test]
+    fn test_saturating_matrix() {
+        let a = Matrix::from([[i8::MAX, -100], [1, 2]]);
+        let b = Matrix::from([[1i8, 100], [1, 1]]);
+        assert_eq!(a.saturating_add(&b), Matrix::from([[i8::MAX, 0], [2, 3]]));
+        assert_eq!(a.saturating_sub(&b), Matrix::from([[126, i8::MIN], [0, 1]]));
+        assert_eq!(a.saturating_mul(2), Matrix::from([[i8::MAX, i8::MIN], [2, 4]]));
+        // 127 * 100 saturates before -100 is added
+        assert_eq!(a.saturating_matmul(&b), Matrix::from([[27, 27], [3, 102]]));
+    }
+
+    #[test]
+    fn test_vector() {
+        let v = Vector::from([u16::MAX, 1, 2]);
+        let w = RowVector::from([1u16, 1, 1]);
+        assert_eq!(v.checked_add(&w), None);
+        assert_eq!(v.checked_sub(&w), Some(Vector::from([u16::MAX - 1, 0, 1])));
+        assert_eq!(v.checked_mul(2), None);
+        assert_eq!(v.wrapping_add(&w), Vector::from([0, 2, 3]));
+        assert_eq!(v.wrapping_sub(&w.t()), Vector::from([u16::MAX - 1, 0, 1]));
+        assert_eq!(v.wrapping_mul(2), Vector::from([u16::MAX - 1, 2, 4]));
+        assert_eq!(v.saturating_add(&w), Vector::from([u16::MAX, 2, 3]));
+        assert_eq!(Vector::from([0u16]).saturating_sub(&Vector::from([1])), Vector::from([0]));
+        assert_eq!(v.view::<2>(0).unwrap().saturating_mul(3), Vector::from([u16::MAX, 3]));
+    }
+}
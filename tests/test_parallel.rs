@@ -0,0 +1,128 @@
+// Compares results computed under the sequential and rayon policies. The
+// threshold is lowered so that the small sizes used here are split.
+#[cfg(all(test, feature = "rayon"))]
+mod tests {
+    use ferrix::{
+        parallel_threshold, parallelism, set_parallel_threshold, with_parallelism, DotProduct,
+        Matrix, MatrixRef, Parallelism, Vector,
+    };
+
+    fn matrix<const R: usize, const C: usize>(seed: f64) -> Matrix<f64, R, C> {
+        Matrix::from(core::array::from_fn(|i| {
+            core::array::from_fn(|j| ((i * C + j) as f64 * seed).sin())
+        }))
+    }
+
+    fn sequential<R>(f: impl FnOnce() -> R) -> R {
+        with_parallelism(Parallelism::Sequential, f)
+    }
+
+    fn parallel<R>(f: impl FnOnce() -> R) -> R {
+        with_parallelism(Parallelism::Rayon(4), f)
+    }
+
+    #[test]
+    fn test_parallel_policy() {
+        set_parallel_threshold(1 << 10);
+        assert_eq!(parallel_threshold(), 1 << 10);
+
+        with_parallelism(Parallelism::Rayon(3), || {
+            assert_eq!(parallelism(), Parallelism::Rayon(3));
+            with_parallelism(Parallelism::Sequential, || {
+                assert_eq!(parallelism(), Parallelism::Sequential);
+            });
+            assert_eq!(parallelism(), Parallelism::Rayon(3));
+        });
+    }
+
+    #[test]
+    fn test_parallel_matmul() {
+        set_parallel_threshold(1 << 10);
+
+        // Each band of rows is computed exactly as it would be sequentially
+        let a = matrix::<67, 53>(0.3);
+        let b = matrix::<53, 71>(0.7);
+        assert_eq!(sequential(|| &a * &b), parallel(|| &a * &b));
+        assert_eq!(sequential(|| &a * b.t().t()), parallel(|| &a * b.t().t()));
+
+        // Inner dimension larger than one blocking panel
+        let a = matrix::<21, 300>(0.1);
+        let b = matrix::<300, 19>(0.2);
+        assert_eq!(sequential(|| &a * &b), parallel(|| &a * &b));
+
+        let a = matrix::<13, 29>(0.3);
+        let b = matrix::<29, 31>(0.7);
+        assert_eq!(sequential(|| &a * &b), parallel(|| &a * &b));
+        assert_eq!(sequential(|| a.t() * &a), parallel(|| a.t() * &a));
+
+        let v = Vector::<f64, 53>::from(core::array::from_fn(|i| i as f64 - 26.0));
+        let a = matrix::<67, 53>(0.3);
+        assert_eq!(sequential(|| &a * &v), parallel(|| &a * &v));
+
+        // Other element types stay sequential
+        let a = Matrix::<i64, 20, 20>::fill(2);
+        assert_eq!(parallel(|| &a * &a), Matrix::fill(80));
+    }
+
+    #[test]
+    fn test_parallel_elementwise() {
+        set_parallel_threshold(1 << 10);
+
+        let a = matrix::<40, 37>(0.3);
+        let b = matrix::<40, 37>(0.7);
+        assert_eq!(sequential(|| &a + &b), parallel(|| &a + &b));
+        assert_eq!(sequential(|| &a * 1.5), parallel(|| &a * 1.5));
+
+        let mut c = a.clone();
+        let mut d = a.clone();
+        sequential(|| c -= &b);
+        parallel(|| d -= &b);
+        assert_eq!(c, d);
+    }
+
+    #[test]
+    fn test_parallel_dot() {
+        set_parallel_threshold(1 << 10);
+
+        let v = Vector::<f64, 2000>::from(core::array::from_fn(|i| (i as f64 * 0.1).cos()));
+        let expected = sequential(|| (&v).dot(&v));
+        let result = parallel(|| (&v).dot(&v));
+        assert!((result - expected).abs() < 1e-9);
+
+        // Partial sums are combined in order
+        assert_eq!(result, parallel(|| (&v).dot(&v)));
+    }
+
+    #[test]
+    fn test_parallel_elimination() {
+        set_parallel_threshold(1 << 10);
+
+        // Every row below a pivot is updated exactly as it would be sequentially
+        let a = matrix::<60, 60>(0.3) + Matrix::eye() * 2.0;
+        assert_eq!(sequential(|| a.det()), parallel(|| a.det()));
+        assert_eq!(sequential(|| a.powi(-1)), parallel(|| a.powi(-1)));
+        assert_eq!(sequential(|| a.powi(-3)), parallel(|| a.powi(-3)));
+
+        let a = matrix::<33, 33>(0.7).map(|x| x as f32);
+        assert_eq!(sequential(|| a.det()), parallel(|| a.det()));
+
+        let singular = Matrix::<f64, 40, 40>::ones();
+        assert_eq!(parallel(|| singular.det()), 0.0);
+    }
+
+    #[test]
+    fn test_parallel_norm() {
+        set_parallel_threshold(1 << 10);
+
+        // The 1-norm decides how often `expm` squares, and its column sums are split
+        let a = matrix::<50, 50>(0.3) * 0.2;
+        let expected = sequential(|| a.expm());
+        let result = parallel(|| a.expm());
+        for (x, y) in result.as_slice().iter().zip(expected.as_slice()) {
+            assert!((x - y).abs() < 1e-12 * y.abs().max(1.0));
+        }
+
+        let v = Vector::<f64, 2000>::from(core::array::from_fn(|i| (i as f64 * 0.1).sin()));
+        assert!((sequential(|| v.magnitude()) - parallel(|| v.magnitude())).abs() < 1e-12);
+    }
+}
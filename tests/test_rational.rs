@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod tests {
+    use ferrix::{Matrix, RowVector, Vector};
+    use num_rational::{BigRational, Rational64};
+
+    fn q(numer: i64, denom: i64) -> BigRational {
+        BigRational::new(numer.into(), denom.into())
+    }
+
+    #[test]
+    fn test_matrix_ops() {
+        let a = Matrix::from([[q(1, 2), q(1, 3)], [q(1, 4), q(1, 5)]]);
+        let b = Matrix::from([[q(1, 2), q(2, 3)], [q(3, 4), q(4, 5)]]);
+        assert_eq!(&a + &b, Matrix::from([[q(1, 1), q(1, 1)], [q(1, 1), q(1, 1)]]));
+        assert_eq!(&a - &b, Matrix::from([[q(0, 1), q(-1, 3)], [q(-1, 2), q(-3, 5)]]));
+        assert_eq!(&a * q(2, 1), Matrix::from([[q(1, 1), q(2, 3)], [q(1, 2), q(2, 5)]]));
+        assert_eq!(&a / q(1, 2), Matrix::from([[q(1, 1), q(2, 3)], [q(1, 2), q(2, 5)]]));
+        assert_eq!(-a.clone(), Matrix::from([[q(-1, 2), q(-1, 3)], [q(-1, 4), q(-1, 5)]]));
+        assert_eq!(a.t() + b.t(), Matrix::from([[q(1, 1), q(1, 1)], [q(1, 1), q(1, 1)]]));
+
+        let mut c = a.clone();
+        c += &b;
+        c *= q(3, 1);
+        assert_eq!(c, Matrix::fill(q(3, 1)));
+    }
+
+    #[test]
+    fn test_matmul() {
+        let a = Matrix::from([[q(1, 2), q(1, 3)], [q(1, 4), q(1, 5)]]);
+        let b = Matrix::from([[q(2, 1), q(0, 1)], [q(0, 1), q(3, 1)]]);
+        let expected = Matrix::from([[q(1, 1), q(1, 1)], [q(1, 2), q(3, 5)]]);
+        assert_eq!(&a * &b, expected);
+        assert_eq!(a.view::<2, 2>((0, 0)).unwrap() * b.t(), expected);
+        assert_eq!(&a * &Vector::from([q(1, 1), q(1, 1)]), Matrix::from([[q(5, 6)], [q(9, 20)]]));
+
+        // Large enough to take the blocked path for floats
+        let big = Matrix::<Rational64, 50, 50>::eye() * Rational64::new(1, 3);
+        assert_eq!(&big * &big, Matrix::eye() * Rational64::new(1, 9));
+    }
+
+    #[test]
+    fn test_vector_ops() {
+        let v = Vector::from([q(1, 2), q(1, 3), q(1, 6)]);
+        let r = RowVector::from([q(1, 2), q(2, 3), q(5, 6)]);
+        assert_eq!(&v + r.t(), Vector::fill(q(1, 1)));
+        assert_eq!(-v.clone(), Vector::from([q(-1, 2), q(-1, 3), q(-1, 6)]));
+        assert_eq!(v.view::<2>(1).unwrap() * q(6, 1), Vector::from([q(2, 1), q(1, 1)]));
+        assert_eq!((r.clone() * v.clone()).into(), q(1, 4) + q(2, 9) + q(5, 36));
+    }
+
+    #[test]
+    fn test_det() {
+        let a = Matrix::from([[q(1, 2), q(1, 3)], [q(1, 4), q(1, 5)]]);
+        assert_eq!(a.det(), q(1, 60));
+
+        // The Hilbert matrix is badly conditioned, but its determinant is exact
+        let h: Matrix<BigRational, 5, 5> = Matrix::from(core::array::from_fn(|i| core::array::from_fn(|j| q(1, (i + j + 1) as i64))));
+        assert_eq!(h.det(), q(1, 266_716_800_000));
+
+        let singular = Matrix::from([[q(1, 1), q(2, 1), q(3, 1)], [q(4, 1), q(5, 1), q(6, 1)], [q(7, 1), q(8, 1), q(9, 1)]]);
+        assert_eq!(singular.det(), q(0, 1));
+
+        let swapped = Matrix::from([[q(0, 1), q(1, 1)], [q(1, 1), q(0, 1)]]);
+        assert_eq!(swapped.det(), q(-1, 1));
+    }
+
+    #[test]
+    fn test_dot() {
+        use ferrix::DotProduct;
+
+        let v = Vector::from([q(1, 2), q(1, 3)]);
+        let w = Vector::from([q(2, 3), q(3, 4)]);
+        assert_eq!((&v).dot(&w), q(7, 12));
+        assert_eq!(RowVector::from([q(1, 2), q(1, 3)]).dot(w.t()), q(7, 12));
+    }
+
+    #[test]
+    fn test_dot_bigint() {
+        use ferrix::DotProduct;
+        use num_bigint::BigInt;
+
+        let v = Vector::from([BigInt::from(1) << 70, BigInt::from(3)]);
+        let w = Vector::from([BigInt::from(2), BigInt::from(-5)]);
+        assert_eq!((&v).dot(&w), (BigInt::from(1) << 71) - 15);
+        assert_eq!(v.t().dot(w.t()), (BigInt::from(1) << 71) - 15);
+    }
+}
@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod tests {
+    use ferrix::{Matrix, MatrixCM, RowVector, Vector};
+
+    #[test]
+    fn test_vector_rem() {
+        let v = Vector::<i32, 3>::from([7, -8, 9]);
+        assert_eq!(v.clone() % 4, Vector::from([3, 0, 1]));
+        assert_eq!(&v % 4, Vector::from([3, 0, 1]));
+        assert_eq!(&v % Vector::from([2, 3, 5]), Vector::from([1, -2, 4]));
+        assert_eq!(&v % RowVector::from([2, 3, 5]).t(), Vector::from([1, -2, 4]));
+        assert_eq!(v.view::<2>(1).unwrap() % 3, Vector::from([-2, 0]));
+        assert_eq!(v.view::<2>(0).unwrap() % v.view::<2>(1).unwrap(), Vector::from([7, -8]));
+        assert_eq!(v % Matrix::from([[2], [3], [5]]), Vector::from([1, -2, 4]));
+
+        let v = Vector::<f64, 2>::from([5.5, -2.5]);
+        assert_eq!(v % 2.0, Vector::from([1.5, -0.5]));
+    }
+
+    #[test]
+    fn test_row_vector_rem() {
+        let v = RowVector::<u32, 3>::from([7, 8, 9]);
+        assert_eq!(&v % 4, RowVector::from([3, 0, 1]));
+        assert_eq!(&v % RowVector::from([2, 3, 5]), RowVector::from([1, 2, 4]));
+        assert_eq!(v.view::<2>(1).unwrap() % 5, RowVector::from([3, 4]));
+    }
+
+    #[test]
+    fn test_matrix_rem() {
+        let m = Matrix::<i64, 2, 2>::from([[10, 11], [12, 13]]);
+        assert_eq!(&m % 4, Matrix::from([[2, 3], [0, 1]]));
+        assert_eq!(&m % Matrix::from([[3, 4], [5, 6]]), Matrix::from([[1, 3], [2, 1]]));
+        assert_eq!(m.t() % 4, Matrix::from([[2, 0], [3, 1]]));
+        assert_eq!(&m % m.t(), Matrix::from([[0, 11], [1, 0]]));
+        assert_eq!(m.view::<1, 2>((1, 0)).unwrap() % 5, Matrix::from([[2, 3]]));
+        assert_eq!(MatrixCM::from(m.clone()) % 4, MatrixCM::from(Matrix::from([[2, 3], [0, 1]])));
+        assert_eq!(MatrixCM::from(m.clone()) % MatrixCM::from(m), MatrixCM::zeros());
+    }
+
+    #[test]
+    fn test_rem_assign() {
+        let mut v = Vector::<i32, 3>::from([7, 8, 9]);
+        v %= 4;
+        assert_eq!(v, Vector::from([3, 0, 1]));
+        v %= Vector::from([2, 5, 1]);
+        assert_eq!(v, Vector::from([1, 0, 0]));
+
+        let mut m = Matrix::<i32, 2, 2>::from([[10, 11], [12, 13]]);
+        m %= &Matrix::from([[3, 4], [5, 6]]);
+        assert_eq!(m, Matrix::from([[1, 3], [2, 1]]));
+
+        let mut m = Matrix::<i32, 2, 2>::from([[10, 11], [12, 13]]);
+        let mut view = m.view_mut::<1, 2>((0, 0)).unwrap();
+        view %= 3;
+        assert_eq!(m, Matrix::from([[1, 2], [12, 13]]));
+        let mut transposed = m.t_mut();
+        transposed %= Matrix::from([[2, 5], [2, 5]]);
+        assert_eq!(m, Matrix::from([[1, 0], [2, 3]]));
+    }
+}
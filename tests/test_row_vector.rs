@@ -51,6 +51,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::absurd_extreme_comparisons)]
     fn test_random_int() {
         let v = RowVector::<i32, 3>::random();
         assert!(i32::MIN <= v[0] && v[0] <= i32::MAX);
@@ -30,9 +30,9 @@ mod tests {
 
     #[test]
     fn test_row_vector_view_magnitude() {
-        let v = RowVector::from([3.0, 4.0, 5.0]);
+        let v = RowVector::from([3.0f64, 4.0, 5.0]);
         let view = v.view::<2>(0).unwrap();
-        assert!((view.magnitude() - 5.0) < f64::EPSILON);
+        assert!((view.magnitude() - 5.0).abs() < f64::EPSILON);
     }
 
     #[test]
@@ -143,6 +143,17 @@ mod tests {
         assert_eq!(view[(0, 4)], 6);
     }
 
+    #[test]
+    fn test_row_vector_view_view() {
+        let vec = RowVector::from([1, 2, 3, 4, 5, 6]);
+        let view = vec.view::<4>(1).unwrap();
+        let inner = view.view::<3>(1).unwrap();
+        assert_eq!(inner, RowVector::from([3, 4, 5]));
+        assert_eq!(inner.view::<1>(2).unwrap(), RowVector::from([5]));
+        assert!(view.view::<2>(3).is_none());
+        assert!(view.view::<0>(0).is_none());
+    }
+
     #[test]
     fn test_row_vector_view_send() {
         fn assert_send<T: Send>() {}
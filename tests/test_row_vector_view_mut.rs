@@ -45,9 +45,9 @@ mod tests {
 
     #[test]
     fn test_row_vector_view_mut_magnitude() {
-        let mut v = RowVector::from([3.0, 4.0, 5.0]);
+        let mut v = RowVector::from([3.0f64, 4.0, 5.0]);
         let view_mut = v.view_mut::<2>(0).unwrap();
-        assert!((view_mut.magnitude() - 5.0) < f64::EPSILON);
+        assert!((view_mut.magnitude() - 5.0).abs() < f64::EPSILON);
     }
 
     #[test]
@@ -198,6 +198,21 @@ mod tests {
         assert_eq!(view_mut[(0, 4)], 2);
     }
 
+    #[test]
+    fn test_row_vector_view_mut_view() {
+        let mut vec = RowVector::from([1, 2, 3, 4, 5, 6]);
+        let mut view = vec.view_mut::<4>(1).unwrap();
+        assert_eq!(view.view::<2>(2).unwrap(), RowVector::from([4, 5]));
+        assert!(view.view::<2>(3).is_none());
+
+        let mut inner = view.view_mut::<3>(1).unwrap();
+        inner[0] = 0;
+        let mut innermost = inner.view_mut::<1>(2).unwrap();
+        innermost[0] = 0;
+        assert!(view.view_mut::<5>(0).is_none());
+        assert_eq!(vec, RowVector::from([1, 2, 0, 4, 0, 6]));
+    }
+
     #[test]
     fn test_row_vector_view_mut_send() {
         fn assert_send<T: Send>() {}
@@ -0,0 +1,149 @@
+// The owned types dispatch f32 and f64 to explicit SIMD kernels, while the views
+// take the scalar path for element-wise ops. These tests compare against scalar
+// results at sizes that leave a remainder after the vectorized loop.
+#[cfg(test)]
+mod tests {
+    use ferrix::{DotProduct, Matrix, RowVector, Vector};
+
+    fn matrix_f64<const R: usize, const C: usize>(seed: f64) -> Matrix<f64, R, C> {
+        Matrix::from(core::array::from_fn(|i| {
+            core::array::from_fn(|j| ((i * C + j) as f64 * seed).sin() + 1.5)
+        }))
+    }
+
+    fn matrix_f32<const R: usize, const C: usize>(seed: f32) -> Matrix<f32, R, C> {
+        Matrix::from(core::array::from_fn(|i| {
+            core::array::from_fn(|j| ((i * C + j) as f32 * seed).sin() + 1.5)
+        }))
+    }
+
+    fn assert_close_f64<const R: usize, const C: usize>(
+        a: &Matrix<f64, R, C>,
+        b: &Matrix<f64, R, C>,
+        tol: f64,
+    ) {
+        for i in 0..R {
+            for j in 0..C {
+                assert!((a[(i, j)] - b[(i, j)]).abs() < tol, "mismatch at ({i}, {j})");
+            }
+        }
+    }
+
+    fn assert_close_f32<const R: usize, const C: usize>(
+        a: &Matrix<f32, R, C>,
+        b: &Matrix<f32, R, C>,
+        tol: f32,
+    ) {
+        for i in 0..R {
+            for j in 0..C {
+                assert!((a[(i, j)] - b[(i, j)]).abs() < tol, "mismatch at ({i}, {j})");
+            }
+        }
+    }
+
+    fn naive_f64<const M: usize, const N: usize, const P: usize>(
+        a: &Matrix<f64, M, N>,
+        b: &Matrix<f64, N, P>,
+    ) -> Matrix<f64, M, P> {
+        Matrix::from(core::array::from_fn(|i| {
+            core::array::from_fn(|j| (0..N).fold(0.0, |acc, k| acc + a[(i, k)] * b[(k, j)]))
+        }))
+    }
+
+    #[test]
+    fn test_simd_elementwise_f64() {
+        let a = matrix_f64::<17, 33>(0.3);
+        let b = matrix_f64::<17, 33>(0.7);
+        let (va, vb) = (a.view::<17, 33>((0, 0)).unwrap(), b.view::<17, 33>((0, 0)).unwrap());
+
+        // Element-wise results are exact
+        assert_eq!(&a + &b, &va + &vb);
+        assert_eq!(&a - &b, &va - &vb);
+        assert_eq!(&a + 2.5, &va + 2.5);
+        assert_eq!(&a - 2.5, &va - 2.5);
+        assert_eq!(&a * 2.5, &va * 2.5);
+        assert_eq!(&a / 2.5, &va / 2.5);
+
+        let mut c = a.clone();
+        c += &b;
+        assert_eq!(c, &va + &vb);
+        c -= &b;
+        assert_eq!(c, &(&va + &vb) - &vb);
+
+        let mut c = a.clone();
+        c *= 3.0;
+        assert_eq!(c, &va * 3.0);
+        c /= 3.0;
+        assert_close_f64(&c, &a, 1e-12);
+    }
+
+    #[test]
+    fn test_simd_elementwise_f32() {
+        let a = matrix_f32::<9, 13>(0.3);
+        let b = matrix_f32::<9, 13>(0.7);
+        let (va, vb) = (a.view::<9, 13>((0, 0)).unwrap(), b.view::<9, 13>((0, 0)).unwrap());
+
+        assert_eq!(&a + &b, &va + &vb);
+        assert_eq!(&a - &b, &va - &vb);
+        assert_eq!(&a * 0.5, &va * 0.5);
+        assert_eq!(&a / 0.5, &va / 0.5);
+
+        let v1 = Vector::<f32, 19>::from(core::array::from_fn(|i| i as f32 * 0.25));
+        let v2 = Vector::<f32, 19>::from(core::array::from_fn(|i| 1.0 - i as f32));
+        let expected = Vector::<f32, 19>::from(core::array::from_fn(|i| v1[i] + v2[i]));
+        assert_eq!(&v1 + &v2, expected);
+
+        let r1 = RowVector::<f32, 19>::from(core::array::from_fn(|i| i as f32 * 0.25));
+        let expected = RowVector::<f32, 19>::from(core::array::from_fn(|i| r1[i] - 1.0));
+        assert_eq!(r1 - 1.0, expected);
+    }
+
+    #[test]
+    fn test_simd_dot() {
+        let v1 = Vector::<f64, 37>::from(core::array::from_fn(|i| (i as f64 * 0.1).cos()));
+        let v2 = Vector::<f64, 37>::from(core::array::from_fn(|i| (i as f64 * 0.2).sin()));
+        let expected = (0..37).fold(0.0, |acc, i| acc + v1[i] * v2[i]);
+        assert!((v1.dot(&v2) - expected).abs() < 1e-12);
+
+        let r1 = RowVector::<f32, 37>::from(core::array::from_fn(|i| (i as f32 * 0.1).cos()));
+        let r2 = RowVector::<f32, 37>::from(core::array::from_fn(|i| (i as f32 * 0.2).sin()));
+        let expected = (0..37).fold(0.0, |acc, i| acc + r1[i] * r2[i]);
+        assert!((r1.dot(&r2) - expected).abs() < 1e-4);
+
+        // Integers take the scalar path
+        let v1 = Vector::<i64, 37>::from(core::array::from_fn(|i| i as i64));
+        assert_eq!((&v1).dot(&v1), (0..37).map(|i| i * i).sum::<i64>());
+    }
+
+    #[test]
+    fn test_simd_matvec() {
+        let m = matrix_f64::<21, 35>(0.4);
+        let v = Vector::<f64, 35>::from(core::array::from_fn(|i| i as f64 - 17.0));
+        let expected = m.view::<21, 35>((0, 0)).unwrap() * &v;
+        assert_close_f64(&(&m * &v), &expected, 1e-10);
+
+        let m = matrix_f32::<7, 11>(0.4);
+        let v = Vector::<f32, 11>::from(core::array::from_fn(|i| i as f32 - 5.0));
+        let expected = m.view::<7, 11>((0, 0)).unwrap() * &v;
+        assert_close_f32(&(&m * &v), &expected, 1e-4);
+    }
+
+    #[test]
+    fn test_simd_matmul() {
+        let a = matrix_f64::<17, 23>(0.3);
+        let b = matrix_f64::<23, 13>(0.9);
+        assert_close_f64(&(&a * &b), &naive_f64(&a, &b), 1e-10);
+
+        let a = matrix_f32::<5, 9>(0.3);
+        let b = matrix_f32::<9, 19>(0.9);
+        let expected = Matrix::<f32, 5, 19>::from(core::array::from_fn(|i| {
+            core::array::from_fn(|j| (0..9).fold(0.0, |acc, k| acc + a[(i, k)] * b[(k, j)]))
+        }));
+        assert_close_f32(&(&a * &b), &expected, 1e-4);
+
+        // Integers take the scalar path
+        let a = Matrix::<i32, 3, 2>::from([[1, 2], [3, 4], [5, 6]]);
+        let b = Matrix::<i32, 2, 3>::from([[1, 0, 2], [0, 1, 3]]);
+        assert_eq!(a * b, Matrix::from([[1, 2, 8], [3, 4, 18], [5, 6, 28]]));
+    }
+}
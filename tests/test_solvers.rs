@@ -0,0 +1,209 @@
+#[cfg(test)]
+mod tests {
+    use ferrix::{bicgstab, cg, gmres, pcg, LinearOperator, Matrix, SparseMatrix, Vector};
+
+    // 1D Poisson matrix: symmetric positive definite
+    fn laplacian<const N: usize>() -> Matrix<f64, N, N> {
+        Matrix::from(core::array::from_fn(|i| {
+            core::array::from_fn(|j| match i.abs_diff(j) {
+                0 => 2.0,
+                1 => -1.0,
+                _ => 0.0,
+            })
+        }))
+    }
+
+    // Non-symmetric, diagonally dominant convection-diffusion matrix
+    fn convection<const N: usize>() -> Matrix<f64, N, N> {
+        Matrix::from(core::array::from_fn(|i| {
+            core::array::from_fn(|j| {
+                if i == j {
+                    4.0
+                } else if j == i + 1 {
+                    -0.5
+                } else if i == j + 1 {
+                    -1.5
+                } else {
+                    0.0
+                }
+            })
+        }))
+    }
+
+    fn rhs<const N: usize>() -> Vector<f64, N> {
+        Vector::from(core::array::from_fn(|i| (i as f64 * 0.7).sin() + 1.0))
+    }
+
+    fn assert_solves<A: LinearOperator<f64, N, N>, const N: usize>(
+        a: &A,
+        x: &Vector<f64, N>,
+        b: &Vector<f64, N>,
+    ) {
+        let ax = a.apply(x);
+        for i in 0..N {
+            assert!((ax[i] - b[i]).abs() < 1e-8, "mismatch at {i}");
+        }
+    }
+
+    #[test]
+    fn test_cg() {
+        let a = laplacian::<20>();
+        let b = rhs::<20>();
+        let (x, report) = cg(&a, &b, Vector::zeros(), 1e-12, 100);
+        assert!(report.converged);
+        assert!(report.iterations <= 20);
+        assert_eq!(report.residuals.len(), report.iterations + 1);
+        assert_solves(&a, &x, &b);
+    }
+
+    #[test]
+    fn test_pcg() {
+        // Badly scaled SPD matrix, where a Jacobi preconditioner helps
+        let scale = |i: usize| 1.0 + i as f64 * 10.0;
+        let lap = laplacian::<30>();
+        let a = Matrix::<f64, 30, 30>::from(core::array::from_fn(|i| {
+            core::array::from_fn(|j| lap[(i, j)] * scale(i).sqrt() * scale(j).sqrt())
+        }));
+        let b = rhs::<30>();
+        let jacobi = |r: &Vector<f64, 30>| Vector::from(core::array::from_fn(|i| r[i] / a[(i, i)]));
+
+        let (x, report) = pcg(&a, &jacobi, &b, Vector::zeros(), 1e-12, 100);
+        assert!(report.converged);
+        assert_solves(&a, &x, &b);
+
+        let (_, plain) = cg(&a, &b, Vector::zeros(), 1e-12, 100);
+        assert!(report.iterations <= plain.iterations);
+    }
+
+    #[test]
+    fn test_gmres() {
+        let a = convection::<25>();
+        let b = rhs::<25>();
+
+        // Full GMRES
+        let (x, report) = gmres(&a, &b, Vector::zeros(), 1e-12, 25, 100);
+        assert!(report.converged);
+        assert_eq!(report.residuals.len(), report.iterations + 1);
+        assert!(report.residuals.windows(2).all(|w| w[1] <= w[0] + 1e-12));
+        assert_solves(&a, &x, &b);
+
+        // Restarted GMRES
+        let (x, report) = gmres(&a, &b, Vector::zeros(), 1e-12, 5, 200);
+        assert!(report.converged);
+        assert_eq!(report.residuals.len(), report.iterations + 1);
+        assert_solves(&a, &x, &b);
+    }
+
+    #[test]
+    fn test_gmres_singular() {
+        // `b` is not in the range of `A`, and `A` is singular on the Krylov subspace of `b`
+        let a = Matrix::from([[2.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 0.0]]);
+        let b = Vector::<f64, 3>::from([2.0, 1.0, 1.0]);
+        let (x, report) = gmres(&a, &b, Vector::zeros(), 1e-12, 3, 100);
+        assert!(!report.converged);
+        assert!(report.iterations < 100);
+        assert_eq!(report.residuals.len(), report.iterations + 1);
+
+        // The minimal residual solution in the span of `b` and `A b`
+        assert!((x[0] - 1.0).abs() < 1e-12);
+        assert!((x[1] - 1.0).abs() < 1e-12);
+        assert!((x[2] - 1.5).abs() < 1e-12);
+        assert!((report.residuals[report.iterations] - 1.0).abs() < 1e-12);
+
+        // The zero operator makes no progress at all
+        let (x, report) = gmres(&Matrix::<f64, 3, 3>::zeros(), &b, Vector::zeros(), 1e-12, 3, 100);
+        assert!(!report.converged);
+        assert_eq!(x, Vector::zeros());
+        assert_eq!(report.residuals, vec![6.0f64.sqrt(); 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Restart must be greater than zero")]
+    fn test_gmres_zero_restart() {
+        let a = convection::<3>();
+        gmres(&a, &rhs::<3>(), Vector::zeros(), 1e-12, 0, 10);
+    }
+
+    #[test]
+    fn test_bicgstab() {
+        let a = convection::<25>();
+        let b = rhs::<25>();
+        let (x, report) = bicgstab(&a, &b, Vector::zeros(), 1e-12, 100);
+        assert!(report.converged);
+        assert_eq!(report.residuals.len(), report.iterations + 1);
+        assert_solves(&a, &x, &b);
+    }
+
+    #[test]
+    fn test_solvers_initial_guess() {
+        let a = laplacian::<10>();
+        let b = rhs::<10>();
+        let (x, _) = cg(&a, &b, Vector::zeros(), 1e-12, 100);
+
+        // Starting at the solution takes no iterations
+        let (_, report) = cg(&a, &b, x.clone(), 1e-6, 100);
+        assert!(report.converged);
+        assert_eq!(report.iterations, 0);
+        let (_, report) = gmres(&a, &b, x.clone(), 1e-6, 5, 100);
+        assert_eq!(report.iterations, 0);
+        let (_, report) = bicgstab(&a, &b, x, 1e-6, 100);
+        assert_eq!(report.iterations, 0);
+    }
+
+    #[test]
+    fn test_solvers_max_iter() {
+        let a = laplacian::<50>();
+        let b = rhs::<50>();
+
+        let (_, report) = cg(&a, &b, Vector::zeros(), 1e-12, 3);
+        assert!(!report.converged);
+        assert_eq!(report.iterations, 3);
+        assert_eq!(report.residuals.len(), 4);
+
+        let (_, report) = gmres(&a, &b, Vector::zeros(), 1e-12, 2, 5);
+        assert!(!report.converged);
+        assert_eq!(report.iterations, 5);
+        assert_eq!(report.residuals.len(), 6);
+
+        let (_, report) = bicgstab(&a, &b, Vector::zeros(), 1e-12, 3);
+        assert!(!report.converged);
+        assert_eq!(report.iterations, 3);
+    }
+
+    #[test]
+    fn test_solvers_closure() {
+        // Matrix-free 1D Poisson operator
+        const N: usize = 40;
+        let a = |x: &Vector<f64, N>| {
+            Vector::from(core::array::from_fn(|i| {
+                let left = if i > 0 { x[i - 1] } else { 0.0 };
+                let right = if i + 1 < N { x[i + 1] } else { 0.0 };
+                2.0 * x[i] - left - right
+            }))
+        };
+        let b = rhs::<N>();
+
+        let (x, report) = cg(&a, &b, Vector::zeros(), 1e-12, 100);
+        assert!(report.converged);
+        assert_solves(&laplacian::<N>(), &x, &b);
+
+        let (x, report) = gmres(&a, &b, Vector::zeros(), 1e-12, N, 100);
+        assert!(report.converged);
+        assert_solves(&a, &x, &b);
+    }
+
+    #[test]
+    fn test_solvers_sparse() {
+        let dense = convection::<30>();
+        let a = SparseMatrix::from_dense(&dense).to_csr();
+        let b = rhs::<30>();
+
+        let (x, report) = bicgstab(&a, &b, Vector::zeros(), 1e-12, 100);
+        assert!(report.converged);
+        assert_solves(&dense, &x, &b);
+
+        let (x, report) = gmres(&a, &b, Vector::zeros(), 1e-12, 10, 200);
+        assert!(report.converged);
+        assert_solves(&dense, &x, &b);
+    }
+}
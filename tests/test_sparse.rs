@@ -0,0 +1,277 @@
+#[cfg(test)]
+mod tests {
+    use core::ops::{Add, Mul};
+    use ferrix::{Matrix, SparseFormat, SparseMatrix, Vector};
+    use num_traits::Zero;
+
+    // An element type that is `Clone` but not `Copy`
+    #[derive(Debug, Clone, PartialEq)]
+    struct Boxed(Box<i64>);
+
+    impl Add for Boxed {
+        type Output = Self;
+
+        fn add(self, other: Self) -> Self {
+            Boxed(Box::new(*self.0 + *other.0))
+        }
+    }
+
+    impl Mul for Boxed {
+        type Output = Self;
+
+        fn mul(self, other: Self) -> Self {
+            Boxed(Box::new(*self.0 * *other.0))
+        }
+    }
+
+    impl Zero for Boxed {
+        fn zero() -> Self {
+            Boxed(Box::new(0))
+        }
+
+        fn is_zero(&self) -> bool {
+            *self.0 == 0
+        }
+    }
+
+    fn dense() -> Matrix<f64, 4, 5> {
+        Matrix::from([
+            [1.0, 0.0, 0.0, 2.0, 0.0],
+            [0.0, 0.0, 3.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0, 0.0],
+            [4.0, 5.0, 0.0, 0.0, 6.0],
+        ])
+    }
+
+    fn naive<const M: usize, const N: usize, const P: usize>(
+        a: &Matrix<f64, M, N>,
+        b: &Matrix<f64, N, P>,
+    ) -> Matrix<f64, M, P> {
+        Matrix::from(core::array::from_fn(|i| {
+            core::array::from_fn(|j| (0..N).fold(0.0, |acc, k| acc + a[(i, k)] * b[(k, j)]))
+        }))
+    }
+
+    #[test]
+    fn test_sparse_triplets() {
+        // Unordered, with duplicates
+        let triplets = [
+            (3, 4, 6.0),
+            (0, 0, 1.0),
+            (3, 0, 4.0),
+            (1, 2, 1.0),
+            (0, 3, 2.0),
+            (3, 1, 5.0),
+            (1, 2, 2.0),
+        ];
+        let a = SparseMatrix::from_triplets(4, 5, &triplets).unwrap();
+        assert_eq!(a.format(), SparseFormat::Coo);
+        assert_eq!(a.shape(), (4, 5));
+        assert_eq!(a.nnz(), 7);
+        assert_eq!(a.get((1, 2)), 3.0);
+        assert_eq!(a.to_dense::<4, 5>().unwrap(), dense());
+
+        let csr = a.to_csr();
+        assert_eq!(csr.nnz(), 6);
+        assert_eq!(
+            csr.iter().map(|(i, j, &v)| (i, j, v)).collect::<Vec<_>>(),
+            vec![
+                (0, 0, 1.0),
+                (0, 3, 2.0),
+                (1, 2, 3.0),
+                (3, 0, 4.0),
+                (3, 1, 5.0),
+                (3, 4, 6.0)
+            ]
+        );
+        assert_eq!(csr.to_dense::<4, 5>().unwrap(), dense());
+
+        let csc = a.to_csc();
+        assert_eq!(csc.nnz(), 6);
+        assert_eq!(
+            csc.iter().map(|(i, j, &v)| (i, j, v)).collect::<Vec<_>>(),
+            vec![
+                (0, 0, 1.0),
+                (3, 0, 4.0),
+                (3, 1, 5.0),
+                (1, 2, 3.0),
+                (0, 3, 2.0),
+                (3, 4, 6.0)
+            ]
+        );
+        assert_eq!(csc.to_dense::<4, 5>().unwrap(), dense());
+
+        for i in 0..4 {
+            for j in 0..5 {
+                assert_eq!(csr.get((i, j)), dense()[(i, j)]);
+                assert_eq!(csc.get((i, j)), dense()[(i, j)]);
+            }
+        }
+
+        assert!(SparseMatrix::from_triplets(4, 5, &[(0, 5, 1.0)]).is_none());
+        assert!(a.to_dense::<5, 4>().is_none());
+    }
+
+    #[test]
+    fn test_sparse_push() {
+        let mut a = SparseMatrix::from_dense(&dense());
+        assert_eq!(a.format(), SparseFormat::Csr);
+        a.push(2, 2, 7.0);
+        a.push(0, 0, 1.0);
+        assert_eq!(a.format(), SparseFormat::Coo);
+
+        let mut expected = dense();
+        expected[(2, 2)] = 7.0;
+        expected[(0, 0)] = 2.0;
+        assert_eq!(a.to_csr().to_dense::<4, 5>().unwrap(), expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index out of bounds")]
+    fn test_sparse_push_out_of_bounds() {
+        let mut a = SparseMatrix::<f64>::new(2, 2);
+        a.push(2, 0, 1.0);
+    }
+
+    #[test]
+    fn test_sparse_dense_conversion() {
+        let a = SparseMatrix::from(dense());
+        assert_eq!(a.nnz(), 6);
+        assert_eq!(a, SparseMatrix::from(&dense()));
+        assert_eq!(a.to_dense::<4, 5>().unwrap(), dense());
+
+        // Equality ignores format and explicit zeros
+        let b = SparseMatrix::from_triplets(4, 5, &[(2, 2, 0.0)]).unwrap();
+        assert_eq!(b, SparseMatrix::new(4, 5));
+        assert_eq!(a.to_csc(), a.to_coo());
+        assert_ne!(a, SparseMatrix::new(4, 5));
+        assert_ne!(a, a.t());
+    }
+
+    #[test]
+    fn test_sparse_transpose() {
+        for a in [
+            SparseMatrix::from(dense()),
+            SparseMatrix::from(dense()).to_csc(),
+            SparseMatrix::from(dense()).to_coo(),
+        ] {
+            let t = a.t();
+            assert_eq!(t.shape(), (5, 4));
+            assert_eq!(t.to_dense::<5, 4>().unwrap(), Matrix::from(dense().t()));
+            assert_eq!(t.t(), a);
+        }
+        assert_eq!(SparseMatrix::from(dense()).t().format(), SparseFormat::Csc);
+    }
+
+    #[test]
+    fn test_sparse_mul_vec() {
+        let x = Vector::from([1.0, 2.0, 3.0, 4.0, 5.0]);
+        let expected = dense() * &x;
+        let a = SparseMatrix::from(dense());
+        for a in [a.clone(), a.to_csc(), a.to_coo()] {
+            let y: Vector<f64, 4> = a.mul_vec(&x).unwrap();
+            assert_eq!(Matrix::from(y), expected);
+        }
+        assert!(a.mul_vec::<5, 5>(&x).is_none());
+    }
+
+    #[test]
+    fn test_sparse_mul_vector() {
+        let x = Vector::from([1.0, 2.0, 3.0, 4.0, 5.0]);
+        let expected = dense() * &x;
+        let a = SparseMatrix::from(dense());
+        for a in [a.clone(), a.to_csc(), a.to_coo()] {
+            assert_eq!(&a * &x, expected.as_slice());
+            assert_eq!(a * x.clone(), expected.as_slice());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Dimension mismatch")]
+    fn test_sparse_mul_vector_dimension_mismatch() {
+        let a = SparseMatrix::from(dense());
+        let _ = &a * &Vector::from([1.0, 2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn test_sparse_matmul() {
+        let b = Matrix::from([
+            [0.0, 1.0, 0.0],
+            [2.0, 0.0, 0.0],
+            [0.0, 0.0, 3.0],
+            [1.0, 0.0, -1.0],
+            [0.0, 0.0, 4.0],
+        ]);
+        let expected = naive(&dense(), &b);
+
+        let a = SparseMatrix::from(dense());
+        let sb = SparseMatrix::from(&b);
+        let product = &a * &sb;
+        assert_eq!(product.format(), SparseFormat::Csr);
+        assert_eq!(product.shape(), (4, 3));
+        assert_eq!(product.to_dense::<4, 3>().unwrap(), expected);
+        assert_eq!(a.to_csc() * sb.to_coo(), product);
+
+        // A^T A
+        let gram = a.t() * &a;
+        assert_eq!(
+            gram.to_dense::<5, 5>().unwrap(),
+            naive(&Matrix::from(dense().t()), &dense())
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Dimension mismatch")]
+    fn test_sparse_matmul_dimension_mismatch() {
+        let a = SparseMatrix::from(dense());
+        let _ = &a * &a;
+    }
+
+    #[test]
+    fn test_sparse_checked_matmul() {
+        let a = SparseMatrix::from(dense());
+        assert!(a.checked_matmul(&a).is_none());
+        assert_eq!(a.checked_matmul(&a.t()), Some(&a * a.t()));
+    }
+
+    #[test]
+    fn test_sparse_large() {
+        // 1D Laplacian assembled element by element
+        const N: usize = 1000;
+        let mut a = SparseMatrix::new(N, N);
+        for e in 0..N - 1 {
+            a.push(e, e, 1.0);
+            a.push(e + 1, e + 1, 1.0);
+            a.push(e, e + 1, -1.0);
+            a.push(e + 1, e, -1.0);
+        }
+        let a = a.to_csr();
+        assert_eq!(a.nnz(), 3 * N - 2);
+        assert_eq!(a.get((1, 1)), 2.0);
+
+        // The constant vector is in the null space
+        let ones = Vector::<f64, N>::ones();
+        let y: Vector<f64, N> = a.mul_vec(&ones).unwrap();
+        assert_eq!(y, Vector::zeros());
+
+        // A^2 is pentadiagonal
+        assert_eq!((&a * &a).nnz(), 5 * N - 6);
+    }
+
+    #[test]
+    fn test_sparse_clone_elements() {
+        let b = |x: i64| Boxed(Box::new(x));
+        let a = SparseMatrix::from_triplets(3, 3, &[(2, 0, b(4)), (0, 1, b(1)), (2, 0, b(1)), (0, 2, b(2))]).unwrap();
+        assert_eq!(a.get((2, 0)), b(5));
+        assert_eq!(a.get((1, 1)), b(0));
+
+        let csr = a.to_csr();
+        assert_eq!(csr.iter().collect::<Vec<_>>(), vec![(0, 1, &b(1)), (0, 2, &b(2)), (2, 0, &b(5))]);
+        assert_eq!(csr, a.to_csc());
+
+        let x = Vector::from([b(1), b(2), b(3)]);
+        let y: Vector<Boxed, 3> = csr.mul_vec(&x).unwrap();
+        assert_eq!(y, Vector::from([b(8), b(0), b(5)]));
+        assert_eq!((&csr * &csr).get((2, 1)), b(5));
+    }
+}
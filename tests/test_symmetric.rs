@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod tests {
+    use ferrix::{Matrix, Symmetric, Vector};
+
+    #[test]
+    fn test_new() {
+        let m = Matrix::from([[1, 2, 3], [2, 4, 5], [3, 5, 6]]);
+        let s = Symmetric::new(m.clone()).unwrap();
+        assert_eq!(s.matrix(), &m);
+        assert_eq!(s[(2, 1)], 5);
+        assert_eq!(Matrix::from(s.clone()), m);
+        assert_eq!(Symmetric::new(Matrix::from([[1, 2], [3, 4]])), None);
+
+        let upper = Matrix::from([[1, 2, 3], [0, 4, 5], [-1, -1, 6]]);
+        assert_eq!(Symmetric::from_upper(&upper), s);
+    }
+
+    #[test]
+    fn test_ops() {
+        let m = Matrix::from([[1.0, 2.0], [2.0, -3.0]]);
+        let s = Symmetric::new(m.clone()).unwrap();
+        let v = Vector::from([0.5, 2.0]);
+
+        assert_eq!(&s * &v, &m * &v);
+        assert_eq!(&s * v.view::<2>(0).unwrap(), &m * &v);
+        assert_eq!(&s * &m, &m * &m);
+        assert_eq!(&s * m.t(), &m * m.t());
+        assert_eq!(&m * &s, &m * &m);
+        assert_eq!(&s * 2.0, Symmetric::new(&m * 2.0).unwrap());
+        assert_eq!(&s + s.clone(), &s * 2.0);
+        assert_eq!(Matrix::from(&s - &s * 2.0), m * -1.0);
+    }
+
+    #[test]
+    fn test_mul() {
+        let m = Matrix::from([[1, 2, 3], [2, 4, 5], [3, 5, 6]]);
+        let s = Symmetric::new(m.clone()).unwrap();
+        let b = Matrix::from([[1, -1], [2, 0], [-3, 4]]);
+        assert_eq!(&s * &b, &m * &b);
+        assert_eq!(&s * b.view::<3, 1>((0, 1)).unwrap(), &m * b.view::<3, 1>((0, 1)).unwrap());
+        assert_eq!(s.clone() * Vector::from([1, 1, 1]), Matrix::from([[6], [11], [14]]));
+        let bt = Matrix::from(b.t());
+        assert_eq!(&bt * &s, &bt * &m);
+        assert_eq!(bt * s, b.t() * &m);
+    }
+}
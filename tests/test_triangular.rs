@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod tests {
+    use ferrix::{LowerTriangular, Matrix, UpperTriangular, Vector};
+
+    #[test]
+    fn test_new() {
+        let m = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        assert_eq!(UpperTriangular::new(m.clone()), None);
+        assert_eq!(LowerTriangular::new(m.clone()), None);
+
+        let u = UpperTriangular::from_upper(&m);
+        let l = LowerTriangular::from_lower(&m);
+        assert_eq!(u.matrix(), &Matrix::from([[1, 2, 3], [0, 5, 6], [0, 0, 9]]));
+        assert_eq!(l.matrix(), &Matrix::from([[1, 0, 0], [4, 5, 0], [7, 8, 9]]));
+        assert_eq!(UpperTriangular::new(u.matrix().clone()), Some(u.clone()));
+        assert_eq!(LowerTriangular::new(l.matrix().clone()), Some(l.clone()));
+        assert_eq!(u[(0, 2)], 3);
+        assert_eq!(l[(0, 2)], 0);
+        assert_eq!(Matrix::from(l.clone()), *l.matrix());
+    }
+
+    #[test]
+    fn test_mul() {
+        let m = Matrix::from([[2, -1, 3], [4, 5, -6], [7, 1, 9]]);
+        let u = UpperTriangular::from_upper(&m);
+        let l = LowerTriangular::from_lower(&m);
+        let (du, dl) = (Matrix::from(u.clone()), Matrix::from(l.clone()));
+        let v = Vector::from([1, -2, 3]);
+        let b = Matrix::from([[1, 2], [3, 4], [5, 6]]);
+
+        assert_eq!(&u * &v, &du * &v);
+        assert_eq!(&l * &v, &dl * &v);
+        assert_eq!(&u * &b, &du * &b);
+        assert_eq!(&l * &b, &dl * &b);
+        assert_eq!(&u * v.view::<3>(0).unwrap(), &du * &v);
+        assert_eq!(&l * b.t().t(), &dl * &b);
+        assert_eq!(&u * m.view::<3, 2>((0, 1)).unwrap(), &du * m.view::<3, 2>((0, 1)).unwrap());
+        assert_eq!(Matrix::from(&u * u.clone()), &du * &du);
+        assert_eq!(Matrix::from(&l * l.clone()), &dl * &dl);
+        assert_eq!(Matrix::from(b.t()) * &u, b.t() * &du);
+        assert_eq!(&m * &l, &m * &dl);
+        assert_eq!(m.clone() * u.clone(), &m * &du);
+        assert_eq!(u * 3, UpperTriangular::from_upper(&(&m * 3)));
+        assert_eq!(l * 3, LowerTriangular::from_lower(&(m * 3)));
+    }
+
+    #[test]
+    fn test_solve() {
+        let m = Matrix::from([[2.0, -1.0, 3.0], [4.0, 5.0, -6.0], [7.0, 1.0, 8.0]]);
+        let u = UpperTriangular::from_upper(&m);
+        let l = LowerTriangular::from_lower(&m);
+        let x = Vector::from([1.0, -2.0, 0.5]);
+
+        assert_eq!(u.solve(&Vector::from(&u * &x)), Some(x.clone()));
+        assert_eq!(l.solve(&Vector::from(&l * &x)), Some(x.clone()));
+        assert_eq!(u.solve_transpose(&Vector::from(u.matrix().t() * &x)), Some(x.clone()));
+        assert_eq!(l.solve_transpose(&Vector::from(l.matrix().t() * &x)), Some(x));
+
+        let singular = UpperTriangular::from_upper(&Matrix::from([[1.0, 2.0], [0.0, 0.0]]));
+        assert_eq!(singular.solve(&Vector::from([1.0, 1.0])), None);
+    }
+}
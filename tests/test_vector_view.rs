@@ -37,9 +37,9 @@ mod tests {
 
     #[test]
     fn test_vector_view_magnitude() {
-        let v = Vector::from([0.0, 3.0, 4.0]);
+        let v = Vector::from([0.0f64, 3.0, 4.0]);
         let view = v.view::<2>(1).unwrap();
-        assert!((view.magnitude() - 5.0) < f64::EPSILON);
+        assert!((view.magnitude() - 5.0).abs() < f64::EPSILON);
     }
 
     #[test]
@@ -155,6 +155,17 @@ mod tests {
         assert_eq!(view[(4, 0)], 4);
     }
 
+    #[test]
+    fn test_vector_view_view() {
+        let vec = Vector::from([1, 2, 3, 4, 5, 6]);
+        let view = vec.view::<4>(1).unwrap();
+        let inner = view.view::<3>(1).unwrap();
+        assert_eq!(inner, Vector::from([3, 4, 5]));
+        assert_eq!(inner.view::<1>(2).unwrap(), Vector::from([5]));
+        assert!(view.view::<2>(3).is_none());
+        assert!(view.view::<0>(0).is_none());
+    }
+
     #[test]
     fn test_vector_view_send() {
         fn assert_send<T: Send>() {}
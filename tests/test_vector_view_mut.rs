@@ -66,14 +66,14 @@ mod tests {
 
     #[test]
     fn test_vector_view_mut_magnitude() {
-        let mut v = Vector::from([2.0, 3.0, 4.0]);
+        let mut v = Vector::from([2.0f64, 3.0, 4.0]);
         let view_mut = v.view_mut::<2>(1).unwrap();
-        assert!((view_mut.magnitude() - 5.0) < f64::EPSILON);
+        assert!((view_mut.magnitude() - 5.0).abs() < f64::EPSILON);
     }
 
     #[test]
     fn test_vector_view_mut_eq() {
-        let mut v1 = Vector::from([2.0, 3.0, 4.0]);
+        let mut v1 = Vector::from([2.0f64, 3.0, 4.0]);
         let v2 = Vector::from([2.0, 3.0]);
         let view_mut1 = v1.view_mut::<2>(0).unwrap();
         assert_eq!(view_mut1, v2);
@@ -81,7 +81,7 @@ mod tests {
 
     #[test]
     fn test_vector_view_mut_ne() {
-        let mut v1 = Vector::from([2.0, 3.0, 4.0]);
+        let mut v1 = Vector::from([2.0f64, 3.0, 4.0]);
         let v2 = Vector::from([2.0, 4.0]);
         let view_mut1 = v1.view_mut::<2>(0).unwrap();
         assert_ne!(view_mut1, v2);
@@ -220,6 +220,21 @@ mod tests {
         view_mut[(0, 1)] = 10;
     }
 
+    #[test]
+    fn test_vector_view_mut_view() {
+        let mut vec = Vector::from([1, 2, 3, 4, 5, 6]);
+        let mut view = vec.view_mut::<4>(1).unwrap();
+        assert_eq!(view.view::<2>(2).unwrap(), Vector::from([4, 5]));
+        assert!(view.view::<2>(3).is_none());
+
+        let mut inner = view.view_mut::<3>(1).unwrap();
+        inner[0] = 0;
+        let mut innermost = inner.view_mut::<1>(2).unwrap();
+        innermost[0] = 0;
+        assert!(view.view_mut::<5>(0).is_none());
+        assert_eq!(vec, Vector::from([1, 2, 0, 4, 0, 6]));
+    }
+
     #[test]
     fn test_vector_view_mut_send() {
         fn assert_send<T: Send>() {}